@@ -594,12 +594,13 @@ Summary:"""
                 "error": f"Transcription failed: {str(e)}"
             }
     
-    def copilot_analyze(self, audio_path: str, context: str) -> Dict[str, Any]:
+    def copilot_analyze(self, audio_path: str, context: str, focus: Optional[str] = None) -> Dict[str, Any]:
         """Analyze audio chunk with running context for Co-Pilot.
         
         Args:
             audio_path: Path to audio file (.wav format)
             context: Running context (previous cycle's summary, empty for first cycle)
+            focus: Optional freeform hint the analysis should prioritize
             
         Returns:
             Dict with type, command, and structured analysis fields
@@ -696,6 +697,13 @@ Respond in JSON format with these exact fields:
 Respond in JSON format with these exact fields:
 {"new_content": "...", "updated_summary": "...", "key_points": [...], "decisions": [...], "action_items": [...], "open_questions": [...], "suggested_questions": [{"question": "...", "reason": "..."}], "key_concepts": [{"term": "...", "context": "..."}]}"""
             
+            # Steer key points and suggested questions toward the user's focus
+            if focus:
+                prompt_text = f"""Focus for this conversation: {focus}
+Prioritize key points and suggested questions related to this focus.
+
+{prompt_text}"""
+            
             # Build messages with audio
             messages = [
                 {"role": "user", "content": prompt_text, "audio": audio}
@@ -873,9 +881,10 @@ Respond in JSON format with these exact fields:
         elif command == "copilot-analyze":
             audio_path = command_data.get("audio_path")
             context = command_data.get("context", "")
+            focus = command_data.get("focus")
             if not audio_path:
                 return {"type": "error", "command": command, "error": "Missing audio_path"}
-            return self.copilot_analyze(audio_path, context)
+            return self.copilot_analyze(audio_path, context, focus)
         
         elif command == "download-model":
             repo_id = command_data.get("repo_id")
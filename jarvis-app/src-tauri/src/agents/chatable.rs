@@ -8,6 +8,17 @@ use async_trait::async_trait;
 use std::path::PathBuf;
 use crate::intelligence::queue::IntelQueue;
 
+/// Context for answering one message.
+#[derive(Debug, Clone, Default)]
+pub struct ChatContext {
+    /// The text the chatbot answers from
+    pub text: String,
+    /// IDs of the gems the text was assembled from, in the order they appear.
+    /// Each gem's ID must appear in `text` so the chatbot can tell which ones
+    /// survived truncation.
+    pub sources: Vec<String>,
+}
+
 /// A content source that can be chatted with.
 /// 
 /// Any type implementing this trait becomes chatbot-compatible. The chatbot
@@ -29,6 +40,34 @@ pub trait Chatable: Send + Sync {
     /// The context text, or an error if context cannot be obtained
     async fn get_context(&self, intel_queue: &IntelQueue) -> Result<String, String>;
 
+    /// Get the context for answering a specific question.
+    ///
+    /// This is what the chatbot calls for each message. Sources whose context
+    /// depends on the question (e.g. retrieval over the gem library) override
+    /// it and list the gems they drew on as `sources`; the default ignores the
+    /// question and returns `get_context()` with no sources.
+    ///
+    /// # Arguments
+    ///
+    /// * `question` - The user's message
+    /// * `max_tokens` - The chatbot's context budget; text over it is cut from
+    ///   the start, so sources that summarize or rank should fit it themselves
+    /// * `intel_queue` - Queue for submitting generation requests if needed
+    async fn get_context_for(&self, _question: &str, _max_tokens: usize, intel_queue: &IntelQueue) -> Result<ChatContext, String> {
+        Ok(ChatContext {
+            text: self.get_context(intel_queue).await?,
+            sources: Vec::new(),
+        })
+    }
+
+    /// Extra instructions for the system message, placed before the context.
+    ///
+    /// Default: none. Kept out of the context so truncating the context to the
+    /// budget never drops them.
+    fn instructions(&self) -> Option<&str> {
+        None
+    }
+
     /// Human-readable label for session log headers.
     /// 
     /// # Examples
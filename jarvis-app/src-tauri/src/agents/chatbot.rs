@@ -3,18 +3,32 @@
 // This module implements a trait-driven chatbot that works with any Chatable source.
 // It manages sessions, builds LLM prompts, submits requests through IntelQueue,
 // and maintains persistent markdown logs.
+//
+// Session logs double as the saved history: each message is written as a
+// `## User (HH:MM:SS)` / `## Assistant (HH:MM:SS)` heading followed by its
+// text, and `parse_session_log` reads them back so `resume_session` can
+// continue a conversation after a restart. Content lines that look like a
+// message heading are escaped with a leading `\` so they round-trip.
+// An assistant message's cited gems follow its text as a
+// `<!-- sources: <id>, <id> -->` line.
 
 use std::collections::HashMap;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use serde::{Deserialize, Serialize};
 use tokio::fs::OpenOptions;
 use tokio::io::AsyncWriteExt;
 
 use super::chatable::Chatable;
+use crate::intelligence::provider::{answer_system_message, answer_system_message_with};
 use crate::intelligence::queue::{IntelCommand, IntelQueue, IntelResponse};
+use crate::intelligence::utils::tail_within_tokens;
+
+/// Default token budget for context in the system prompt (~14k characters)
+const DEFAULT_CONTEXT_TOKENS: usize = 3_500;
 
-/// Maximum characters of context to include in system prompt
-const MAX_CONTEXT_CHARS: usize = 14_000;
+/// Fraction of the model's context window given to source context; the rest
+/// is left for the instructions, history and the response
+const CONTEXT_WINDOW_DIVISOR: usize = 4;
 
 /// Maximum number of exchanges (user + assistant pairs) to include in history
 const MAX_HISTORY_EXCHANGES: usize = 10;
@@ -22,6 +36,8 @@ const MAX_HISTORY_EXCHANGES: usize = 10;
 /// Chatbot engine managing multiple concurrent chat sessions
 pub struct Chatbot {
     sessions: HashMap<String, ChatSession>,
+    /// Token budget for source context in the system prompt
+    context_tokens: usize,
 }
 
 /// A single chat session with a content source
@@ -38,6 +54,10 @@ pub struct ChatMessage {
     pub role: String,       // "user" | "assistant"
     pub content: String,
     pub timestamp: String,  // "HH:MM:SS"
+    /// Gem IDs the answer's context was drawn from (assistant messages from
+    /// sources that retrieve gems, e.g. library chat)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sources: Option<Vec<String>>,
 }
 
 impl Chatbot {
@@ -45,9 +65,15 @@ impl Chatbot {
     pub fn new() -> Self {
         Self {
             sessions: HashMap::new(),
+            context_tokens: DEFAULT_CONTEXT_TOKENS,
         }
     }
 
+    /// Size the context budget from the active model's context window (tokens)
+    pub fn set_model_context_length(&mut self, context_length: usize) {
+        self.context_tokens = (context_length / CONTEXT_WINDOW_DIVISOR).max(DEFAULT_CONTEXT_TOKENS);
+    }
+
     /// Start a new chat session against any Chatable source.
     ///
     /// Creates the session and log file immediately. Does NOT trigger context
@@ -66,12 +92,10 @@ impl Chatbot {
         source: &dyn Chatable,
     ) -> Result<String, String> {
         // Generate session ID (no context generation here — caller handles preparation)
-        let timestamp = chrono::Utc::now().timestamp();
-        let session_id = format!("chat_{}", timestamp);
+        let session_id = new_session_id(chrono::Utc::now().timestamp());
 
         // Create log file path
-        let log_filename = format!("chat_session_{}.md", timestamp);
-        let log_path = source.session_dir().join(log_filename);
+        let log_path = source.session_dir().join(log_filename(&session_id));
 
         // Ensure session directory exists
         if let Some(parent) = log_path.parent() {
@@ -130,16 +154,20 @@ impl Chatbot {
             .ok_or_else(|| "Session not found".to_string())?;
 
         // Get fresh context from source
-        let context = source.get_context(intel_queue).await?;
-
-        // Build system message with truncated context
-        let truncated_context = truncate_context(&context, MAX_CONTEXT_CHARS);
-        let system_msg = format!(
-            "You are a helpful assistant. Answer questions based on the following context. \
-             Be concise and accurate. If the answer isn't in the context, say so.\n\n\
-             --- CONTEXT ---\n{}",
-            truncated_context
-        );
+        let context = source.get_context_for(user_message, self.context_tokens, intel_queue).await?;
+
+        // Build system message with the most recent context that fits the budget
+        let truncated_context = tail_within_tokens(&context.text, self.context_tokens);
+
+        // Cite only the gems still in the context after truncation
+        let sources: Vec<String> = context.sources
+            .into_iter()
+            .filter(|id| truncated_context.contains(id.as_str()))
+            .collect();
+        let system_msg = match source.instructions() {
+            Some(instructions) => answer_system_message_with(instructions, truncated_context),
+            None => answer_system_message(truncated_context),
+        };
 
         // Assemble messages: system + history (last 10 exchanges) + user message
         let mut llm_messages: Vec<(String, String)> = vec![
@@ -172,11 +200,13 @@ impl Chatbot {
             role: "user".into(),
             content: user_message.to_string(),
             timestamp: now.clone(),
+            sources: None,
         };
         let assistant_msg = ChatMessage {
             role: "assistant".into(),
             content: assistant_text.clone(),
             timestamp: chrono::Local::now().format("%H:%M:%S").to_string(),
+            sources: (!sources.is_empty()).then_some(sources),
         };
 
         session.messages.push(user_msg.clone());
@@ -184,11 +214,9 @@ impl Chatbot {
 
         // Append to session log
         let log_entry = format!(
-            "## User ({})\n{}\n\n## Assistant ({})\n{}\n\n---\n\n",
-            user_msg.timestamp,
-            user_msg.content,
-            assistant_msg.timestamp,
-            assistant_msg.content,
+            "{}{}---\n\n",
+            format_log_message(&user_msg),
+            format_log_message(&assistant_msg),
         );
 
         let mut file = OpenOptions::new()
@@ -218,6 +246,50 @@ impl Chatbot {
         Ok(session.messages.clone())
     }
 
+    /// Reload a session from its log file so the conversation can continue.
+    ///
+    /// New messages are appended to the same log. If the session is already
+    /// in memory, its current history is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `session_id` - The session ID (`chat_<timestamp>_<suffix>`)
+    /// * `log_path` - The session's `chat_session_<timestamp>_<suffix>.md` log
+    ///
+    /// # Returns
+    ///
+    /// The restored message history
+    pub async fn resume_session(
+        &mut self,
+        session_id: &str,
+        log_path: &Path,
+    ) -> Result<Vec<ChatMessage>, String> {
+        if let Some(session) = self.sessions.get(session_id) {
+            return Ok(session.messages.clone());
+        }
+
+        let log = tokio::fs::read_to_string(log_path).await
+            .map_err(|e| format!("Failed to read session log: {}", e))?;
+        let messages = parse_session_log(&log);
+        let created_at = session_started_at(&log)
+            .or_else(|| session_timestamp(session_id).and_then(|ts| chrono::DateTime::from_timestamp(ts, 0)))
+            .unwrap_or_else(chrono::Utc::now);
+
+        self.sessions.insert(session_id.to_string(), ChatSession {
+            session_id: session_id.to_string(),
+            messages: messages.clone(),
+            log_path: log_path.to_path_buf(),
+            created_at: created_at.to_rfc3339(),
+        });
+
+        Ok(messages)
+    }
+
+    /// Whether a session is currently loaded in memory
+    pub fn has_session(&self, session_id: &str) -> bool {
+        self.sessions.contains_key(session_id)
+    }
+
     /// Remove session from memory.
     /// 
     /// The session log file remains on disk.
@@ -230,24 +302,210 @@ impl Chatbot {
     }
 }
 
-/// Truncate context to last N characters if it exceeds the limit.
-/// 
-/// Takes the tail (most recent content) rather than the head, as recent
-/// content is typically most relevant for answering questions.
-/// 
-/// # Arguments
-/// 
-/// * `text` - The context text
-/// * `max_chars` - Maximum characters to keep
-/// 
-/// # Returns
-/// 
-/// A string slice containing at most `max_chars` characters from the end
-fn truncate_context(text: &str, max_chars: usize) -> &str {
-    if text.len() <= max_chars {
-        text
+/// Session IDs are `chat_<unix timestamp>_<random suffix>` (older sessions
+/// have no suffix)
+const SESSION_ID_PREFIX: &str = "chat_";
+
+/// Session logs are named `chat_session_<unix timestamp>_<random suffix>.md`
+const LOG_FILE_PREFIX: &str = "chat_session_";
+
+/// New session ID for a session started at `timestamp`; the random suffix
+/// keeps sessions started in the same second apart
+fn new_session_id(timestamp: i64) -> String {
+    let suffix = uuid::Uuid::new_v4().simple().to_string();
+    format!("{}{}_{}", SESSION_ID_PREFIX, timestamp, &suffix[..8])
+}
+
+/// Log filename for a session ID
+pub fn log_filename(session_id: &str) -> String {
+    let key = session_id.strip_prefix(SESSION_ID_PREFIX).unwrap_or(session_id);
+    format!("{}{}.md", LOG_FILE_PREFIX, key)
+}
+
+/// Session ID for a log filename, or None if it isn't a session log
+pub fn session_id_from_log_filename(filename: &str) -> Option<String> {
+    let key = filename.strip_prefix(LOG_FILE_PREFIX)?.strip_suffix(".md")?;
+    let (timestamp, suffix) = match key.split_once('_') {
+        Some((timestamp, suffix)) => (timestamp, Some(suffix)),
+        None => (key, None),
+    };
+    let valid_timestamp = !timestamp.is_empty() && timestamp.chars().all(|c| c.is_ascii_digit());
+    let valid_suffix = suffix.is_none_or(|s| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphanumeric()));
+    (valid_timestamp && valid_suffix).then(|| format!("{}{}", SESSION_ID_PREFIX, key))
+}
+
+/// Unix timestamp a session started at, from its ID
+pub fn session_timestamp(session_id: &str) -> Option<i64> {
+    let key = session_id.strip_prefix(SESSION_ID_PREFIX)?;
+    key.split('_').next()?.parse().ok()
+}
+
+/// Log heading for a message role ("User" / "Assistant")
+fn role_heading(role: &str) -> &'static str {
+    if role == "user" { "User" } else { "Assistant" }
+}
+
+/// Whether `line` would be read back as a message heading
+fn is_message_heading(line: &str) -> bool {
+    parse_message_heading(line).is_some()
+}
+
+/// Gem IDs of a `<!-- sources: <id>, <id> -->` line
+fn parse_sources_line(line: &str) -> Option<Vec<String>> {
+    let ids = line.strip_prefix("<!-- sources: ")?.strip_suffix(" -->")?;
+    Some(ids.split(", ").map(str::to_string).collect())
+}
+
+/// Whether a content line has to be escaped to round-trip
+fn needs_escape(line: &str) -> bool {
+    is_message_heading(line) || parse_sources_line(line).is_some()
+}
+
+/// Role and timestamp of a `## User (HH:MM:SS)` / `## Assistant (HH:MM:SS)` line
+fn parse_message_heading(line: &str) -> Option<(&'static str, String)> {
+    let rest = line.strip_prefix("## ")?;
+    let (role, rest) = if let Some(rest) = rest.strip_prefix("User (") {
+        ("user", rest)
     } else {
-        // Take the tail — most recent content is most relevant
-        &text[text.len() - max_chars..]
+        ("assistant", rest.strip_prefix("Assistant (")?)
+    };
+    let timestamp = rest.strip_suffix(')')?;
+    (timestamp.len() == 8 && timestamp.chars().all(|c| c.is_ascii_digit() || c == ':'))
+        .then(|| (role, timestamp.to_string()))
+}
+
+/// One message as written to the session log
+fn format_log_message(message: &ChatMessage) -> String {
+    let content = message.content
+        .lines()
+        .map(|line| {
+            let unescaped = line.trim_start_matches('\\');
+            if needs_escape(unescaped) { format!("\\{}", line) } else { line.to_string() }
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    let sources = match &message.sources {
+        Some(ids) if !ids.is_empty() => format!("\n<!-- sources: {} -->", ids.join(", ")),
+        _ => String::new(),
+    };
+    format!("## {} ({})\n{}{}\n\n", role_heading(&message.role), message.timestamp, content, sources)
+}
+
+/// When a session started, from its log's `**Started:**` header (local time)
+fn session_started_at(log: &str) -> Option<chrono::DateTime<chrono::Utc>> {
+    let started = log.lines().find_map(|line| line.strip_prefix("**Started:** "))?;
+    let naive = chrono::NaiveDateTime::parse_from_str(started.trim(), "%Y-%m-%d %H:%M:%S").ok()?;
+    naive
+        .and_local_timezone(chrono::Local)
+        .earliest()
+        .map(|local| local.with_timezone(&chrono::Utc))
+}
+
+/// Messages in a session log written by `Chatbot`, in order
+///
+/// The header and the `---` separators between exchanges are skipped.
+pub fn parse_session_log(log: &str) -> Vec<ChatMessage> {
+    fn finish(current: Option<(&'static str, String, Vec<&str>)>, messages: &mut Vec<ChatMessage>) {
+        if let Some((role, timestamp, mut lines)) = current {
+            // Drop the blank lines and exchange separator that follow the text
+            while lines.last().is_some_and(|l| l.trim().is_empty() || *l == "---") {
+                lines.pop();
+            }
+            let sources = lines.last().and_then(|l| parse_sources_line(l));
+            if sources.is_some() {
+                lines.pop();
+            }
+            messages.push(ChatMessage {
+                role: role.to_string(),
+                content: lines.join("\n"),
+                timestamp,
+                sources,
+            });
+        }
+    }
+
+    let mut messages = Vec::new();
+    let mut current: Option<(&'static str, String, Vec<&str>)> = None;
+
+    for line in log.lines() {
+        if let Some((role, timestamp)) = parse_message_heading(line) {
+            finish(current.take(), &mut messages);
+            current = Some((role, timestamp, Vec::new()));
+        } else if let Some((_, _, lines)) = current.as_mut() {
+            lines.push(line);
+        }
+    }
+    finish(current, &mut messages);
+
+    // Undo the escaping applied by `format_log_message`
+    for message in &mut messages {
+        if message.content.lines().any(|l| l.starts_with('\\')) {
+            message.content = message.content
+                .lines()
+                .map(|line| match line.strip_prefix('\\') {
+                    Some(rest) if needs_escape(rest.trim_start_matches('\\')) => rest,
+                    _ => line,
+                })
+                .collect::<Vec<_>>()
+                .join("\n");
+        }
+    }
+
+    messages
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(role: &str, content: &str, timestamp: &str) -> ChatMessage {
+        ChatMessage { role: role.into(), content: content.into(), timestamp: timestamp.into(), sources: None }
+    }
+
+    #[test]
+    fn test_session_log_round_trip() {
+        let messages = vec![
+            message("user", "What was decided?", "10:00:00"),
+            message("assistant", "## Decisions\n\n---\n\n## User (10:00:05)\n- Ship Friday", "10:00:04"),
+            message("user", "Thanks", "10:01:00"),
+            ChatMessage {
+                sources: Some(vec!["gem-1".into(), "gem-2".into()]),
+                ..message("assistant", "See [1] and [2].\n<!-- sources: not-a-gem -->", "10:01:02")
+            },
+        ];
+
+        let mut log = "# Chat Session\n\n**Label:** Recording x\n**Started:** 2026-01-01 10:00:00\n\n---\n\n".to_string();
+        for exchange in messages.chunks(2) {
+            log.push_str(&format!("{}{}---\n\n", format_log_message(&exchange[0]), format_log_message(&exchange[1])));
+        }
+
+        let started = session_started_at(&log).unwrap().with_timezone(&chrono::Local);
+        assert_eq!(started.format("%Y-%m-%d %H:%M:%S").to_string(), "2026-01-01 10:00:00");
+        assert_eq!(session_started_at("# Chat Session\n\n---\n\n"), None);
+
+        let parsed = parse_session_log(&log);
+        assert_eq!(parsed.len(), messages.len());
+        for (parsed, original) in parsed.iter().zip(&messages) {
+            assert_eq!(parsed.role, original.role);
+            assert_eq!(parsed.content, original.content);
+            assert_eq!(parsed.timestamp, original.timestamp);
+            assert_eq!(parsed.sources, original.sources);
+        }
+    }
+
+    #[test]
+    fn test_session_id_log_filename_mapping() {
+        assert_eq!(log_filename("chat_1700000000"), "chat_session_1700000000.md");
+        assert_eq!(session_id_from_log_filename("chat_session_1700000000.md").as_deref(), Some("chat_1700000000"));
+        assert_eq!(session_id_from_log_filename("transcript.md"), None);
+        assert_eq!(session_id_from_log_filename("chat_session_.md"), None);
+
+        let session_id = new_session_id(1700000000);
+        assert!(session_id.starts_with("chat_1700000000_"));
+        assert_ne!(session_id, new_session_id(1700000000));
+        assert_eq!(session_id_from_log_filename(&log_filename(&session_id)), Some(session_id.clone()));
+        assert_eq!(session_timestamp(&session_id), Some(1700000000));
+        assert_eq!(session_timestamp("chat_1700000000"), Some(1700000000));
+        assert_eq!(session_id_from_log_filename("chat_session_1700000000_.md"), None);
     }
 }
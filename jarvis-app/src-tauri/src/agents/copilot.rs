@@ -14,7 +14,7 @@ use serde::{Deserialize, Serialize};
 
 use crate::files::{SAMPLE_RATE, BYTES_PER_SAMPLE, CHANNELS};
 use crate::wav::WavConverter;
-use crate::intelligence::provider::IntelProvider;
+use crate::intelligence::provider::{copilot_prompt, IntelProvider};
 
 /// Co-Pilot agent state containing all analysis results
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +36,11 @@ pub struct CoPilotState {
     
     /// Suggested questions to ask next (max 5)
     pub suggested_questions: Vec<SuggestedQuestion>,
+
+    /// Normalized text of every question the user dismissed this session,
+    /// so they are not suggested again in later cycles
+    #[serde(skip)]
+    dismissed_questions: Vec<String>,
     
     /// Key concepts (technical terms, names, topics)
     pub key_concepts: Vec<KeyConcept>,
@@ -104,6 +109,7 @@ impl Default for CoPilotState {
             action_items: Vec::new(),
             open_questions: Vec::new(),
             suggested_questions: Vec::new(),
+            dismissed_questions: Vec::new(),
             key_concepts: Vec::new(),
             cycle_metadata: CycleMetadata {
                 cycle_number: 0,
@@ -116,6 +122,10 @@ impl Default for CoPilotState {
     }
 }
 
+/// Token budget for the running summary passed as context each cycle,
+/// leaving most of the Omni model's window for the audio chunk
+const MAX_SUMMARY_CONTEXT_TOKENS: usize = 4_000;
+
 /// Result of a single cycle execution (for logging purposes)
 struct CycleExecutionResult {
     audio_duration: u64,
@@ -331,49 +341,31 @@ async fn run_single_cycle(
         settings.audio_overlap,
     ).await?;
     
-    // Get running context from state
+    // Get running context from state (most recent part if the summary grew too long)
     let context = {
         let state_guard = state.lock().await;
         if state_guard.cycle_metadata.cycle_number == 0 {
             String::new() // First cycle - empty context
         } else {
-            state_guard.running_summary.clone()
+            crate::intelligence::utils::tail_within_tokens(
+                &state_guard.running_summary,
+                MAX_SUMMARY_CONTEXT_TOKENS,
+            ).to_string()
         }
     };
     
+    // Blank focus means general analysis
+    let focus = settings.focus.as_deref()
+        .map(str::trim)
+        .filter(|f| !f.is_empty());
+
     // Reconstruct prompt text for logging (mirrors Python sidecar logic)
-    let prompt_text = if context.is_empty() {
-        "This is the start of a conversation. Analyze the audio and provide:\n\
-         1. What was discussed\n\
-         2. Summary of the conversation\n\
-         3. Key points mentioned\n\
-         4. Any decisions made\n\
-         5. Action items identified\n\
-         6. Open questions raised\n\
-         7. Suggested questions to ask next (with reasons)\n\
-         8. Key concepts (technical terms, names, topics) with brief context\n\n\
-         Respond in JSON format.".to_string()
-    } else {
-        format!(
-            "Previous conversation summary:\n{}\n\n\
-             Analyze the new audio segment and provide:\n\
-             1. What new content was discussed\n\
-             2. Updated summary of the entire conversation so far\n\
-             3. Key points mentioned\n\
-             4. Any decisions made\n\
-             5. Action items identified\n\
-             6. Open questions raised\n\
-             7. Suggested questions to ask next (with reasons)\n\
-             8. Key concepts (technical terms, names, topics) with brief context\n\n\
-             Respond in JSON format.",
-            context
-        )
-    };
+    let prompt_text = copilot_prompt(&context, focus);
 
     // Call provider with timeout
     let analysis_result = tokio::time::timeout(
         std::time::Duration::from_secs(120),
-        provider.copilot_analyze(&temp_path, &context),
+        provider.copilot_analyze(&temp_path, &context, focus),
     ).await;
     
     // Clean up temp file
@@ -401,6 +393,64 @@ async fn run_single_cycle(
     })
 }
 
+/// Normalize a question for duplicate detection
+///
+/// Lowercases, drops punctuation and collapses whitespace, so
+/// "What's the timeline?" and "whats the  timeline" compare equal.
+fn normalize_question(question: &str) -> String {
+    question
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Replace the suggested questions with this cycle's suggestions
+///
+/// - Questions matching a dismissed question (normalized) are dropped
+/// - Near-identical questions within the cycle are collapsed to the first
+/// - A question already suggested in an earlier cycle keeps its `cycle_added`
+/// - At most 5 questions are kept
+fn merge_suggested_questions(
+    state: &mut CoPilotState,
+    questions: Vec<crate::intelligence::provider::CoPilotQuestion>,
+    cycle_number: u32,
+) {
+    let mut merged: Vec<SuggestedQuestion> = Vec::new();
+    let mut seen: Vec<String> = Vec::new();
+
+    for new_q in questions {
+        let normalized = normalize_question(&new_q.question);
+        if normalized.is_empty()
+            || seen.contains(&normalized)
+            || state.dismissed_questions.contains(&normalized)
+        {
+            continue;
+        }
+
+        let cycle_added = state.suggested_questions.iter()
+            .find(|old_q| normalize_question(&old_q.question) == normalized)
+            .map(|old_q| old_q.cycle_added)
+            .unwrap_or(cycle_number);
+
+        seen.push(normalized);
+        merged.push(SuggestedQuestion {
+            question: new_q.question,
+            reason: new_q.reason,
+            cycle_added,
+            dismissed: false,
+        });
+        if merged.len() == 5 {
+            break;
+        }
+    }
+
+    state.suggested_questions = merged;
+}
+
 /// Internal helper to update state (used by run_single_cycle)
 async fn update_state_internal(
     state: &Arc<TokioMutex<CoPilotState>>,
@@ -437,22 +487,9 @@ async fn update_state_internal(
         }
     }
     
-    // Replace suggested questions (keep max 5, preserve dismissed state)
+    // Replace suggested questions (keep max 5, drop duplicates and dismissed)
     let next_cycle_number = state_guard.cycle_metadata.cycle_number + 1;
-    let mut new_questions = Vec::new();
-    for new_q in result.suggested_questions {
-        // Check if this question was previously dismissed
-        let was_dismissed = state_guard.suggested_questions.iter()
-            .any(|old_q| old_q.question == new_q.question && old_q.dismissed);
-        
-        new_questions.push(SuggestedQuestion {
-            question: new_q.question,
-            reason: new_q.reason,
-            cycle_added: next_cycle_number,
-            dismissed: was_dismissed,
-        });
-    }
-    state_guard.suggested_questions = new_questions.into_iter().take(5).collect();
+    merge_suggested_questions(&mut state_guard, result.suggested_questions, next_cycle_number);
     
     // Merge key concepts (increment mention_count for existing)
     for new_concept in result.key_concepts {
@@ -1072,8 +1109,8 @@ impl CoPilotAgent {
     /// Dismiss a suggested question by index
     /// 
     /// Marks the question at the specified index as dismissed. Dismissed questions
-    /// will not be shown in the UI but will be preserved if the same question is
-    /// suggested again in a future cycle.
+    /// will not be shown in the UI, and the same (or a near-identical) question
+    /// is not suggested again for the rest of the session.
     /// 
     /// # Arguments
     /// 
@@ -1092,7 +1129,53 @@ impl CoPilotAgent {
         let mut state = self.state.lock().await;
         if let Some(question) = state.suggested_questions.get_mut(index) {
             question.dismissed = true;
+            let normalized = normalize_question(&question.question);
+            if !state.dismissed_questions.contains(&normalized) {
+                state.dismissed_questions.push(normalized);
+            }
         }
     }
 
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intelligence::provider::CoPilotQuestion;
+
+    fn question(text: &str) -> CoPilotQuestion {
+        CoPilotQuestion {
+            question: text.to_string(),
+            reason: "test".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_merge_suggested_questions_dedupes_across_cycles() {
+        let mut state = CoPilotState::default();
+
+        // Cycle 1: near-identical questions collapse to one
+        merge_suggested_questions(
+            &mut state,
+            vec![question("What's the timeline?"), question("whats the  timeline"), question("Who owns the launch?")],
+            1,
+        );
+        assert_eq!(state.suggested_questions.len(), 2);
+        assert_eq!(state.suggested_questions[0].question, "What's the timeline?");
+
+        // User dismisses the ownership question
+        state.suggested_questions[1].dismissed = true;
+        state.dismissed_questions.push(normalize_question("Who owns the launch?"));
+
+        // Cycle 2: overlapping suggestions keep their original cycle, dismissed stays gone
+        merge_suggested_questions(
+            &mut state,
+            vec![question("What is the budget?"), question("What's the Timeline"), question("who owns the launch")],
+            2,
+        );
+        let texts: Vec<&str> = state.suggested_questions.iter().map(|q| q.question.as_str()).collect();
+        assert_eq!(texts, vec!["What is the budget?", "What's the Timeline"]);
+        assert_eq!(state.suggested_questions[0].cycle_added, 2);
+        assert_eq!(state.suggested_questions[1].cycle_added, 1);
+    }
+}
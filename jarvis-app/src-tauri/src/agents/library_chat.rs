@@ -0,0 +1,292 @@
+// LibraryChatSource — the Whole Gem Library Conforms to Chatable
+//
+// This module lets the chatbot answer questions across every saved gem. On each
+// message it searches the library for the question with the active search
+// provider (FTS or semantic), loads the top matches and passes them as numbered
+// sources, asking the model to cite them. Sources are fitted to the chatbot's
+// context budget from the head — shorter excerpts first, then fewer
+// sources — so its tail-trim never cuts the best matches.
+
+use async_trait::async_trait;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::chatable::{ChatContext, Chatable};
+use crate::gems::{Gem, GemStore};
+use crate::intelligence::queue::IntelQueue;
+use crate::intelligence::utils::{estimate_tokens, head_within_tokens};
+use crate::search::SearchResultProvider;
+
+/// Number of matching gems included as context per message
+const TOP_K: usize = 5;
+
+/// Characters of each gem's content included when all sources fit
+const GEM_CONTENT_CHARS: usize = 2_400;
+
+/// Excerpt lengths tried, longest first, before dropping a source
+const EXCERPT_CHARS: &[usize] = &[GEM_CONTENT_CHARS, GEM_CONTENT_CHARS / 2, GEM_CONTENT_CHARS / 4, 0];
+
+/// Common words left out of the search query (they match nearly every gem)
+const STOPWORDS: &[&str] = &[
+    "a", "about", "all", "am", "an", "and", "any", "are", "as", "at", "be", "been", "but", "by",
+    "can", "could", "did", "do", "does", "for", "from", "had", "has", "have", "how", "i", "if",
+    "in", "into", "is", "it", "its", "me", "my", "of", "on", "or", "our", "should", "so", "tell",
+    "than", "that", "the", "their", "them", "then", "there", "these", "they",
+    "this", "to", "was", "we", "were", "what", "when", "where", "which", "who", "why", "will",
+    "with", "would", "you", "your",
+];
+
+/// Instructions for the system message (outside the context, so never trimmed)
+const CITATION_INSTRUCTIONS: &str = "The context is a set of numbered sources from the user's saved gems. \
+     Cite the sources you use inline as [n] and end with a \"Sources:\" line listing each cited \
+     source's number and title. If no source answers the question, say so.";
+
+/// The user's gem library, searched per question
+pub struct LibraryChatSource {
+    gem_store: Arc<dyn GemStore>,
+    search_provider: Arc<dyn SearchResultProvider>,
+}
+
+impl LibraryChatSource {
+    pub fn new(gem_store: Arc<dyn GemStore>, search_provider: Arc<dyn SearchResultProvider>) -> Self {
+        Self {
+            gem_store,
+            search_provider,
+        }
+    }
+
+    /// Top matching gems for `question`, best first (missing gems are skipped)
+    async fn matching_gems(&self, question: &str) -> Result<Vec<(Gem, String)>, String> {
+        let terms = question_keywords(question);
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+        let query = if self.search_provider.takes_fts_query() {
+            fts_query(&terms)
+        } else {
+            terms.join(" ")
+        };
+        let results = self.search_provider.search(&query, TOP_K, None).await?;
+
+        let mut gems = Vec::new();
+        for result in results {
+            if let Ok(Some(gem)) = self.gem_store.get(&result.gem_id).await {
+                gems.push((gem, result.matched_chunk));
+            }
+        }
+        Ok(gems)
+    }
+}
+
+/// Where library chat session logs are written
+pub fn library_session_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("com.jarvis.app")
+        .join("library")
+        .join("chat_sessions")
+}
+
+/// Keywords of a natural-language question, lowercased and deduplicated,
+/// without stopwords, punctuation or single letters
+fn question_keywords(question: &str) -> Vec<String> {
+    let mut terms: Vec<String> = Vec::new();
+    for word in question.split(|c: char| !c.is_alphanumeric()) {
+        let word = word.to_lowercase();
+        let single_letter = word.chars().count() == 1 && word.chars().all(char::is_alphabetic);
+        if word.is_empty() || single_letter || STOPWORDS.contains(&word.as_str()) || terms.contains(&word) {
+            continue;
+        }
+        terms.push(word);
+    }
+    terms
+}
+
+/// FTS5 query for `terms`: each quoted and ORed, so punctuation can't break
+/// the syntax and a gem needn't contain every word of the question
+fn fts_query(terms: &[String]) -> String {
+    terms.iter()
+        .map(|term| format!("\"{}\"", term))
+        .collect::<Vec<_>>()
+        .join(" OR ")
+}
+
+/// One numbered source: title and id, then summary, matched passage and the
+/// first `excerpt_chars` characters of the content
+fn format_source(number: usize, gem: &Gem, matched_chunk: &str, excerpt_chars: usize) -> String {
+    let mut section = format!("[{}] {} (gem {})", number, gem.title, gem.id);
+
+    let summary = gem.ai_enrichment.as_ref()
+        .and_then(|e| e.get("summary"))
+        .and_then(|s| s.as_str());
+    if let Some(summary) = summary {
+        section.push_str(&format!("\nSummary: {}", summary));
+    }
+    if !matched_chunk.trim().is_empty() {
+        section.push_str(&format!("\nMatched: {}", matched_chunk.trim()));
+    }
+    let body = gem.content.as_deref()
+        .or(gem.transcript.as_deref())
+        .or(gem.description.as_deref())
+        .unwrap_or_default();
+    if excerpt_chars > 0 && !body.trim().is_empty() {
+        let excerpt: String = body.trim().chars().take(excerpt_chars).collect();
+        section.push_str(&format!("\n{}", excerpt));
+    }
+
+    section
+}
+
+/// Numbered sources for `gems` (best first) within `max_tokens`, and how many
+/// of them are included
+///
+/// Shortens every excerpt before dropping the lowest-ranked source. If even
+/// the best source alone is over budget, it is cut from the end.
+fn fit_sources(gems: &[(Gem, String)], max_tokens: usize) -> (String, usize) {
+    let format_all = |count: usize, excerpt_chars: usize| {
+        gems[..count].iter()
+            .enumerate()
+            .map(|(i, (gem, matched_chunk))| format_source(i + 1, gem, matched_chunk, excerpt_chars))
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    };
+
+    for count in (1..=gems.len()).rev() {
+        for &excerpt_chars in EXCERPT_CHARS {
+            let text = format_all(count, excerpt_chars);
+            if estimate_tokens(&text) <= max_tokens {
+                return (text, count);
+            }
+        }
+    }
+
+    let count = gems.len().min(1);
+    (head_within_tokens(&format_all(count, 0), max_tokens).to_string(), count)
+}
+
+#[async_trait]
+impl Chatable for LibraryChatSource {
+    /// Without a question there is nothing to retrieve
+    async fn get_context(&self, _intel_queue: &IntelQueue) -> Result<String, String> {
+        Ok(String::new())
+    }
+
+    async fn get_context_for(&self, question: &str, max_tokens: usize, _intel_queue: &IntelQueue) -> Result<ChatContext, String> {
+        let gems = self.matching_gems(question).await?;
+        if gems.is_empty() {
+            return Ok(ChatContext {
+                text: "No saved gems match this question.".to_string(),
+                sources: Vec::new(),
+            });
+        }
+
+        let (text, count) = fit_sources(&gems, max_tokens);
+        Ok(ChatContext {
+            text,
+            sources: gems.into_iter().take(count).map(|(gem, _)| gem.id).collect(),
+        })
+    }
+
+    fn instructions(&self) -> Option<&str> {
+        Some(CITATION_INSTRUCTIONS)
+    }
+
+    fn label(&self) -> String {
+        "Gem library".to_string()
+    }
+
+    fn session_dir(&self) -> PathBuf {
+        library_session_dir()
+    }
+
+    async fn needs_preparation(&self) -> bool {
+        // Sources are searched per message — nothing to generate up front
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gems::test_utils::test_gem;
+
+    #[test]
+    fn test_question_keywords() {
+        let terms = question_keywords("What's new in Tokio? (e.g. the 1.x scheduler - AND more)");
+        assert_eq!(terms, vec!["new", "tokio", "1", "scheduler", "more"]);
+        assert_eq!(fts_query(&terms), r#""new" OR "tokio" OR "1" OR "scheduler" OR "more""#);
+        assert!(question_keywords("what is it?").is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_matching_gems_for_question() {
+        use crate::gems::SqliteGemStore;
+        use crate::search::FtsResultProvider;
+
+        let gem_store: Arc<dyn GemStore> = Arc::new(SqliteGemStore::new_in_memory().unwrap());
+        for gem in [
+            Gem { title: "Tokio 1.40 release notes".to_string(), ..test_gem("tokio") },
+            Gem { title: "Sourdough starter".to_string(), ..test_gem("bread") },
+        ] {
+            gem_store.save(gem).await.unwrap();
+        }
+        let source = LibraryChatSource::new(gem_store.clone(), Arc::new(FtsResultProvider::new(gem_store)));
+
+        // Punctuation would be FTS5 syntax errors if passed through raw
+        let gems = source.matching_gems("what's new in tokio?").await.unwrap();
+        let ids: Vec<&str> = gems.iter().map(|(gem, _)| gem.id.as_str()).collect();
+        assert_eq!(ids, vec!["tokio"]);
+
+        assert!(source.matching_gems("what is it?").await.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_format_source() {
+        let gem = Gem {
+            source_url: "https://example.com/tokio".to_string(),
+            title: "Tokio internals".to_string(),
+            description: Some("Unused when there is content".to_string()),
+            content: Some(format!("  {}", "x".repeat(GEM_CONTENT_CHARS + 100))),
+            captured_at: "2024-01-01T00:00:00Z".to_string(),
+            ai_enrichment: Some(serde_json::json!({ "summary": "How the scheduler works" })),
+            ..test_gem("gem-1")
+        };
+
+        let section = format_source(2, &gem, " work stealing ", GEM_CONTENT_CHARS);
+        let mut lines = section.lines();
+        assert_eq!(lines.next(), Some("[2] Tokio internals (gem gem-1)"));
+        assert_eq!(lines.next(), Some("Summary: How the scheduler works"));
+        assert_eq!(lines.next(), Some("Matched: work stealing"));
+        assert_eq!(lines.next().map(|l| l.len()), Some(GEM_CONTENT_CHARS));
+        assert!(!section.contains("Unused"));
+    }
+
+    #[test]
+    fn test_fit_sources_keeps_best_matches_within_budget() {
+        let gems: Vec<(Gem, String)> = (1..=5)
+            .map(|i| {
+                let gem = Gem {
+                    title: format!("Gem {}", i),
+                    content: Some("データ".repeat(GEM_CONTENT_CHARS)),
+                    ..test_gem(&format!("gem-{}", i))
+                };
+                (gem, "一致".repeat(400))
+            })
+            .collect();
+
+        // Each matched passage alone is ~800 tokens, so even without excerpts
+        // the lowest-ranked source has to go
+        let (text, count) = fit_sources(&gems, 3_500);
+        assert!(estimate_tokens(&text) <= 3_500);
+        assert!(text.starts_with("[1] Gem 1 (gem gem-1)"), "the best match leads");
+        for (i, (gem, _)) in gems.iter().enumerate() {
+            assert_eq!(text.contains(&gem.id), i < count);
+        }
+        assert_eq!(count, 4);
+
+        // A tiny budget keeps the start of the best source only
+        let (text, count) = fit_sources(&gems, 10);
+        assert_eq!(count, 1);
+        assert!(text.starts_with("[1] Gem 1 (gem gem-1)") && estimate_tokens(&text) <= 10);
+    }
+}
@@ -2,5 +2,6 @@ pub mod copilot;
 pub mod chatable;
 pub mod chatbot;
 pub mod recording_chat;
+pub mod library_chat;
 pub mod project_chat;
 pub mod project_agent;
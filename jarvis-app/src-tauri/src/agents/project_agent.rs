@@ -14,7 +14,8 @@ use chrono::Utc;
 use super::chatbot::{Chatbot, ChatMessage};
 use super::project_chat::ProjectChatSource;
 use crate::gems::{GemStore, Gem};
-use crate::intelligence::provider::IntelProvider;
+use crate::intelligence::provider::{IntelProvider, MAX_ANSWER_CONTEXT_TOKENS};
+use crate::intelligence::utils::{estimate_tokens, head_within_tokens};
 use crate::intelligence::queue::IntelQueue;
 use crate::knowledge::KnowledgeStore;
 use crate::projects::ProjectStore;
@@ -96,8 +97,6 @@ Rules:
 - Add a brief synthesis of cross-cutting themes at the end
 - Use markdown formatting"#;
 
-const SUMMARY_QA_PROMPT: &str = r#"You are answering questions about a project summary. Use the summary and source material provided to give specific, grounded answers. If the answer isn't in the provided material, say so."#;
-
 /// Persistent agent for project research, summarization, and chat.
 ///
 /// Registered in Tauri state as Arc<TokioMutex<ProjectResearchAgent>>.
@@ -224,7 +223,7 @@ impl ProjectResearchAgent {
         }
 
         // Gem search — find existing gems relevant to the project
-        let gem_results = self.search_provider.search(&project.title, 20).await?;
+        let gem_results = self.search_provider.search(&project.title, 20, None).await?;
         eprintln!("Projects/Research: {} raw gem search results", gem_results.len());
 
         // Enrich with full gem data (same pattern as search_gems command)
@@ -696,26 +695,20 @@ impl ProjectResearchAgent {
     ) -> Result<String, String> {
         eprintln!("Projects/Summary: Answering question ({} chars)", question.len());
 
-        // Truncate composite_doc if too long (preserve beginning with oldest/foundational gems)
-        let max_context = 10000;
-        let truncated_composite = if composite_doc.len() > max_context {
-            &composite_doc[..max_context]
+        // The summary the question is about stays whole; only the source
+        // material is cut to fit (keeping its beginning with the
+        // oldest/foundational gems), so `answer` never trims the context
+        let summary_section = format!("## Generated Summary\n\n{}\n\n## Source Material\n\n", summary);
+        let material_budget = MAX_ANSWER_CONTEXT_TOKENS.saturating_sub(estimate_tokens(&summary_section));
+        let material = if material_budget == 0 {
+            ""
         } else {
-            composite_doc
+            head_within_tokens(composite_doc, material_budget)
         };
-
-        // Build context string
-        let context = format!(
-            "## Generated Summary\n\n{}\n\n## Source Material\n\n{}",
-            summary, truncated_composite
-        );
+        let context = format!("{}{}", summary_section, material);
 
         // Call LLM
-        let user_message = format!("{}\n\nQuestion: {}", context, question);
-        let answer = self.intel_provider.chat(&[
-            ("system".to_string(), SUMMARY_QA_PROMPT.to_string()),
-            ("user".to_string(), user_message),
-        ]).await?;
+        let answer = self.intel_provider.answer(question, &context).await?;
 
         eprintln!("Projects/Summary: Answer generated ({} chars)", answer.len());
         Ok(answer)
@@ -767,6 +760,8 @@ impl ProjectResearchAgent {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         // Step 3: Save gem to database
@@ -3,14 +3,27 @@
 // This module makes recordings chatbot-compatible by implementing the Chatable trait.
 // It handles transcript loading from disk (fast path) or generation via IntelQueue
 // (slow path), and persists generated transcripts for reuse.
+//
+// Long transcripts are fitted to `ChatSettings::context_budget_chars` (or the
+// chatbot's context budget, if smaller): the most recent part is kept verbatim and everything before it is replaced by a
+// summary. The summary is cached in the recording folder
+// (`earlier_summary.md`) with the length and hash of the prefix it covers, so
+// it's only regenerated once the verbatim part outgrows the budget.
 
 use async_trait::async_trait;
-use std::path::PathBuf;
-use tauri::{AppHandle, Emitter};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Emitter, Manager};
 
-use super::chatable::Chatable;
+use super::chatable::{ChatContext, Chatable};
+use crate::browser::extractors::content_hash;
 use crate::intelligence::queue::{IntelCommand, IntelQueue, IntelResponse};
+use crate::intelligence::utils::{estimate_tokens, head_within_tokens, tail_within_tokens, ASCII_CHARS_PER_TOKEN};
+use crate::settings::{ChatSettings, SettingsManager};
 use crate::wav::WavConverter;
+use std::sync::{Arc, RwLock};
+
+/// Cached summary of the transcript before the verbatim window
+const EARLIER_SUMMARY_FILE: &str = "earlier_summary.md";
 
 /// A recording that can be chatted with
 pub struct RecordingChatSource {
@@ -57,15 +70,27 @@ impl RecordingChatSource {
     pub fn transcript_path(&self) -> PathBuf {
         self.recording_dir().join("transcript.md")
     }
-}
 
-#[async_trait]
-impl Chatable for RecordingChatSource {
-    async fn get_context(&self, intel_queue: &IntelQueue) -> Result<String, String> {
+    /// Configured context budget, converted to estimated tokens
+    fn context_budget_tokens(&self) -> usize {
+        let chars = self.app_handle
+            .try_state::<Arc<RwLock<SettingsManager>>>()
+            .and_then(|manager| manager.read().ok().map(|m| m.get().chat.context_budget_chars))
+            .unwrap_or_else(|| ChatSettings::default().context_budget_chars);
+        chars / ASCII_CHARS_PER_TOKEN
+    }
+
+    /// Where the summary of the earlier transcript is cached
+    fn earlier_summary_path(&self) -> PathBuf {
+        self.recording_dir().join(EARLIER_SUMMARY_FILE)
+    }
+
+    /// The transcript from disk, generating (and saving) it if missing
+    async fn load_transcript(&self, intel_queue: &IntelQueue) -> Result<String, String> {
         let transcript_path = self.transcript_path();
 
-        // Fast path: transcript exists on disk
-        if transcript_path.exists() {
+        // Fast path: transcript exists on disk and covers the whole recording
+        if crate::recording::has_current_transcript(&self.recording_dir()) {
             return tokio::fs::read_to_string(&transcript_path).await
                 .map_err(|e| format!("Failed to read transcript: {}", e));
         }
@@ -73,6 +98,9 @@ impl Chatable for RecordingChatSource {
         // Slow path: generate transcript
         self.on_preparation_status("preparing", "Generating transcript...");
 
+        // Blocks appending to this recording until the transcript is written
+        let _reading = crate::recording::RecordingReadGuard::acquire(&self.filename)?;
+
         // Convert PCM to WAV
         let pcm_path = self.recordings_dir.join(&self.filename);
         let wav_data = WavConverter::pcm_to_wav(&pcm_path)?;
@@ -107,15 +135,124 @@ impl Chatable for RecordingChatSource {
             transcript,
         );
 
-        // Ensure per-recording directory exists before writing
-        let _ = tokio::fs::create_dir_all(self.recording_dir()).await;
-
         // Write transcript file (ignore errors — we still have the transcript in memory)
-        let _ = tokio::fs::write(&transcript_path, &transcript_md).await;
+        let _ = crate::recording::write_transcript(&self.recording_dir(), &transcript_md).await;
 
         self.on_preparation_status("ready", "Ready to chat");
         Ok(transcript)
     }
+}
+
+/// Summary of a transcript prefix, as cached in `earlier_summary.md`
+#[derive(Debug, PartialEq)]
+struct EarlierSummary {
+    /// Length in bytes of the summarized prefix
+    covered_bytes: usize,
+
+    /// `content_hash` of the summarized prefix, to notice a re-transcription
+    covered_hash: String,
+
+    summary: String,
+}
+
+impl EarlierSummary {
+    /// Whether this summary still describes the start of `transcript`
+    fn covers(&self, transcript: &str) -> bool {
+        transcript.is_char_boundary(self.covered_bytes)
+            && content_hash(&transcript[..self.covered_bytes]) == self.covered_hash
+    }
+
+    /// First line `<!-- covers: <bytes> <hash> -->`, then the summary
+    fn to_file_text(&self) -> String {
+        format!("<!-- covers: {} {} -->\n{}", self.covered_bytes, self.covered_hash, self.summary)
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        let (header, summary) = text.split_once('\n')?;
+        let fields = header.strip_prefix("<!-- covers: ")?.strip_suffix(" -->")?;
+        let (bytes, hash) = fields.split_once(' ')?;
+        Some(Self {
+            covered_bytes: bytes.parse().ok()?,
+            covered_hash: hash.to_string(),
+            summary: summary.to_string(),
+        })
+    }
+}
+
+/// Fit `transcript` into `budget` estimated tokens, caching the summary of
+/// the earlier part at `summary_path`
+///
+/// Short transcripts are returned as is. Otherwise the cached summary is
+/// reused while the transcript after it still fits; when it doesn't, the
+/// earlier part is summarized again, leaving half the budget for the
+/// verbatim tail so the next few messages can reuse the new summary. The
+/// summary is cut to the other half if the model runs long. If
+/// summarization fails, only the tail is sent.
+async fn fit_to_budget(transcript: String, budget: usize, summary_path: &Path, intel_queue: &IntelQueue) -> String {
+    if estimate_tokens(&transcript) <= budget {
+        return transcript;
+    }
+
+    if let Some(cached) = tokio::fs::read_to_string(summary_path).await.ok()
+        .and_then(|text| EarlierSummary::parse(&text))
+        .filter(|cached| cached.covers(&transcript))
+    {
+        let context = compose_context(&cached.summary, &transcript[cached.covered_bytes..]);
+        if estimate_tokens(&context) <= budget {
+            return context;
+        }
+    }
+
+    let recent = tail_within_tokens(&transcript, budget / 2);
+    let earlier = &transcript[..transcript.len() - recent.len()];
+    match intel_queue.submit(IntelCommand::Summarize { content: earlier.to_string() }).await {
+        Ok(IntelResponse::Summary(summary)) => {
+            let cached = EarlierSummary {
+                covered_bytes: earlier.len(),
+                covered_hash: content_hash(earlier),
+                // The headings come out of the summary's half
+                summary: head_within_tokens(&summary, (budget / 2).saturating_sub(estimate_tokens(&compose_context("", ""))))
+                    .to_string(),
+            };
+            // Cache failures only cost a re-summarize next time
+            let _ = tokio::fs::write(summary_path, cached.to_file_text()).await;
+            compose_context(&cached.summary, recent)
+        }
+        result => {
+            if let Err(e) = result {
+                eprintln!("Chat: Failed to summarize earlier transcript: {}", e);
+            }
+            format!("[Earlier part of the transcript omitted]\n\n{}", recent)
+        }
+    }
+}
+
+/// Context text for a summarized transcript
+fn compose_context(summary: &str, recent: &str) -> String {
+    format!(
+        "Summary of the earlier part of the recording:\n{}\n\nMost recent part of the transcript:\n{}",
+        summary, recent
+    )
+}
+
+#[async_trait]
+impl Chatable for RecordingChatSource {
+    async fn get_context(&self, intel_queue: &IntelQueue) -> Result<String, String> {
+        let transcript = self.load_transcript(intel_queue).await?;
+        let budget = self.context_budget_tokens();
+        Ok(fit_to_budget(transcript, budget, &self.earlier_summary_path(), intel_queue).await)
+    }
+
+    /// Fits the transcript to the chatbot's budget too, when that is smaller,
+    /// so its tail-trim never cuts the summary
+    async fn get_context_for(&self, _question: &str, max_tokens: usize, intel_queue: &IntelQueue) -> Result<ChatContext, String> {
+        let transcript = self.load_transcript(intel_queue).await?;
+        let budget = self.context_budget_tokens().min(max_tokens);
+        Ok(ChatContext {
+            text: fit_to_budget(transcript, budget, &self.earlier_summary_path(), intel_queue).await,
+            sources: Vec::new(),
+        })
+    }
 
     fn label(&self) -> String {
         format!("Recording {}", self.stem())
@@ -161,3 +298,72 @@ fn get_recordings_dir(_app_handle: &AppHandle) -> Result<PathBuf, String> {
     
     Ok(recordings_dir)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_earlier_summary_cache() {
+        let transcript = "[00:00] Intro and agenda.\n[10:00] Budget review.\n[50:00] Wrap-up.";
+        let prefix = &transcript[..transcript.find("[50:00]").unwrap()];
+        let cached = EarlierSummary {
+            covered_bytes: prefix.len(),
+            covered_hash: content_hash(prefix),
+            summary: "Agenda, then the budget.\nNo decisions.".to_string(),
+        };
+
+        let parsed = EarlierSummary::parse(&cached.to_file_text()).unwrap();
+        assert_eq!(parsed, cached);
+        assert!(parsed.covers(transcript));
+        assert!(parsed.covers(&format!("{}\n[55:00] Late question.", transcript)), "growing transcripts keep the summary");
+        assert!(!parsed.covers(&transcript.replace("Budget", "Hiring")), "re-transcribed text invalidates it");
+        assert!(!parsed.covers("short"));
+
+        assert_eq!(EarlierSummary::parse("no header"), None);
+    }
+
+    /// Provider whose summaries are a fixed sentence
+    struct FixedSummaryProvider;
+
+    #[async_trait]
+    impl crate::intelligence::IntelProvider for FixedSummaryProvider {
+        async fn check_availability(&self) -> crate::intelligence::AvailabilityResult {
+            crate::intelligence::AvailabilityResult { available: true, reason: None }
+        }
+
+        async fn generate_tags(&self, _content: &str) -> crate::error::JarvisResult<Vec<String>> {
+            Ok(Vec::new())
+        }
+
+        async fn summarize(&self, _content: &str) -> crate::error::JarvisResult<String> {
+            Ok("The team reviewed the budget.".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fit_to_budget_keeps_recent_half_verbatim() {
+        let dir = tempfile::tempdir().unwrap();
+        let summary_path = dir.path().join(EARLIER_SUMMARY_FILE);
+        let intel_queue = IntelQueue::new(Arc::new(FixedSummaryProvider));
+
+        // Just over a 1,000-token budget
+        let transcript: String = (0..122).map(|i| format!("[{:02}:00] Speaker {} said a thing.\n", i % 60, i)).collect();
+        let budget = 1_000;
+        assert!(estimate_tokens(&transcript) > budget && estimate_tokens(&transcript) < budget + 100);
+
+        let context = fit_to_budget(transcript.clone(), budget, &summary_path, &intel_queue).await;
+        assert!(estimate_tokens(&context) <= budget);
+        assert!(context.contains("The team reviewed the budget."));
+        let recent = context.split_once("Most recent part of the transcript:\n").unwrap().1;
+        assert!(transcript.ends_with(recent));
+        let recent_tokens = estimate_tokens(recent);
+        assert!(recent_tokens > budget / 2 - 20 && recent_tokens <= budget / 2, "kept {} tokens", recent_tokens);
+
+        // The cached summary is reused while the transcript grows
+        let grown = format!("{}[59:30] One more remark.\n", transcript);
+        let context = fit_to_budget(grown, budget, &summary_path, &intel_queue).await;
+        assert!(context.ends_with("[59:30] One more remark.\n"));
+        assert!(EarlierSummary::parse(&std::fs::read_to_string(&summary_path).unwrap()).is_some());
+    }
+}
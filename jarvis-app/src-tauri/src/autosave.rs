@@ -0,0 +1,427 @@
+// Recording-to-gem autosave
+//
+// When `RecordingSettings::auto_save_gem` is on, every recording is transcribed
+// once it stops and saved as a gem via the same path as the UI's "Save as Gem"
+// (`transcribe_recording` then `save_recording_gem`). A transcript already on
+// disk is reused instead of transcribing again. Recordings that already have a
+// (non-draft) gem are skipped unless audio was appended to them, and nothing is
+// saved in demo mode. Emits "gem-auto-saved" with `{ gem_id, filename }`.
+//
+// When `RecordingSettings::incremental_transcript_save` is on, final segments
+// from the live transcription are flushed every 30s into a draft gem keyed by
+// recording filename (`source_meta.draft = true`). `save_recording_gem` later
+// turns the draft into the final gem. Flushes run one at a time and the draft
+// records how many segments it holds (`source_meta.draft_segments`), so a late
+// periodic flush can't overwrite the final one with fewer segments.
+
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Listener, Manager};
+
+use crate::gems::{Gem, GemStore};
+use crate::intelligence::IntelProvider;
+use crate::settings::SettingsManager;
+use crate::transcription::TranscriptionSegment;
+
+/// How often live transcript segments are written to the draft gem
+const INCREMENTAL_FLUSH_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Subscribe to recording events and autosave finished recordings
+pub fn setup_auto_save(app: &AppHandle) {
+    // Filename of the recording in progress and whether it was appended to
+    let current = Arc::new(Mutex::new(None::<(String, bool)>));
+
+    let started = current.clone();
+    app.listen("recording-started", move |event| {
+        if let Ok(mut current) = started.lock() {
+            *current = started_recording(event.payload());
+        }
+    });
+
+    let app_handle = app.clone();
+    app.listen("recording-stopped", move |_| {
+        let Some((filename, appended)) = current.lock().ok().and_then(|mut current| current.take()) else {
+            return;
+        };
+        if !auto_save_enabled(&app_handle) {
+            return;
+        }
+
+        let app_handle = app_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            match auto_save_recording(&app_handle, &filename, appended).await {
+                Ok(Some(gem_id)) => {
+                    eprintln!("Autosave: Saved recording {} as gem {}", filename, gem_id);
+                    let payload = serde_json::json!({ "gem_id": gem_id, "filename": filename });
+                    if let Err(e) = app_handle.emit("gem-auto-saved", payload) {
+                        eprintln!("Autosave: Failed to emit gem-auto-saved: {}", e);
+                    }
+                }
+                Ok(None) => {
+                    eprintln!("Autosave: Recording {} already has a gem, skipping", filename);
+                }
+                Err(e) => eprintln!("Autosave: Failed to save recording {}: {}", filename, e),
+            }
+        });
+    });
+}
+
+fn auto_save_enabled(app_handle: &AppHandle) -> bool {
+    if in_demo_mode(app_handle) {
+        return false;
+    }
+    app_handle
+        .try_state::<Arc<RwLock<SettingsManager>>>()
+        .and_then(|manager| manager.read().ok().map(|m| m.get().recording.auto_save_gem))
+        .unwrap_or(false)
+}
+
+/// Filename and `appended` flag from a "recording-started" payload
+fn started_recording(payload: &str) -> Option<(String, bool)> {
+    let payload = serde_json::from_str::<serde_json::Value>(payload).ok()?;
+    let filename = payload.get("filename")?.as_str()?.to_string();
+    let appended = payload.get("appended").and_then(|v| v.as_bool()).unwrap_or(false);
+    Some((filename, appended))
+}
+
+/// Nothing is saved while read-only demo mode is on
+fn in_demo_mode(app_handle: &AppHandle) -> bool {
+    app_handle
+        .try_state::<crate::commands::DemoMode>()
+        .is_some_and(|demo_mode| demo_mode.is_enabled())
+}
+
+/// True for gems created by incremental transcript saving and not yet finalized
+pub fn is_draft_gem(gem: &Gem) -> bool {
+    gem.source_meta.get("draft").and_then(|v| v.as_bool()).unwrap_or(false)
+}
+
+/// Existing gem for a recording, if any
+async fn recording_gem(gem_store: &dyn GemStore, filename: &str) -> Result<Option<Gem>, String> {
+    match gem_store.find_by_recording_filename(filename).await? {
+        Some(preview) => Ok(gem_store.get(&preview.id).await?),
+        None => Ok(None),
+    }
+}
+
+/// How autosave handles a stopped recording
+#[derive(Debug, PartialEq)]
+enum AutoSavePlan {
+    /// Already saved as a gem and no audio was added since
+    Skip,
+    /// Save the gem from the transcript already on disk
+    UseSavedTranscript,
+    /// Transcribe the recording, then save the gem
+    Transcribe,
+}
+
+/// Decide what to do with a recording given its existing gem, whether this
+/// session appended to it, and whether a current transcript is on disk
+fn plan_auto_save(existing: Option<&Gem>, appended: bool, has_transcript: bool) -> AutoSavePlan {
+    if existing.is_some_and(|gem| !is_draft_gem(gem)) && !appended {
+        AutoSavePlan::Skip
+    } else if has_transcript {
+        AutoSavePlan::UseSavedTranscript
+    } else {
+        AutoSavePlan::Transcribe
+    }
+}
+
+/// Transcribe (unless already transcribed) and save one recording; Ok(None)
+/// if it already has a gem
+async fn auto_save_recording(app_handle: &AppHandle, filename: &str, appended: bool) -> Result<Option<String>, String> {
+    let gem_store = app_handle.state::<Arc<dyn GemStore>>();
+    let file_manager = app_handle.state::<crate::files::FileManager>();
+    let recording_dir = file_manager.get_recordings_dir().join(filename.trim_end_matches(".pcm"));
+    let existing = recording_gem(&**gem_store, filename).await?;
+
+    let (transcript, language) = match plan_auto_save(
+        existing.as_ref(),
+        appended,
+        crate::recording::has_current_transcript(&recording_dir),
+    ) {
+        AutoSavePlan::Skip => return Ok(None),
+        AutoSavePlan::UseSavedTranscript => {
+            let transcript = tokio::fs::read_to_string(recording_dir.join("transcript.md")).await
+                .map_err(|e| format!("Failed to read transcript: {}", e))?;
+            let language = crate::intelligence::utils::detect_language(&transcript)
+                .unwrap_or_default()
+                .to_string();
+            (transcript, language)
+        }
+        AutoSavePlan::Transcribe => {
+            let intel_provider = app_handle.state::<Arc<dyn IntelProvider>>();
+            let timeout_secs = app_handle.state::<Arc<RwLock<SettingsManager>>>()
+                .read()
+                .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+                .get()
+                .intelligence
+                .transcript_timeout_secs;
+            let result = crate::commands::transcribe_recording_inner(filename, &**intel_provider, timeout_secs).await?;
+            (result.transcript, result.language)
+        }
+    };
+
+    let created_at = std::fs::metadata(file_manager.get_recordings_dir().join(filename))
+        .and_then(|metadata| metadata.created())
+        .ok()
+        .and_then(|created| created.duration_since(std::time::UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or_else(|| chrono::Utc::now().timestamp() as u64);
+
+    let gem = crate::commands::save_recording_gem(
+        app_handle.clone(),
+        filename.to_string(),
+        transcript,
+        language,
+        created_at,
+        None,
+        app_handle.state(),
+        app_handle.state(),
+        app_handle.state(),
+        app_handle.state(),
+    )
+    .await?;
+
+    Ok(Some(gem.id))
+}
+
+/// Live transcript of the recording in progress
+struct DraftRecording {
+    filename: String,
+    /// Unix seconds, used for the gem title
+    created_at: u64,
+    /// Text of each final segment received so far
+    segments: Vec<String>,
+    /// Number of segments already written to the draft gem
+    flushed: usize,
+}
+
+/// Subscribe to recording and transcription events and keep a draft gem
+/// up to date while recording
+pub fn setup_incremental_save(app: &AppHandle) {
+    let draft = Arc::new(Mutex::new(None::<DraftRecording>));
+    let flush_lock = Arc::new(tokio::sync::Mutex::new(()));
+
+    let started = draft.clone();
+    let app_handle = app.clone();
+    let loop_flush_lock = flush_lock.clone();
+    app.listen("recording-started", move |event| {
+        if !incremental_save_enabled(&app_handle) {
+            return;
+        }
+        let Some(filename) = serde_json::from_str::<serde_json::Value>(event.payload())
+            .ok()
+            .and_then(|payload| payload.get("filename")?.as_str().map(String::from))
+        else {
+            return;
+        };
+        if let Ok(mut draft) = started.lock() {
+            *draft = Some(DraftRecording {
+                filename: filename.clone(),
+                created_at: chrono::Utc::now().timestamp() as u64,
+                segments: Vec::new(),
+                flushed: 0,
+            });
+        }
+        spawn_flush_loop(app_handle.clone(), started.clone(), loop_flush_lock.clone(), filename);
+    });
+
+    let updates = draft.clone();
+    app.listen("transcription-update", move |event| {
+        let Ok(segment) = serde_json::from_str::<TranscriptionSegment>(event.payload()) else {
+            return;
+        };
+        if !segment.is_final || segment.text.trim().is_empty() {
+            return;
+        }
+        if let Ok(mut draft) = updates.lock() {
+            if let Some(draft) = draft.as_mut() {
+                draft.segments.push(segment.text.trim().to_string());
+            }
+        }
+    });
+
+    // Final flush once transcription has drained the remaining audio
+    let stopped = draft;
+    let app_handle = app.clone();
+    app.listen("transcription-stopped", move |_| {
+        let Some(draft) = stopped.lock().ok().and_then(|mut draft| draft.take()) else {
+            return;
+        };
+        if draft.segments.len() == draft.flushed {
+            return;
+        }
+        let app_handle = app_handle.clone();
+        let flush_lock = flush_lock.clone();
+        tauri::async_runtime::spawn(async move {
+            let gem_store = app_handle.state::<Arc<dyn GemStore>>();
+            if let Err(e) = flush_draft(&**gem_store, &flush_lock, &draft.filename, draft.created_at, &draft.segments).await {
+                eprintln!("Autosave: Final transcript flush failed for {}: {}", draft.filename, e);
+            }
+        });
+    });
+}
+
+fn incremental_save_enabled(app_handle: &AppHandle) -> bool {
+    if in_demo_mode(app_handle) {
+        return false;
+    }
+    app_handle
+        .try_state::<Arc<RwLock<SettingsManager>>>()
+        .and_then(|manager| manager.read().ok().map(|m| m.get().recording.incremental_transcript_save))
+        .unwrap_or(false)
+}
+
+/// Flush new segments every INCREMENTAL_FLUSH_INTERVAL until the draft for
+/// `filename` is finished (taken by transcription-stopped or replaced by a
+/// new recording)
+fn spawn_flush_loop(
+    app_handle: AppHandle,
+    draft: Arc<Mutex<Option<DraftRecording>>>,
+    flush_lock: Arc<tokio::sync::Mutex<()>>,
+    filename: String,
+) {
+    tauri::async_runtime::spawn(async move {
+        loop {
+            tokio::time::sleep(INCREMENTAL_FLUSH_INTERVAL).await;
+
+            let pending = {
+                let Ok(mut guard) = draft.lock() else { return };
+                match guard.as_mut() {
+                    Some(current) if current.filename == filename => {
+                        if current.segments.len() == current.flushed {
+                            None
+                        } else {
+                            current.flushed = current.segments.len();
+                            Some((current.created_at, current.segments.clone()))
+                        }
+                    }
+                    _ => return,
+                }
+            };
+
+            if let Some((created_at, segments)) = pending {
+                let gem_store = app_handle.state::<Arc<dyn GemStore>>();
+                if let Err(e) = flush_draft(&**gem_store, &flush_lock, &filename, created_at, &segments).await {
+                    eprintln!("Autosave: Incremental transcript flush failed for {}: {}", filename, e);
+                }
+            }
+        }
+    });
+}
+
+/// Upsert the draft gem for `filename` with the transcript so far
+///
+/// Holds `flush_lock` throughout so flushes don't interleave. Leaves a
+/// recording's gem alone once it has been saved for real, and a draft that
+/// already holds at least as many segments.
+async fn flush_draft(
+    gem_store: &dyn GemStore,
+    flush_lock: &tokio::sync::Mutex<()>,
+    filename: &str,
+    created_at: u64,
+    segments: &[String],
+) -> Result<(), String> {
+    let _flushing = flush_lock.lock().await;
+    let mut gem = match recording_gem(gem_store, filename).await? {
+        Some(gem) if !is_draft_gem(&gem) => return Ok(()),
+        Some(gem) if draft_segment_count(&gem) >= segments.len() => return Ok(()),
+        Some(gem) => gem,
+        None => {
+            let mut gem = crate::commands::new_recording_gem(filename, created_at);
+            gem.source_meta["draft"] = serde_json::Value::Bool(true);
+            gem
+        }
+    };
+
+    let transcript = segments.join(" ");
+    gem.source_meta["draft_segments"] = serde_json::json!(segments.len());
+    gem.transcript_language = crate::intelligence::utils::detect_language(&transcript).map(String::from);
+    gem.transcript = Some(transcript);
+    gem_store.save(gem).await?;
+
+    eprintln!("Autosave: Saved {} transcript segments of {} to draft gem", segments.len(), filename);
+    Ok(())
+}
+
+/// Number of segments a draft gem's transcript was flushed from
+fn draft_segment_count(gem: &Gem) -> usize {
+    gem.source_meta.get("draft_segments").and_then(|v| v.as_u64()).unwrap_or(0) as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gems::test_utils::test_gem;
+
+    #[test]
+    fn test_started_recording_payload() {
+        assert_eq!(
+            started_recording(r#"{"filename":"a.pcm","appended":true}"#),
+            Some(("a.pcm".to_string(), true))
+        );
+        assert_eq!(started_recording(r#"{"filename":"a.pcm"}"#), Some(("a.pcm".to_string(), false)));
+        assert_eq!(started_recording(r#"{"appended":true}"#), None);
+    }
+
+    #[test]
+    fn test_plan_auto_save() {
+        let saved = test_gem("saved");
+        let mut draft = test_gem("draft");
+        draft.source_meta["draft"] = serde_json::Value::Bool(true);
+
+        // New recordings are transcribed unless a transcript is already on disk
+        assert_eq!(plan_auto_save(None, false, false), AutoSavePlan::Transcribe);
+        assert_eq!(plan_auto_save(None, false, true), AutoSavePlan::UseSavedTranscript);
+
+        // A saved gem is left alone unless audio was appended to it
+        assert_eq!(plan_auto_save(Some(&saved), false, true), AutoSavePlan::Skip);
+        assert_eq!(plan_auto_save(Some(&saved), true, false), AutoSavePlan::Transcribe);
+
+        // Drafts from incremental saving are always finalized
+        assert_eq!(plan_auto_save(Some(&draft), false, false), AutoSavePlan::Transcribe);
+    }
+
+    fn segments(texts: &[&str]) -> Vec<String> {
+        texts.iter().map(|t| t.to_string()).collect()
+    }
+
+    #[tokio::test]
+    async fn test_flush_draft_ignores_stale_flushes() {
+        let gem_store = crate::gems::SqliteGemStore::new_in_memory().unwrap();
+        let flush_lock = tokio::sync::Mutex::new(());
+
+        flush_draft(&gem_store, &flush_lock, "a.pcm", 0, &segments(&["one", "two"])).await.unwrap();
+        let draft = recording_gem(&gem_store, "a.pcm").await.unwrap().unwrap();
+        assert!(is_draft_gem(&draft));
+        assert_eq!(draft.transcript.as_deref(), Some("one two"));
+
+        // A periodic flush that lands after the final one doesn't overwrite it
+        let (last, stale) = tokio::join!(
+            flush_draft(&gem_store, &flush_lock, "a.pcm", 0, &segments(&["one", "two", "three", "four"])),
+            flush_draft(&gem_store, &flush_lock, "a.pcm", 0, &segments(&["one", "two", "three"])),
+        );
+        last.unwrap();
+        stale.unwrap();
+        let draft = recording_gem(&gem_store, "a.pcm").await.unwrap().unwrap();
+        assert_eq!(draft.transcript.as_deref(), Some("one two three four"));
+        assert_eq!(draft_segment_count(&draft), 4);
+    }
+
+    #[tokio::test]
+    async fn test_flush_draft_leaves_saved_gem_alone() {
+        let gem_store = crate::gems::SqliteGemStore::new_in_memory().unwrap();
+        let flush_lock = tokio::sync::Mutex::new(());
+        let saved = Gem {
+            transcript: Some("final transcript".to_string()),
+            ..crate::commands::new_recording_gem("b.pcm", 0)
+        };
+        gem_store.save(saved).await.unwrap();
+
+        flush_draft(&gem_store, &flush_lock, "b.pcm", 0, &segments(&["partial"])).await.unwrap();
+        let gem = recording_gem(&gem_store, "b.pcm").await.unwrap().unwrap();
+        assert!(!is_draft_gem(&gem));
+        assert_eq!(gem.transcript.as_deref(), Some("final transcript"));
+    }
+}
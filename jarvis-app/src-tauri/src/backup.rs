@@ -0,0 +1,311 @@
+// Whole-app backup archives for `create_backup` / `restore_backup`
+//
+// A backup is a gzipped tar archive laid out as:
+//
+//   manifest.json   BackupManifest
+//   gems.db         database snapshot (gems, projects, search history)
+//   settings.json   Settings, without API keys
+//   knowledge/...   per-gem knowledge folders
+//   recordings/...  recording sidecar files (transcripts, folders); the
+//                   audio itself only when `include_recordings` is set
+//
+// The database snapshot is taken by the caller (`GemStore::backup_to`) so it
+// is consistent; this module only packs and unpacks files.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{JarvisError, JarvisResult};
+use crate::settings::Settings;
+
+/// Archive layout version written to the manifest; restore refuses newer ones
+pub const BACKUP_FORMAT_VERSION: u32 = 1;
+
+const MANIFEST_FILE: &str = "manifest.json";
+pub const DATABASE_FILE: &str = "gems.db";
+pub const SETTINGS_FILE: &str = "settings.json";
+pub const KNOWLEDGE_DIR: &str = "knowledge";
+pub const RECORDINGS_DIR: &str = "recordings";
+
+/// Describes what a backup archive contains
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format_version: u32,
+
+    /// RFC 3339 timestamp of when the backup was taken
+    pub created_at: String,
+
+    /// Version of the app that wrote the backup
+    pub app_version: String,
+
+    /// Whether recording audio files are included
+    pub includes_recordings: bool,
+}
+
+/// Everything that goes into a backup
+pub struct BackupSources<'a> {
+    /// Snapshot written by `GemStore::backup_to`
+    pub db_snapshot: &'a Path,
+    pub settings: &'a Settings,
+    pub knowledge_dir: &'a Path,
+    pub recordings_dir: &'a Path,
+}
+
+/// Write a backup archive to `out_path`
+///
+/// The archive is written next to `out_path` first and renamed into place,
+/// so a failed backup never leaves a truncated file behind.
+pub fn write_archive(
+    out_path: &Path,
+    sources: &BackupSources,
+    include_recordings: bool,
+) -> JarvisResult<BackupManifest> {
+    let manifest = BackupManifest {
+        format_version: BACKUP_FORMAT_VERSION,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        app_version: env!("CARGO_PKG_VERSION").to_string(),
+        includes_recordings: include_recordings,
+    };
+
+    let partial_path = PathBuf::from(format!("{}.partial", out_path.display()));
+    let result = write_entries(&partial_path, &manifest, sources, include_recordings)
+        .and_then(|()| std::fs::rename(&partial_path, out_path).map_err(JarvisError::from));
+    if result.is_err() {
+        let _ = std::fs::remove_file(&partial_path);
+    }
+    result.map(|()| manifest)
+}
+
+fn write_entries(
+    path: &Path,
+    manifest: &BackupManifest,
+    sources: &BackupSources,
+    include_recordings: bool,
+) -> JarvisResult<()> {
+    let file = File::create(path)
+        .map_err(|e| JarvisError::Io(format!("Failed to create backup '{}': {}", path.display(), e)))?;
+    let mut builder = tar::Builder::new(GzEncoder::new(file, Compression::default()));
+
+    let manifest_json = serde_json::to_vec_pretty(manifest)
+        .map_err(|e| JarvisError::Parse(format!("Failed to serialize backup manifest: {}", e)))?;
+    append_bytes(&mut builder, MANIFEST_FILE, &manifest_json)?;
+
+    let settings_json = serde_json::to_vec_pretty(&sources.settings.without_secrets())
+        .map_err(|e| JarvisError::Parse(format!("Failed to serialize settings: {}", e)))?;
+    append_bytes(&mut builder, SETTINGS_FILE, &settings_json)?;
+
+    builder.append_path_with_name(sources.db_snapshot, DATABASE_FILE)?;
+    append_dir(&mut builder, sources.knowledge_dir, Path::new(KNOWLEDGE_DIR), true)?;
+    append_dir(&mut builder, sources.recordings_dir, Path::new(RECORDINGS_DIR), include_recordings)?;
+
+    builder.into_inner()?.finish()?;
+    Ok(())
+}
+
+fn append_bytes<W: std::io::Write>(builder: &mut tar::Builder<W>, name: &str, bytes: &[u8]) -> JarvisResult<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(0o644);
+    header.set_mtime(chrono::Utc::now().timestamp().max(0) as u64);
+    header.set_cksum();
+    builder.append_data(&mut header, name, bytes)?;
+    Ok(())
+}
+
+/// Add the files under `dir` as `prefix/...`, skipping hidden entries,
+/// symlinks (they could point outside the data directory, or loop) and,
+/// unless `include_audio`, audio files. A missing `dir` adds nothing.
+fn append_dir<W: std::io::Write>(
+    builder: &mut tar::Builder<W>,
+    dir: &Path,
+    prefix: &Path,
+    include_audio: bool,
+) -> JarvisResult<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        // Unlike `path.is_dir()`, the entry's file type doesn't follow symlinks
+        let file_type = entry.file_type()?;
+        if name.to_string_lossy().starts_with('.') || file_type.is_symlink() {
+            continue;
+        }
+        if file_type.is_dir() {
+            append_dir(builder, &path, &prefix.join(name), include_audio)?;
+        } else if include_audio || !is_audio_file(&path) {
+            builder.append_path_with_name(&path, prefix.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+/// Recording audio (large, and optional in a backup)
+fn is_audio_file(path: &Path) -> bool {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| ["pcm", "wav", "m4a", "mp3"].iter().any(|a| e.eq_ignore_ascii_case(a)))
+}
+
+/// Unpack a backup archive into `staging_dir` and check it can be restored
+///
+/// Nothing outside `staging_dir` is touched; the caller restores from there.
+///
+/// # Errors
+///
+/// Returns an error if the archive can't be read, has no manifest or
+/// database, or was written by a newer backup format.
+pub fn unpack_archive(in_path: &Path, staging_dir: &Path) -> JarvisResult<BackupManifest> {
+    let file = File::open(in_path)
+        .map_err(|e| JarvisError::Io(format!("Failed to open backup '{}': {}", in_path.display(), e)))?;
+    // `unpack` refuses entries that would land outside `staging_dir`
+    tar::Archive::new(GzDecoder::new(file))
+        .unpack(staging_dir)
+        .map_err(|e| JarvisError::Validation(format!("Not a readable backup archive: {}", e)))?;
+
+    let manifest_text = std::fs::read_to_string(staging_dir.join(MANIFEST_FILE))
+        .map_err(|_| JarvisError::Validation("Backup has no manifest".to_string()))?;
+    let manifest: BackupManifest = serde_json::from_str(&manifest_text)
+        .map_err(|e| JarvisError::Parse(format!("Invalid backup manifest: {}", e)))?;
+    if manifest.format_version > BACKUP_FORMAT_VERSION {
+        return Err(JarvisError::Validation(format!(
+            "Backup format {} is newer than this app supports ({})",
+            manifest.format_version, BACKUP_FORMAT_VERSION
+        )));
+    }
+    if !staging_dir.join(DATABASE_FILE).is_file() {
+        return Err(JarvisError::Validation("Backup has no database".to_string()));
+    }
+    Ok(manifest)
+}
+
+/// Copy the tree at `src` into `dst`, overwriting files with the same path
+///
+/// With `replace`, `dst` is emptied first; otherwise files only in `dst`
+/// are kept. A missing `src` copies nothing.
+pub fn restore_dir(src: &Path, dst: &Path, replace: bool) -> JarvisResult<()> {
+    if replace && dst.exists() {
+        std::fs::remove_dir_all(dst)?;
+    }
+    std::fs::create_dir_all(dst)?;
+    if !src.is_dir() {
+        return Ok(());
+    }
+    for entry in std::fs::read_dir(src)? {
+        let path = entry?.path();
+        let Some(name) = path.file_name() else {
+            continue;
+        };
+        if path.is_dir() {
+            restore_dir(&path, &dst.join(name), false)?;
+        } else {
+            std::fs::copy(&path, dst.join(name))?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_archive_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+
+        let db = root.join("snapshot.db");
+        std::fs::write(&db, "db").unwrap();
+        let knowledge = root.join("knowledge");
+        std::fs::create_dir_all(knowledge.join("gem-1")).unwrap();
+        std::fs::write(knowledge.join("gem-1/gem.md"), "# Gem").unwrap();
+        let recordings = root.join("recordings");
+        std::fs::create_dir_all(recordings.join("meeting")).unwrap();
+        std::fs::write(recordings.join("meeting.pcm"), [0u8; 32]).unwrap();
+        std::fs::write(recordings.join("meeting/transcript.md"), "hello").unwrap();
+        std::fs::write(recordings.join(".DS_Store"), "").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(root, knowledge.join("loop")).unwrap();
+
+        let mut settings = Settings::default();
+        settings.search.tavily_api_key = Some("tvly-secret".to_string());
+        let sources = BackupSources {
+            db_snapshot: &db,
+            settings: &settings,
+            knowledge_dir: &knowledge,
+            recordings_dir: &recordings,
+        };
+        let archive = root.join("backup.tar.gz");
+        let manifest = write_archive(&archive, &sources, false).unwrap();
+        assert!(!manifest.includes_recordings);
+        assert!(!root.join("backup.tar.gz.partial").exists());
+
+        let staging = root.join("staging");
+        let unpacked = unpack_archive(&archive, &staging).unwrap();
+        assert_eq!(unpacked.format_version, BACKUP_FORMAT_VERSION);
+        assert_eq!(std::fs::read_to_string(staging.join(DATABASE_FILE)).unwrap(), "db");
+        let archived = std::fs::read_to_string(staging.join(SETTINGS_FILE)).unwrap();
+        assert!(!archived.contains("tvly-secret"), "API keys are left out of backups");
+        assert!(staging.join("knowledge/gem-1/gem.md").is_file());
+        assert!(staging.join("recordings/meeting/transcript.md").is_file());
+        assert!(!staging.join("recordings/meeting.pcm").exists(), "audio only with include_recordings");
+        assert!(!staging.join("recordings/.DS_Store").exists());
+        assert!(!staging.join("knowledge/loop").exists(), "symlinks are skipped");
+
+        // Restoring recordings merges; knowledge replaces
+        let live_recordings = root.join("live-recordings");
+        std::fs::create_dir_all(&live_recordings).unwrap();
+        std::fs::write(live_recordings.join("meeting.pcm"), [1u8; 8]).unwrap();
+        restore_dir(&staging.join(RECORDINGS_DIR), &live_recordings, false).unwrap();
+        assert!(live_recordings.join("meeting.pcm").exists());
+        assert!(live_recordings.join("meeting/transcript.md").exists());
+
+        std::fs::write(knowledge.join("stale.md"), "old").unwrap();
+        restore_dir(&staging.join(KNOWLEDGE_DIR), &knowledge, true).unwrap();
+        assert!(!knowledge.join("stale.md").exists());
+        assert!(knowledge.join("gem-1/gem.md").exists());
+    }
+
+    #[test]
+    fn test_unpack_rejects_non_backups() {
+        let dir = tempfile::tempdir().unwrap();
+        let bogus = dir.path().join("bogus.tar.gz");
+        std::fs::write(&bogus, "not an archive").unwrap();
+        assert!(unpack_archive(&bogus, &dir.path().join("staging")).is_err());
+    }
+
+    #[test]
+    fn test_keep_secrets_across_restore() {
+        use crate::settings::ApiEndpointConfig;
+
+        let endpoint = |name: &str, api_key: Option<&str>| ApiEndpointConfig {
+            name: name.to_string(),
+            base_url: "https://api.example.com/v1".to_string(),
+            api_key: api_key.map(String::from),
+            model: "model".to_string(),
+        };
+        let mut current = Settings::default();
+        current.intelligence.api_endpoints = vec![endpoint("work", Some("sk-work")), endpoint("gone", Some("sk-gone"))];
+        current.search.tavily_api_key = Some("tvly-key".to_string());
+
+        let mut backed_up = current.clone();
+        backed_up.intelligence.api_endpoints.push(endpoint("new", Some("sk-new")));
+        let mut restored = backed_up.without_secrets();
+        restored.intelligence.api_endpoints.retain(|e| e.name != "gone");
+        assert!(restored.intelligence.api_endpoints.iter().all(|e| e.api_key.is_none()));
+
+        restored.keep_secrets(&current);
+        let keys: Vec<Option<&str>> = restored.intelligence.api_endpoints.iter().map(|e| e.api_key.as_deref()).collect();
+        assert_eq!(keys, vec![Some("sk-work"), None]);
+        assert_eq!(restored.search.tavily_api_key.as_deref(), Some("tvly-key"));
+    }
+}
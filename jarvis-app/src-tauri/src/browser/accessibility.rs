@@ -5,6 +5,10 @@ use core_foundation::string::{CFString, CFStringRef};
 #[cfg(target_os = "macos")]
 use core_foundation::array::CFArrayRef;
 #[cfg(target_os = "macos")]
+use core_foundation::boolean::CFBoolean;
+#[cfg(target_os = "macos")]
+use core_foundation::dictionary::{CFDictionary, CFDictionaryRef};
+#[cfg(target_os = "macos")]
 use std::process::Command;
 #[cfg(target_os = "macos")]
 use std::thread;
@@ -72,12 +76,16 @@ pub const K_AX_VALUE_ATTRIBUTE: &str = "AXValue";
 pub const K_AX_CHILDREN_ATTRIBUTE: &str = "AXChildren";
 #[cfg(target_os = "macos")]
 pub const K_AX_PLACEHOLDER_VALUE_ATTRIBUTE: &str = "AXPlaceholderValue";
+/// Value of `kAXTrustedCheckOptionPrompt` — asks macOS to show the permission prompt
+#[cfg(target_os = "macos")]
+pub const K_AX_TRUSTED_CHECK_OPTION_PROMPT: &str = "AXTrustedCheckOptionPrompt";
 
 // FFI declarations
 #[cfg(target_os = "macos")]
 #[link(name = "ApplicationServices", kind = "framework")]
 extern "C" {
     pub fn AXIsProcessTrusted() -> bool;
+    pub fn AXIsProcessTrustedWithOptions(options: CFDictionaryRef) -> bool;
     pub fn AXUIElementCreateApplication(pid: i32) -> AXUIElementRef;
     pub fn AXUIElementCopyAttributeValue(
         element: AXUIElementRef,
@@ -127,23 +135,50 @@ impl AccessibilityReader {
         unsafe { AXIsProcessTrusted() }
     }
 
+    /// Check permission, showing the macOS "grant accessibility access" prompt if not trusted
+    pub fn request_permission() -> bool {
+        let key = CFString::new(K_AX_TRUSTED_CHECK_OPTION_PROMPT);
+        let options = CFDictionary::from_CFType_pairs(&[(
+            key.as_CFType(),
+            CFBoolean::true_value().as_CFType(),
+        )]);
+        unsafe { AXIsProcessTrustedWithOptions(options.as_concrete_TypeRef()) }
+    }
+
     pub fn find_chrome_pid() -> Result<i32, String> {
-        // Use osascript to query NSWorkspace for Chrome's process ID
+        Self::find_pid_by_bundle_id("com.google.Chrome")
+            .map_err(|_| "Chrome is not running".to_string())
+    }
+
+    /// Find the ChatGPT desktop app's process ID
+    pub fn find_chatgpt_app_pid() -> Result<i32, String> {
+        Self::find_pid_by_bundle_id("com.openai.chat")
+            .map_err(|_| "ChatGPT desktop app is not running".to_string())
+    }
+
+    /// Find the process ID of a running app by its bundle identifier
+    pub fn find_pid_by_bundle_id(bundle_id: &str) -> Result<i32, String> {
+        // Use osascript to query System Events for the app's process ID
         let output = Command::new("osascript")
             .arg("-e")
-            .arg(r#"tell application "System Events" to get unix id of processes whose bundle identifier is "com.google.Chrome""#)
+            .arg(format!(
+                r#"tell application "System Events" to get unix id of processes whose bundle identifier is "{}""#,
+                bundle_id
+            ))
             .output()
             .map_err(|e| format!("Failed to execute osascript: {}", e))?;
 
+        let not_running = || format!("{} is not running", bundle_id);
+
         if !output.status.success() {
-            return Err("Chrome is not running".to_string());
+            return Err(not_running());
         }
 
         let stdout = String::from_utf8_lossy(&output.stdout);
         let pid_str = stdout.trim();
         
         if pid_str.is_empty() {
-            return Err("Chrome is not running".to_string());
+            return Err(not_running());
         }
 
         // Parse the first PID (osascript returns comma-separated list if multiple instances)
@@ -151,7 +186,7 @@ impl AccessibilityReader {
         
         first_pid
             .parse::<i32>()
-            .map_err(|_| "Chrome is not running".to_string())
+            .map_err(|_| not_running())
     }
 
     pub fn find_web_areas(pid: i32) -> Result<Vec<WebArea>, String> {
@@ -171,6 +206,24 @@ impl AccessibilityReader {
         }
     }
 
+    /// Find web areas whose title contains any of the given substrings (case-insensitive)
+    ///
+    /// Lets callers target a specific chat tool (e.g. `&["claude", "anthropic"]`)
+    /// without hardcoding its title matching here.
+    pub fn find_web_areas_matching(pid: i32, title_patterns: &[&str]) -> Result<Vec<WebArea>, String> {
+        let web_areas = Self::find_web_areas(pid)?;
+        Ok(web_areas
+            .into_iter()
+            .filter(|wa| Self::title_matches(&wa.title, title_patterns))
+            .collect())
+    }
+
+    /// True if `title` contains any of `patterns`, ignoring case
+    pub fn title_matches(title: &str, patterns: &[&str]) -> bool {
+        let lower = title.to_lowercase();
+        patterns.iter().any(|p| lower.contains(&p.to_lowercase()))
+    }
+
     unsafe fn traverse_for_web_areas(
         element: AXUIElementRef,
         web_areas: &mut Vec<WebArea>,
@@ -226,6 +279,21 @@ impl AccessibilityReader {
         Ok(text_blocks)
     }
 
+    /// Extract text from an app's entire accessibility tree (native apps with no web area)
+    pub fn extract_app_text(pid: i32) -> Result<Vec<TextBlock>, String> {
+        let mut text_blocks = Vec::new();
+        unsafe {
+            let app_element = AXUIElementCreateApplication(pid);
+            if app_element.is_null() {
+                return Err(format!("Failed to access application with pid {}", pid));
+            }
+            let result = Self::traverse_for_text(app_element, &mut text_blocks, 0, None);
+            CFRelease(app_element);
+            result?;
+        }
+        Ok(text_blocks)
+    }
+
     unsafe fn traverse_for_text(
         element: AXUIElementRef,
         text_blocks: &mut Vec<TextBlock>,
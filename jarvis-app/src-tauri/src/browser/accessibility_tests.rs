@@ -10,4 +10,13 @@ mod tests {
         // The result should be either true or false, not panic
         assert!(result == true || result == false);
     }
+
+    #[test]
+    fn test_title_matches_is_case_insensitive_substring() {
+        let patterns = &["claude", "Side Panel"];
+        assert!(AccessibilityReader::title_matches("Claude - Anthropic", patterns));
+        assert!(AccessibilityReader::title_matches("Chrome side panel", patterns));
+        assert!(!AccessibilityReader::title_matches("ChatGPT", patterns));
+        assert!(!AccessibilityReader::title_matches("Claude", &[]));
+    }
 }
@@ -0,0 +1,133 @@
+// Bookmark export parsing for `import_bookmarks`
+//
+// Supports the Netscape bookmark HTML format (exported by Chrome, Firefox,
+// Safari and Edge) and Chrome's `Bookmarks` JSON file. Folders are
+// flattened; only http(s) URLs are kept, each once, in file order.
+
+use std::collections::HashSet;
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// A bookmarked page
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bookmark {
+    pub url: String,
+    pub title: String,
+}
+
+/// `<A HREF="...">title</A>` entries in a Netscape bookmark file
+static ANCHOR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)<a\s[^>]*?href\s*=\s*"([^"]*)"[^>]*>(.*?)</a>"#).unwrap()
+});
+
+/// Parse a bookmark export, detecting the format from its content
+///
+/// # Errors
+///
+/// Returns an error if the file looks like JSON but isn't valid Chrome
+/// bookmarks JSON.
+pub fn parse_bookmarks(text: &str) -> Result<Vec<Bookmark>, String> {
+    let bookmarks = if text.trim_start().starts_with('{') {
+        let json: serde_json::Value = serde_json::from_str(text)
+            .map_err(|e| format!("Invalid bookmarks JSON: {}", e))?;
+        let roots = json
+            .get("roots")
+            .ok_or_else(|| "Bookmarks JSON has no \"roots\" (expected Chrome's Bookmarks file)".to_string())?;
+        let mut bookmarks = Vec::new();
+        collect_json_bookmarks(roots, &mut bookmarks);
+        bookmarks
+    } else {
+        ANCHOR_REGEX
+            .captures_iter(text)
+            .map(|c| Bookmark {
+                url: decode_entities(c[1].trim()),
+                title: decode_entities(c[2].trim()),
+            })
+            .collect()
+    };
+
+    let mut seen = HashSet::new();
+    Ok(bookmarks
+        .into_iter()
+        .filter(|b| b.url.starts_with("http://") || b.url.starts_with("https://"))
+        .filter(|b| seen.insert(b.url.clone()))
+        .collect())
+}
+
+/// Walk Chrome's folder tree, collecting `"type": "url"` nodes
+fn collect_json_bookmarks(node: &serde_json::Value, out: &mut Vec<Bookmark>) {
+    match node {
+        serde_json::Value::Object(map) => {
+            if map.get("type").and_then(|t| t.as_str()) == Some("url") {
+                if let Some(url) = map.get("url").and_then(|u| u.as_str()) {
+                    out.push(Bookmark {
+                        url: url.to_string(),
+                        title: map.get("name").and_then(|n| n.as_str()).unwrap_or_default().to_string(),
+                    });
+                }
+                return;
+            }
+            map.values().for_each(|v| collect_json_bookmarks(v, out));
+        }
+        serde_json::Value::Array(items) => items.iter().for_each(|v| collect_json_bookmarks(v, out)),
+        _ => {}
+    }
+}
+
+/// Decode the HTML entities bookmark exporters emit
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_netscape_html() {
+        let html = r#"<!DOCTYPE NETSCAPE-Bookmark-file-1>
+<DL><p>
+    <DT><H3>Rust</H3>
+    <DL><p>
+        <DT><A HREF="https://doc.rust-lang.org/book/" ADD_DATE="1700000000">The Book &amp; more</A>
+        <DT><A HREF="javascript:void(0)">Bookmarklet</A>
+    </DL><p>
+    <DT><a href="https://example.com/?a=1&amp;b=2">Example</a>
+    <DT><A HREF="https://doc.rust-lang.org/book/">Duplicate</A>
+</DL><p>"#;
+
+        let bookmarks = parse_bookmarks(html).unwrap();
+        assert_eq!(
+            bookmarks,
+            vec![
+                Bookmark { url: "https://doc.rust-lang.org/book/".into(), title: "The Book & more".into() },
+                Bookmark { url: "https://example.com/?a=1&b=2".into(), title: "Example".into() },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_chrome_json() {
+        let json = r#"{"roots": {
+            "bookmark_bar": {"type": "folder", "name": "Bar", "children": [
+                {"type": "url", "name": "Tokio", "url": "https://tokio.rs/"},
+                {"type": "folder", "name": "Nested", "children": [
+                    {"type": "url", "name": "Serde", "url": "https://serde.rs/"}
+                ]}
+            ]},
+            "other": {"type": "folder", "children": [
+                {"type": "url", "name": "Local", "url": "chrome://settings"}
+            ]}
+        }}"#;
+
+        let urls: Vec<_> = parse_bookmarks(json).unwrap().into_iter().map(|b| b.url).collect();
+        assert_eq!(urls, vec!["https://tokio.rs/", "https://serde.rs/"]);
+
+        assert!(parse_bookmarks(r#"{"not": "bookmarks"}"#).is_err());
+    }
+}
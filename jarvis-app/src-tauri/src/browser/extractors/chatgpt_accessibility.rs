@@ -0,0 +1,291 @@
+// ChatGPT conversation extractor — uses macOS Accessibility API
+// Reads the conversation from the ChatGPT desktop app, or from a ChatGPT web area
+// in Chrome when DOM extraction fails (client-rendered content not yet in the page).
+// Messages are separated on ChatGPT's screen-reader labels ("You said:", "ChatGPT said:").
+
+#[cfg(target_os = "macos")]
+use crate::browser::accessibility::{AccessibilityReader, TextBlock};
+
+use super::PageGist;
+#[cfg(target_os = "macos")]
+use crate::browser::tabs::{extract_domain, SourceType};
+
+/// Where a ChatGPT conversation was found
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChatGptSurface {
+    /// Native ChatGPT desktop app (com.openai.chat)
+    DesktopApp,
+    /// ChatGPT web area inside Chrome (tab or side panel)
+    Chrome,
+}
+
+impl ChatGptSurface {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChatGptSurface::DesktopApp => "desktop_app",
+            ChatGptSurface::Chrome => "chrome",
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+struct ConversationData {
+    full_text: String,
+    message_count: u32,
+    first_prompt: String,
+    /// The first two messages in full, which identify the conversation as it grows
+    opening: String,
+}
+
+/// Title substrings that identify a ChatGPT web area
+pub const CHATGPT_WEB_AREA_TITLES: &[&str] = &["chatgpt", "openai"];
+
+/// Map a ChatGPT screen-reader label to the message author it introduces
+#[cfg(target_os = "macos")]
+fn author_marker(text: &str) -> Option<&'static str> {
+    let label = text.trim().trim_start_matches("## ").trim();
+    match label {
+        "You said:" => Some("You"),
+        "ChatGPT said:" => Some("ChatGPT"),
+        _ => None,
+    }
+}
+
+/// Reconstruct the conversation from text blocks, splitting on author labels.
+///
+/// Text before the first label (sidebar, history, page chrome) is ignored, and
+/// reading stops at the message composer.
+#[cfg(target_os = "macos")]
+fn reconstruct_conversation(text_blocks: &[TextBlock]) -> Result<ConversationData, String> {
+    let mut conversation_parts = Vec::new();
+    let mut current: Option<(&'static str, String)> = None;
+    let mut first_prompt = String::new();
+
+    let mut flush = |current: &mut Option<(&'static str, String)>, parts: &mut Vec<String>| {
+        if let Some((author, message)) = current.take() {
+            let message = message.trim();
+            if message.is_empty() {
+                return;
+            }
+            if author == "You" && first_prompt.is_empty() {
+                first_prompt = message.chars().take(200).collect();
+            }
+            parts.push(format!("--- {} ---\n{}", author, message));
+        }
+    };
+
+    for block in text_blocks {
+        // The composer placeholder marks the end of the conversation
+        if block.role == "AXTextField" && block.text.starts_with("[input:") {
+            break;
+        }
+
+        // Skip duplicate heading text (already captured by the parent AXHeading)
+        if block.role == "AXStaticText" && block.parent_role.as_deref() == Some("AXHeading") {
+            continue;
+        }
+
+        if let Some(author) = author_marker(&block.text) {
+            flush(&mut current, &mut conversation_parts);
+            current = Some((author, String::new()));
+            continue;
+        }
+
+        if let Some((_, message)) = current.as_mut() {
+            if !message.is_empty() {
+                message.push('\n');
+            }
+            message.push_str(&block.text);
+        }
+    }
+    flush(&mut current, &mut conversation_parts);
+
+    if conversation_parts.is_empty() {
+        return Err("No ChatGPT conversation found. Open a conversation in the ChatGPT app or in Chrome first.".to_string());
+    }
+
+    Ok(ConversationData {
+        full_text: conversation_parts.join("\n\n"),
+        message_count: conversation_parts.len() as u32,
+        first_prompt,
+        opening: conversation_parts.iter().take(2).cloned().collect::<Vec<_>>().join("\n\n"),
+    })
+}
+
+/// Strip ChatGPT's suffix from a page title ("Rust lifetimes - ChatGPT" -> "Rust lifetimes")
+#[cfg(target_os = "macos")]
+fn clean_title(title: &str) -> Option<String> {
+    let title = title.trim().trim_end_matches("- ChatGPT").trim();
+    if title.is_empty() || title.eq_ignore_ascii_case("chatgpt") {
+        None
+    } else {
+        Some(title.to_string())
+    }
+}
+
+/// Stable URL for a desktop-app conversation, derived from its opening
+/// messages, so re-capturing the same conversation updates the existing gem.
+///
+/// The opening includes the first reply, so conversations that start with
+/// the same (or an empty, e.g. image-only) prompt still get distinct URLs.
+#[cfg(target_os = "macos")]
+fn desktop_conversation_url(opening: &str) -> String {
+    use sha2::{Digest, Sha256};
+    let digest = Sha256::digest(opening.as_bytes());
+    let hex: String = digest.iter().take(8).map(|b| format!("{:02x}", b)).collect();
+    format!("jarvis://chatgpt-desktop/{}", hex)
+}
+
+/// Build PageGist from conversation data
+#[cfg(target_os = "macos")]
+fn build_page_gist(
+    page_url: String,
+    page_title: Option<String>,
+    conversation_data: ConversationData,
+    surface: ChatGptSurface,
+) -> PageGist {
+    let domain = extract_domain(&page_url);
+    let topic = page_title.unwrap_or_else(|| {
+        conversation_data.first_prompt.chars().take(60).collect()
+    });
+    let title = format!("ChatGPT: {}", topic);
+
+    let extra = serde_json::json!({
+        "message_count": conversation_data.message_count,
+        "extraction_method": "accessibility_api",
+        "surface": surface.as_str(),
+    });
+
+    PageGist {
+        url: page_url,
+        title,
+        source_type: SourceType::Chat,
+        domain,
+        author: Some("ChatGPT".to_string()),
+        description: Some(conversation_data.first_prompt),
+        content_excerpt: Some(conversation_data.full_text),
+        published_date: None,
+        image_url: None,
+        extra,
+    }
+}
+
+/// Detect where a ChatGPT conversation is currently visible.
+///
+/// Prefers the desktop app when it is running; otherwise looks for a ChatGPT
+/// web area in Chrome. Returns None if neither is found.
+#[cfg(target_os = "macos")]
+pub fn detect_surface() -> Option<ChatGptSurface> {
+    if AccessibilityReader::find_chatgpt_app_pid().is_ok() {
+        return Some(ChatGptSurface::DesktopApp);
+    }
+
+    let pid = AccessibilityReader::find_chrome_pid().ok()?;
+    let web_areas = AccessibilityReader::find_web_areas_matching(pid, CHATGPT_WEB_AREA_TITLES).ok()?;
+    (!web_areas.is_empty()).then_some(ChatGptSurface::Chrome)
+}
+
+/// Extract the ChatGPT conversation from the desktop app or Chrome
+#[cfg(target_os = "macos")]
+pub async fn extract() -> Result<PageGist, String> {
+    if !AccessibilityReader::check_permission() {
+        return Err("Accessibility permission not granted. Please enable accessibility access for this app in System Settings > Privacy & Security > Accessibility.".to_string());
+    }
+
+    // Desktop app first — it has no DOM to fall back on
+    if let Ok(pid) = AccessibilityReader::find_chatgpt_app_pid() {
+        let text_blocks = AccessibilityReader::extract_app_text(pid)?;
+        eprintln!("[ChatGptExtractor] Desktop app: extracted {} text blocks", text_blocks.len());
+
+        let conversation_data = reconstruct_conversation(&text_blocks).map_err(|e| {
+            eprintln!("[ChatGptExtractor] {}", e);
+            e
+        })?;
+        let page_url = desktop_conversation_url(&conversation_data.opening);
+        return Ok(build_page_gist(page_url, None, conversation_data, ChatGptSurface::DesktopApp));
+    }
+
+    let pid = AccessibilityReader::find_chrome_pid().map_err(|_| {
+        "Neither the ChatGPT desktop app nor Chrome is running".to_string()
+    })?;
+
+    // Extract all needed data BEFORE any await (web areas are not Send)
+    let (page_title, text_blocks) = {
+        let web_areas = AccessibilityReader::find_web_areas_matching(pid, CHATGPT_WEB_AREA_TITLES)?;
+        let web_area = web_areas.first().ok_or_else(|| {
+            "No ChatGPT conversation found. Open a ChatGPT conversation in Chrome first.".to_string()
+        })?;
+
+        let text_blocks = AccessibilityReader::extract_text_content(web_area.element)?;
+        eprintln!("[ChatGptExtractor] Chrome: extracted {} text blocks from {:?}", text_blocks.len(), web_area.title);
+        (clean_title(&web_area.title), text_blocks)
+    };
+
+    let conversation_data = reconstruct_conversation(&text_blocks).map_err(|e| {
+        eprintln!("[ChatGptExtractor] {}", e);
+        e
+    })?;
+
+    let page_url = crate::browser::adapters::chrome::get_active_tab_url_sync().map_err(|e| {
+        eprintln!("[ChatGptExtractor] Failed to get active tab URL: {}", e);
+        e
+    })?;
+
+    Ok(build_page_gist(page_url, page_title, conversation_data, ChatGptSurface::Chrome))
+}
+
+/// Non-macOS stub implementation
+#[cfg(not(target_os = "macos"))]
+pub async fn extract() -> Result<PageGist, String> {
+    Err("ChatGPT conversation capture is only available on macOS".to_string())
+}
+
+#[cfg(all(test, target_os = "macos"))]
+mod tests {
+    use super::*;
+
+    fn block(role: &str, text: &str) -> TextBlock {
+        TextBlock { text: text.to_string(), role: role.to_string(), depth: 0, parent_role: None }
+    }
+
+    fn conversation(prompt: &str, reply: &str, follow_up: &str) -> ConversationData {
+        let mut blocks = vec![block("AXStaticText", "Sidebar history")];
+        for (label, text) in [("You said:", prompt), ("ChatGPT said:", reply), ("You said:", follow_up)] {
+            blocks.push(block("AXHeading", label));
+            if !text.is_empty() {
+                blocks.push(block("AXStaticText", text));
+            }
+        }
+        blocks.push(block("AXTextField", "[input: Message ChatGPT]"));
+        reconstruct_conversation(&blocks).unwrap()
+    }
+
+    #[test]
+    fn test_reconstruct_conversation() {
+        let data = conversation("What is a lifetime?", "A lifetime is a region of code.", "Thanks");
+        assert_eq!(data.message_count, 3);
+        assert_eq!(data.first_prompt, "What is a lifetime?");
+        assert!(data.full_text.starts_with("--- You ---\nWhat is a lifetime?\n\n--- ChatGPT ---"));
+        assert!(!data.full_text.contains("Sidebar"));
+    }
+
+    #[test]
+    fn test_desktop_conversation_url_is_stable_and_distinct() {
+        let url = |data: ConversationData| desktop_conversation_url(&data.opening);
+
+        // The same conversation after a follow-up keeps its URL
+        assert_eq!(
+            url(conversation("What is a lifetime?", "A region of code.", "Thanks")),
+            url(conversation("What is a lifetime?", "A region of code.", "One more question")),
+        );
+        // Empty (image-only) or identical prompts no longer collide
+        assert_ne!(
+            url(conversation("", "That's a cat.", "Thanks")),
+            url(conversation("", "That's a bridge.", "Thanks")),
+        );
+        assert_ne!(
+            url(conversation("Hi", "Hello! How can I help?", "Thanks")),
+            url(conversation("Hi", "Hi there!", "Thanks")),
+        );
+    }
+}
@@ -16,13 +16,13 @@ struct ConversationData {
     first_prompt: String,
 }
 
+/// Title substrings that identify the Claude extension side panel web area
+pub const CLAUDE_WEB_AREA_TITLES: &[&str] = &["claude", "anthropic", "side panel"];
+
 /// Check if a web area title looks like the Claude extension side panel
 #[cfg(target_os = "macos")]
 fn is_claude_web_area(title: &str) -> bool {
-    let lower = title.to_lowercase();
-    lower.contains("claude")
-        || lower.contains("anthropic")
-        || lower.contains("side panel")
+    AccessibilityReader::title_matches(title, CLAUDE_WEB_AREA_TITLES)
 }
 
 /// Find the Claude web area from a list of web areas
@@ -0,0 +1,127 @@
+// User-configured extraction — runs a per-domain JS snippet in the tab
+// `BrowserSettings::domain_js_overrides` maps a domain to JavaScript that
+// `execute_js_in_tab` evaluates in place of the built-in extractor. The
+// snippet returns either the page text as a string, or
+// `JSON.stringify({title, content, author, description})` with any subset
+// of those fields. `prepare_gist` falls back to the built-in extractor when
+// the snippet fails or returns no content.
+
+use std::collections::HashMap;
+
+use super::PageGist;
+use crate::browser::adapters::chrome::ChromeAppleScriptAdapter;
+use crate::browser::adapters::BrowserAdapter;
+use crate::browser::tabs::SourceType;
+use serde::Deserialize;
+
+/// Fields a snippet may return as JSON
+#[derive(Debug, Default, Deserialize)]
+struct ScriptOutput {
+    title: Option<String>,
+    content: Option<String>,
+    author: Option<String>,
+    description: Option<String>,
+}
+
+/// Snippet for `domain`: the entry for the domain itself or the closest
+/// parent domain ("blog.example.com" uses "example.com" if it has none)
+pub fn find_override<'a>(overrides: &'a HashMap<String, String>, domain: &str) -> Option<&'a str> {
+    overrides
+        .iter()
+        .filter(|(key, _)| {
+            let key = key.trim().trim_start_matches("www.");
+            domain.eq_ignore_ascii_case(key)
+                || domain.to_lowercase().ends_with(&format!(".{}", key.to_lowercase()))
+        })
+        .max_by_key(|(key, _)| key.len())
+        .map(|(_, script)| script.as_str())
+}
+
+/// Interpret a snippet's result: a JSON object of fields, or plain text content
+fn parse_output(raw: &str) -> ScriptOutput {
+    let raw = raw.trim();
+    if raw.starts_with('{') {
+        if let Ok(output) = serde_json::from_str::<ScriptOutput>(raw) {
+            return output;
+        }
+    }
+    ScriptOutput {
+        content: Some(raw.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Extract a gist by running `script` in the tab showing `url`
+///
+/// # Errors
+///
+/// Returns an error if the tab can't be scripted or the snippet returns no
+/// content; the caller then uses the built-in extractor.
+pub async fn extract(
+    url: &str,
+    source_type: &SourceType,
+    domain: &str,
+    script: &str,
+) -> Result<PageGist, String> {
+    let adapter = ChromeAppleScriptAdapter;
+
+    let output = parse_output(&adapter.execute_js_in_tab(url, script).await?);
+    let content = output
+        .content
+        .filter(|c| !c.trim().is_empty())
+        .ok_or_else(|| format!("JS override for {} returned no content", domain))?;
+
+    let title = match output.title.filter(|t| !t.trim().is_empty()) {
+        Some(title) => title,
+        None => adapter
+            .execute_js_in_tab(url, "document.title")
+            .await
+            .map(|t| t.trim().to_string())
+            .ok()
+            .filter(|t| !t.is_empty())
+            .unwrap_or_else(|| "Unknown".to_string()),
+    };
+
+    Ok(PageGist {
+        url: url.to_string(),
+        title,
+        source_type: source_type.clone(),
+        domain: domain.to_string(),
+        author: output.author,
+        description: output.description,
+        content_excerpt: Some(content),
+        published_date: None,
+        image_url: None,
+        extra: serde_json::json!({ "js_override": true }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_override_prefers_closest_domain() {
+        let overrides: HashMap<String, String> = [
+            ("example.com".to_string(), "parent".to_string()),
+            ("blog.example.com".to_string(), "blog".to_string()),
+        ]
+        .into();
+
+        assert_eq!(find_override(&overrides, "example.com"), Some("parent"));
+        assert_eq!(find_override(&overrides, "docs.example.com"), Some("parent"));
+        assert_eq!(find_override(&overrides, "blog.example.com"), Some("blog"));
+        assert_eq!(find_override(&overrides, "notexample.com"), None);
+    }
+
+    #[test]
+    fn test_parse_output() {
+        let output = parse_output(r#"{"title": "Post", "content": "Body"}"#);
+        assert_eq!(output.title.as_deref(), Some("Post"));
+        assert_eq!(output.content.as_deref(), Some("Body"));
+
+        let plain = parse_output("  Just the text\n");
+        assert_eq!(plain.title, None);
+        assert_eq!(plain.content.as_deref(), Some("Just the text"));
+    }
+}
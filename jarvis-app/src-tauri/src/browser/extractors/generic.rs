@@ -4,7 +4,6 @@ use super::PageGist;
 use crate::browser::tabs::SourceType;
 use regex::Regex;
 use std::sync::LazyLock;
-use std::time::Duration;
 
 // Module-level regex patterns for content extraction
 pub(crate) static ARTICLE_REGEX: LazyLock<Regex> = LazyLock::new(|| {
@@ -17,15 +16,7 @@ pub(crate) static TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
 
 /// Extract a gist from any web page using OG metadata and basic content extraction
 pub async fn extract(url: &str, source_type: &SourceType, domain: &str) -> Result<PageGist, String> {
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(10))
-        .user_agent("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36")
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-
-    let html = client
-        .get(url)
-        .send()
+    let html = crate::browser::http::send(crate::browser::http::get("generic", url))
         .await
         .map_err(|e| format!("Failed to fetch page: {}", e))?
         .text()
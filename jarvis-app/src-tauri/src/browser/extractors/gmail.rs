@@ -9,19 +9,31 @@ use crate::browser::adapters::BrowserAdapter;
 use crate::browser::tabs::SourceType;
 use serde::Deserialize;
 
+/// A single message within a Gmail thread
+#[derive(Debug, Deserialize)]
+struct GmailMessage {
+    sender: Option<String>,
+    sender_email: Option<String>,
+    date: Option<String>,
+    body: Option<String>,
+}
+
 /// Data extracted from Gmail DOM via JavaScript
 #[derive(Deserialize)]
 struct GmailDomData {
     subject: Option<String>,
     sender: Option<String>,
     participants: Option<Vec<String>>,
-    email_count: Option<u32>,
-    thread_text: Option<String>,
+    messages: Option<Vec<GmailMessage>>,
     is_thread: Option<bool>,
 }
 
+/// Thread text longer than this many characters is truncated
+const MAX_THREAD_CHARS: usize = 50_000;
+
 /// JavaScript that extracts Gmail thread data in one call.
-/// Uses only stable selectors: document.title, [role="main"], [data-message-id], [email].
+/// Uses only stable selectors: document.title, [role="main"], [data-message-id], [email],
+/// span[title] (message date) and div[dir] (message body).
 const EXTRACT_JS: &str = r#"(function(){
   var d = {};
   var rawTitle = document.title || '';
@@ -35,20 +47,25 @@ const EXTRACT_JS: &str = r#"(function(){
     d.sender = null;
   }
   var main = document.querySelector('[role="main"]');
-  var threadParts = [];
-  var emailCount = 0;
+  var messages = [];
   if (main) {
-    var messages = main.querySelectorAll('[data-message-id]');
-    emailCount = messages.length;
-    for (var i = 0; i < messages.length; i++) {
-      var text = messages[i].innerText;
-      if (text && text.trim().length > 0) {
-        threadParts.push('--- Email ' + (i + 1) + ' ---\n' + text.trim());
+    var nodes = main.querySelectorAll('[data-message-id]');
+    for (var i = 0; i < nodes.length; i++) {
+      var node = nodes[i];
+      var m = {};
+      var from = node.querySelector('[email]');
+      if (from) {
+        m.sender_email = from.getAttribute('email');
+        m.sender = from.getAttribute('name') || (from.innerText || '').trim() || null;
       }
+      var dateEl = node.querySelector('span[title]');
+      m.date = dateEl ? dateEl.getAttribute('title') : null;
+      var bodyEl = node.querySelector('div[dir="ltr"], div[dir="auto"]');
+      m.body = ((bodyEl || node).innerText || '').trim();
+      messages.push(m);
     }
   }
-  d.email_count = emailCount;
-  d.thread_text = threadParts.join('\n\n');
+  d.messages = messages;
   var emailAttrs = [];
   if (main) {
     var spans = main.querySelectorAll('[email]');
@@ -60,13 +77,46 @@ const EXTRACT_JS: &str = r#"(function(){
     }
   }
   d.participants = emailAttrs;
-  d.is_thread = emailCount > 0;
-  if (d.thread_text && d.thread_text.length > 50000) {
-    d.thread_text = d.thread_text.substring(0, 50000) + '\n\n[thread truncated]';
-  }
+  d.is_thread = messages.length > 0;
   return JSON.stringify(d);
 })()"#;
 
+/// Format thread messages in order with a separator and From/Date header each.
+/// Messages with an empty body (e.g. collapsed, not yet loaded) keep their header.
+fn format_thread(messages: &[GmailMessage]) -> String {
+    let total = messages.len();
+    let mut parts = Vec::with_capacity(total);
+
+    for (i, message) in messages.iter().enumerate() {
+        let mut part = format!("--- Message {} of {} ---", i + 1, total);
+
+        let from = match (&message.sender, &message.sender_email) {
+            (Some(name), Some(email)) if name != email => Some(format!("{} <{}>", name, email)),
+            (Some(name), _) => Some(name.clone()),
+            (None, Some(email)) => Some(email.clone()),
+            (None, None) => None,
+        };
+        if let Some(from) = from {
+            part.push_str(&format!("\nFrom: {}", from));
+        }
+        if let Some(date) = message.date.as_ref().filter(|d| !d.is_empty()) {
+            part.push_str(&format!("\nDate: {}", date));
+        }
+        if let Some(body) = message.body.as_ref().filter(|b| !b.is_empty()) {
+            part.push_str(&format!("\n\n{}", body));
+        }
+
+        parts.push(part);
+    }
+
+    let mut text = parts.join("\n\n");
+    if let Some((cut, _)) = text.char_indices().nth(MAX_THREAD_CHARS) {
+        text.truncate(cut);
+        text.push_str("\n\n[thread truncated]");
+    }
+    text
+}
+
 /// Extract a gist from a Gmail email thread via DOM extraction
 pub async fn extract(
     url: &str,
@@ -91,9 +141,15 @@ pub async fn extract(
         .subject
         .filter(|s| !s.is_empty())
         .unwrap_or_else(|| "Unknown Thread".to_string());
-    let email_count = data.email_count.unwrap_or(0);
+    let messages = data.messages.unwrap_or_default();
+    let email_count = messages.len() as u32;
     let participants = data.participants.unwrap_or_default();
 
+    // The thread's originator is the sender of the first message
+    let originator = messages.first().and_then(|m| {
+        m.sender.clone().or_else(|| m.sender_email.clone())
+    });
+
     // Description: "3 emails · alice@x.com, bob@y.com"
     let description = {
         let count_part = format!(
@@ -108,7 +164,7 @@ pub async fn extract(
         }
     };
 
-    let thread_text = data.thread_text.filter(|t| !t.is_empty());
+    let thread_text = Some(format_thread(&messages)).filter(|t| !t.is_empty());
 
     let mut extra = serde_json::Map::new();
     extra.insert(
@@ -130,7 +186,7 @@ pub async fn extract(
         title: subject,
         source_type: source_type.clone(),
         domain: domain.to_string(),
-        author: data.sender,
+        author: originator.or(data.sender),
         description: Some(description),
         content_excerpt: thread_text,
         published_date: None,
@@ -138,3 +194,42 @@ pub async fn extract(
         extra: serde_json::Value::Object(extra),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(sender: Option<&str>, email: Option<&str>, date: Option<&str>, body: &str) -> GmailMessage {
+        GmailMessage {
+            sender: sender.map(String::from),
+            sender_email: email.map(String::from),
+            date: date.map(String::from),
+            body: Some(body.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_format_thread_keeps_order_and_headers() {
+        let messages = vec![
+            message(Some("Alice"), Some("alice@x.com"), Some("Mon, Jan 1, 10:00 AM"), "Kickoff"),
+            message(None, Some("bob@y.com"), None, "Reply"),
+        ];
+
+        let text = format_thread(&messages);
+
+        assert_eq!(
+            text,
+            "--- Message 1 of 2 ---\nFrom: Alice <alice@x.com>\nDate: Mon, Jan 1, 10:00 AM\n\nKickoff\n\n\
+             --- Message 2 of 2 ---\nFrom: bob@y.com\n\nReply"
+        );
+    }
+
+    #[test]
+    fn test_format_thread_truncates_long_threads() {
+        let body = "é".repeat(MAX_THREAD_CHARS + 10);
+        let text = format_thread(&[message(None, None, None, &body)]);
+
+        assert!(text.ends_with("[thread truncated]"));
+        assert!(text.chars().count() < MAX_THREAD_CHARS + 30);
+    }
+}
@@ -1,8 +1,9 @@
 // Medium article extractor — uses DOM extraction via Chrome adapter
-// Extracts metadata + full article text directly from the browser DOM,
-// bypassing paywalls and getting authenticated content.
+// Extracts metadata + full article text directly from the browser DOM, so a
+// logged-in member gets the full text of member-only posts. Falls back to the
+// public HTML (generic extractor) when the tab can't be read.
 
-use super::PageGist;
+use super::{generic, PageGist};
 use crate::browser::adapters::chrome::ChromeAppleScriptAdapter;
 use crate::browser::adapters::BrowserAdapter;
 use crate::browser::tabs::SourceType;
@@ -23,6 +24,17 @@ fn extract_reading_time(text: &str) -> Option<u32> {
         .and_then(|m| m.as_str().parse::<u32>().ok())
 }
 
+/// Mark the generic extractor's `extra` as lacking member-only text, keeping
+/// any fields it already has
+fn without_member_content(extra: serde_json::Value) -> serde_json::Value {
+    let mut fields = match extra {
+        serde_json::Value::Object(fields) => fields,
+        _ => serde_json::Map::new(),
+    };
+    fields.insert("member_content".to_string(), serde_json::Value::Bool(false));
+    serde_json::Value::Object(fields)
+}
+
 /// Data extracted from Medium DOM via JavaScript
 #[derive(Deserialize)]
 struct MediumDomData {
@@ -33,6 +45,10 @@ struct MediumDomData {
     author: Option<String>,
     published_date: Option<String>,
     article_text: Option<String>,
+    /// Page is marked "Member-only story"
+    member_only: Option<bool>,
+    /// Page shows a paywall prompt (article text is only a preview)
+    paywalled: Option<bool>,
 }
 
 /// JavaScript that extracts all Medium metadata + article text in one call.
@@ -67,6 +83,13 @@ const EXTRACT_JS: &str = r#"(function(){
   }
   var article = document.querySelector('article');
   d.article_text = article ? article.innerText : '';
+  var bodyText = document.body ? document.body.innerText : '';
+  d.member_only = bodyText.indexOf('Member-only story') !== -1;
+  var paywallPhrases = ['The author made this story available to Medium members only', 'Become a member to read', 'Create an account to read the full story', 'Read the full story with a free account'];
+  d.paywalled = false;
+  for (var k = 0; k < paywallPhrases.length; k++) {
+    if (bodyText.indexOf(paywallPhrases[k]) !== -1) d.paywalled = true;
+  }
   return JSON.stringify(d);
 })()"#;
 
@@ -79,7 +102,15 @@ pub async fn extract(
     let adapter = ChromeAppleScriptAdapter;
 
     // Single JS call extracts all metadata + full article text from the DOM
-    let json_str = adapter.execute_js_in_tab(url, EXTRACT_JS).await?;
+    let json_str = match adapter.execute_js_in_tab(url, EXTRACT_JS).await {
+        Ok(json_str) => json_str,
+        Err(e) => {
+            eprintln!("Medium: Tab not readable ({}), falling back to public HTML", e);
+            let mut gist = generic::extract(url, source_type, domain).await?;
+            gist.extra = without_member_content(gist.extra);
+            return Ok(gist);
+        }
+    };
 
     let data: MediumDomData = serde_json::from_str(json_str.trim())
         .map_err(|e| format!("Failed to parse Medium page data: {}", e))?;
@@ -100,6 +131,11 @@ pub async fn extract(
         date_str
     });
 
+    // Full text of a member-only post was captured (not just the paywall preview)
+    let paywalled = data.paywalled.unwrap_or(false);
+    let member_content =
+        data.member_only.unwrap_or(false) && !paywalled && !article_text.is_empty();
+
     // Full article content (no truncation — user wants the complete article)
     let content_excerpt = if article_text.is_empty() {
         None
@@ -109,6 +145,13 @@ pub async fn extract(
 
     // Build extra JSON with Medium-specific fields
     let mut extra = serde_json::Map::new();
+    extra.insert(
+        "member_content".to_string(),
+        serde_json::Value::Bool(member_content),
+    );
+    if paywalled {
+        extra.insert("paywalled".to_string(), serde_json::Value::Bool(true));
+    }
     if let Some(pub_name) = data.publication.as_ref() {
         extra.insert(
             "publication".to_string(),
@@ -135,3 +178,21 @@ pub async fn extract(
         extra: serde_json::Value::Object(extra),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_public_fallback_keeps_generic_extra() {
+        assert_eq!(
+            without_member_content(serde_json::Value::Null),
+            json!({ "member_content": false })
+        );
+        assert_eq!(
+            without_member_content(json!({ "reading_time_minutes": 8, "member_content": true })),
+            json!({ "reading_time_minutes": 8, "member_content": false })
+        );
+    }
+}
@@ -1,10 +1,15 @@
 // Extractor router — dispatches to the right extractor based on SourceType
 
 pub mod chatgpt;
+pub mod chatgpt_accessibility;
 pub mod claude_extension;
+pub mod custom_js;
 pub mod generic;
 pub mod gmail;
 pub mod medium;
+pub mod notion;
+
+use std::collections::HashMap;
 
 use super::tabs::SourceType;
 use super::youtube::scrape_youtube_gist;
@@ -26,23 +31,125 @@ pub struct PageGist {
     pub extra: serde_json::Value,
 }
 
+/// Options for `prepare_gist`, from `BrowserSettings` and
+/// `IntelligenceSettings::max_content_chars`
+#[derive(Debug, Clone, Default)]
+pub struct GistOptions {
+    /// Cut the content excerpt to this many characters (0 = no limit)
+    pub max_content_chars: usize,
+
+    /// Record `content_hash` of the full excerpt in `extra.content_hash`
+    pub content_hash: bool,
+
+    /// Per-domain JS snippets that replace the built-in extractor (see `custom_js`)
+    pub domain_js_overrides: HashMap<String, String>,
+}
+
 /// Route URL to the right extractor and produce a gist
-pub async fn prepare_gist(url: &str, source_type: &SourceType) -> Result<PageGist, String> {
+///
+/// A `domain_js_overrides` snippet for the URL's domain is tried first; if it
+/// fails or returns nothing, the built-in extractor runs as usual.
+pub async fn prepare_gist(
+    url: &str,
+    source_type: &SourceType,
+    options: &GistOptions,
+) -> Result<PageGist, String> {
     let domain = super::tabs::extract_domain(url);
 
-    match source_type {
-        SourceType::YouTube => youtube_gist(url, &domain).await,
-        SourceType::Email => gmail::extract(url, source_type, &domain).await,
-        SourceType::Chat => chatgpt::extract(url, source_type, &domain).await,
-        _ if domain.contains("medium.com") => medium::extract(url, source_type, &domain).await,
-        _ => generic::extract(url, source_type, &domain).await,
+    let js_gist = match custom_js::find_override(&options.domain_js_overrides, &domain) {
+        Some(script) => custom_js::extract(url, source_type, &domain, script)
+            .await
+            .map_err(|e| eprintln!("Extractors: JS override failed ({}), using built-in extractor", e))
+            .ok(),
+        None => None,
+    };
+
+    let mut gist = match js_gist {
+        Some(gist) => gist,
+        None => match source_type {
+            SourceType::YouTube => youtube_gist(url, &domain).await,
+            SourceType::Email => gmail::extract(url, source_type, &domain).await,
+            SourceType::Chat => chatgpt::extract(url, source_type, &domain).await,
+            _ if notion::is_notion_domain(&domain) => notion::extract(url, source_type, &domain).await,
+            _ if domain.contains("medium.com") => medium::extract(url, source_type, &domain).await,
+            _ => generic::extract(url, source_type, &domain).await,
+        }?,
+    };
+
+    finish_excerpt(&mut gist, options);
+    Ok(gist)
+}
+
+/// Hash and truncate the gist's excerpt as `options` ask, recording both in
+/// `extra`
+fn finish_excerpt(gist: &mut PageGist, options: &GistOptions) {
+    // Hashed before truncation, so pages sharing a long prefix still differ
+    let hash = gist.content_excerpt.as_deref()
+        .filter(|_| options.content_hash)
+        .map(content_hash);
+    let original_length = gist.content_excerpt.as_mut()
+        .and_then(|excerpt| truncate_content(excerpt, options.max_content_chars));
+    if hash.is_none() && original_length.is_none() {
+        return;
+    }
+
+    if !gist.extra.is_object() {
+        gist.extra = serde_json::json!({});
+    }
+    if let Some(hash) = hash {
+        gist.extra["content_hash"] = serde_json::Value::String(hash);
+    }
+    if let Some(original_length) = original_length {
+        gist.extra["excerpt_truncated"] = serde_json::Value::Bool(true);
+        gist.extra["original_excerpt_length"] = serde_json::json!(original_length);
     }
 }
 
+/// Appended to content cut at `max_content_chars`
+const TRUNCATION_MARKER: &str = "\n\n[truncated]";
+
+/// Cut `text` at a UTF-8 boundary so that, with `TRUNCATION_MARKER` appended,
+/// it is at most `max_chars` characters (0 = no limit)
+///
+/// The marker counts toward the limit, so cutting the result again (e.g. an
+/// extracted excerpt when its gem is saved) leaves it intact. Returns the
+/// original length in characters if the text was cut.
+pub fn truncate_content(text: &mut String, max_chars: usize) -> Option<usize> {
+    if max_chars == 0 {
+        return None;
+    }
+    text.char_indices().nth(max_chars)?;
+    let original_length = text.chars().count();
+    let keep = max_chars.saturating_sub(TRUNCATION_MARKER.chars().count());
+    let cut = text.char_indices().nth(keep).map_or(text.len(), |(i, _)| i);
+    text.truncate(cut);
+    text.push_str(TRUNCATION_MARKER);
+    Some(original_length)
+}
+
+/// SHA-256 of an extracted excerpt (hex), stored as `source_meta.content_hash`
+/// so re-extraction can tell whether the source changed
+pub fn content_hash(content: &str) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(content.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Options for `merge_gists`
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MergeOptions {
+    /// Drop conversation paragraphs that repeat a page paragraph (compared
+    /// after normalizing whitespace, case and `>` quote markers)
+    pub dedup_paragraphs: bool,
+}
+
+/// Paragraphs shorter than this (normalized) are never treated as duplicates,
+/// so short replies like "Yes, exactly." survive
+const MIN_DEDUP_PARAGRAPH_CHARS: usize = 40;
+
 /// Merge a page/article gist with a Claude conversation gist into a single gist.
 /// The page gist provides primary metadata (url, title, author, dates).
 /// The Claude conversation is appended to the content.
-pub fn merge_gists(page_gist: PageGist, claude_gist: PageGist) -> PageGist {
+pub fn merge_gists(page_gist: PageGist, claude_gist: PageGist, options: &MergeOptions) -> PageGist {
     let mut content_parts: Vec<String> = Vec::new();
 
     if let Some(ref excerpt) = page_gist.content_excerpt {
@@ -52,6 +159,10 @@ pub fn merge_gists(page_gist: PageGist, claude_gist: PageGist) -> PageGist {
     }
 
     if let Some(ref conversation) = claude_gist.content_excerpt {
+        let conversation = match (&page_gist.content_excerpt, options.dedup_paragraphs) {
+            (Some(page), true) => remove_repeated_paragraphs(conversation, page),
+            _ => conversation.clone(),
+        };
         if !conversation.trim().is_empty() {
             content_parts.push(format!("--- Claude Conversation ---\n{}", conversation));
         }
@@ -87,6 +198,35 @@ pub fn merge_gists(page_gist: PageGist, claude_gist: PageGist) -> PageGist {
     }
 }
 
+/// Paragraphs (blank-line separated) of `text` whose normalized form doesn't
+/// appear among the paragraphs of `reference`, rejoined with blank lines
+fn remove_repeated_paragraphs(text: &str, reference: &str) -> String {
+    let seen: std::collections::HashSet<String> = paragraphs(reference)
+        .map(normalize_paragraph)
+        .filter(|p| p.chars().count() >= MIN_DEDUP_PARAGRAPH_CHARS)
+        .collect();
+
+    paragraphs(text)
+        .filter(|p| !seen.contains(&normalize_paragraph(p)))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn paragraphs(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n").map(str::trim).filter(|p| !p.is_empty())
+}
+
+/// Lowercased words of `paragraph`, single-spaced, with quote markers removed
+fn normalize_paragraph(paragraph: &str) -> String {
+    paragraph
+        .lines()
+        .map(|line| line.trim_start().trim_start_matches('>'))
+        .flat_map(str::split_whitespace)
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 /// Wrap existing YouTube scraper into PageGist format
 async fn youtube_gist(url: &str, domain: &str) -> Result<PageGist, String> {
     let yt = scrape_youtube_gist(url).await?;
@@ -108,3 +248,98 @@ async fn youtube_gist(url: &str, domain: &str) -> Result<PageGist, String> {
         }),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_content_at_char_boundary() {
+        let mut excerpt = "héllo wörld, ".repeat(3);
+        assert_eq!(truncate_content(&mut excerpt, 20), Some(39));
+        assert_eq!(excerpt, "héllo w\n\n[truncated]");
+
+        // Within the limit, marker included: cutting again changes nothing
+        assert_eq!(truncate_content(&mut excerpt, 20), None);
+        assert_eq!(excerpt, "héllo w\n\n[truncated]");
+
+        let mut short = "short".to_string();
+        assert_eq!(truncate_content(&mut short, 100), None);
+        assert_eq!(short, "short");
+
+        let mut unlimited = "x".repeat(200_000);
+        assert_eq!(truncate_content(&mut unlimited, 0), None);
+        assert_eq!(unlimited.len(), 200_000);
+    }
+
+    fn gist(content: &str) -> PageGist {
+        PageGist {
+            url: "https://example.com/post".to_string(),
+            title: "Post".to_string(),
+            source_type: SourceType::Article,
+            domain: "example.com".to_string(),
+            author: None,
+            description: None,
+            content_excerpt: Some(content.to_string()),
+            published_date: None,
+            image_url: None,
+            extra: serde_json::Value::Null,
+        }
+    }
+
+    #[test]
+    fn test_merge_gists_dedup_paragraphs() {
+        let page = "Intro paragraph that sets the scene.\n\nRust's ownership model guarantees memory safety without a garbage collector.\n\nClosing thoughts.";
+        let conversation = "User: Can you explain this?\n\n> Rust's ownership   model guarantees memory safety\n> without a garbage collector.\n\nClaude: It means the compiler tracks who owns each value.\n\nClosing thoughts.";
+
+        let merged = merge_gists(gist(page), gist(conversation), &MergeOptions { dedup_paragraphs: true });
+        let content = merged.content_excerpt.unwrap();
+        let (_, claude_part) = content.split_once("--- Claude Conversation ---").unwrap();
+        assert!(!claude_part.contains("ownership"), "quoted page passage should be dropped");
+        assert!(claude_part.contains("compiler tracks"));
+        // Short paragraphs are kept even when repeated
+        assert!(claude_part.contains("Closing thoughts."));
+
+        let merged = merge_gists(gist(page), gist(conversation), &MergeOptions::default());
+        let content = merged.content_excerpt.unwrap();
+        let (_, claude_part) = content.split_once("--- Claude Conversation ---").unwrap();
+        assert!(claude_part.contains("ownership"), "dedup is off by default");
+    }
+
+    #[test]
+    fn test_finish_excerpt_hashes_full_content() {
+        let gist_with = |excerpt: &str| PageGist {
+            url: "https://example.com/a".to_string(),
+            title: "A".to_string(),
+            source_type: SourceType::Article,
+            domain: "example.com".to_string(),
+            author: None,
+            description: None,
+            content_excerpt: Some(excerpt.to_string()),
+            published_date: None,
+            image_url: None,
+            extra: serde_json::Value::Null,
+        };
+        let options = GistOptions { max_content_chars: 20, content_hash: true, ..Default::default() };
+
+        let mut first = gist_with("same prefix, first ending");
+        let mut second = gist_with("same prefix, second ending");
+        finish_excerpt(&mut first, &options);
+        finish_excerpt(&mut second, &options);
+        assert_eq!(first.content_excerpt, second.content_excerpt);
+        assert_eq!(first.extra["content_hash"], content_hash("same prefix, first ending"));
+        assert_ne!(first.extra["content_hash"], second.extra["content_hash"]);
+
+        // Off by default
+        let mut untracked = gist_with("short");
+        finish_excerpt(&mut untracked, &GistOptions::default());
+        assert_eq!(untracked.extra, serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_content_hash() {
+        let hash = content_hash("hello");
+        assert_eq!(hash, "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824");
+        assert_ne!(hash, content_hash("hello "));
+    }
+}
@@ -0,0 +1,250 @@
+// Notion page extractor — converts the open tab's DOM to Markdown
+// Notion renders pages client-side, so fetched HTML has little more than the
+// title. The tab's live DOM (`get_tab_html`) has every block as a
+// `notion-<type>-block` element; each block's text is read from its
+// `data-content-editable-leaf` element and rendered by type: headings as
+// `#`, lists as `-` / `1.` / `- [ ]`, quotes and callouts as `>`, code
+// fenced. Nesting isn't kept (nested items are rendered flat).
+//
+// Private pages are only readable when the tab is signed in to Notion. If
+// the tab can't be read or shows no blocks (login wall, page not shared),
+// the public HTML is used instead (generic extractor) and `extra` says why.
+
+use super::generic::{decode_html_entities, extract_html_title, extract_og_content, TAG_REGEX};
+use super::{generic, PageGist};
+use crate::browser::adapters::chrome::ChromeAppleScriptAdapter;
+use crate::browser::adapters::BrowserAdapter;
+use crate::browser::tabs::SourceType;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Opening tag of a block element, capturing the block type
+/// (e.g. `header`, `bulleted_list`, `to_do`)
+static BLOCK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)<div\b[^>]*?\bclass="[^"]*?\bnotion-([a-z_]+)-block\b[^"]*"[^>]*>"#).unwrap()
+});
+
+/// Editable text of a block (inline markup only, so the first closing
+/// div/heading ends it)
+static LEAF_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?is)<[a-z0-9]+\b[^>]*\bdata-content-editable-leaf="true"[^>]*>(.*?)</(?:div|h[1-6])>"#).unwrap()
+});
+
+static BR_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)<br\s*/?>").unwrap()
+});
+
+/// Shown in `extra.note` when the page had to be read from public HTML
+const DOM_CAPTURE_NOTE: &str =
+    "Notion page content needs the page open in Chrome (signed in for private pages); only public metadata was captured";
+
+/// Whether `domain` serves Notion pages (workspace app or published sites)
+pub fn is_notion_domain(domain: &str) -> bool {
+    domain == "notion.so"
+        || domain.ends_with(".notion.so")
+        || domain == "notion.site"
+        || domain.ends_with(".notion.site")
+}
+
+/// A block read from the page, in document order
+#[derive(Debug, PartialEq)]
+struct NotionBlock {
+    kind: String,
+    text: String,
+    checked: bool,
+}
+
+/// Blocks in `html`, skipping those without text (except dividers)
+fn parse_blocks(html: &str) -> Vec<NotionBlock> {
+    let starts: Vec<_> = BLOCK_REGEX.captures_iter(html).collect();
+    let mut blocks = Vec::new();
+
+    for (i, caps) in starts.iter().enumerate() {
+        let tag = caps.get(0).unwrap();
+        let kind = caps[1].to_lowercase();
+        // A block's own markup runs until the next block (its first child
+        // or next sibling) starts
+        let end = starts.get(i + 1).map_or(html.len(), |next| next.get(0).unwrap().start());
+        let own = &html[tag.start()..end];
+
+        let text = LEAF_REGEX
+            .captures(own)
+            .map(|leaf| leaf_text(&leaf[1], kind == "code"))
+            .unwrap_or_default();
+        if text.is_empty() && kind != "divider" {
+            continue;
+        }
+        let checked = kind == "to_do" && own.contains(r#"aria-checked="true""#);
+        blocks.push(NotionBlock { kind, text, checked });
+    }
+
+    blocks
+}
+
+/// Plain text of a leaf's inner HTML; code keeps its line breaks and indentation
+fn leaf_text(inner: &str, preserve_whitespace: bool) -> String {
+    let text = BR_REGEX.replace_all(inner, "\n");
+    let text = decode_html_entities(&TAG_REGEX.replace_all(&text, ""));
+    if preserve_whitespace {
+        text.trim_matches('\n').to_string()
+    } else {
+        text.trim().to_string()
+    }
+}
+
+/// Page title (the leading `page` block) and the remaining blocks as Markdown
+fn blocks_to_markdown(blocks: &[NotionBlock]) -> (Option<String>, String) {
+    let (title, body) = match blocks.first() {
+        Some(first) if first.kind == "page" => (Some(first.text.clone()), &blocks[1..]),
+        _ => (None, blocks),
+    };
+
+    let mut markdown = String::new();
+    let mut previous_was_item = false;
+    let mut number = 0;
+
+    for block in body {
+        let is_item = matches!(block.kind.as_str(), "bulleted_list" | "numbered_list" | "to_do" | "toggle" | "page");
+        number = if block.kind == "numbered_list" { number + 1 } else { 0 };
+
+        let rendered = match block.kind.as_str() {
+            "header" => format!("# {}", block.text),
+            "sub_header" => format!("## {}", block.text),
+            "sub_sub_header" => format!("### {}", block.text),
+            "bulleted_list" | "toggle" | "page" => format!("- {}", block.text),
+            "numbered_list" => format!("{}. {}", number, block.text),
+            "to_do" => format!("- [{}] {}", if block.checked { "x" } else { " " }, block.text),
+            "quote" | "callout" => block.text.lines().map(|l| format!("> {}", l)).collect::<Vec<_>>().join("\n"),
+            "code" => format!("```\n{}\n```", block.text),
+            "divider" => "---".to_string(),
+            _ => block.text.clone(),
+        };
+
+        if !markdown.is_empty() {
+            markdown.push_str(if is_item && previous_was_item { "\n" } else { "\n\n" });
+        }
+        markdown.push_str(&rendered);
+        previous_was_item = is_item;
+    }
+
+    (title, markdown)
+}
+
+/// Extract a gist from a Notion page via the open tab's DOM
+pub async fn extract(
+    url: &str,
+    source_type: &SourceType,
+    domain: &str,
+) -> Result<PageGist, String> {
+    let adapter = ChromeAppleScriptAdapter;
+
+    let html = match adapter.get_tab_html(url).await {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("Notion: Tab not readable ({}), falling back to public HTML", e);
+            return public_fallback(url, source_type, domain).await;
+        }
+    };
+
+    let blocks = parse_blocks(&html);
+    let (page_title, markdown) = blocks_to_markdown(&blocks);
+    if markdown.is_empty() {
+        eprintln!("Notion: No blocks in tab (private or unshared page?), falling back to public HTML");
+        return public_fallback(url, source_type, domain).await;
+    }
+
+    let title = page_title
+        .or_else(|| extract_og_content(&html, "og:title"))
+        .or_else(|| extract_html_title(&html).map(|t| t.trim_end_matches(" | Notion").to_string()))
+        .unwrap_or_else(|| "Untitled".to_string());
+
+    Ok(PageGist {
+        url: url.to_string(),
+        title,
+        source_type: source_type.clone(),
+        domain: domain.to_string(),
+        author: None,
+        description: extract_og_content(&html, "og:description"),
+        content_excerpt: Some(markdown),
+        published_date: None,
+        image_url: extract_og_content(&html, "og:image"),
+        extra: serde_json::json!({
+            "dom_captured": true,
+            "block_count": blocks.len(),
+        }),
+    })
+}
+
+/// Gist from the public HTML, flagged as missing the page content
+async fn public_fallback(url: &str, source_type: &SourceType, domain: &str) -> Result<PageGist, String> {
+    let mut gist = generic::extract(url, source_type, domain).await?;
+    gist.extra = serde_json::json!({
+        "dom_captured": false,
+        "note": DOM_CAPTURE_NOTE,
+    });
+    Ok(gist)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn block(kind: &str, inner: &str) -> String {
+        format!(
+            r#"<div data-block-id="{kind}" class="notion-selectable notion-{kind}-block"><div><div spellcheck="true" data-content-editable-leaf="true" contenteditable="false">{inner}</div></div>"#
+        )
+    }
+
+    #[test]
+    fn test_blocks_to_markdown() {
+        let html = [
+            r#"<html><head><title>Runbook | Notion</title></head><body><div class="notion-page-content">"#.to_string(),
+            r#"<div class="notion-page-block"><h1 data-content-editable-leaf="true">Runbook</h1></div>"#.to_string(),
+            block("header", "Deploy"),
+            block("text", "Run the <b>release</b> script &amp; wait."),
+            block("numbered_list", "Tag"),
+            block("numbered_list", "Push"),
+            // Nested bullet inside the second item
+            block("bulleted_list", "CI runs"),
+            r#"<div class="notion-to_do-block"><div role="checkbox" aria-checked="true"></div><div data-content-editable-leaf="true">Announce</div></div>"#.to_string(),
+            r#"<div class="notion-divider-block"><div role="separator"></div></div>"#.to_string(),
+            block("code", "cargo build<br>  --release"),
+            block("quote", "Never on Fridays"),
+            block("column_list", ""),
+            "</div></body></html>".to_string(),
+        ]
+        .concat();
+
+        let blocks = parse_blocks(&html);
+        assert!(blocks.iter().all(|b| b.kind != "column_list"), "blocks without text are skipped");
+        assert!(blocks.iter().any(|b| b.kind == "to_do" && b.checked));
+
+        let (title, markdown) = blocks_to_markdown(&blocks);
+        assert_eq!(title.as_deref(), Some("Runbook"));
+        assert_eq!(
+            markdown,
+            "# Deploy\n\n\
+             Run the release script & wait.\n\n\
+             1. Tag\n2. Push\n- CI runs\n- [x] Announce\n\n\
+             ---\n\n\
+             ```\ncargo build\n  --release\n```\n\n\
+             > Never on Fridays"
+        );
+    }
+
+    #[test]
+    fn test_login_page_has_no_blocks() {
+        let html = r#"<html><head><title>Notion – Log in</title></head><body><form><input type="email"></form></body></html>"#;
+        let (title, markdown) = blocks_to_markdown(&parse_blocks(html));
+        assert_eq!(title, None);
+        assert!(markdown.is_empty());
+    }
+
+    #[test]
+    fn test_is_notion_domain() {
+        assert!(is_notion_domain("notion.so"));
+        assert!(is_notion_domain("acme.notion.site"));
+        assert!(!is_notion_domain("notnotion.so"));
+        assert!(!is_notion_domain("example.com"));
+    }
+}
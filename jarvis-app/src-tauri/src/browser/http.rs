@@ -0,0 +1,214 @@
+// Shared HTTP client for page extraction and YouTube metadata
+//
+// Built from `NetworkSettings` (proxy, connect/read/overall timeouts,
+// user-agent) at startup and rebuilt whenever settings are saved, so
+// extractors pick up proxy changes without a restart. Fetchers go through `get(fetcher, url)`,
+// which swaps in that fetcher's `user_agent_overrides` entry when one is
+// configured, and `send`, which retries transient failures.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, RwLock};
+use std::time::Duration;
+
+use reqwest::header::USER_AGENT;
+
+use crate::settings::NetworkSettings;
+
+/// Client plus the per-fetcher user-agents it was configured with
+struct HttpState {
+    client: reqwest::Client,
+    user_agent_overrides: HashMap<String, String>,
+}
+
+static STATE: LazyLock<RwLock<HttpState>> = LazyLock::new(|| {
+    let client = build_client(&NetworkSettings::default()).unwrap_or_else(|e| {
+        eprintln!("HTTP: {}; using reqwest defaults", e);
+        reqwest::Client::new()
+    });
+    RwLock::new(HttpState { client, user_agent_overrides: HashMap::new() })
+});
+
+/// GET `url` with the shared client, using `fetcher`'s user-agent override
+/// (see `USER_AGENT_OVERRIDE_KEYS`) if one is set
+pub fn get(fetcher: &str, url: &str) -> reqwest::RequestBuilder {
+    let state = STATE.read().unwrap_or_else(|e| e.into_inner());
+    let request = state.client.get(url);
+    match state.user_agent_overrides.get(fetcher) {
+        Some(user_agent) => request.header(USER_AGENT, user_agent.as_str()),
+        None => request,
+    }
+}
+
+/// Attempts `send` makes before giving up
+const MAX_ATTEMPTS: u32 = 3;
+
+/// Wait before the first retry; doubles after each further attempt
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+
+/// Send `request`, retrying connection errors, timeouts and 5xx responses
+/// with exponential backoff
+///
+/// Other responses (including 4xx) are returned as-is for the caller to
+/// inspect. Only use this for idempotent requests without a streamed body.
+///
+/// # Errors
+///
+/// The last failure, with the number of attempts made.
+pub async fn send(request: reqwest::RequestBuilder) -> Result<reqwest::Response, String> {
+    let mut attempt = 1;
+    loop {
+        let Some(this_attempt) = request.try_clone() else {
+            // Streaming bodies can't be replayed; send once
+            return request.send().await.map_err(|e| e.to_string());
+        };
+        let failure = match this_attempt.send().await {
+            Ok(response) if !is_retryable_status(response.status()) => return Ok(response),
+            Ok(response) => format!("server returned {}", response.status()),
+            Err(e) if e.is_connect() || e.is_timeout() => e.to_string(),
+            Err(e) => return Err(e.to_string()),
+        };
+
+        if attempt >= MAX_ATTEMPTS {
+            return Err(format!("{} (after {} attempts)", failure, attempt));
+        }
+        let delay = backoff_delay(attempt);
+        eprintln!("HTTP: Attempt {} failed ({}); retrying in {:?}", attempt, failure, delay);
+        tokio::time::sleep(delay).await;
+        attempt += 1;
+    }
+}
+
+/// 5xx responses are worth retrying; 4xx won't change on a retry
+fn is_retryable_status(status: reqwest::StatusCode) -> bool {
+    status.is_server_error()
+}
+
+/// Delay after failed attempt number `attempt` (1-based)
+fn backoff_delay(attempt: u32) -> Duration {
+    INITIAL_BACKOFF * 2u32.pow(attempt.saturating_sub(1))
+}
+
+/// Rebuild the shared client from `settings`
+///
+/// On error the previous client stays in use.
+pub fn configure(settings: &NetworkSettings) -> Result<(), String> {
+    let client = build_client(settings)?;
+    *STATE.write().unwrap_or_else(|e| e.into_inner()) = HttpState {
+        client,
+        user_agent_overrides: settings.user_agent_overrides.clone(),
+    };
+    Ok(())
+}
+
+/// Build a client from `settings` (an empty proxy URL connects directly)
+pub fn build_client(settings: &NetworkSettings) -> Result<reqwest::Client, String> {
+    let mut builder = reqwest::Client::builder()
+        .connect_timeout(Duration::from_secs(settings.connect_timeout_secs))
+        .read_timeout(Duration::from_secs(settings.read_timeout_secs))
+        .timeout(Duration::from_secs(settings.request_timeout_secs))
+        .user_agent(settings.user_agent.as_str());
+
+    if let Some(proxy_url) = settings.proxy_url.as_deref().map(str::trim).filter(|u| !u.is_empty()) {
+        let proxy = reqwest::Proxy::all(proxy_url)
+            .map_err(|e| format!("Invalid proxy URL '{}': {}", proxy_url, e))?;
+        builder = builder.proxy(proxy);
+    }
+
+    builder.build().map_err(|e| format!("Failed to create HTTP client: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_client_validates_proxy() {
+        assert!(build_client(&NetworkSettings::default()).is_ok());
+
+        let with_proxy = NetworkSettings {
+            proxy_url: Some("http://proxy.example.com:8080".to_string()),
+            ..Default::default()
+        };
+        assert!(build_client(&with_proxy).is_ok());
+
+        let blank_proxy = NetworkSettings {
+            proxy_url: Some("  ".to_string()),
+            ..Default::default()
+        };
+        assert!(build_client(&blank_proxy).is_ok());
+
+        let bad_proxy = NetworkSettings {
+            proxy_url: Some("not a url".to_string()),
+            ..Default::default()
+        };
+        assert!(build_client(&bad_proxy).unwrap_err().contains("Invalid proxy URL"));
+        assert!(bad_proxy.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_request_timeout_covers_a_stalled_body() {
+        use std::io::{Read, Write};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let _ = stream.read(&mut [0u8; 1024]);
+            // Promise a body, then trickle it slower than the overall timeout
+            let _ = stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 100\r\n\r\n");
+            for _ in 0..10 {
+                std::thread::sleep(Duration::from_millis(400));
+                if stream.write_all(b"x").is_err() {
+                    break;
+                }
+            }
+        });
+
+        let settings = NetworkSettings { request_timeout_secs: 1, ..Default::default() };
+        let client = build_client(&settings).unwrap();
+        let started = std::time::Instant::now();
+        let body = client.get(&url).send().await.unwrap().text().await;
+
+        assert!(body.unwrap_err().is_timeout());
+        assert!(started.elapsed() < Duration::from_secs(3));
+    }
+
+    #[test]
+    fn test_get_applies_user_agent_override() {
+        let settings = NetworkSettings {
+            user_agent_overrides: HashMap::from([("youtube".to_string(), "TestAgent/1.0".to_string())]),
+            ..Default::default()
+        };
+        assert!(settings.validate().is_ok());
+        configure(&settings).unwrap();
+
+        let request = get("youtube", "https://www.youtube.com/").build().unwrap();
+        assert_eq!(request.headers()[USER_AGENT], "TestAgent/1.0");
+
+        // No override: the client's default user-agent applies at send time
+        let request = get("generic", "https://example.com/").build().unwrap();
+        assert!(request.headers().get(USER_AGENT).is_none());
+
+        configure(&NetworkSettings::default()).unwrap();
+
+        let unknown = NetworkSettings {
+            user_agent_overrides: HashMap::from([("vimeo".to_string(), "x".to_string())]),
+            ..Default::default()
+        };
+        assert!(unknown.validate().unwrap_err().contains("vimeo"));
+    }
+
+    #[test]
+    fn test_retry_policy() {
+        use reqwest::StatusCode;
+
+        assert!(is_retryable_status(StatusCode::INTERNAL_SERVER_ERROR));
+        assert!(is_retryable_status(StatusCode::SERVICE_UNAVAILABLE));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(!is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+        assert!(!is_retryable_status(StatusCode::OK));
+
+        assert_eq!(backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(backoff_delay(2), Duration::from_millis(1000));
+    }
+}
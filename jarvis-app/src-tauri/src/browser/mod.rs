@@ -5,7 +5,9 @@ pub mod accessibility;
 #[cfg(all(test, target_os = "macos"))]
 mod accessibility_tests;
 pub mod adapters;
+pub mod bookmarks;
 pub mod extractors;
+pub mod http;
 pub mod observer;
 pub mod tabs;
 pub mod youtube;
@@ -40,7 +40,8 @@ impl BrowserObserver {
 
     /// Start the browser observer
     /// 
-    /// Spawns a background task that polls Chrome's active tab URL every 3 seconds.
+    /// Spawns a background task that polls Chrome's active tab URL every 3 seconds
+    /// and emits "browser-observer-changed" with `{ running: true }`.
     /// Returns an error if the observer is already running.
     pub async fn start(&mut self) -> Result<(), String> {
         if self.is_running {
@@ -109,12 +110,14 @@ impl BrowserObserver {
             eprintln!("BrowserObserver: Polling task terminated");
         });
 
+        self.emit_state_changed();
         Ok(())
     }
 
     /// Stop the browser observer
     /// 
-    /// Sends stop signal to background task and resets internal state.
+    /// Sends stop signal to background task, resets internal state and emits
+    /// "browser-observer-changed" with `{ running: false }`.
     /// Returns an error if the observer is not running.
     pub async fn stop(&mut self) -> Result<(), String> {
         if !self.is_running {
@@ -131,8 +134,15 @@ impl BrowserObserver {
         // Reset state
         self.is_running = false;
 
+        self.emit_state_changed();
         Ok(())
     }
+
+    fn emit_state_changed(&self) {
+        if let Err(e) = self.app_handle.emit("browser-observer-changed", serde_json::json!({ "running": self.is_running })) {
+            eprintln!("BrowserObserver: Failed to emit state change: {}", e);
+        }
+    }
 }
 
 /// Poll Chrome for the active tab URL using AppleScript
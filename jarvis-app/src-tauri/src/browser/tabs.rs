@@ -20,6 +20,16 @@ pub enum SourceType {
     Other,
 }
 
+impl SourceType {
+    /// Serialized name, as stored in `Gem::source_type` (e.g. "QA")
+    pub fn name(&self) -> String {
+        serde_json::to_value(self)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_default()
+    }
+}
+
 /// Enriched browser tab with classification
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BrowserTab {
@@ -103,6 +113,7 @@ pub fn classify_url(url: &str) -> SourceType {
         || domain.contains("readthedocs.io")
         || domain.contains("docs.python.org")
         || domain.contains("docs.google.com")
+        || super::extractors::notion::is_notion_domain(domain)
     {
         return SourceType::Docs;
     }
@@ -182,6 +193,16 @@ pub async fn list_all_tabs() -> Result<Vec<BrowserTab>, String> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_source_type_name_round_trips() {
+        for source_type in [SourceType::YouTube, SourceType::QA, SourceType::Other] {
+            let name = source_type.name();
+            let parsed: SourceType = serde_json::from_value(serde_json::Value::String(name)).unwrap();
+            assert_eq!(parsed, source_type);
+        }
+        assert_eq!(SourceType::QA.name(), "QA");
+    }
+
     #[test]
     fn test_extract_domain_https() {
         assert_eq!(extract_domain("https://www.github.com/repo"), "github.com");
@@ -250,6 +271,14 @@ mod tests {
             classify_url("https://developer.mozilla.org/en-US/docs/Web"),
             SourceType::Docs
         );
+        assert_eq!(
+            classify_url("https://acme.notion.site/Runbook-0123456789abcdef"),
+            SourceType::Docs
+        );
+        assert_ne!(
+            classify_url("https://notion.so.evil.com/page"),
+            SourceType::Docs
+        );
     }
 
     #[test]
@@ -26,18 +26,13 @@ pub struct QuickMetadata {
 /// 
 /// Makes a lightweight API call to get title, author, and thumbnail.
 /// Returns QuickMetadata or an error message.
-/// Timeout: 3 seconds
+/// Uses the shared client (network settings), capped at 3 seconds. This is
+/// the fast path, so it is tried once rather than retried.
 pub async fn fetch_oembed_metadata(video_url: &str) -> Result<QuickMetadata, String> {
-    // Create HTTP client with 3-second timeout
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(3))
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-    
     // Build oEmbed API request
-    let response = client
-        .get("https://www.youtube.com/oembed")
+    let response = crate::browser::http::get("youtube", "https://www.youtube.com/oembed")
         .query(&[("url", video_url), ("format", "json")])
+        .timeout(Duration::from_secs(3))
         .send()
         .await
         .map_err(|e| format!("Failed to fetch oEmbed metadata: {}", e))?;
@@ -76,16 +71,8 @@ pub async fn fetch_oembed_metadata(video_url: &str) -> Result<QuickMetadata, Str
 /// Fetches the YouTube page HTML and extracts metadata fields.
 /// Returns a YouTubeGist with video information or an error message.
 pub async fn scrape_youtube_gist(url: &str) -> Result<YouTubeGist, String> {
-    // Create HTTP client with 10-second timeout
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(10))
-        .build()
-        .map_err(|e| format!("Failed to create HTTP client: {}", e))?;
-    
-    // Fetch page HTML with timeout
-    let html = client
-        .get(url)
-        .send()
+    // Fetch page HTML (timeouts come from network settings)
+    let html = crate::browser::http::send(crate::browser::http::get("youtube", url))
         .await
         .map_err(|e| format!("Failed to fetch YouTube page: {}", e))?
         .text()
@@ -1,19 +1,26 @@
-use crate::files::{FileManager, RecordingMetadata};
-use crate::gems::{Gem, GemPreview, GemStore};
+use crate::error::{JarvisError, JarvisResult};
+use crate::files::{FileManager, RecordingFilter, RecordingIntegrity, RecordingMetadata};
+use crate::gems::{CompactionResult, Gem, GemActionItems, GemPreview, GemStore, LanguageCount, SearchIndexRepair, TagCooccurrence, TranscriptMatch, DEFAULT_PREVIEW_CHARS, GEM_LABELS};
 use crate::intelligence::{IntelProvider, LlmModelInfo, LlmModelManager, VenvManager};
 use crate::intelligence::provider::TranscriptResult;
 use crate::intelligence::queue::IntelQueue;
+use crate::intelligence::timings::{self, TimedOperation};
+use crate::intelligence::utils::{with_timeout, ProviderTimeouts};
 use crate::agents::chatable::Chatable;
+use crate::agents::copilot::KeyConcept;
 use crate::agents::chatbot::{Chatbot, ChatMessage};
+use crate::agents::library_chat::LibraryChatSource;
 use crate::agents::recording_chat::RecordingChatSource;
 use crate::platform::PlatformDetector;
 use crate::recording::RecordingManager;
 use crate::search::SearchResultProvider;
-use crate::settings::{ModelManager, Settings, SettingsManager};
+use crate::settings::{ModelManager, Settings, SettingsManager, ShortcutSettings};
+use crate::shortcuts::ShortcutManager;
 use crate::transcription::{TranscriptionManager, TranscriptionSegment, TranscriptionStatus, WhisperKitProvider};
 use crate::wav::WavConverter;
 use serde::Serialize;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::{Arc, Mutex, RwLock};
 use tauri::{AppHandle, Emitter, Manager, State};
 
@@ -39,8 +46,10 @@ fn log_gem_save(msg: &str) {
 /// for persistence. It generates a new UUID and timestamp, and merges
 /// published_date and image_url into source_meta alongside the extra field.
 fn page_gist_to_gem(gist: crate::browser::extractors::PageGist) -> Gem {
+    let content_language = detect_content_language(gist.content_excerpt.as_deref(), gist.description.as_deref());
+
     // Merge published_date and image_url into source_meta
-    let source_meta = if let serde_json::Value::Object(mut map) = gist.extra {
+    let mut source_meta = if let serde_json::Value::Object(mut map) = gist.extra {
         // Start with the extra field as base
         if let Some(published_date) = gist.published_date {
             map.insert("published_date".to_string(), serde_json::Value::String(published_date));
@@ -60,6 +69,9 @@ fn page_gist_to_gem(gist: crate::browser::extractors::PageGist) -> Gem {
         }
         serde_json::Value::Object(map)
     };
+    if let Some(language) = content_language {
+        source_meta["content_language"] = serde_json::Value::String(language.to_string());
+    }
 
     Gem {
         id: uuid::Uuid::new_v4().to_string(),
@@ -75,6 +87,89 @@ fn page_gist_to_gem(gist: crate::browser::extractors::PageGist) -> Gem {
         ai_enrichment: None,
         transcript: None,
         transcript_language: None,
+        user_notes: None,
+        label: None,
+    }
+}
+
+/// Language of captured web content (ISO 639-1), stored as
+/// `source_meta.content_language`; detected from the content, else the description
+fn detect_content_language(content: Option<&str>, description: Option<&str>) -> Option<&'static str> {
+    content
+        .and_then(crate::intelligence::utils::detect_language)
+        .or_else(|| description.and_then(crate::intelligence::utils::detect_language))
+}
+
+/// Reject gem source URLs that would collide in the `UNIQUE` source_url column
+///
+/// The URL must be absolute with a scheme and a path-style body: http(s),
+/// `jarvis://` (recordings), and `file://` pass; empty strings, relative
+/// paths, and `about:blank`-style URLs from failed extractions don't.
+/// Returns the URL without surrounding whitespace, which is what gets stored.
+fn validate_source_url(url: &str) -> Result<&str, String> {
+    let url = url.trim();
+    if url.is_empty() {
+        return Err("Cannot save gem: the page URL is empty".to_string());
+    }
+    match reqwest::Url::parse(url) {
+        Ok(parsed) if !parsed.cannot_be_a_base() => Ok(url),
+        _ => Err(format!("Cannot save gem: '{}' is not an absolute URL", url)),
+    }
+}
+
+/// Truncate gem content to at most `max_chars` characters (0 = no limit)
+/// with `truncate_content`, the same cut extracted excerpts get
+///
+/// When content is cut, `source_meta` records `content_truncated: true` and
+/// `original_content_length` (in characters) so the full size is still known.
+fn truncate_gem_content(gem: &mut Gem, max_chars: usize) {
+    let Some(content) = gem.content.as_mut() else {
+        return;
+    };
+    let Some(original_length) = crate::browser::extractors::truncate_content(content, max_chars) else {
+        return;
+    };
+
+    if !gem.source_meta.is_object() {
+        gem.source_meta = serde_json::json!({});
+    }
+    if let Some(meta) = gem.source_meta.as_object_mut() {
+        meta.insert("content_truncated".to_string(), serde_json::Value::Bool(true));
+        meta.insert("original_content_length".to_string(), serde_json::json!(original_length));
+    }
+}
+
+/// Download the gem's `image_url` into the thumbnail cache and record the
+/// local path as `source_meta.image_local`
+///
+/// Best-effort: on any failure the gem is returned unchanged and keeps
+/// relying on the remote URL.
+async fn cache_gem_image(gem_store: &dyn GemStore, gem: Gem) -> Gem {
+    let Some(image_url) = gem.source_meta.get("image_url").and_then(|v| v.as_str()) else {
+        return gem;
+    };
+
+    let local_path = match crate::gems::thumbnails::cache_thumbnail(&gem.id, image_url).await {
+        Ok(path) => path,
+        Err(e) => {
+            eprintln!("Thumbnail caching failed for gem {}: {}", gem.id, e);
+            return gem;
+        }
+    };
+
+    let mut updated = gem.clone();
+    if let Some(meta) = updated.source_meta.as_object_mut() {
+        meta.insert(
+            "image_local".to_string(),
+            serde_json::Value::String(local_path.to_string_lossy().to_string()),
+        );
+    }
+    match gem_store.save(updated).await {
+        Ok(saved) => saved,
+        Err(e) => {
+            eprintln!("Failed to record cached thumbnail for gem {}: {}", gem.id, e);
+            gem
+        }
     }
 }
 
@@ -107,6 +202,14 @@ fn extract_recording_path(gem: &Gem) -> Option<PathBuf> {
     Some(data_dir.join("com.jarvis.app").join("recordings").join(filename))
 }
 
+/// Bare filename of a recording path, as keyed by `RecordingReadGuard`
+fn recording_file_name(recording_path: &Path) -> String {
+    recording_path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
 /// Result of content enrichment including AI-generated metadata and optional transcript
 struct EnrichmentResult {
     ai_enrichment: serde_json::Value,
@@ -114,10 +217,70 @@ struct EnrichmentResult {
     transcript_language: Option<String>,
 }
 
+/// Text to generate tags and a summary from
+///
+/// With `prefer_transcript`, a non-empty transcript wins over content; otherwise
+/// content, then description. `None` if the gem has nothing to enrich.
+fn enrichment_text(gem: &Gem, prefer_transcript: bool) -> Option<&str> {
+    let transcript = gem.transcript.as_deref().filter(|_| prefer_transcript);
+    transcript
+        .into_iter()
+        .chain(gem.content.as_deref())
+        .chain(gem.description.as_deref())
+        .find(|s| !s.trim().is_empty())
+}
+
+/// Copy refreshed page data from `gist` onto `gem`
+///
+/// Updates `content`, `description`, and `source_meta.image_url` /
+/// `source_meta.published_date` / `source_meta.content_hash`. Fields the
+/// extractor didn't return are left as they were; everything else (id,
+/// captured_at, tags, notes) is untouched.
+///
+/// Returns whether the extracted content differs from what was saved: when
+/// both the gem and the gist carry a `content_hash` (see
+/// `BrowserSettings::track_content_changes`) those are compared, otherwise
+/// the saved `content` itself.
+fn apply_reextracted_gist(gem: &mut Gem, gist: crate::browser::extractors::PageGist) -> bool {
+    let content_hash = |meta: &serde_json::Value| {
+        meta.get("content_hash").and_then(|h| h.as_str()).map(str::to_string)
+    };
+    let new_hash = content_hash(&gist.extra);
+    let changed = match (content_hash(&gem.source_meta), &new_hash) {
+        (Some(previous), Some(new)) => previous != *new,
+        _ => gist.content_excerpt.is_some() && gist.content_excerpt != gem.content,
+    };
+
+    if gist.content_excerpt.is_some() {
+        gem.content = gist.content_excerpt;
+    }
+    if gist.description.is_some() {
+        gem.description = gist.description;
+    }
+
+    if !gem.source_meta.is_object() {
+        gem.source_meta = serde_json::json!({});
+    }
+    let content_language = detect_content_language(gem.content.as_deref(), gem.description.as_deref());
+    for (key, value) in [
+        ("image_url", gist.image_url),
+        ("published_date", gist.published_date),
+        ("content_hash", new_hash),
+        ("content_language", content_language.map(str::to_string)),
+    ] {
+        if let Some(value) = value {
+            gem.source_meta[key] = serde_json::Value::String(value);
+        }
+    }
+
+    changed
+}
+
 /// Helper function to enrich content with AI-generated metadata and optional transcript
 /// 
 /// This function calls the IntelProvider to generate tags, summary, and optionally
 /// a transcript (for recording gems). It builds the complete enrichment result.
+/// When no new transcript is generated, the gem's existing one is kept.
 /// 
 /// # Arguments
 /// 
@@ -127,6 +290,7 @@ struct EnrichmentResult {
 /// * `provider_name` - The name of the provider being used
 /// * `model_name` - Optional model name (for MLX provider)
 /// * `transcription_engine` - The transcription engine setting ("whisper-rs", "whisperkit", "mlx-omni")
+/// * `timeouts` - Time limits for each provider call (`JarvisError::Timeout` when exceeded)
 /// 
 /// # Returns
 /// 
@@ -139,11 +303,19 @@ async fn enrich_content(
     provider_name: &str,
     model_name: Option<&str>,
     transcription_engine: &str,
-) -> Result<EnrichmentResult, String> {
+    timeouts: ProviderTimeouts,
+) -> JarvisResult<EnrichmentResult> {
+    let started = std::time::Instant::now();
+
     // Generate transcript first (if applicable) so we can use it for tags/summary
     let (transcript, transcript_language) = if transcription_engine == "mlx-omni" {
         if let Some(recording_path) = extract_recording_path(gem) {
-            match provider.generate_transcript(&recording_path).await {
+            // Blocks appending to this recording while it is transcribed
+            let transcribed = match crate::recording::RecordingReadGuard::acquire(&recording_file_name(&recording_path)) {
+                Ok(_reading) => with_timeout(timeouts.transcript_secs, "Transcription", provider.generate_transcript(&recording_path)).await,
+                Err(e) => Err(e),
+            };
+            match transcribed {
                 Ok(result) => (Some(result.transcript), Some(result.language)),
                 Err(e) => {
                     eprintln!("Failed to generate transcript for {}: {}",
@@ -162,10 +334,16 @@ async fn enrich_content(
     let text_for_enrichment = transcript.as_deref().unwrap_or(content);
 
     // Generate tags
-    let tags = provider.generate_tags(text_for_enrichment).await?;
+    let tags = with_timeout(timeouts.tags_secs, "Tag generation", provider.generate_tags(text_for_enrichment)).await?;
 
     // Generate summary
-    let summary = provider.summarize(text_for_enrichment).await?;
+    let summary = with_timeout(timeouts.summary_secs, "Summarization", provider.summarize(text_for_enrichment)).await?;
+
+    // Keep the gem's existing transcript when none was generated
+    let (transcript, transcript_language) = match transcript {
+        Some(t) => (Some(t), transcript_language),
+        None => (gem.transcript.clone(), gem.transcript_language.clone()),
+    };
 
     // Build ai_enrichment JSON
     let mut ai_enrichment = serde_json::json!({
@@ -179,6 +357,7 @@ async fn enrich_content(
         ai_enrichment["model"] = serde_json::Value::String(model.to_string());
     }
 
+    timings::record(TimedOperation::Enrich, started.elapsed());
     Ok(EnrichmentResult {
         ai_enrichment,
         transcript,
@@ -249,42 +428,60 @@ async fn enrich_content(
 #[tauri::command]
 pub async fn save_gem(
     app_handle: tauri::AppHandle,
-    gist: crate::browser::extractors::PageGist,
+    mut gist: crate::browser::extractors::PageGist,
     gem_store: State<'_, Arc<dyn GemStore>>,
     intel_provider: State<'_, Arc<dyn IntelProvider>>,
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
     // Convert PageGist to Gem using the helper function
     log_gem_save(&format!("save_gem called: url={}, title={}", gist.url, gist.title));
+    match validate_source_url(&gist.url) {
+        Ok(url) => gist.url = url.to_string(),
+        Err(e) => {
+            log_gem_save(&format!("save_gem: REJECTED {}", e));
+            return Err(e);
+        }
+    }
     let mut gem = page_gist_to_gem(gist);
     log_gem_save(&format!("save_gem: gem id={}, content_len={:?}", gem.id, gem.content.as_ref().map(|c| c.len())));
 
+    // Keep oversized page content out of the DB (and enrichment)
+    let max_content_chars = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence
+        .max_content_chars;
+    truncate_gem_content(&mut gem, max_content_chars);
+
     // Check if AI enrichment is available
     let availability = intel_provider.check_availability().await;
     log_gem_save(&format!("save_gem: intel available={}", availability.available));
 
     if availability.available {
         // Get provider name and model from settings
-        let (provider_name, model_name, transcription_engine) = {
+        let (provider_name, model_name, transcription_engine, prefer_transcript, timeouts) = {
             let manager = settings_manager.read()
                 .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
             let s = manager.get();
             (
                 s.intelligence.provider.clone(), 
                 s.intelligence.active_model.clone(),
-                s.transcription.transcription_engine.clone()
+                s.transcription.transcription_engine.clone(),
+                s.intelligence.prefer_transcript_for_enrichment,
+                ProviderTimeouts::from(&s.intelligence),
             )
         };
         let model_ref = if provider_name == "mlx" { Some(model_name.as_str()) } else { None };
 
-        // Get content for enrichment (prefer content, fall back to description)
-        let content_to_enrich = gem.content.as_ref()
-            .or(gem.description.as_ref())
-            .filter(|s| !s.trim().is_empty());
+        // Get text for enrichment (transcript if preferred, else content, else description)
+        let content_to_enrich = enrichment_text(&gem, prefer_transcript).map(str::to_string);
 
         if let Some(content) = content_to_enrich {
             // Try to enrich, but don't fail the save if enrichment fails
-            match enrich_content(&**intel_provider, content, &gem, &provider_name, model_ref, &transcription_engine).await {
+            match enrich_content(&**intel_provider, &content, &gem, &provider_name, model_ref, &transcription_engine, timeouts).await {
                 Ok(enrichment_result) => {
                     gem.ai_enrichment = Some(enrichment_result.ai_enrichment);
                     gem.transcript = enrichment_result.transcript;
@@ -301,7 +498,18 @@ pub async fn save_gem(
 
     // Save via GemStore trait (with or without enrichment)
     log_gem_save(&format!("save_gem: saving gem id={}", gem.id));
-    let result = gem_store.save(gem).await;
+    let result = gem_store.save(gem).await.map_err(String::from);
+
+    // Cache the OG image locally (keyed by the saved id — an upsert keeps the existing one)
+    let cache_images = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .browser
+        .cache_gem_images;
+    let result = match result {
+        Ok(saved_gem) if cache_images => Ok(cache_gem_image(&**gem_store, saved_gem).await),
+        other => other,
+    };
     match &result {
         Ok(g) => log_gem_save(&format!("save_gem: SUCCESS id={}", g.id)),
         Err(e) => log_gem_save(&format!("save_gem: ERROR {}", e)),
@@ -329,6 +537,325 @@ pub async fn save_gem(
     result
 }
 
+/// Save pasted text as a gem
+///
+/// Creates a `Note` gem with a generated `jarvis://note/{uuid}` source URL
+/// (see `new_text_gem`), then follows the same path as `save_gem`: optional
+/// AI enrichment, knowledge files, and search indexing. Given tags are kept
+/// ahead of any AI-generated ones.
+///
+/// # Arguments
+///
+/// * `title` - Note title (required)
+/// * `content` - Note text (required; truncated to `max_content_chars`)
+/// * `tags` - Optional tags
+/// * `enrich` - Run AI enrichment when available (default: true)
+///
+/// # Returns
+///
+/// * `Ok(Gem)` - The saved gem
+/// * `Err(String)` - Blank title or content, or the save failed
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn create_text_gem(
+    app_handle: tauri::AppHandle,
+    title: String,
+    content: String,
+    tags: Option<Vec<String>>,
+    enrich: Option<bool>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
+    let mut gem = new_text_gem(&title, &content, tags)?;
+
+    let settings = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get();
+    truncate_gem_content(&mut gem, settings.intelligence.max_content_chars);
+
+    if enrich.unwrap_or(true) && intel_provider.check_availability().await.available {
+        let provider_name = settings.intelligence.provider.as_str();
+        let model_ref = (provider_name == "mlx").then_some(settings.intelligence.active_model.as_str());
+        let content_to_enrich = gem.content.clone().unwrap_or_default();
+
+        match enrich_content(
+            &**intel_provider,
+            &content_to_enrich,
+            &gem,
+            provider_name,
+            model_ref,
+            &settings.transcription.transcription_engine,
+            ProviderTimeouts::from(&settings.intelligence),
+        ).await {
+            Ok(mut enrichment_result) => {
+                let tag_list = |v: Option<&serde_json::Value>| -> Vec<String> {
+                    v.and_then(|e| e.get("tags"))
+                        .and_then(|t| serde_json::from_value(t.clone()).ok())
+                        .unwrap_or_default()
+                };
+                let tags = merge_tags(
+                    tag_list(gem.ai_enrichment.as_ref()),
+                    tag_list(Some(&enrichment_result.ai_enrichment)),
+                );
+                enrichment_result.ai_enrichment["tags"] = serde_json::json!(tags);
+                gem.ai_enrichment = Some(enrichment_result.ai_enrichment);
+            }
+            Err(e) => eprintln!("Failed to enrich note {}: {}", gem.id, e),
+        }
+    }
+
+    let saved_gem = gem_store.save(gem).await.map_err(String::from)?;
+    after_gem_saved(&app_handle, &saved_gem).await;
+
+    Ok(saved_gem)
+}
+
+/// Write a saved gem's knowledge files and (re-)index it for search
+///
+/// Best-effort: failures are logged and don't undo the save.
+async fn after_gem_saved(app_handle: &tauri::AppHandle, gem: &Gem) {
+    write_knowledge_files(app_handle, gem).await;
+    index_saved_gem(app_handle, gem).await;
+}
+
+/// Write a saved gem's knowledge files (best-effort, like `after_gem_saved`)
+async fn write_knowledge_files(app_handle: &tauri::AppHandle, gem: &Gem) {
+    if let Some(ks) = app_handle.try_state::<Arc<dyn crate::knowledge::KnowledgeStore>>() {
+        if let Err(e) = ks.create(gem).await {
+            eprintln!("Knowledge file update failed for gem {}: {}", gem.id, e);
+        }
+    }
+}
+
+/// (Re-)index a saved gem for search (best-effort, like `after_gem_saved`)
+async fn index_saved_gem(app_handle: &tauri::AppHandle, gem: &Gem) {
+    if let Some(provider) = app_handle.try_state::<Arc<dyn SearchResultProvider>>() {
+        if let Err(e) = provider.index_gem(&gem.id).await {
+            eprintln!("Search: Failed to index gem {}: {}", gem.id, e);
+        }
+    }
+}
+
+/// Counts reported by the import commands
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ImportReport {
+    /// Gems created
+    pub imported: usize,
+
+    /// Items already in the library, or that couldn't be read or parsed
+    pub skipped: usize,
+}
+
+/// Import a folder of Markdown notes as gems
+///
+/// Walks `path` recursively for `.md` files (hidden files and folders are
+/// skipped) and saves each as a `Note` gem. Frontmatter `title`, `tags` and
+/// `date` are used when present; otherwise the title comes from the first
+/// `# ` heading or the filename (see `gems::markdown_import`).
+///
+/// Each file gets a `jarvis://note/{hash}` source URL derived from its path,
+/// so importing the same folder again skips files already imported.
+/// Content is truncated to `max_content_chars` as in `save_gem`. Imported
+/// notes are not enriched; use `enrich_gem` afterwards if wanted.
+///
+/// # Returns
+///
+/// * `Ok(ImportReport)` - Imported and skipped counts
+/// * `Err(String)` - `path` isn't a readable directory
+#[tauri::command]
+pub async fn import_markdown_dir(
+    path: String,
+    app_handle: tauri::AppHandle,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<ImportReport, String> {
+    use crate::gems::markdown_import::{find_markdown_files, note_url_for_path, MarkdownNote};
+
+    demo_mode.ensure_writable()?;
+
+    let max_content_chars = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence
+        .max_content_chars;
+
+    let dir = std::fs::canonicalize(&path)
+        .map_err(|e| format!("Cannot open folder '{}': {}", path, e))?;
+    if !dir.is_dir() {
+        return Err(format!("'{}' is not a folder", path));
+    }
+    let files = find_markdown_files(&dir)
+        .map_err(|e| format!("Failed to read folder '{}': {}", path, e))?;
+
+    let mut report = ImportReport::default();
+    for file in files {
+        let source_url = note_url_for_path(&file);
+        if gem_store.find_by_source_url(&source_url).await.map_err(String::from)?.is_some() {
+            report.skipped += 1;
+            continue;
+        }
+
+        let text = match tokio::fs::read_to_string(&file).await {
+            Ok(text) => text,
+            Err(e) => {
+                eprintln!("Import: Skipping {}: {}", file.display(), e);
+                report.skipped += 1;
+                continue;
+            }
+        };
+        let file_name = file.file_name().and_then(|n| n.to_str()).unwrap_or_default();
+        let note = MarkdownNote::parse(file_name, &text);
+
+        let mut gem = match new_text_gem(&note.title, &note.body, Some(note.tags)) {
+            Ok(gem) => gem,
+            Err(e) => {
+                eprintln!("Import: Skipping {}: {}", file.display(), e);
+                report.skipped += 1;
+                continue;
+            }
+        };
+        gem.source_url = source_url;
+        gem.source_meta = serde_json::json!({
+            "source": "markdown_import",
+            "path": file.to_string_lossy(),
+        });
+        if let Some(date) = note.date {
+            gem.captured_at = date;
+        }
+
+        truncate_gem_content(&mut gem, max_content_chars);
+
+        let saved_gem = gem_store.save(gem).await.map_err(String::from)?;
+        report.imported += 1;
+        after_gem_saved(&app_handle, &saved_gem).await;
+    }
+
+    Ok(report)
+}
+
+/// Pages fetched at once when `import_bookmarks` runs extractors
+const BOOKMARK_EXTRACT_CONCURRENCY: usize = 4;
+
+/// Import a browser bookmarks export as gems
+///
+/// Accepts a Netscape bookmark HTML export or Chrome's `Bookmarks` JSON (see
+/// `browser::bookmarks`). Each bookmark becomes a gem with its URL and title,
+/// its `source_type` classified by `tabs::classify_url`, and its content
+/// truncated to `max_content_chars`. Bookmarks already in the library are
+/// skipped.
+///
+/// With `extract`, the page extractors run to fill in content and metadata,
+/// at most `BOOKMARK_EXTRACT_CONCURRENCY` pages at a time; a page that fails
+/// to extract is still saved with its URL and title. A
+/// `bookmark-import-progress` event with `{ processed, total, imported }` is
+/// emitted after each bookmark.
+///
+/// # Arguments
+///
+/// * `path` - Path to the export file
+/// * `extract` - Fetch each page to fill in content (default: false)
+///
+/// # Returns
+///
+/// * `Ok(ImportReport)` - Imported and skipped counts
+/// * `Err(String)` - The file can't be read or parsed, or a save fails
+#[tauri::command]
+pub async fn import_bookmarks(
+    path: String,
+    extract: Option<bool>,
+    app_handle: tauri::AppHandle,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<ImportReport, String> {
+    use crate::browser::extractors::{prepare_gist, PageGist};
+    use crate::browser::tabs::{classify_url, extract_domain};
+    use futures_util::StreamExt;
+
+    demo_mode.ensure_writable()?;
+
+    let text = tokio::fs::read_to_string(&path).await
+        .map_err(|e| format!("Failed to read bookmarks file '{}': {}", path, e))?;
+    let bookmarks = crate::browser::bookmarks::parse_bookmarks(&text)?;
+    let total = bookmarks.len();
+    let gist_options = gist_options(&settings_manager)?;
+    let max_content_chars = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence
+        .max_content_chars;
+
+    let mut report = ImportReport::default();
+    let mut new_bookmarks = Vec::new();
+    for bookmark in bookmarks {
+        if gem_store.find_by_source_url(&bookmark.url).await?.is_some() {
+            report.skipped += 1;
+        } else {
+            new_bookmarks.push(bookmark);
+        }
+    }
+
+    let extract = extract.unwrap_or(false);
+    let gist_options = &gist_options;
+    let mut gists = futures_util::stream::iter(new_bookmarks)
+        .map(|bookmark| async move {
+            let source_type = classify_url(&bookmark.url);
+            let extracted = if extract {
+                prepare_gist(&bookmark.url, &source_type, gist_options).await
+                    .map_err(|e| eprintln!("Import: Extraction failed for {}: {}", bookmark.url, e))
+                    .ok()
+            } else {
+                None
+            };
+            let mut gist = extracted.unwrap_or_else(|| PageGist {
+                domain: extract_domain(&bookmark.url),
+                url: bookmark.url.clone(),
+                title: String::new(),
+                source_type,
+                author: None,
+                description: None,
+                content_excerpt: None,
+                published_date: None,
+                image_url: None,
+                extra: serde_json::json!({}),
+            });
+            if gist.title.trim().is_empty() {
+                gist.title = if bookmark.title.is_empty() { bookmark.url } else { bookmark.title };
+            }
+            gist
+        })
+        .buffered(BOOKMARK_EXTRACT_CONCURRENCY);
+
+    let mut processed = report.skipped;
+    while let Some(gist) = gists.next().await {
+        let mut gem = page_gist_to_gem(gist);
+        truncate_gem_content(&mut gem, max_content_chars);
+
+        let saved_gem = gem_store.save(gem).await?;
+        report.imported += 1;
+        processed += 1;
+        after_gem_saved(&app_handle, &saved_gem).await;
+
+        let _ = app_handle.emit("bookmark-import-progress", serde_json::json!({
+            "processed": processed,
+            "total": total,
+            "imported": report.imported,
+        }));
+    }
+
+    crate::notifications::notify_job_completed(
+        &app_handle,
+        "Bookmark import",
+        &format!("Imported {} of {} bookmarks", report.imported, total),
+    );
+    Ok(report)
+}
+
 /// List gems with pagination
 ///
 /// This command returns all gems ordered by captured_at descending (most recent first).
@@ -338,6 +865,7 @@ pub async fn save_gem(
 ///
 /// * `limit` - Optional maximum number of gems to return (default: 50)
 /// * `offset` - Optional number of gems to skip for pagination (default: 0)
+/// * `preview_chars` - Optional length of `content_preview` in characters (default: 200)
 /// * `gem_store` - Managed state containing the GemStore trait object
 ///
 /// # Returns
@@ -364,7 +892,7 @@ pub async fn save_gem(
 ///   title: string;
 ///   author?: string;
 ///   description?: string;
-///   content_preview?: string;  // Truncated to 200 characters
+///   content_preview?: string;  // Truncated to preview_chars (default 200)
 ///   captured_at: string;
 /// }
 ///
@@ -386,14 +914,22 @@ pub async fn save_gem(
 /// } catch (error) {
 ///   console.error(`Failed to list gems: ${error}`);
 /// }
+///
+/// // Dense list view with longer previews
+/// const gems: GemPreview[] = await invoke('list_gems', { previewChars: 500 });
 /// ```
 #[tauri::command]
 pub async fn list_gems(
     limit: Option<usize>,
     offset: Option<usize>,
+    preview_chars: Option<usize>,
     gem_store: State<'_, Arc<dyn GemStore>>,
 ) -> Result<Vec<GemPreview>, String> {
-    gem_store.list(limit.unwrap_or(50), offset.unwrap_or(0)).await
+    gem_store.list_with_preview(
+        limit.unwrap_or(50),
+        offset.unwrap_or(0),
+        preview_chars.unwrap_or(DEFAULT_PREVIEW_CHARS),
+    ).await.map_err(String::from)
 }
 
 /// Delete a gem by ID
@@ -437,27 +973,84 @@ pub async fn delete_gem(
     app_handle: tauri::AppHandle,
     id: String,
     gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
-    gem_store.delete(&id).await?;
+    demo_mode.ensure_writable()?;
+
+    if !gem_store.delete(&id).await? {
+        return Err(format!("Gem with id '{}' not found", id));
+    }
+    remove_gem_artifacts(&app_handle, &id, "delete_gem").await;
+    Ok(())
+}
+
+/// Delete several gems at once
+///
+/// IDs that don't exist are skipped rather than failing the batch. Stops at
+/// the first storage error; gems deleted before it stay deleted.
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - IDs of the gems that were deleted
+/// * `Err(String)` - Error message if a delete fails
+#[tauri::command]
+pub async fn delete_gems(
+    app_handle: tauri::AppHandle,
+    ids: Vec<String>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Vec<String>, String> {
+    demo_mode.ensure_writable()?;
+
+    let (deleted, error) = delete_existing_gems(&**gem_store, ids).await;
+    for id in &deleted {
+        remove_gem_artifacts(&app_handle, id, "delete_gems").await;
+    }
+    match error {
+        Some(e) => Err(e),
+        None => Ok(deleted),
+    }
+}
+
+/// Delete `ids` in order, skipping missing ones, until a storage error
+///
+/// Returns the IDs deleted and the error that stopped the batch, if any.
+async fn delete_existing_gems(gem_store: &dyn GemStore, ids: Vec<String>) -> (Vec<String>, Option<String>) {
+    let mut deleted = Vec::new();
+    for id in ids {
+        match gem_store.delete(&id).await {
+            Ok(true) => deleted.push(id),
+            Ok(false) => {}
+            Err(e) => return (deleted, Some(e.to_string())),
+        }
+    }
+    (deleted, None)
+}
+
+/// Remove a deleted gem's thumbnail, knowledge files, and search index entry
+///
+/// Best-effort: failures are logged. `caller` names the command in search logs.
+async fn remove_gem_artifacts(app_handle: &tauri::AppHandle, id: &str, caller: &str) {
+    if let Err(e) = crate::gems::thumbnails::remove_thumbnail(id).await {
+        eprintln!("Thumbnail deletion failed for gem {}: {}", id, e);
+    }
     
     // Delete knowledge files
     if let Some(ks) = app_handle.try_state::<Arc<dyn crate::knowledge::KnowledgeStore>>() {
-        if let Err(e) = ks.delete(&id).await {
+        if let Err(e) = ks.delete(id).await {
             eprintln!("Knowledge file deletion failed for gem {}: {}", id, e);
         }
     }
     
     // Remove from search index
     if let Some(provider) = app_handle.try_state::<Arc<dyn SearchResultProvider>>() {
-        eprintln!("Search: Removing gem {} from index (delete_gem)", id);
-        if let Err(e) = provider.remove_gem(&id).await {
+        eprintln!("Search: Removing gem {} from index ({})", id, caller);
+        if let Err(e) = provider.remove_gem(id).await {
             eprintln!("Search: Failed to remove gem {}: {}", id, e);
         } else {
-            eprintln!("Search: Remove request sent for gem {} (delete_gem)", id);
+            eprintln!("Search: Remove request sent for gem {} ({})", id, caller);
         }
     }
-
-    Ok(())
 }
 
 #[tauri::command]
@@ -465,122 +1058,863 @@ pub async fn update_gem_title(
     id: String,
     title: String,
     gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
-    gem_store.update_title(&id, &title).await
+    demo_mode.ensure_writable()?;
+
+    gem_store.update_title(&id, &title).await.map_err(String::from)
 }
 
-/// Get a gem by ID
-///
-/// This command retrieves a gem from the store by its unique identifier.
-/// Returns None if the gem is not found (not an error), returns Err only
-/// on database errors.
-///
-/// # Arguments
-///
-/// * `id` - The unique identifier of the gem to retrieve
-/// * `gem_store` - Managed state containing the GemStore trait object
-///
-/// # Returns
+/// Set the user's own notes on a gem
 ///
-/// * `Ok(Some(Gem))` - Gem found and returned with full content
-/// * `Ok(None)` - Gem not found (not an error condition)
-/// * `Err(String)` - Error message if database operation fails
+/// Notes are separate from the AI summary, so re-enriching a gem leaves them
+/// untouched. Blank notes clear the field.
+#[tauri::command]
+pub async fn set_gem_notes(
+    id: String,
+    notes: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
+    let notes = notes.trim();
+    let notes = if notes.is_empty() { None } else { Some(notes) };
+    gem_store.update_notes(&id, notes).await?;
+
+    gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))
+}
+
+/// Check a color label against the fixed palette (`GEM_LABELS`)
 ///
-/// # Errors
+/// Matching is case-insensitive; returns the canonical lowercase name.
+fn validate_gem_label(label: &str) -> Result<&'static str, String> {
+    let label = label.trim().to_lowercase();
+    GEM_LABELS
+        .iter()
+        .find(|l| **l == label)
+        .copied()
+        .ok_or_else(|| format!("Unknown label '{}'. Expected one of: {}", label, GEM_LABELS.join(", ")))
+}
+
+/// Set or clear a gem's color label
 ///
-/// Returns an error if:
-/// - The GemStore get operation fails
-/// - Database connection issues occur
+/// A gem has at most one label (unlike tags), taken from a fixed palette:
+/// red, orange, yellow, green, blue, purple, gray. Pass `null` to clear it.
+/// Returns the updated gem, like `set_gem_notes`.
+#[tauri::command]
+pub async fn set_gem_label(
+    id: String,
+    label: Option<String>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
+    let label = label.as_deref().map(validate_gem_label).transpose()?;
+    gem_store.update_label(&id, label).await?;
+
+    gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))
+}
+
+/// List gems with the given color label (most recent first)
+#[tauri::command]
+pub async fn list_gems_by_label(
+    label: String,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<GemPreview>, String> {
+    let label = validate_gem_label(&label)?;
+    gem_store.filter_by_label(label, limit.unwrap_or(50), offset.unwrap_or(0)).await.map_err(String::from)
+}
+
+/// Record that the user opened a gem
 ///
-/// Note: A missing gem (None) is not considered an error - it's a valid result
-/// indicating the gem doesn't exist in the store.
+/// Call when a gem's detail view is shown. Bumps its view count and sets
+/// `last_viewed_at`, which feed `list_recently_viewed` and
+/// `list_most_viewed`. Views aren't recorded in demo mode.
+#[tauri::command]
+pub async fn mark_gem_viewed(
+    id: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<(), String> {
+    if demo_mode.is_enabled() {
+        return Ok(());
+    }
+    gem_store.mark_viewed(&id).await.map_err(String::from)
+}
+
+/// List gems the user has opened, most recently viewed first
 ///
 /// # Examples
 ///
 /// ```typescript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// interface Gem {
-///   id: string;
-///   source_type: string;
-///   source_url: string;
-///   domain: string;
-///   title: string;
-///   author?: string;
-///   description?: string;
-///   content?: string;
-///   source_meta: Record<string, any>;
-///   captured_at: string;
-/// }
-///
-/// try {
-///   const gem: Gem | null = await invoke('get_gem', {
-///     id: '550e8400-e29b-41d4-a716-446655440000'
-///   });
-///   
-///   if (gem) {
-///     console.log(`Found gem: ${gem.title}`);
-///   } else {
-///     console.log('Gem not found');
-///   }
-/// } catch (error) {
-///   console.error(`Database error: ${error}`);
+/// const recent: ViewedGem[] = await invoke('list_recently_viewed', { limit: 10 });
+/// ```
+#[tauri::command]
+pub async fn list_recently_viewed(
+    limit: Option<usize>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<crate::gems::ViewedGem>, String> {
+    gem_store.list_recently_viewed(limit.unwrap_or(20)).await.map_err(String::from)
+}
+
+/// List gems the user has opened, most often viewed first
+#[tauri::command]
+pub async fn list_most_viewed(
+    limit: Option<usize>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<crate::gems::ViewedGem>, String> {
+    gem_store.list_most_viewed(limit.unwrap_or(20)).await.map_err(String::from)
+}
+
+/// Fold `secondary` into `primary`
+///
+/// Transcripts are joined in chronological order (by `captured_at`), tags are
+/// unioned (case-insensitive, primary's first), and everything else keeps the
+/// primary's values. The secondary's recording filename is recorded in
+/// `source_meta.merged_recording_filenames`.
+fn merge_gem_contents(primary: &mut Gem, secondary: &Gem) {
+    let transcripts = if secondary.captured_at < primary.captured_at {
+        [secondary.transcript.as_deref(), primary.transcript.as_deref()]
+    } else {
+        [primary.transcript.as_deref(), secondary.transcript.as_deref()]
+    };
+    let parts: Vec<&str> = transcripts
+        .into_iter()
+        .flatten()
+        .filter(|t| !t.trim().is_empty())
+        .collect();
+    if !parts.is_empty() {
+        primary.transcript = Some(parts.join("\n\n"));
+    }
+    if primary.transcript_language.is_none() {
+        primary.transcript_language = secondary.transcript_language.clone();
+    }
+
+    let tags_of = |gem: &Gem| -> Vec<String> {
+        gem.ai_enrichment
+            .as_ref()
+            .and_then(|e| e.get("tags"))
+            .and_then(|t| serde_json::from_value(t.clone()).ok())
+            .unwrap_or_default()
+    };
+    let mut tags = tags_of(primary);
+    for tag in tags_of(secondary) {
+        if !tags.iter().any(|t| t.eq_ignore_ascii_case(&tag)) {
+            tags.push(tag);
+        }
+    }
+    match primary.ai_enrichment.as_mut() {
+        Some(enrichment) if enrichment.is_object() => enrichment["tags"] = serde_json::json!(tags),
+        _ => {
+            if let Some(enrichment) = secondary.ai_enrichment.clone() {
+                primary.ai_enrichment = Some(enrichment);
+            }
+        }
+    }
+
+    if let Some(filename) = secondary.source_meta.get("recording_filename").cloned() {
+        if !primary.source_meta.is_object() {
+            primary.source_meta = serde_json::json!({});
+        }
+        let merged = &mut primary.source_meta["merged_recording_filenames"];
+        if !merged.is_array() {
+            *merged = serde_json::json!([]);
+        }
+        if let Some(list) = merged.as_array_mut() {
+            list.push(filename);
+        }
+    }
+}
+
+/// Merge two gems for parts of the same meeting into one
+///
+/// Concatenates transcripts in chronological order, unions tags, and keeps the
+/// primary's title and metadata. Projects containing the secondary gem gain
+/// the primary instead. The secondary gem and its knowledge files are then deleted.
+///
+/// # Arguments
+///
+/// * `primary_id` - The gem to keep
+/// * `secondary_id` - The gem merged into it and deleted
+///
+/// # Returns
+///
+/// * `Ok(Gem)` - The merged primary gem
+/// * `Err(String)` - Either gem is missing, the IDs are equal, or a store operation failed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const merged = await invoke('merge_recording_gems', { primaryId, secondaryId });
+/// ```
+#[tauri::command]
+pub async fn merge_recording_gems(
+    app_handle: tauri::AppHandle,
+    primary_id: String,
+    secondary_id: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    project_store: State<'_, Arc<dyn crate::projects::ProjectStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
+    if primary_id == secondary_id {
+        return Err("Cannot merge a gem into itself".to_string());
+    }
+    let mut primary = gem_store.get(&primary_id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", primary_id))?;
+    let secondary = gem_store.get(&secondary_id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", secondary_id))?;
+
+    // Move project memberships before deleting cascades them away
+    for project in project_store.get_gem_projects(&secondary_id).await? {
+        project_store.add_gems(&project.id, std::slice::from_ref(&primary_id)).await?;
+    }
+
+    merge_gem_contents(&mut primary, &secondary);
+    let merged = gem_store.merge_into(primary, &secondary_id).await?;
+    remove_gem_artifacts(&app_handle, &secondary_id, "merge_recording_gems").await;
+
+    // Regenerate the primary's knowledge files and search entry
+    after_gem_saved(&app_handle, &merged).await;
+
+    eprintln!("Gems: Merged gem {} into {}", secondary_id, merged.id);
+    Ok(merged)
+}
+
+/// Compact the gem database
+///
+/// Runs FTS index optimization followed by `VACUUM` so that space freed by
+/// deleted gems is returned to the filesystem. Intended as an occasional
+/// maintenance action for large libraries.
+///
+/// **Note:** The database connection is locked for the entire operation, so
+/// other gem and project commands will wait until compaction completes.
+///
+/// # Arguments
+///
+/// * `gem_store` - Managed state containing the GemStore trait object
+///
+/// # Returns
+///
+/// * `Ok(CompactionResult)` - Database size in bytes before and after compaction
+/// * `Err(String)` - Error message if compaction fails
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { size_before_bytes, size_after_bytes } = await invoke('compact_database');
+/// console.log(`Reclaimed ${size_before_bytes - size_after_bytes} bytes`);
+/// ```
+#[tauri::command]
+pub async fn compact_database(
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<CompactionResult, String> {
+    demo_mode.ensure_writable()?;
+
+    let result = gem_store.compact().await?;
+    eprintln!(
+        "Gems: Compacted database {} -> {} bytes",
+        result.size_before_bytes, result.size_after_bytes
+    );
+    Ok(result)
+}
+
+/// Report from `repair_library`
+#[derive(Debug, Clone, Serialize)]
+pub struct LibraryRepairReport {
+    /// What was found and fixed in the full-text search index
+    pub search_index: SearchIndexRepair,
+
+    /// IDs of gems that have no knowledge files (regenerate with
+    /// `regenerate_gem_knowledge`)
+    pub gems_missing_knowledge: Vec<String>,
+}
+
+/// Check the gem library for inconsistencies and fix what can be fixed
+///
+/// Recreates missing FTS sync triggers, drops index entries for gems that no
+/// longer exist, re-indexes gems missing from the index, and reports gems
+/// without knowledge files. Safe to run repeatedly; a healthy library is left
+/// untouched.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke('repair_library');
+/// if (report.search_index.index_rebuilt) console.log('Search index rebuilt');
+/// ```
+#[tauri::command]
+pub async fn repair_library(
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    knowledge_store: State<'_, Arc<dyn crate::knowledge::KnowledgeStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<LibraryRepairReport, String> {
+    demo_mode.ensure_writable()?;
+
+    let search_index = gem_store.repair_search_index().await?;
+    eprintln!(
+        "Gems: Repair — triggers restored: {}, {} orphaned / {} missing index entries, rebuilt: {}",
+        search_index.triggers_restored,
+        search_index.orphaned_entries,
+        search_index.missing_entries,
+        search_index.index_rebuilt
+    );
+
+    let indexed: std::collections::HashSet<String> =
+        knowledge_store.list_indexed().await?.into_iter().collect();
+    let gems_missing_knowledge: Vec<String> = crate::gems::list_all_gems(&**gem_store)
+        .await?
+        .into_iter()
+        .map(|gem| gem.id)
+        .filter(|id| !indexed.contains(id))
+        .collect();
+
+    Ok(LibraryRepairReport {
+        search_index,
+        gems_missing_knowledge,
+    })
+}
+
+/// Rebuild the keyword (FTS) search index from the gems table
+///
+/// Clears the index and re-indexes every gem, recreating the sync triggers.
+/// Unlike `repair_library`, it doesn't check anything first, so it also
+/// fixes entries that exist but hold stale text (e.g. after editing the
+/// database by hand). Holds the database lock while it runs.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of gems indexed
+/// * `Err(String)` - Database error (the index is left as it was)
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const count = await invoke('rebuild_fts_index');
+/// console.log(`Re-indexed ${count} gems`);
+/// ```
+#[tauri::command]
+pub async fn rebuild_fts_index(
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<usize, String> {
+    demo_mode.ensure_writable()?;
+
+    let count = gem_store.rebuild_fts_index().await?;
+    eprintln!("Gems: Rebuilt FTS index ({} gems)", count);
+    Ok(count)
+}
+
+/// Back up the whole app to a single archive
+///
+/// Bundles the gems database (gems, projects, search history), settings
+/// (without API keys), the knowledge folder and recording sidecar files into
+/// a `.tar.gz` at `out_path`. The database is snapshotted while its
+/// connection is locked, so gem and project commands wait until the snapshot
+/// is taken and the archive never contains a half-written transaction.
+///
+/// # Arguments
+///
+/// * `out_path` - Where to write the archive; must not already exist
+/// * `include_recordings` - Also include recording audio (default `false`;
+///   audio can be large)
+///
+/// # Returns
+///
+/// * `Ok(BackupManifest)` - What the archive contains
+/// * `Err(String)` - `out_path` exists, or the snapshot or archive failed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const manifest = await invoke('create_backup', {
+///   outPath: '/Users/me/Desktop/jarvis-backup.tar.gz',
+///   includeRecordings: false,
+/// });
+/// ```
+#[tauri::command]
+pub async fn create_backup(
+    app_handle: AppHandle,
+    out_path: String,
+    include_recordings: Option<bool>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    file_manager: State<'_, FileManager>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<crate::backup::BackupManifest, String> {
+    demo_mode.ensure_writable()?;
+
+    let out_path = PathBuf::from(out_path);
+    if out_path.exists() {
+        return Err(format!("'{}' already exists", out_path.display()));
+    }
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let settings = settings_manager
+        .read()
+        .map_err(|e| format!("Failed to acquire read lock: {}", e))?
+        .get();
+    let recordings_dir = file_manager.get_recordings_dir().to_path_buf();
+
+    std::fs::create_dir_all(&app_data_dir).map_err(|e| format!("Failed to create app data dir: {}", e))?;
+    let snapshot = app_data_dir.join(format!(".backup-{}.db", uuid::Uuid::new_v4()));
+    gem_store.backup_to(&snapshot).await?;
+
+    let include_recordings = include_recordings.unwrap_or(false);
+    let result = tokio::task::spawn_blocking({
+        let snapshot = snapshot.clone();
+        move || {
+            crate::backup::write_archive(
+                &out_path,
+                &crate::backup::BackupSources {
+                    db_snapshot: &snapshot,
+                    settings: &settings,
+                    knowledge_dir: &app_data_dir.join("knowledge"),
+                    recordings_dir: &recordings_dir,
+                },
+                include_recordings,
+            )
+        }
+    })
+    .await
+    .map_err(|e| format!("Backup task failed: {}", e));
+    let _ = std::fs::remove_file(&snapshot);
+
+    let manifest = result??;
+    eprintln!("Backup: Created backup (recordings included: {})", manifest.includes_recordings);
+    Ok(manifest)
+}
+
+/// Replace `dir` with the copy moved aside to `previous`, or remove it if
+/// there was none
+fn put_back_dir(previous: &std::path::Path, dir: &std::path::Path) {
+    let _ = std::fs::remove_dir_all(dir);
+    if !previous.exists() {
+        return;
+    }
+    if let Err(e) = std::fs::rename(previous, dir) {
+        eprintln!("Backup: Failed to put back {}: {}", dir.display(), e);
+    }
+}
+
+/// Restore the whole app from a `create_backup` archive
+///
+/// Replaces the gems database, settings and knowledge folder with the
+/// backup's copies; recording files from the backup are added alongside
+/// existing ones. The archive is unpacked and validated before anything is
+/// touched. The knowledge folder is swapped in first and put back if the
+/// database can't be restored, so a failed restore leaves the library as it
+/// was. API keys aren't in backups, so the current ones are kept. The search
+/// index is rebuilt in the background afterwards.
+///
+/// # Arguments
+///
+/// * `in_path` - Archive written by `create_backup`
+/// * `overwrite` - Must be `true`; confirms the current library is replaced
+///
+/// # Returns
+///
+/// * `Ok(BackupManifest)` - Manifest of the restored backup
+/// * `Err(String)` - Not confirmed, recording in progress, invalid archive,
+///   or a restore step failed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// if (confirm('Replace your library with this backup?')) {
+///   await invoke('restore_backup', { inPath: path, overwrite: true });
 /// }
 /// ```
 #[tauri::command]
-pub async fn get_gem(
-    id: String,
+#[allow(clippy::too_many_arguments)]
+pub async fn restore_backup(
+    app_handle: AppHandle,
+    in_path: String,
+    overwrite: bool,
     gem_store: State<'_, Arc<dyn GemStore>>,
-) -> Result<Option<Gem>, String> {
-    gem_store.get(&id).await
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    file_manager: State<'_, FileManager>,
+    recording_manager: State<'_, Mutex<RecordingManager>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<crate::backup::BackupManifest, String> {
+    use crate::backup::{DATABASE_FILE, KNOWLEDGE_DIR, RECORDINGS_DIR, SETTINGS_FILE};
+
+    demo_mode.ensure_writable()?;
+
+    if !overwrite {
+        return Err("Restoring a backup replaces the current library; pass overwrite to confirm".to_string());
+    }
+    if recording_manager
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock: {}", e))?
+        .is_recording()
+    {
+        return Err("Cannot restore a backup while recording".to_string());
+    }
+
+    let app_data_dir = app_handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("Failed to get app data dir: {}", e))?;
+    let staging = app_data_dir.join(format!(".restore-{}", uuid::Uuid::new_v4()));
+    let recordings_dir = file_manager.get_recordings_dir().to_path_buf();
+
+    let result = async {
+        let (manifest, mut settings) = tokio::task::spawn_blocking({
+            let staging = staging.clone();
+            move || -> JarvisResult<(crate::backup::BackupManifest, Settings)> {
+                let manifest = crate::backup::unpack_archive(std::path::Path::new(&in_path), &staging)?;
+                let text = std::fs::read_to_string(staging.join(SETTINGS_FILE))?;
+                let settings: Settings = serde_json::from_str(&text)
+                    .map_err(|e| JarvisError::Parse(format!("Invalid settings in backup: {}", e)))?;
+                Ok((manifest, settings))
+            }
+        })
+        .await
+        .map_err(|e| format!("Restore task failed: {}", e))??;
+
+        // Knowledge first, keeping the current folder aside until the
+        // database swap has succeeded
+        let knowledge_dir = app_data_dir.join("knowledge");
+        let previous_knowledge = app_data_dir.join(format!(".knowledge-pre-restore-{}", uuid::Uuid::new_v4()));
+        tokio::task::spawn_blocking({
+            let staging = staging.clone();
+            let knowledge_dir = knowledge_dir.clone();
+            let previous_knowledge = previous_knowledge.clone();
+            move || -> JarvisResult<()> {
+                if knowledge_dir.exists() {
+                    std::fs::rename(&knowledge_dir, &previous_knowledge)?;
+                }
+                let restored = crate::backup::restore_dir(&staging.join(KNOWLEDGE_DIR), &knowledge_dir, true);
+                if restored.is_err() {
+                    put_back_dir(&previous_knowledge, &knowledge_dir);
+                }
+                restored
+            }
+        })
+        .await
+        .map_err(|e| format!("Restore task failed: {}", e))??;
+
+        if let Err(e) = gem_store.restore_from(&staging.join(DATABASE_FILE)).await {
+            put_back_dir(&previous_knowledge, &knowledge_dir);
+            return Err(e.into());
+        }
+        let _ = std::fs::remove_dir_all(&previous_knowledge);
+
+        {
+            let manager = settings_manager
+                .read()
+                .map_err(|e| format!("Failed to acquire read lock: {}", e))?;
+            settings.keep_secrets(&manager.get());
+            manager.update(settings.clone())?;
+        }
+        crate::browser::http::configure(&settings.network)?;
+        let _ = app_handle.emit("settings-changed", &settings.redacted());
+
+        tokio::task::spawn_blocking({
+            let staging = staging.clone();
+            move || crate::backup::restore_dir(&staging.join(RECORDINGS_DIR), &recordings_dir, false)
+        })
+        .await
+        .map_err(|e| format!("Restore task failed: {}", e))??;
+
+        Ok::<_, String>(manifest)
+    }
+    .await;
+    let _ = std::fs::remove_dir_all(&staging);
+    let manifest = result?;
+
+    if let Some(provider) = app_handle.try_state::<Arc<dyn SearchResultProvider>>() {
+        let provider = provider.inner().clone();
+        tokio::spawn(async move {
+            match provider.reindex_all().await {
+                Ok(count) => eprintln!("Backup: Re-indexed {} gems after restore", count),
+                Err(e) => eprintln!("Backup: Re-index after restore failed: {}", e),
+            }
+        });
+    }
+
+    eprintln!("Backup: Restored backup created {}", manifest.created_at);
+    Ok(manifest)
 }
 
-/// Enrich a gem with AI-generated tags and summary
+/// Get a gem by ID
 ///
-/// This command enriches an existing gem by generating tags and a summary
-/// using the IntelProvider. It fetches the gem, enriches it, and saves it back.
+/// This command retrieves a gem from the store by its unique identifier.
+/// Returns None if the gem is not found (not an error), returns Err only
+/// on database errors.
 ///
 /// # Arguments
 ///
-/// * `id` - The unique identifier of the gem to enrich
+/// * `id` - The unique identifier of the gem to retrieve
 /// * `gem_store` - Managed state containing the GemStore trait object
-/// * `intel_provider` - Managed state containing the IntelProvider trait object
 ///
 /// # Returns
 ///
-/// * `Ok(Gem)` - The enriched gem with ai_enrichment populated
-/// * `Err(String)` - Error message if enrichment fails
+/// * `Ok(Some(Gem))` - Gem found and returned with full content
+/// * `Ok(None)` - Gem not found (not an error condition)
+/// * `Err(String)` - Error message if database operation fails
 ///
 /// # Errors
 ///
 /// Returns an error if:
-/// - The IntelProvider is not available
-/// - The gem with the specified ID does not exist
-/// - The gem has no content or description to enrich
-/// - The enrichment process fails (tag generation or summarization)
+/// - The GemStore get operation fails
+/// - Database connection issues occur
+///
+/// Note: A missing gem (None) is not considered an error - it's a valid result
+/// indicating the gem doesn't exist in the store.
 ///
 /// # Examples
 ///
 /// ```typescript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
+/// interface Gem {
+///   id: string;
+///   source_type: string;
+///   source_url: string;
+///   domain: string;
+///   title: string;
+///   author?: string;
+///   description?: string;
+///   content?: string;
+///   source_meta: Record<string, any>;
+///   captured_at: string;
+/// }
+///
 /// try {
-///   const enrichedGem = await invoke('enrich_gem', {
+///   const gem: Gem | null = await invoke('get_gem', {
 ///     id: '550e8400-e29b-41d4-a716-446655440000'
 ///   });
-///   console.log(`Enriched with ${enrichedGem.ai_enrichment.tags.length} tags`);
+///   
+///   if (gem) {
+///     console.log(`Found gem: ${gem.title}`);
+///   } else {
+///     console.log('Gem not found');
+///   }
 /// } catch (error) {
-///   console.error(`Failed to enrich gem: ${error}`);
+///   console.error(`Database error: ${error}`);
 /// }
 /// ```
 #[tauri::command]
-pub async fn enrich_gem(
-    app_handle: tauri::AppHandle,
+pub async fn get_gem(
     id: String,
     gem_store: State<'_, Arc<dyn GemStore>>,
-    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+) -> Result<Option<Gem>, String> {
+    gem_store.get(&id).await.map_err(String::from)
+}
+
+/// Get a gem's cached thumbnail image
+///
+/// Returns the bytes of `~/.jarvis/thumbnails/{id}.jpg`, written by `save_gem`
+/// when `cache_gem_images` is enabled. Errors if the gem doesn't exist or has
+/// no cached thumbnail — the UI should fall back to `source_meta.image_url`.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const bytes: number[] = await invoke('get_gem_thumbnail', { id: gem.id });
+/// const url = URL.createObjectURL(new Blob([new Uint8Array(bytes)], { type: 'image/jpeg' }));
+/// ```
+#[tauri::command]
+pub async fn get_gem_thumbnail(
+    id: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<u8>, String> {
+    let gem = gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem {} not found", id))?;
+
+    let path = crate::gems::thumbnails::thumbnail_path(&gem.id)?;
+    tokio::fs::read(&path)
+        .await
+        .map_err(|e| format!("No cached thumbnail for gem {}: {}", gem.id, e))
+}
+
+/// Enrich a gem with AI-generated tags and summary
+///
+/// This command enriches an existing gem by generating tags and a summary
+/// using the IntelProvider. It fetches the gem, enriches it, and saves it back.
+/// The enrichment it replaces is kept in `source_meta.enrichment_history`
+/// (see `enrich_gem_with_diff`).
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the gem to enrich
+/// * `gem_store` - Managed state containing the GemStore trait object
+/// * `intel_provider` - Managed state containing the IntelProvider trait object
+///
+/// # Returns
+///
+/// * `Ok(Gem)` - The enriched gem with ai_enrichment populated
+/// * `Err(String)` - Error message if enrichment fails
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The IntelProvider is not available
+/// - The gem with the specified ID does not exist
+/// - The gem has no content or description to enrich
+/// - The enrichment process fails (tag generation or summarization)
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// try {
+///   const enrichedGem = await invoke('enrich_gem', {
+///     id: '550e8400-e29b-41d4-a716-446655440000'
+///   });
+///   console.log(`Enriched with ${enrichedGem.ai_enrichment.tags.length} tags`);
+/// } catch (error) {
+///   console.error(`Failed to enrich gem: ${error}`);
+/// }
+/// ```
+#[tauri::command]
+pub async fn enrich_gem(
+    app_handle: tauri::AppHandle,
+    id: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<Gem, String> {
+    enrich_gem_with_diff(app_handle, id, gem_store, intel_provider, settings_manager, demo_mode)
+        .await
+        .map(|result| result.gem)
+}
+
+/// Previous enrichments kept per gem in `source_meta.enrichment_history`
+const ENRICHMENT_HISTORY_LIMIT: usize = 5;
+
+/// What re-enriching a gem changed
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct EnrichmentDiff {
+    /// Tags in the new enrichment but not the previous one
+    pub tags_added: Vec<String>,
+
+    /// Tags in the previous enrichment but not the new one
+    pub tags_removed: Vec<String>,
+
+    /// Whether the summary text differs
+    pub summary_changed: bool,
+}
+
+impl EnrichmentDiff {
+    /// Compare two `ai_enrichment` values; with no previous enrichment every
+    /// tag counts as added. Tags are compared case-insensitively.
+    fn between(previous: Option<&serde_json::Value>, new: &serde_json::Value) -> Self {
+        fn tags(enrichment: Option<&serde_json::Value>) -> Vec<String> {
+            enrichment
+                .and_then(|e| e.get("tags"))
+                .and_then(|t| t.as_array())
+                .map(|tags| tags.iter().filter_map(|t| t.as_str().map(str::to_string)).collect())
+                .unwrap_or_default()
+        }
+        fn missing_from(tags: &[String], other: &[String]) -> Vec<String> {
+            tags.iter()
+                .filter(|t| !other.iter().any(|o| o.eq_ignore_ascii_case(t)))
+                .cloned()
+                .collect()
+        }
+        let summary = |e: Option<&serde_json::Value>| {
+            e.and_then(|e| e.get("summary")).and_then(|s| s.as_str()).map(str::trim).map(str::to_string)
+        };
+
+        let (old_tags, new_tags) = (tags(previous), tags(Some(new)));
+        Self {
+            tags_added: missing_from(&new_tags, &old_tags),
+            tags_removed: missing_from(&old_tags, &new_tags),
+            summary_changed: summary(previous) != summary(Some(new)),
+        }
+    }
+}
+
+/// Append `previous` to `source_meta.enrichment_history`, newest last,
+/// dropping the oldest entries beyond `ENRICHMENT_HISTORY_LIMIT`
+fn push_enrichment_history(gem: &mut Gem, previous: serde_json::Value) {
+    if !gem.source_meta.is_object() {
+        gem.source_meta = serde_json::json!({});
+    }
+    let history = &mut gem.source_meta["enrichment_history"];
+    if !history.is_array() {
+        *history = serde_json::json!([]);
+    }
+    if let Some(entries) = history.as_array_mut() {
+        entries.push(serde_json::json!({
+            "replaced_at": chrono::Utc::now().to_rfc3339(),
+            "enrichment": previous,
+        }));
+        let excess = entries.len().saturating_sub(ENRICHMENT_HISTORY_LIMIT);
+        entries.drain(..excess);
+    }
+}
+
+/// Result of `enrich_gem_with_diff`
+#[derive(Debug, Clone, Serialize)]
+pub struct EnrichGemResult {
+    pub gem: Gem,
+    pub diff: EnrichmentDiff,
+}
+
+/// Enrich a gem as `enrich_gem` does, also returning what changed
+///
+/// The diff compares against the gem's enrichment before this call. That
+/// enrichment is appended to `source_meta.enrichment_history` as
+/// `{ replaced_at, enrichment }`; only the last 5 are kept.
+///
+/// # Returns
+///
+/// * `Ok(EnrichGemResult)` - The enriched gem and the diff against its previous enrichment
+/// * `Err(String)` - Same errors as `enrich_gem`
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { gem, diff } = await invoke('enrich_gem_with_diff', { id });
+/// if (diff.tags_added.length) {
+///   showToast(`Added tags: ${diff.tags_added.join(', ')}`);
+/// }
+/// ```
+#[tauri::command]
+pub async fn enrich_gem_with_diff(
+    app_handle: tauri::AppHandle,
+    id: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<EnrichGemResult, String> {
+    demo_mode.ensure_writable()?;
+
     // Check availability first
     let availability = intel_provider.check_availability().await;
     if !availability.available {
@@ -589,77 +1923,667 @@ pub async fn enrich_gem(
             availability.reason.unwrap_or_else(|| "Unknown reason".to_string())
         ));
     }
-    
-    // Get provider name and model from settings
-    let (provider_name, model_name, transcription_engine) = {
-        let manager = settings_manager.read()
-            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
-        let s = manager.get();
-        (
-            s.intelligence.provider.clone(), 
-            s.intelligence.active_model.clone(),
-            s.transcription.transcription_engine.clone()
-        )
-    };
-    let model_ref = if provider_name == "mlx" { Some(model_name.as_str()) } else { None };
+    
+    // Get provider name and model from settings
+    let (provider_name, model_name, transcription_engine, prefer_transcript, timeouts) = {
+        let manager = settings_manager.read()
+            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+        let s = manager.get();
+        (
+            s.intelligence.provider.clone(), 
+            s.intelligence.active_model.clone(),
+            s.transcription.transcription_engine.clone(),
+            s.intelligence.prefer_transcript_for_enrichment,
+            ProviderTimeouts::from(&s.intelligence),
+        )
+    };
+    let model_ref = if provider_name == "mlx" { Some(model_name.as_str()) } else { None };
+
+    // Fetch gem by ID
+    let mut gem = gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))?;
+
+    // Get text for enrichment (transcript if preferred, else content, else description)
+    let content_to_enrich = enrichment_text(&gem, prefer_transcript)
+        .map(str::to_string)
+        .ok_or_else(|| "Gem has no content or description to enrich".to_string())?;
+
+    // Enrich the content
+    let enrichment_result = match enrich_content(&**intel_provider, &content_to_enrich, &gem, &provider_name, model_ref, &transcription_engine, timeouts).await {
+        Ok(enrichment) => enrichment,
+        Err(e) => {
+            // Sidecar crash (broken pipe, closed connection) or timeout (sidecar restarted)
+            if matches!(e, JarvisError::Sidecar(_) | JarvisError::Timeout(_)) {
+                // Emit event to frontend for toast notification
+                let _ = app_handle.emit("mlx-sidecar-error", serde_json::json!({
+                    "error": e.to_string()
+                }));
+            }
+            return Err(e.into());
+        }
+    };
+    
+    // Update gem with enrichment, keeping the one it replaces
+    let diff = EnrichmentDiff::between(gem.ai_enrichment.as_ref(), &enrichment_result.ai_enrichment);
+    if let Some(previous) = gem.ai_enrichment.take() {
+        push_enrichment_history(&mut gem, previous);
+    }
+    gem.ai_enrichment = Some(enrichment_result.ai_enrichment);
+    gem.transcript = enrichment_result.transcript;
+    gem.transcript_language = enrichment_result.transcript_language;
+    
+    // Save and return
+    let result = gem_store.save(gem).await.map_err(String::from);
+    
+    // Update knowledge files
+    if let Ok(ref enriched_gem) = result {
+        if let Some(ks) = app_handle.try_state::<Arc<dyn crate::knowledge::KnowledgeStore>>() {
+            // Update enrichment subfile
+            if let Some(ref enrichment) = enriched_gem.ai_enrichment {
+                let formatted = crate::knowledge::assembler::format_enrichment(enrichment);
+                if let Err(e) = ks.update_subfile(&enriched_gem.id, "enrichment.md", &formatted).await {
+                    eprintln!("Knowledge enrichment update failed: {}", e);
+                }
+            }
+        }
+        
+        // Update search index (enrichment changes tags/summary which improves search)
+        if let Some(provider) = app_handle.try_state::<Arc<dyn SearchResultProvider>>() {
+            eprintln!("Search: Re-indexing gem {} (enrich_gem)", enriched_gem.id);
+            if let Err(e) = provider.index_gem(&enriched_gem.id).await {
+                eprintln!("Search: Failed to re-index gem {}: {}", enriched_gem.id, e);
+            } else {
+                eprintln!("Search: Re-index request sent for gem {} (enrich_gem)", enriched_gem.id);
+            }
+        }
+    }
+
+    result.map(|gem| EnrichGemResult { gem, diff })
+}
+
+/// Counts reported by `enrich_all_pending`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BatchEnrichReport {
+    /// Gems enriched
+    pub enriched: usize,
+
+    /// Gems whose enrichment failed (logged and skipped)
+    pub failed: usize,
+}
+
+/// Enrich every gem in `scope`, one at a time, as by `enrich_gem`
+///
+/// `scope` has the same meaning as for `estimate_batch` with `"enrich"`
+/// (`missing` = gems without a summary). A failing gem is logged and skipped.
+/// After each gem a `batch-enrich-progress` event is emitted with
+/// `{ processed, total, enriched, failed }`, and a native notification is
+/// shown when the batch completes or can't start (see `notifications`).
+///
+/// # Returns
+///
+/// * `Ok(BatchEnrichReport)` - Enriched and failed counts
+/// * `Err(String)` - AI provider unavailable or the gem list couldn't be read
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+/// import { listen } from '@tauri-apps/api/event';
+///
+/// await listen('batch-enrich-progress', (event) => {
+///   console.log(`${event.payload.processed}/${event.payload.total}`);
+/// });
+/// const { enriched, failed } = await invoke('enrich_all_pending', { scope: 'missing' });
+/// ```
+#[tauri::command]
+pub async fn enrich_all_pending(
+    app_handle: tauri::AppHandle,
+    scope: BatchScope,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<BatchEnrichReport, String> {
+    demo_mode.ensure_writable()?;
+
+    let availability = intel_provider.check_availability().await;
+    if !availability.available {
+        let error = format!(
+            "AI enrichment not available: {}",
+            availability.reason.unwrap_or_else(|| "Unknown reason".to_string())
+        );
+        crate::notifications::notify_job_failed(&app_handle, "Batch enrichment", &error);
+        return Err(error);
+    }
+
+    let ids: Vec<String> = match crate::gems::list_all_gems(&**gem_store).await {
+        Ok(gems) => gems
+            .into_iter()
+            .filter(|g| match &scope {
+                BatchScope::All => true,
+                BatchScope::Missing => g.summary.is_none(),
+                BatchScope::Ids(ids) => ids.contains(&g.id),
+            })
+            .map(|g| g.id)
+            .collect(),
+        Err(e) => {
+            let error = String::from(e);
+            crate::notifications::notify_job_failed(&app_handle, "Batch enrichment", &error);
+            return Err(error);
+        }
+    };
+    let total = ids.len();
+    let mut report = BatchEnrichReport::default();
+
+    eprintln!("Gems: Batch enriching {} gems", total);
+
+    for (processed, id) in ids.into_iter().enumerate() {
+        match enrich_gem(
+            app_handle.clone(),
+            id.clone(),
+            gem_store.clone(),
+            intel_provider.clone(),
+            settings_manager.clone(),
+            demo_mode.clone(),
+        ).await {
+            Ok(_) => report.enriched += 1,
+            Err(e) => {
+                eprintln!("Gems: Batch enrichment failed for gem {}: {}", id, e);
+                report.failed += 1;
+            }
+        }
+
+        let _ = app_handle.emit("batch-enrich-progress", serde_json::json!({
+            "processed": processed + 1,
+            "total": total,
+            "enriched": report.enriched,
+            "failed": report.failed,
+        }));
+    }
+
+    eprintln!("Gems: Batch enrichment complete ({} enriched, {} failed)", report.enriched, report.failed);
+    if total > 0 && report.enriched == 0 {
+        crate::notifications::notify_job_failed(
+            &app_handle,
+            "Batch enrichment",
+            &format!("None of {} gems could be enriched", total),
+        );
+    } else {
+        crate::notifications::notify_job_completed(
+            &app_handle,
+            "Batch enrichment",
+            &format!("Enriched {} of {} gems", report.enriched, total),
+        );
+    }
+    Ok(report)
+}
+
+/// Result of `reextract_gem`
+#[derive(Debug, Clone, Serialize)]
+pub struct ReextractResult {
+    pub gem: Gem,
+
+    /// Whether the source content differs from what was saved
+    pub changed: bool,
+}
+
+/// Refresh a gem's content from its source page
+///
+/// Re-runs the extractor for the gem's `source_type` on its `source_url` and
+/// updates content, description, image URL, published date and content hash
+/// (see `apply_reextracted_gist`); id, captured_at, tags and user notes are
+/// kept. Knowledge files and the search index are refreshed. With `reenrich`,
+/// the gem is then enriched again as by `enrich_gem`.
+///
+/// Recordings and notes (`jarvis://` URLs) have no web source and are rejected.
+///
+/// # Returns
+///
+/// * `Ok(ReextractResult)` - The refreshed gem and whether its content changed
+/// * `Err(String)` - Gem not found, no web source, or extraction/save failed
+#[tauri::command]
+pub async fn reextract_gem(
+    app_handle: tauri::AppHandle,
+    id: String,
+    reenrich: Option<bool>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<ReextractResult, String> {
+    demo_mode.ensure_writable()?;
+
+    let mut gem = gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))?;
+    let changed = reextract_into(&mut gem, &settings_manager).await?;
+
+    let saved_gem = gem_store.save(gem).await?;
+    if !reenrich.unwrap_or(false) {
+        after_gem_saved(&app_handle, &saved_gem).await;
+        return Ok(ReextractResult { gem: saved_gem, changed });
+    }
+
+    // Enrichment re-indexes the gem itself; only index here if it fails
+    write_knowledge_files(&app_handle, &saved_gem).await;
+    match enrich_gem(app_handle.clone(), saved_gem.id.clone(), gem_store, intel_provider, settings_manager, demo_mode).await {
+        Ok(gem) => Ok(ReextractResult { gem, changed }),
+        Err(e) => {
+            index_saved_gem(&app_handle, &saved_gem).await;
+            Err(e)
+        }
+    }
+}
+
+/// Correct a gem's source type
+///
+/// `source_type` must be the name of a `SourceType` variant (e.g. "Code",
+/// "Article"); anything else is rejected when the arguments are deserialized.
+/// With `reextract`, the extractor for the new type is run on the
+/// gem's source URL as by `reextract_gem`. Knowledge files and the search
+/// index are refreshed.
+///
+/// Recordings and notes (`jarvis://` URLs) keep their own source type.
+///
+/// # Returns
+///
+/// * `Ok(Gem)` - The updated gem
+/// * `Err(String)` - Gem not found, or extraction/save failed
+#[tauri::command]
+pub async fn set_gem_source_type(
+    app_handle: tauri::AppHandle,
+    id: String,
+    source_type: crate::browser::tabs::SourceType,
+    reextract: Option<bool>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
+    let mut gem = gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))?;
+    if gem.source_url.starts_with("jarvis://") {
+        return Err(format!("{} gems keep their own source type", gem.source_type));
+    }
+
+    gem.source_type = source_type.name();
+    if reextract.unwrap_or(false) {
+        reextract_into(&mut gem, &settings_manager).await?;
+    }
+
+    let saved_gem = gem_store.save(gem).await?;
+    after_gem_saved(&app_handle, &saved_gem).await;
+
+    Ok(saved_gem)
+}
+
+/// Re-run the extractor for `gem` and apply the result (content truncated
+/// to `max_content_chars`), returning whether the content changed
+async fn reextract_into(
+    gem: &mut Gem,
+    settings_manager: &Arc<RwLock<SettingsManager>>,
+) -> Result<bool, String> {
+    let gist = fetch_source_gist(gem, settings_manager).await?;
+    let changed = apply_reextracted_gist(gem, gist);
+
+    let max_content_chars = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence
+        .max_content_chars;
+    truncate_gem_content(gem, max_content_chars);
+    Ok(changed)
+}
+
+/// Maximum number of source pages `check_gems_for_updates` fetches at once
+const UPDATE_CHECK_CONCURRENCY: usize = 4;
+
+/// Re-fetch saved pages and report which have changed at the source
+///
+/// Requires `settings.browser.track_content_changes`. Checks the `limit` most
+/// recent gems that have a web source and a stored `content_hash` (gems
+/// captured while tracking was off have no baseline and are skipped;
+/// `reextract_gem` adds one). Nothing is saved — call
+/// `reextract_gem` on the reported gems to pull in the new content. Pages
+/// that fail to load are logged and left out.
+///
+/// # Returns
+///
+/// * `Ok(Vec<GemPreview>)` - Gems whose source content has changed
+/// * `Err(String)` - Content change tracking is off, or listing gems failed
+#[tauri::command]
+pub async fn check_gems_for_updates(
+    limit: usize,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+) -> Result<Vec<GemPreview>, String> {
+    use futures_util::StreamExt;
+
+    let track_content_changes = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .browser
+        .track_content_changes;
+    if !track_content_changes {
+        return Err("Checking for updates needs content change tracking (enable browser.track_content_changes)".to_string());
+    }
+
+    let mut candidates = Vec::new();
+    for preview in gem_store.list(limit, 0).await? {
+        if preview.source_url.starts_with("jarvis://") {
+            continue;
+        }
+        let Some(gem) = gem_store.get(&preview.id).await? else {
+            continue;
+        };
+        let Some(stored_hash) = gem.source_meta.get("content_hash").and_then(|h| h.as_str()) else {
+            continue;
+        };
+        let stored_hash = stored_hash.to_string();
+        candidates.push((preview, gem, stored_hash));
+    }
+
+    let settings_manager: &Arc<RwLock<SettingsManager>> = &settings_manager;
+    let updated = futures_util::stream::iter(candidates)
+        .map(|(preview, gem, stored_hash)| async move {
+            match fetch_source_gist(&gem, settings_manager).await {
+                Ok(gist) => gist.extra.get("content_hash")
+                    .and_then(|h| h.as_str())
+                    .is_some_and(|hash| hash != stored_hash)
+                    .then_some(preview),
+                Err(e) => {
+                    eprintln!("Update check: Failed to fetch {}: {}", gem.source_url, e);
+                    None
+                }
+            }
+        })
+        .buffered(UPDATE_CHECK_CONCURRENCY)
+        .filter_map(|preview| async move { preview })
+        .collect()
+        .await;
+
+    Ok(updated)
+}
+
+/// Run the extractor for `gem`'s source type on its source URL
+async fn fetch_source_gist(
+    gem: &Gem,
+    settings_manager: &Arc<RwLock<SettingsManager>>,
+) -> Result<crate::browser::extractors::PageGist, String> {
+    if gem.source_url.starts_with("jarvis://") {
+        return Err("This gem has no web source to re-extract from".to_string());
+    }
+
+    let source_type: crate::browser::tabs::SourceType =
+        serde_json::from_str(&format!("\"{}\"", gem.source_type))
+            .unwrap_or(crate::browser::tabs::SourceType::Other);
+    crate::browser::extractors::prepare_gist(
+        &gem.source_url,
+        &source_type,
+        &gist_options(settings_manager)?,
+    ).await
+}
+
+/// Turn provider concepts into Co-Pilot `KeyConcept`s, counting
+/// case-insensitive mentions of each term in `text` (at least 1)
+fn to_key_concepts(
+    concepts: Vec<crate::intelligence::provider::CoPilotConcept>,
+    text: &str,
+) -> Vec<KeyConcept> {
+    let haystack = text.to_lowercase();
+    concepts
+        .into_iter()
+        .map(|concept| {
+            let mentions = haystack.matches(&concept.term.to_lowercase()).count();
+            KeyConcept {
+                term: concept.term,
+                context: concept.context,
+                cycle_added: 0,
+                mention_count: mentions.max(1) as u32,
+            }
+        })
+        .collect()
+}
+
+/// Extract key concepts from a gem for a glossary view
+///
+/// Uses the gem's transcript if present, otherwise its content or description.
+/// Concepts reuse the Co-Pilot `KeyConcept` type; `cycle_added` is always 0 and
+/// `mention_count` counts occurrences of the term in the gem text.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the gem
+/// * `cache` - If true, return concepts cached in `source_meta.key_concepts`
+///   when present, and cache freshly extracted ones there
+///
+/// # Returns
+///
+/// * `Ok(Vec<KeyConcept>)` - The gem's key concepts
+/// * `Err(String)` - Gem not found, has no text, or extraction failed, or
+///   caching new concepts in demo mode
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const concepts: KeyConcept[] = await invoke('extract_key_concepts', { id, cache: true });
+/// ```
+#[tauri::command]
+pub async fn extract_key_concepts(
+    id: String,
+    cache: Option<bool>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Vec<KeyConcept>, String> {
+    let cache = cache.unwrap_or(false);
+    let mut gem = gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))?;
+
+    if cache {
+        if let Some(cached) = gem.source_meta.get("key_concepts") {
+            if let Ok(concepts) = serde_json::from_value::<Vec<KeyConcept>>(cached.clone()) {
+                return Ok(concepts);
+            }
+        }
+        // Caching would write the gem; refuse before spending time on extraction
+        demo_mode.ensure_writable()?;
+    }
+
+    let availability = intel_provider.check_availability().await;
+    if !availability.available {
+        return Err(format!(
+            "AI provider not available: {}",
+            availability.reason.unwrap_or_else(|| "Unknown reason".to_string())
+        ));
+    }
+
+    let text = gem.transcript.as_ref()
+        .or(gem.content.as_ref())
+        .or(gem.description.as_ref())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| "Gem has no transcript, content, or description".to_string())?;
+
+    let concepts = to_key_concepts(intel_provider.extract_concepts(text).await?, text);
+
+    if cache {
+        if !gem.source_meta.is_object() {
+            gem.source_meta = serde_json::json!({});
+        }
+        gem.source_meta["key_concepts"] = serde_json::to_value(&concepts)
+            .map_err(|e| format!("Failed to serialize key concepts: {}", e))?;
+        gem_store.save(gem).await?;
+    }
+
+    Ok(concepts)
+}
+
+/// Extract action items from a recording gem and store them in `source_meta.action_items`
+///
+/// Runs the IntelProvider over the gem's transcript (falling back to the
+/// real-time content). Items have the same shape as Co-Pilot's `action_items`.
+/// Re-running replaces the stored list; items extracted again stay done.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the recording gem
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - The extracted action items (possibly empty)
+/// * `Err(String)` - Gem not found, not a recording, has no transcript, or extraction failed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const items: string[] = await invoke('extract_action_items', { id });
+/// ```
+#[tauri::command]
+pub async fn extract_action_items(
+    id: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Vec<String>, String> {
+    demo_mode.ensure_writable()?;
+
+    let mut gem = gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))?;
+
+    if gem.source_meta.get("recording_filename").is_none() {
+        return Err("Action items can only be extracted from recording gems".to_string());
+    }
+
+    let availability = intel_provider.check_availability().await;
+    if !availability.available {
+        return Err(format!(
+            "AI provider not available: {}",
+            availability.reason.unwrap_or_else(|| "Unknown reason".to_string())
+        ));
+    }
+
+    let transcript = gem.transcript.as_ref()
+        .or(gem.content.as_ref())
+        .filter(|s| !s.trim().is_empty())
+        .ok_or_else(|| "Recording gem has no transcript".to_string())?;
+
+    let action_items = intel_provider.extract_action_items(transcript).await?;
+    eprintln!("Gems: Extracted {} action items from gem {}", action_items.len(), id);
+
+    crate::gems::action_items::replace_action_items(&mut gem.source_meta, &action_items);
+    gem_store.save(gem).await?;
+
+    Ok(action_items)
+}
+
+/// Mark an action item of a recording gem done, or open again
+///
+/// Done items stay in `source_meta.action_items` and are also listed in
+/// `source_meta.done_action_items`; `list_action_items_across_gems` and the
+/// export only include open items.
+///
+/// # Arguments
+///
+/// * `id` - The unique identifier of the recording gem
+/// * `item` - The action item text, as returned by `extract_action_items`
+/// * `done` - `true` to mark it done, `false` to reopen it
+///
+/// # Returns
+///
+/// * `Ok(Vec<String>)` - The gem's remaining open action items
+/// * `Err(String)` - Gem not found, `item` is not one of its action items, or the save failed
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const open: string[] = await invoke('set_action_item_done', { id, item, done: true });
+/// ```
+#[tauri::command]
+pub async fn set_action_item_done(
+    id: String,
+    item: String,
+    done: bool,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<Vec<String>, String> {
+    demo_mode.ensure_writable()?;
+
+    let mut gem = gem_store.get(&id).await?
+        .ok_or_else(|| format!("Gem with id '{}' not found", id))?;
+
+    let open = crate::gems::action_items::set_action_item_done(&mut gem.source_meta, &item, done)?;
+    gem_store.save(gem).await?;
+
+    Ok(open)
+}
+
+/// List open action items from all recording gems, most recent recording first
+///
+/// Items marked done with `set_action_item_done` are left out, as are gems
+/// with no open items.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const groups: GemActionItems[] = await invoke('list_action_items_across_gems');
+/// ```
+#[tauri::command]
+pub async fn list_action_items_across_gems(
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<GemActionItems>, String> {
+    gem_store.list_action_items().await.map_err(String::from)
+}
+
+/// Export all recording gems' open action items to a task file in ~/.jarvis/exports/
+///
+/// Items are grouped by source gem with a link to it. Formats:
+/// - `"markdown"` - `- [ ]` checklist
+/// - `"ics"` - iCalendar VTODO entries
+/// - `"csv"` - one row per item
+///
+/// # Returns
+///
+/// * `Ok(String)` - Full path of the written file
+/// * `Err(String)` - No action items to export, or the file couldn't be written
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const path = await invoke('export_action_items', { format: 'markdown' });
+/// ```
+#[tauri::command]
+pub async fn export_action_items(
+    format: crate::gems::action_items::ActionItemFormat,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<String, String> {
+    let groups = gem_store.list_action_items().await?;
+    if groups.is_empty() {
+        return Err("No action items to export. Extract action items from a recording first.".to_string());
+    }
 
-    // Fetch gem by ID
-    let mut gem = gem_store.get(&id).await?
-        .ok_or_else(|| format!("Gem with id '{}' not found", id))?;
+    let contents = crate::gems::action_items::format_action_items(&groups, format, chrono::Utc::now());
 
-    // Get content for enrichment (prefer content, fall back to description)
-    let content_to_enrich = gem.content.as_ref()
-        .or(gem.description.as_ref())
-        .filter(|s| !s.trim().is_empty())
-        .ok_or_else(|| "Gem has no content or description to enrich".to_string())?;
+    let home = dirs::home_dir().ok_or("Could not find home directory")?;
+    let exports_dir = home.join(".jarvis").join("exports");
+    std::fs::create_dir_all(&exports_dir)
+        .map_err(|e| format!("Failed to create exports directory: {}", e))?;
 
-    // Enrich the content
-    let enrichment_result = match enrich_content(&**intel_provider, content_to_enrich, &gem, &provider_name, model_ref, &transcription_engine).await {
-        Ok(enrichment) => enrichment,
-        Err(e) => {
-            // Check if error indicates sidecar crash (broken pipe)
-            if e.contains("broken pipe") || e.contains("closed connection") || e.contains("Sidecar") {
-                // Emit event to frontend for toast notification
-                let _ = app_handle.emit("mlx-sidecar-error", serde_json::json!({
-                    "error": e.clone()
-                }));
-            }
-            return Err(e);
-        }
-    };
-    
-    // Update gem with enrichment
-    gem.ai_enrichment = Some(enrichment_result.ai_enrichment);
-    gem.transcript = enrichment_result.transcript;
-    gem.transcript_language = enrichment_result.transcript_language;
-    
-    // Save and return
-    let result = gem_store.save(gem).await;
-    
-    // Update knowledge files
-    if let Ok(ref enriched_gem) = result {
-        if let Some(ks) = app_handle.try_state::<Arc<dyn crate::knowledge::KnowledgeStore>>() {
-            // Update enrichment subfile
-            if let Some(ref enrichment) = enriched_gem.ai_enrichment {
-                let formatted = crate::knowledge::assembler::format_enrichment(enrichment);
-                if let Err(e) = ks.update_subfile(&enriched_gem.id, "enrichment.md", &formatted).await {
-                    eprintln!("Knowledge enrichment update failed: {}", e);
-                }
-            }
-        }
-        
-        // Update search index (enrichment changes tags/summary which improves search)
-        if let Some(provider) = app_handle.try_state::<Arc<dyn SearchResultProvider>>() {
-            eprintln!("Search: Re-indexing gem {} (enrich_gem)", enriched_gem.id);
-            if let Err(e) = provider.index_gem(&enriched_gem.id).await {
-                eprintln!("Search: Failed to re-index gem {}: {}", enriched_gem.id, e);
-            } else {
-                eprintln!("Search: Re-index request sent for gem {} (enrich_gem)", enriched_gem.id);
-            }
-        }
-    }
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S");
+    let file_path = exports_dir.join(format!("action-items-{}.{}", timestamp, format.extension()));
+    std::fs::write(&file_path, contents)
+        .map_err(|e| format!("Failed to write action items file: {}", e))?;
 
-    result
+    Ok(file_path.to_string_lossy().to_string())
 }
 
 /// Transcribe a recording gem and regenerate tags/summary from the transcript
@@ -683,7 +2607,10 @@ pub async fn transcribe_gem(
     gem_store: State<'_, Arc<dyn GemStore>>,
     intel_provider: State<'_, Arc<dyn IntelProvider>>,
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
     // Check availability first
     let availability = intel_provider.check_availability().await;
     if !availability.available {
@@ -707,12 +2634,19 @@ pub async fn transcribe_gem(
     }
 
     // Generate transcript
-    let result = intel_provider.generate_transcript(&recording_path).await
+    let timeout_secs = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence
+        .transcript_timeout_secs;
+    // Generate transcript (blocks appending to this recording meanwhile)
+    let _reading = crate::recording::RecordingReadGuard::acquire(&recording_file_name(&recording_path))?;
+    let result = with_timeout(timeout_secs, "Transcription", intel_provider.generate_transcript(&recording_path)).await
         .map_err(|e| {
-            if e.contains("not supported") {
+            if matches!(e, JarvisError::Unsupported(_)) {
                 "Current AI provider does not support transcription".to_string()
             } else {
-                e
+                e.to_string()
             }
         })?;
 
@@ -745,7 +2679,7 @@ pub async fn transcribe_gem(
     }
 
     // Save and return
-    let result = gem_store.save(gem).await;
+    let result = gem_store.save(gem).await.map_err(String::from);
     
     // Recreate all knowledge files (transcript + re-enrichment changes multiple things)
     if let Ok(ref gem) = result {
@@ -795,9 +2729,10 @@ pub async fn transcribe_gem(
 /// - The transcription process fails
 
 /// Helper function for transcribe_recording that can be tested without Tauri State
-async fn transcribe_recording_inner(
+pub(crate) async fn transcribe_recording_inner(
     filename: &str,
     provider: &dyn IntelProvider,
+    timeout_secs: u64,
 ) -> Result<TranscriptResult, String> {
     // Security: Validate filename doesn't contain path separators
     if filename.contains('/') || filename.contains('\\') || filename.contains("..") {
@@ -819,8 +2754,9 @@ async fn transcribe_recording_inner(
     let recording_dir = recordings_dir.join(stem);
     let transcript_path = recording_dir.join("transcript.md");
 
-    // Fast path: transcript already exists on disk (generated by Chat or previous Transcribe)
-    if transcript_path.exists() {
+    // Fast path: transcript already exists on disk (generated by Chat or
+    // previous Transcribe) and still covers the whole recording
+    if crate::recording::has_current_transcript(&recording_dir) {
         let content = tokio::fs::read_to_string(&transcript_path).await
             .map_err(|e| format!("Failed to read transcript: {}", e))?;
         return Ok(TranscriptResult {
@@ -838,13 +2774,14 @@ async fn transcribe_recording_inner(
         ));
     }
 
-    // Generate transcript
-    let result = provider.generate_transcript(&recording_path).await
+    // Generate transcript (blocks appending to this recording meanwhile)
+    let _reading = crate::recording::RecordingReadGuard::acquire(filename)?;
+    let result = with_timeout(timeout_secs, "Transcription", provider.generate_transcript(&recording_path)).await
         .map_err(|e| {
-            if e.contains("not supported") {
+            if matches!(e, JarvisError::Unsupported(_)) {
                 "Current AI provider does not support transcription".to_string()
             } else {
-                e
+                e.to_string()
             }
         })?;
 
@@ -855,8 +2792,7 @@ async fn transcribe_recording_inner(
         chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
         result.transcript,
     );
-    let _ = tokio::fs::create_dir_all(&recording_dir).await;
-    let _ = tokio::fs::write(&transcript_path, &transcript_md).await;
+    let _ = crate::recording::write_transcript(&recording_dir, &transcript_md).await;
 
     Ok(result)
 }
@@ -865,8 +2801,121 @@ async fn transcribe_recording_inner(
 pub async fn transcribe_recording(
     filename: String,
     intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<TranscriptResult, String> {
-    transcribe_recording_inner(&filename, &**intel_provider).await
+    demo_mode.ensure_writable()?;
+
+    let timeout_secs = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence
+        .transcript_timeout_secs;
+    transcribe_recording_inner(&filename, &**intel_provider, timeout_secs).await
+}
+
+/// Recent timings of AI provider calls
+///
+/// Per operation (`enrich`, `tags`, `summarize`, `transcribe`): calls and
+/// failures since the app started, plus the average and last duration of
+/// recent successful calls. Operations not yet called are omitted. Useful
+/// for comparing models and diagnosing slow enrichment.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const metrics = await invoke('provider_metrics');
+/// // [{ operation: 'summarize', count: 12, failures: 0, average_ms: 2140, last_ms: 1980 }, ...]
+/// ```
+#[tauri::command]
+pub fn provider_metrics() -> Vec<timings::OperationMetrics> {
+    timings::snapshot()
+}
+
+/// Which items a batch operation would cover
+#[derive(Debug, Clone, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchScope {
+    /// Every gem (enrich) or recording (transcribe)
+    All,
+
+    /// Only gems without enrichment, or recordings without a saved transcript
+    Missing,
+
+    /// These gem IDs (enrich) or recording filenames (transcribe)
+    Ids(Vec<String>),
+}
+
+/// Result of `estimate_batch`
+#[derive(Debug, Clone, Serialize)]
+pub struct BatchEstimate {
+    pub item_count: usize,
+
+    /// `item_count` times the recent average per item; `None` until at least
+    /// one item has been processed since the app started
+    pub estimated_seconds: Option<u64>,
+}
+
+/// Estimate how many items a batch job covers and how long it will take
+///
+/// `operation` is `"enrich"` (gems) or `"transcribe"` (recordings). The time
+/// estimate uses the rolling average of recent per-item timings recorded by
+/// enrichment and transcription (see `intelligence::timings`).
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { item_count, estimated_seconds } = await invoke('estimate_batch', {
+///   operation: 'enrich',
+///   scope: 'missing', // or 'all', or { ids: [...] }
+/// });
+/// ```
+#[tauri::command]
+pub async fn estimate_batch(
+    app_handle: AppHandle,
+    operation: String,
+    scope: BatchScope,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    file_manager: State<'_, FileManager>,
+) -> Result<BatchEstimate, String> {
+    let (op, item_count) = match operation.as_str() {
+        "enrich" => {
+            let gems = crate::gems::list_all_gems(&**gem_store).await?;
+            let count = match &scope {
+                BatchScope::All => gems.len(),
+                BatchScope::Missing => gems.iter().filter(|g| g.summary.is_none()).count(),
+                BatchScope::Ids(ids) => gems.iter().filter(|g| ids.contains(&g.id)).count(),
+            };
+            (TimedOperation::Enrich, count)
+        }
+        "transcribe" => {
+            let recordings = file_manager.list_recordings()?;
+            let count = match &scope {
+                BatchScope::All => recordings.len(),
+                BatchScope::Missing => {
+                    let mut count = 0;
+                    for recording in &recordings {
+                        let source = RecordingChatSource::new(app_handle.clone(), recording.filename.clone())?;
+                        if !source.transcript_path().exists() {
+                            count += 1;
+                        }
+                    }
+                    count
+                }
+                BatchScope::Ids(ids) => recordings.iter().filter(|r| ids.contains(&r.filename)).count(),
+            };
+            (TimedOperation::Transcribe, count)
+        }
+        other => return Err(format!("Unknown batch operation '{}' (expected enrich or transcribe)", other)),
+    };
+
+    let estimated_seconds = timings::average(op)
+        .map(|per_item| (per_item.as_secs_f64() * item_count as f64).ceil() as u64);
+    Ok(BatchEstimate { item_count, estimated_seconds })
 }
 
 /// Check if a recording has an associated gem
@@ -889,7 +2938,7 @@ pub async fn check_recording_gem(
     filename: String,
     gem_store: State<'_, Arc<dyn GemStore>>,
 ) -> Result<Option<GemPreview>, String> {
-    gem_store.find_by_recording_filename(&filename).await
+    gem_store.find_by_recording_filename(&filename).await.map_err(String::from)
 }
 
 /// Check which recordings have associated gems (batch operation)
@@ -912,15 +2961,86 @@ pub async fn check_recording_gems_batch(
     filenames: Vec<String>,
     gem_store: State<'_, Arc<dyn GemStore>>,
 ) -> Result<std::collections::HashMap<String, GemPreview>, String> {
-    let mut result = std::collections::HashMap::new();
-    
-    for filename in filenames {
-        if let Some(gem_preview) = gem_store.find_by_recording_filename(&filename).await? {
-            result.insert(filename, gem_preview);
-        }
+    gem_store.find_by_recording_filenames(&filenames).await.map_err(String::from)
+}
+
+/// New, unsaved gem for a recording, with a deterministic `jarvis://recording/` URL
+///
+/// `created_at` (Unix seconds) is used for the title. Transcript and
+/// enrichment are left empty for the caller to fill in.
+pub(crate) fn new_recording_gem(filename: &str, created_at: u64) -> Gem {
+    let title = if let Some(dt) = chrono::DateTime::from_timestamp(created_at as i64, 0) {
+        format!("Audio Transcript - {}", dt.format("%Y-%m-%d %H:%M:%S"))
+    } else {
+        format!("Audio Transcript - {}", filename)
+    };
+
+    Gem {
+        id: uuid::Uuid::new_v4().to_string(),
+        source_type: "Other".to_string(),
+        source_url: format!("jarvis://recording/{}", filename),
+        domain: "jarvis-app".to_string(),
+        title,
+        author: None,
+        description: None,
+        content: None,
+        source_meta: serde_json::json!({
+            "recording_filename": filename,
+            "source": "recording_transcription"
+        }),
+        captured_at: chrono::Utc::now().to_rfc3339(),
+        ai_enrichment: None,
+        transcript: None,
+        transcript_language: None,
+        user_notes: None,
+        label: None,
     }
-    
-    Ok(result)
+}
+
+/// New, unsaved gem for pasted text, with a generated `jarvis://note/` URL
+///
+/// `tags` are trimmed and de-duplicated (case-insensitively) and stored in
+/// `ai_enrichment.tags` so tag search and filtering work as for enriched gems.
+pub(crate) fn new_text_gem(title: &str, content: &str, tags: Option<Vec<String>>) -> Result<Gem, String> {
+    let title = title.trim();
+    if title.is_empty() {
+        return Err("Note title cannot be empty".to_string());
+    }
+    if content.trim().is_empty() {
+        return Err("Note content cannot be empty".to_string());
+    }
+
+    let id = uuid::Uuid::new_v4().to_string();
+    let tags = merge_tags(tags.unwrap_or_default(), Vec::new());
+
+    Ok(Gem {
+        source_url: format!("jarvis://note/{}", id),
+        id,
+        source_type: "Note".to_string(),
+        domain: "jarvis-app".to_string(),
+        title: title.to_string(),
+        author: None,
+        description: None,
+        content: Some(content.to_string()),
+        source_meta: serde_json::json!({ "source": "note" }),
+        captured_at: chrono::Utc::now().to_rfc3339(),
+        ai_enrichment: (!tags.is_empty()).then(|| serde_json::json!({ "tags": tags })),
+        transcript: None,
+        transcript_language: None,
+        user_notes: None,
+        label: None,
+    })
+}
+
+/// `first` followed by `second`, trimmed, without blanks or case-insensitive duplicates
+fn merge_tags(first: Vec<String>, second: Vec<String>) -> Vec<String> {
+    let mut seen = std::collections::HashSet::new();
+    first
+        .into_iter()
+        .chain(second)
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty() && seen.insert(t.to_lowercase()))
+        .collect()
 }
 
 /// Save or update a recording gem with transcript
@@ -944,6 +3064,7 @@ pub async fn check_recording_gems_batch(
 /// * `Ok(Gem)` - The saved or updated gem
 /// * `Err(String)` - Error message if save fails
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn save_recording_gem(
     app_handle: tauri::AppHandle,
     filename: String,
@@ -954,7 +3075,10 @@ pub async fn save_recording_gem(
     gem_store: State<'_, Arc<dyn GemStore>>,
     intel_provider: State<'_, Arc<dyn IntelProvider>>,
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
     // Check for existing gem
     log_gem_save(&format!("save_recording_gem called: filename={}, transcript_len={}, language={}, copilot_data={}",
         filename, transcript.len(), language, copilot_data.is_some()));
@@ -984,6 +3108,11 @@ pub async fn save_recording_gem(
         existing.transcript = Some(transcript.clone());
         existing.transcript_language = Some(language.clone());
 
+        // A draft from incremental saving becomes the final gem
+        if let Some(meta) = existing.source_meta.as_object_mut() {
+            meta.remove("draft");
+        }
+
         // Add Co-Pilot data if provided (Requirement 10.1, 10.2)
         if let Some(copilot) = copilot_data {
             existing.source_meta["copilot"] = copilot;
@@ -993,39 +3122,17 @@ pub async fn save_recording_gem(
     } else {
         // Create new gem with deterministic URL
         log_gem_save(&format!("creating new gem for filename={}", filename));
-        let title = if let Some(dt) = chrono::DateTime::from_timestamp(created_at as i64, 0) {
-            format!("Audio Transcript - {}", dt.format("%Y-%m-%d %H:%M:%S"))
-        } else {
-            format!("Audio Transcript - {}", filename)
-        };
-
-        let mut source_meta = serde_json::json!({
-            "recording_filename": filename,
-            "source": "recording_transcription"
-        });
+        let mut new_gem = new_recording_gem(&filename, created_at);
 
         // Add Co-Pilot data if provided (Requirement 10.1, 10.2)
         if let Some(copilot) = copilot_data {
-            source_meta["copilot"] = copilot;
+            new_gem.source_meta["copilot"] = copilot;
         }
 
-        let new_id = uuid::Uuid::new_v4().to_string();
-        log_gem_save(&format!("new gem id={}", new_id));
-        Gem {
-            id: new_id,
-            source_type: "Other".to_string(),
-            source_url: format!("jarvis://recording/{}", filename),
-            domain: "jarvis-app".to_string(),
-            title,
-            author: None,
-            description: None,
-            content: None,
-            source_meta,
-            captured_at: chrono::Utc::now().to_rfc3339(),
-            ai_enrichment: None,
-            transcript: Some(transcript.clone()),
-            transcript_language: Some(language.clone()),
-        }
+        log_gem_save(&format!("new gem id={}", new_gem.id));
+        new_gem.transcript = Some(transcript.clone());
+        new_gem.transcript_language = Some(language.clone());
+        new_gem
     };
     
     // Try to generate AI enrichment (tags/summary) from transcript
@@ -1071,7 +3178,7 @@ pub async fn save_recording_gem(
 
     // Save and return
     log_gem_save(&format!("saving gem id={} to store", gem.id));
-    let result = gem_store.save(gem).await;
+    let result = gem_store.save(gem).await.map_err(String::from);
     match &result {
         Ok(g) => log_gem_save(&format!("SUCCESS gem saved id={}", g.id)),
         Err(e) => log_gem_save(&format!("ERROR gem_store.save failed: {}", e)),
@@ -1131,6 +3238,17 @@ pub async fn check_intel_availability(
     Ok(intel_provider.check_availability().await)
 }
 
+/// Get the operations the active intelligence provider supports
+///
+/// The frontend uses this to hide actions the provider can't perform, such
+/// as "Transcribe" when the loaded model has no audio support.
+#[tauri::command]
+pub async fn get_provider_capabilities(
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+) -> Result<crate::intelligence::ProviderCapabilities, String> {
+    Ok(intel_provider.capabilities())
+}
+
 /// Check MLX dependencies (Python and mlx packages)
 ///
 /// This command checks if Python is installed and accessible, and provides
@@ -1232,85 +3350,414 @@ pub async fn check_mlx_dependencies(
                 format!("Failed to check Python: {}", e)
             };
 
-            Ok(MlxDiagnostics {
-                python_found: false,
-                python_version: None,
-                python_error: Some(error_msg),
-                venv_status: venv_status_str.clone(),
-                venv_python_path: venv_python,
-            })
-        }
+            Ok(MlxDiagnostics {
+                python_found: false,
+                python_version: None,
+                python_error: Some(error_msg),
+                venv_status: venv_status_str.clone(),
+                venv_python_path: venv_python,
+            })
+        }
+    }
+}
+
+#[tauri::command]
+pub async fn setup_mlx_venv(
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    venv_manager: State<'_, Arc<VenvManager>>,
+    app_handle: tauri::AppHandle,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
+    let python_path = {
+        let manager = settings_manager.read()
+            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+        manager.get().intelligence.python_path.clone()
+    };
+
+    venv_manager.setup(&python_path, &app_handle).await
+}
+
+#[tauri::command]
+pub async fn reset_mlx_venv(
+    venv_manager: State<'_, Arc<VenvManager>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
+    venv_manager.reset()
+}
+
+/// Filter gems by tag
+///
+/// This command returns gems that have the specified tag in their ai_enrichment.
+/// Results are ordered by captured_at descending (most recent first).
+///
+/// # Arguments
+///
+/// * `tag` - The tag to filter by (exact match)
+/// * `limit` - Optional maximum number of gems to return (default: 50)
+/// * `offset` - Optional number of gems to skip for pagination (default: 0)
+/// * `gem_store` - Managed state containing the GemStore trait object
+///
+/// # Returns
+///
+/// * `Ok(Vec<GemPreview>)` - Array of gem previews with the specified tag
+/// * `Err(String)` - Error message if filtering fails
+///
+/// # Errors
+///
+/// Returns an error if:
+/// - The GemStore filter_by_tag operation fails
+/// - Database connection issues occur
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// try {
+///   const gems = await invoke('filter_gems_by_tag', {
+///     tag: 'rust',
+///     limit: 20
+///   });
+///   console.log(`Found ${gems.length} gems tagged with 'rust'`);
+/// } catch (error) {
+///   console.error(`Failed to filter gems: ${error}`);
+/// }
+/// ```
+#[tauri::command]
+pub async fn filter_gems_by_tag(
+    tag: String,
+    limit: Option<usize>,
+    offset: Option<usize>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<GemPreview>, String> {
+    gem_store.filter_by_tag(&tag, limit.unwrap_or(50), offset.unwrap_or(0)).await.map_err(String::from)
+}
+
+/// Pairs of tags that appear together on gems
+///
+/// Returns each unordered tag pair carried by at least `min_count` gems
+/// (default 2), most frequent first. Tags are compared case-insensitively
+/// and returned lowercased. Feeds the tag graph; pairs that nearly always
+/// co-occur are candidates for merging with `rename_tag`.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const pairs = await invoke('tag_cooccurrence', { minCount: 3 });
+/// // [{ tag_a: 'async', tag_b: 'rust', count: 12 }, ...]
+/// ```
+#[tauri::command]
+pub async fn tag_cooccurrence(
+    min_count: Option<usize>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<TagCooccurrence>, String> {
+    gem_store.tag_cooccurrence(min_count.unwrap_or(2)).await.map_err(String::from)
+}
+
+/// Rename a tag on every gem in the library
+///
+/// Matches `old` case-insensitively; a gem that already has `new` keeps a
+/// single copy. All gems change in one transaction. Knowledge enrichment
+/// files and the search index are refreshed for the affected gems.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of gems whose tags changed
+/// * `Err(String)` - Empty tag name or database error
+#[tauri::command]
+pub async fn rename_tag(
+    app_handle: tauri::AppHandle,
+    old: String,
+    new: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<usize, String> {
+    demo_mode.ensure_writable()?;
+
+    let (old, new) = (old.trim(), new.trim());
+    if old.is_empty() || new.is_empty() {
+        return Err("Tag names cannot be empty".to_string());
     }
+    let changed = gem_store.rename_tag(old, Some(new)).await?;
+    resync_gem_enrichment(&app_handle, gem_store.inner(), &changed).await;
+    Ok(changed.len())
 }
 
+/// Remove a tag from every gem in the library (case-insensitive)
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of gems the tag was removed from
+/// * `Err(String)` - Empty tag name or database error
 #[tauri::command]
-pub async fn setup_mlx_venv(
-    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
-    venv_manager: State<'_, Arc<VenvManager>>,
+pub async fn delete_tag(
     app_handle: tauri::AppHandle,
-) -> Result<(), String> {
-    let python_path = {
-        let manager = settings_manager.read()
-            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
-        manager.get().intelligence.python_path.clone()
-    };
+    tag: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<usize, String> {
+    demo_mode.ensure_writable()?;
 
-    venv_manager.setup(&python_path, &app_handle).await
+    let tag = tag.trim();
+    if tag.is_empty() {
+        return Err("Tag name cannot be empty".to_string());
+    }
+    let changed = gem_store.rename_tag(tag, None).await?;
+    resync_gem_enrichment(&app_handle, gem_store.inner(), &changed).await;
+    Ok(changed.len())
+}
+
+/// Rewrite the knowledge enrichment subfile and re-index each gem after its
+/// ai_enrichment changed outside `enrich_gem`
+async fn resync_gem_enrichment(app_handle: &AppHandle, gem_store: &Arc<dyn GemStore>, gem_ids: &[String]) {
+    let ks = app_handle.try_state::<Arc<dyn crate::knowledge::KnowledgeStore>>();
+    let search = app_handle.try_state::<Arc<dyn SearchResultProvider>>();
+
+    for id in gem_ids {
+        if let Some(ks) = &ks {
+            match gem_store.get(id).await {
+                Ok(Some(gem)) => {
+                    if let Some(ref enrichment) = gem.ai_enrichment {
+                        let formatted = crate::knowledge::assembler::format_enrichment(enrichment);
+                        if let Err(e) = ks.update_subfile(id, "enrichment.md", &formatted).await {
+                            eprintln!("Knowledge enrichment update failed for gem {}: {}", id, e);
+                        }
+                    }
+                }
+                Ok(None) => {}
+                Err(e) => eprintln!("Failed to load gem {} for knowledge update: {}", id, e),
+            }
+        }
+        if let Some(provider) = &search {
+            if let Err(e) = provider.index_gem(id).await {
+                eprintln!("Search: Failed to re-index gem {}: {}", id, e);
+            }
+        }
+    }
 }
 
+/// Type-ahead suggestions for the search box
+///
+/// Returns up to `limit` (default 8) distinct gem titles and tags matching
+/// `prefix`, most frequent first. Much cheaper than running `search_gems` on
+/// every keystroke.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const suggestions = await invoke<string[]>('search_suggestions', { prefix: 'ru', limit: 5 });
+/// // ['rust', 'Rust async book', ...]
+/// ```
 #[tauri::command]
-pub async fn reset_mlx_venv(
-    venv_manager: State<'_, Arc<VenvManager>>,
-) -> Result<(), String> {
-    venv_manager.reset()
+pub async fn search_suggestions(
+    prefix: String,
+    limit: Option<usize>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<String>, String> {
+    gem_store.suggest(&prefix, limit.unwrap_or(8)).await.map_err(String::from)
 }
 
-/// Filter gems by tag
+/// Search what was said in recordings
 ///
-/// This command returns gems that have the specified tag in their ai_enrichment.
-/// Results are ordered by captured_at descending (most recent first).
+/// Runs a full-text search restricted to gem transcripts (titles, descriptions,
+/// and content are ignored), ranked by relevance. Each match carries a short
+/// transcript excerpt with the matched terms wrapped in `**`.
 ///
 /// # Arguments
 ///
-/// * `tag` - The tag to filter by (exact match)
+/// * `query` - FTS5 search query (e.g., `budget`, `"ship it friday"`); parentheses
+///   and column filters are rejected outside quotes
+/// * `limit` - Optional maximum number of matches to return (default: 20)
+/// * `gem_store` - Managed state containing the GemStore trait object
+///
+/// # Returns
+///
+/// * `Ok(Vec<TranscriptMatch>)` - Gem previews with transcript snippets
+/// * `Err(String)` - Error message if the query is malformed or the search fails
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const matches = await invoke('search_transcripts', { query: '"ship it friday"' });
+/// ```
+#[tauri::command]
+pub async fn search_transcripts(
+    query: String,
+    limit: Option<usize>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<TranscriptMatch>, String> {
+    gem_store.search_transcripts(&query, limit.unwrap_or(20)).await.map_err(String::from)
+}
+
+/// Filter gems by language
+///
+/// Returns gems whose language exactly matches the given ISO 639-1 code,
+/// ordered by captured_at descending (most recent first). The language is
+/// `transcript_language` when set, else `source_meta.content_language`
+/// (detected from web content when the gem is saved or re-extracted).
+///
+/// # Arguments
+///
+/// * `language` - ISO 639-1 language code to filter by (e.g., "en", "zh")
 /// * `limit` - Optional maximum number of gems to return (default: 50)
 /// * `offset` - Optional number of gems to skip for pagination (default: 0)
 /// * `gem_store` - Managed state containing the GemStore trait object
 ///
 /// # Returns
 ///
-/// * `Ok(Vec<GemPreview>)` - Array of gem previews with the specified tag
+/// * `Ok(Vec<GemPreview>)` - Array of gem previews in the specified language
 /// * `Err(String)` - Error message if filtering fails
 ///
-/// # Errors
-///
-/// Returns an error if:
-/// - The GemStore filter_by_tag operation fails
-/// - Database connection issues occur
-///
 /// # Examples
 ///
 /// ```typescript
 /// import { invoke } from '@tauri-apps/api/core';
 ///
-/// try {
-///   const gems = await invoke('filter_gems_by_tag', {
-///     tag: 'rust',
-///     limit: 20
-///   });
-///   console.log(`Found ${gems.length} gems tagged with 'rust'`);
-/// } catch (error) {
-///   console.error(`Failed to filter gems: ${error}`);
-/// }
+/// const gems = await invoke('filter_gems_by_language', { language: 'zh', limit: 20 });
 /// ```
 #[tauri::command]
-pub async fn filter_gems_by_tag(
-    tag: String,
+pub async fn filter_gems_by_language(
+    language: String,
     limit: Option<usize>,
     offset: Option<usize>,
     gem_store: State<'_, Arc<dyn GemStore>>,
 ) -> Result<Vec<GemPreview>, String> {
-    gem_store.filter_by_tag(&tag, limit.unwrap_or(50), offset.unwrap_or(0)).await
+    gem_store.filter_by_language(&language, limit.unwrap_or(50), offset.unwrap_or(0)).await.map_err(String::from)
+}
+
+/// List languages present in the library
+///
+/// Returns each distinct language with the number of gems in it, most common
+/// first. A gem's language is its `transcript_language`, or for web content
+/// the `source_meta.content_language` detected at save time. Gems without a
+/// detected language are excluded.
+/// Intended for building a language facet in the gems list.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const languages = await invoke('list_gem_languages');
+/// // [{ language: 'en', count: 42 }, { language: 'zh', count: 7 }]
+/// ```
+#[tauri::command]
+pub async fn list_gem_languages(
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<LanguageCount>, String> {
+    gem_store.list_languages().await.map_err(String::from)
+}
+
+/// Number of gems processed between `transcript-language-backfill-progress` events
+const LANGUAGE_BACKFILL_BATCH_SIZE: usize = 25;
+
+/// Detect and fill in missing transcript languages
+///
+/// Older recording gems may have a transcript but no `transcript_language`
+/// (e.g., transcribed before language detection existed). This command runs a
+/// local language detector over each such transcript, saves the detected ISO
+/// 639-1 code, and refreshes the gem's knowledge `transcript.md`.
+///
+/// Gems are processed in batches; after each batch a
+/// `transcript-language-backfill-progress` event is emitted with
+/// `{ processed, total, updated }`. Transcripts whose language cannot be
+/// detected reliably are left unchanged.
+///
+/// # Returns
+///
+/// * `Ok(usize)` - Number of gems whose language was updated
+/// * `Err(String)` - Error message if the store query or a save fails
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+/// import { listen } from '@tauri-apps/api/event';
+///
+/// await listen('transcript-language-backfill-progress', (event) => {
+///   console.log(`${event.payload.processed}/${event.payload.total}`);
+/// });
+/// const updated = await invoke('backfill_transcript_language');
+/// ```
+#[tauri::command]
+pub async fn backfill_transcript_language(
+    app_handle: tauri::AppHandle,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<usize, String> {
+    demo_mode.ensure_writable()?;
+
+    let ids = gem_store.find_missing_transcript_language().await?;
+    let total = ids.len();
+    let mut processed = 0;
+    let mut updated = 0;
+
+    eprintln!("Gems: Backfilling transcript language for {} gems", total);
+
+    for batch in ids.chunks(LANGUAGE_BACKFILL_BATCH_SIZE) {
+        for id in batch {
+            processed += 1;
+
+            let Some((saved, language)) = backfill_gem_language(gem_store.as_ref(), id).await? else {
+                continue;
+            };
+            updated += 1;
+
+            if let (Some(ks), Some(transcript)) = (
+                app_handle.try_state::<Arc<dyn crate::knowledge::KnowledgeStore>>(),
+                saved.transcript.as_deref(),
+            ) {
+                let formatted = crate::knowledge::assembler::format_transcript(transcript, language);
+                if let Err(e) = ks.update_subfile(&saved.id, "transcript.md", &formatted).await {
+                    eprintln!("Knowledge file update failed for gem {}: {}", saved.id, e);
+                }
+            }
+        }
+
+        let _ = app_handle.emit("transcript-language-backfill-progress", serde_json::json!({
+            "processed": processed,
+            "total": total,
+            "updated": updated,
+        }));
+    }
+
+    eprintln!("Gems: Transcript language backfill complete ({} of {} updated)", updated, total);
+    crate::notifications::notify_job_completed(
+        &app_handle,
+        "Transcript language backfill",
+        &format!("Updated {} of {} gems", updated, total),
+    );
+    Ok(updated)
+}
+
+/// Detect and save the transcript language of gem `id`, returning the saved
+/// gem and its language (None if the gem is gone or the language unclear)
+async fn backfill_gem_language(
+    gem_store: &dyn GemStore,
+    id: &str,
+) -> Result<Option<(Gem, &'static str)>, String> {
+    let Some(mut gem) = gem_store.get(id).await? else {
+        return Ok(None);
+    };
+    let Some(language) = gem.transcript.as_deref()
+        .and_then(crate::intelligence::utils::detect_language)
+    else {
+        return Ok(None);
+    };
+
+    gem.transcript_language = Some(language.to_string());
+    let saved = gem_store.save(gem).await?;
+    Ok(Some((saved, language)))
 }
 
 
@@ -1368,7 +3815,33 @@ pub async fn start_recording(
         .map_err(|e| format!("Failed to acquire lock on RecordingManager: {}", e))?;
     
     let recordings_dir = file_manager.get_recordings_dir();
-    recording_manager.start_recording(recordings_dir)
+    recording_manager.start_recording(recordings_dir).map_err(String::from)
+}
+
+/// Resume recording into an existing recording file
+///
+/// New audio is appended to `filename` instead of starting a new file. See
+/// `RecordingManager::start_recording_append` for validation rules.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The recording's filename
+/// * `Err(String)` - A recording is in progress, the file is missing, not
+///   raw PCM, or currently being transcribed
+#[tauri::command]
+pub async fn start_recording_append(
+    filename: String,
+    state: State<'_, Mutex<RecordingManager>>,
+    file_manager: State<'_, FileManager>,
+) -> Result<String, String> {
+    let mut recording_manager = state
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock on RecordingManager: {}", e))?;
+
+    let recordings_dir = file_manager.get_recordings_dir();
+    recording_manager
+        .start_recording_append(recordings_dir, &filename)
+        .map_err(String::from)
 }
 
 /// Stop the current recording
@@ -1413,7 +3886,96 @@ pub async fn stop_recording(
         .lock()
         .map_err(|e| format!("Failed to acquire lock on RecordingManager: {}", e))?;
     
-    recording_manager.stop_recording()
+    recording_manager.stop_recording().map_err(String::from)
+}
+
+/// Recording state after a toggle (payload of the `recording-toggled` event)
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingToggleResult {
+    /// True if a recording is now in progress
+    pub recording: bool,
+    /// Filename of the recording that was just started (None when stopping)
+    pub filename: Option<String>,
+}
+
+/// Start recording if idle, stop it if active, and emit `recording-toggled`.
+///
+/// Shared by the `toggle_recording` command and the global shortcut. Must run
+/// inside the async runtime (starting a recording spawns tokio tasks).
+pub(crate) fn toggle_recording_state(app_handle: &AppHandle) -> Result<RecordingToggleResult, String> {
+    let result = {
+        let state = app_handle.state::<Mutex<RecordingManager>>();
+        let mut recording_manager = state
+            .lock()
+            .map_err(|e| format!("Failed to acquire lock on RecordingManager: {}", e))?;
+
+        if recording_manager.is_recording() {
+            recording_manager.stop_recording()?;
+            RecordingToggleResult { recording: false, filename: None }
+        } else {
+            let file_manager = app_handle.state::<FileManager>();
+            let filename = recording_manager.start_recording(file_manager.get_recordings_dir())?;
+            RecordingToggleResult { recording: true, filename: Some(filename) }
+        }
+    };
+
+    if let Err(e) = app_handle.emit("recording-toggled", &result) {
+        eprintln!("Warning: Failed to emit recording-toggled event: {}", e);
+    }
+
+    Ok(result)
+}
+
+/// Toggle recording: start if idle, stop if a recording is in progress
+///
+/// Uses `RecordingManager::is_recording` to decide. The usual
+/// `recording-started` / `recording-stopped` events are still emitted, plus a
+/// `recording-toggled` event carrying the resulting state for the tray/UI.
+///
+/// # Returns
+///
+/// * `Ok(RecordingToggleResult)` - The state after toggling
+/// * `Err(String)` - Error from starting or stopping the recording
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { recording, filename } = await invoke('toggle_recording');
+/// ```
+#[tauri::command]
+pub async fn toggle_recording(app_handle: AppHandle) -> Result<RecordingToggleResult, String> {
+    toggle_recording_state(&app_handle)
+}
+
+/// Get the most recent audio level of the active recording
+///
+/// The same values are pushed as `audio-level` events (~20/s) while
+/// recording; this command is for polling clients that missed them.
+///
+/// # Returns
+///
+/// * `Ok(Some(AudioLevel))` - RMS and peak in dBFS of the latest audio chunk
+/// * `Ok(None)` - Not recording, or no audio has been captured yet
+/// * `Err(String)` - Failed to acquire the RecordingManager lock
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const level = await invoke('get_audio_level');
+/// if (level) console.log(`RMS ${level.rms_dbfs} dBFS`);
+/// ```
+#[tauri::command]
+pub fn get_audio_level(
+    state: State<'_, Mutex<RecordingManager>>,
+) -> Result<Option<crate::transcription::AudioLevel>, String> {
+    let manager = state
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock on RecordingManager: {}", e))?;
+    Ok(manager.audio_level())
 }
 
 /// List all recordings in the recordings directory
@@ -1437,29 +3999,120 @@ pub async fn stop_recording(
 /// - File metadata cannot be accessed
 /// 
 /// # Examples
-/// 
+/// 
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+/// 
+/// interface RecordingMetadata {
+///   filename: string;
+///   size_bytes: number;
+///   created_at: number;
+///   duration_seconds: number;
+/// }
+/// 
+/// try {
+///   const recordings: RecordingMetadata[] = await invoke('list_recordings');
+///   console.log(`Found ${recordings.length} recordings`);
+/// } catch (error) {
+///   console.error(`Failed to list recordings: ${error}`);
+/// }
+/// ```
+#[tauri::command]
+pub fn list_recordings(
+    state: State<'_, FileManager>,
+) -> Result<Vec<RecordingMetadata>, String> {
+    state.list_recordings()
+}
+
+/// Check a recording's PCM file before transcribing it
+///
+/// Interrupted recordings can leave empty or truncated files. `likely_valid`
+/// is true when the file is nonempty and holds a whole number of 16-bit
+/// samples. A missing file is reported with `exists: false`.
+#[tauri::command]
+pub fn verify_recording(
+    filename: String,
+    state: State<'_, FileManager>,
+) -> Result<RecordingIntegrity, String> {
+    state.verify_recording(&filename)
+}
+
+/// List recordings filtered by duration and creation date, newest first
+///
+/// Bounds are inclusive and optional (see `RecordingFilter`).
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// // Meetings longer than 30 minutes from the last week
+/// const long = await invoke<RecordingMetadata[]>('filter_recordings', {
+///   filter: {
+///     min_duration_seconds: 1800,
+///     created_after: Math.floor(Date.now() / 1000) - 7 * 24 * 3600,
+///   },
+/// });
+/// ```
+#[tauri::command]
+pub fn filter_recordings(
+    filter: RecordingFilter,
+    state: State<'_, FileManager>,
+) -> Result<Vec<RecordingMetadata>, String> {
+    state.filter_recordings(&filter)
+}
+
+/// A recording with its gem and saved-transcript status (see `list_recordings_detailed`)
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordingDetails {
+    #[serde(flatten)]
+    pub metadata: RecordingMetadata,
+    /// True if a gem was saved from this recording
+    pub has_gem: bool,
+    /// ID of that gem
+    pub gem_id: Option<String>,
+    /// True if `recordings/{stem}/transcript.md` exists (see `get_saved_transcript`)
+    pub has_transcript: bool,
+}
+
+/// List recordings along with whether each has a gem and a saved transcript
+///
+/// Replaces calling `list_recordings`, `check_recording_gems_batch`, and
+/// `get_saved_transcript` per item. Same order as `list_recordings`.
+///
+/// # Examples
+///
 /// ```typescript
 /// import { invoke } from '@tauri-apps/api/core';
-/// 
-/// interface RecordingMetadata {
-///   filename: string;
-///   size_bytes: number;
-///   created_at: number;
-///   duration_seconds: number;
-/// }
-/// 
-/// try {
-///   const recordings: RecordingMetadata[] = await invoke('list_recordings');
-///   console.log(`Found ${recordings.length} recordings`);
-/// } catch (error) {
-///   console.error(`Failed to list recordings: ${error}`);
-/// }
+///
+/// const recordings = await invoke<RecordingDetails[]>('list_recordings_detailed');
+/// const unsaved = recordings.filter(r => !r.has_gem);
 /// ```
 #[tauri::command]
-pub fn list_recordings(
-    state: State<'_, FileManager>,
-) -> Result<Vec<RecordingMetadata>, String> {
-    state.list_recordings()
+pub async fn list_recordings_detailed(
+    app_handle: AppHandle,
+    file_manager: State<'_, FileManager>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<RecordingDetails>, String> {
+    let recordings = file_manager.list_recordings()?;
+    let filenames: Vec<String> = recordings.iter().map(|r| r.filename.clone()).collect();
+    let mut gems = gem_store.find_by_recording_filenames(&filenames).await?;
+
+    let mut details = Vec::with_capacity(recordings.len());
+    for metadata in recordings {
+        let gem_id = gems.remove(&metadata.filename).map(|gem| gem.id);
+        let has_transcript = RecordingChatSource::new(app_handle.clone(), metadata.filename.clone())?
+            .transcript_path()
+            .exists();
+        details.push(RecordingDetails {
+            metadata,
+            has_gem: gem_id.is_some(),
+            gem_id,
+            has_transcript,
+        });
+    }
+
+    Ok(details)
 }
 
 /// Convert a PCM recording to WAV format for playback
@@ -1577,10 +4230,38 @@ pub fn convert_to_wav(
 pub fn delete_recording(
     filename: String,
     state: State<'_, FileManager>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     state.delete_recording(&filename)
 }
 
+/// Concatenate recordings, in order, into a new recording
+///
+/// Byte-level append of the raw PCM files, no re-encoding, on a blocking
+/// thread. Inputs can't be appended to while they are copied. See
+/// `FileManager::concat_recordings` for validation rules.
+///
+/// # Returns
+///
+/// * `Ok(String)` - Filename of the new recording
+/// * `Err(String)` - Invalid or missing input, or the output name is taken
+#[tauri::command]
+pub async fn concat_recordings(
+    filenames: Vec<String>,
+    output_name: String,
+    state: State<'_, FileManager>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<String, String> {
+    demo_mode.ensure_writable()?;
+
+    let file_manager = state.inner().clone();
+    tokio::task::spawn_blocking(move || file_manager.concat_recordings(&filenames, &output_name))
+        .await
+        .map_err(|e| format!("Concatenation task failed: {}", e))?
+}
+
 /// Check if the current platform is supported for recording
 /// 
 /// This command returns true if the current platform supports audio recording
@@ -1662,6 +4343,7 @@ pub fn open_system_settings() -> Result<(), String> {
 ///   start_ms: number;
 ///   end_ms: number;
 ///   is_final: boolean;
+///   confidence: number | null;
 /// }
 /// 
 /// try {
@@ -1718,6 +4400,180 @@ pub async fn get_transcription_status(
     Ok(manager.get_status().await)
 }
 
+/// Result of switching the live transcription engine
+#[derive(Debug, Clone, Serialize)]
+pub struct TranscriptionEngineSwitch {
+    /// Engine that was requested (now saved in settings)
+    pub engine: String,
+
+    /// Name of the provider actually in use
+    pub provider: String,
+
+    /// True when the requested engine was unavailable and whisper-rs is used instead
+    pub fell_back: bool,
+}
+
+/// Switch the live transcription engine without restarting the app
+///
+/// Builds the provider with the same selection and fallback rules as
+/// startup (see `transcription::build_provider`), swaps it into the
+/// TranscriptionManager, and saves `transcription_engine` to settings.
+/// If transcription was disabled at startup, the manager is created now.
+///
+/// # Arguments
+///
+/// * `engine` - "whisper-rs", "whisperkit", or "mlx-omni"
+///
+/// # Returns
+///
+/// * `Ok(TranscriptionEngineSwitch)` - The provider in use, and whether it is a fallback
+/// * `Err(String)` - Unknown engine, transcription is active, invalid
+///   transcription settings, or no provider could be initialized
+#[tauri::command]
+pub async fn switch_transcription_engine(
+    engine: String,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    app_handle: tauri::AppHandle,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<TranscriptionEngineSwitch, String> {
+    demo_mode.ensure_writable()?;
+
+    if !["whisper-rs", "whisperkit", "mlx-omni"].contains(&engine.as_str()) {
+        return Err(format!(
+            "Transcription engine must be 'whisper-rs', 'whisperkit', or 'mlx-omni', got '{}'",
+            engine
+        ));
+    }
+
+    let mut updated = settings_manager
+        .read()
+        .map_err(|e| format!("Failed to acquire read lock: {}", e))?
+        .get();
+    updated.transcription.transcription_engine = engine.clone();
+
+    let config = crate::transcription::TranscriptionConfig::from_settings(&updated.transcription);
+    config.validate()?;
+
+    // Model loading blocks for seconds, so keep it off the async runtime
+    let provider = tokio::task::spawn_blocking({
+        let settings = updated.transcription.clone();
+        let app_handle = app_handle.clone();
+        move || crate::transcription::build_provider(&settings, &app_handle, &config)
+    })
+    .await
+    .map_err(|e| format!("Transcription engine initialization task failed: {}", e))??;
+    let provider_name = provider.name().to_string();
+
+    match app_handle.try_state::<tokio::sync::Mutex<TranscriptionManager>>() {
+        Some(state) => {
+            state.lock().await.replace_provider(provider, &updated.transcription).await?;
+        }
+        None => {
+            let manager = TranscriptionManager::new(provider, app_handle.clone(), &updated.transcription);
+            app_handle.manage(tokio::sync::Mutex::new(manager));
+        }
+    }
+
+    settings_manager
+        .read()
+        .map_err(|e| format!("Failed to acquire read lock: {}", e))?
+        .update(updated.clone())?;
+    app_handle
+        .emit("settings-changed", &updated.redacted())
+        .map_err(|e| format!("Failed to emit settings-changed event: {}", e))?;
+
+    Ok(TranscriptionEngineSwitch {
+        fell_back: engine == "whisperkit" && provider_name != "whisperkit",
+        engine,
+        provider: provider_name,
+    })
+}
+
+/// Get transcription pipeline diagnostics for the current or most recent recording
+///
+/// When the transcriber can't keep up, AudioRouter waits for it briefly (each
+/// wait is an overrun) and drops the audio if it is still behind. These
+/// counters show whether that happened; dropped frames, frequent overruns or a
+/// growing lag suggest switching to a lighter engine or model.
+///
+/// # Returns
+///
+/// * `Ok(TranscriptionDiagnostics)` - `{ dropped_frames, overruns, overrun_wait_ms, buffer_high_water, processing_lag_ms }`
+/// * `Err(String)` - Failed to acquire the RecordingManager lock
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { dropped_frames, overruns } = await invoke('transcription_diagnostics');
+/// if (dropped_frames > 0) console.warn(`Transcription skipped ${dropped_frames / 16000}s of audio`);
+/// else if (overruns > 0) console.warn(`Transcription fell behind ${overruns} times`);
+/// ```
+#[tauri::command]
+pub fn transcription_diagnostics(
+    state: State<'_, Mutex<RecordingManager>>,
+) -> Result<crate::transcription::TranscriptionDiagnostics, String> {
+    let manager = state
+        .lock()
+        .map_err(|e| format!("Failed to acquire lock on RecordingManager: {}", e))?;
+    Ok(manager.transcription_diagnostics())
+}
+
+/// Read-only "demo mode" flag, toggled with `set_demo_mode`
+///
+/// Runtime only (not persisted). While enabled, every command that changes
+/// the library, projects, knowledge files, search index or history, models
+/// or settings, or that creates or restores backups, fails instead of
+/// writing. Gem views simply aren't recorded.
+#[derive(Default)]
+pub struct DemoMode(AtomicBool);
+
+impl DemoMode {
+    pub fn is_enabled(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+
+    /// Error out if demo mode is on; call first in any command that writes
+    pub fn ensure_writable(&self) -> Result<(), String> {
+        if self.is_enabled() {
+            return Err("Not available in read-only demo mode".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Turn read-only demo mode on or off
+///
+/// Emits a "demo-mode-changed" event with the new value.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('set_demo_mode', { enabled: true });
+/// await invoke('save_gem', { gist }); // rejects: "Not available in read-only demo mode"
+/// ```
+#[tauri::command]
+pub fn set_demo_mode(
+    enabled: bool,
+    demo_mode: State<'_, DemoMode>,
+    app_handle: tauri::AppHandle,
+) -> Result<(), String> {
+    demo_mode.0.store(enabled, Ordering::SeqCst);
+    eprintln!("Demo mode {}", if enabled { "enabled" } else { "disabled" });
+    app_handle
+        .emit("demo-mode-changed", enabled)
+        .map_err(|e| format!("Failed to emit demo-mode-changed event: {}", e))
+}
+
+/// Whether read-only demo mode is on
+#[tauri::command]
+pub fn get_demo_mode(demo_mode: State<'_, DemoMode>) -> bool {
+    demo_mode.is_enabled()
+}
+
 /// Get current application settings
 /// 
 /// This command returns the current settings including transcription engine
@@ -1761,7 +4617,7 @@ pub fn get_settings(
     let manager = state
         .read()
         .map_err(|e| format!("Failed to acquire read lock: {}", e))?;
-    Ok(manager.get())
+    Ok(manager.get().redacted())
 }
 
 /// Update application settings
@@ -1807,21 +4663,93 @@ pub fn update_settings(
     settings: Settings,
     state: State<'_, Arc<RwLock<SettingsManager>>>,
     app_handle: tauri::AppHandle,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     let manager = state
         .read()
         .map_err(|e| format!("Failed to acquire read lock: {}", e))?;
     
+    // get_settings hands out redacted keys; keep the stored ones unless replaced
+    let mut settings = settings;
+    settings.keep_secrets(&manager.get());
     manager.update(settings.clone())?;
+    crate::browser::http::configure(&settings.network)?;
     
     // Emit settings-changed event
     app_handle
-        .emit("settings-changed", &settings)
+        .emit("settings-changed", &settings.redacted())
         .map_err(|e| format!("Failed to emit settings-changed event: {}", e))?;
     
     Ok(())
 }
 
+/// Update global shortcuts and re-register them immediately
+///
+/// Validates the accelerators (parseable, no two actions sharing a combo),
+/// re-registers every shortcut, then persists the new `shortcuts` section.
+/// If any shortcut fails to register (e.g. already taken by another app),
+/// the previous shortcuts are restored and nothing is saved.
+///
+/// # Arguments
+///
+/// * `settings` - New accelerator strings; an empty string disables that action
+/// * `state` - Managed state containing the SettingsManager
+/// * `app_handle` - Tauri app handle for registering shortcuts and emitting events
+///
+/// # Returns
+///
+/// * `Ok(())` - Shortcuts registered and saved
+/// * `Err(String)` - Invalid accelerator, conflict, or registration/persistence failure
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('update_shortcuts', {
+///   settings: { toggle_recording: 'Cmd+Shift+R', capture_tab: 'Cmd+Alt+G', toggle_copilot: '' }
+/// });
+/// ```
+#[tauri::command]
+pub fn update_shortcuts(
+    settings: ShortcutSettings,
+    state: State<'_, Arc<RwLock<SettingsManager>>>,
+    app_handle: tauri::AppHandle,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
+    settings.validate()?;
+
+    let manager = state
+        .read()
+        .map_err(|e| format!("Failed to acquire read lock: {}", e))?;
+    let previous = manager.get();
+    let shortcut_manager = ShortcutManager::new(app_handle.clone());
+
+    let mut updated = previous.clone();
+    updated.shortcuts = settings;
+
+    if let Err(e) = shortcut_manager
+        .apply(&updated.shortcuts)
+        .and_then(|_| manager.update(updated.clone()))
+    {
+        // Put the old bindings back so the user isn't left without shortcuts
+        if let Err(restore_err) = shortcut_manager.apply(&previous.shortcuts) {
+            eprintln!("Warning: Failed to restore previous shortcuts: {}", restore_err);
+        }
+        return Err(e);
+    }
+
+    app_handle
+        .emit("settings-changed", &updated.redacted())
+        .map_err(|e| format!("Failed to emit settings-changed event: {}", e))?;
+
+    Ok(())
+}
+
 /// List all supported Whisper models with their status
 /// 
 /// This command returns information about all supported models including:
@@ -1923,7 +4851,10 @@ pub async fn list_models(
 pub async fn download_model(
     model_name: String,
     state: State<'_, Arc<ModelManager>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     state.download_model(model_name).await
 }
 
@@ -2003,7 +4934,10 @@ pub async fn cancel_download(
 pub async fn delete_model(
     model_name: String,
     state: State<'_, Arc<ModelManager>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     state.delete_model(model_name).await
 }
 
@@ -2156,7 +5090,10 @@ pub async fn list_whisperkit_models(
 pub async fn download_whisperkit_model(
     model_name: String,
     state: State<'_, Arc<ModelManager>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     state.download_whisperkit_model(model_name).await
 }
 
@@ -2320,6 +5257,10 @@ pub async fn get_observer_status(
 /// 
 /// interface BrowserSettings {
 ///   observer_enabled: boolean;
+///   cache_gem_images: boolean;
+///   dedup_merged_paragraphs: boolean;
+///   track_content_changes: boolean;
+///   domain_js_overrides: Record<string, string>;
 /// }
 /// 
 /// try {
@@ -2371,7 +5312,10 @@ pub async fn update_browser_settings(
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
     browser_observer: State<'_, Arc<tokio::sync::Mutex<crate::browser::BrowserObserver>>>,
     observer_enabled: bool,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     // Get current settings
     let mut settings = {
         let manager = settings_manager.read()
@@ -2417,11 +5361,77 @@ pub async fn list_browser_tabs() -> Result<Vec<crate::browser::tabs::BrowserTab>
 pub async fn prepare_tab_gist(
     url: String,
     source_type: String,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
 ) -> Result<crate::browser::extractors::PageGist, String> {
     let st: crate::browser::tabs::SourceType =
         serde_json::from_str(&format!("\"{}\"", source_type))
             .unwrap_or(crate::browser::tabs::SourceType::Other);
-    crate::browser::extractors::prepare_gist(&url, &st).await
+    let gist_options = gist_options(&settings_manager)?;
+    crate::browser::extractors::prepare_gist(&url, &st, &gist_options).await
+}
+
+/// Result of `preview_extraction`
+#[derive(Debug, Clone, Serialize)]
+pub struct ExtractionPreview {
+    pub gist: crate::browser::extractors::PageGist,
+
+    /// Time spent fetching and extracting
+    pub timing_ms: u64,
+}
+
+/// Run the extractor for a URL and return what it produced, without saving
+///
+/// Same extraction as `prepare_tab_gist` (fetch, extract, truncate to
+/// `intelligence.max_content_chars`), but nothing is persisted: no gem, knowledge
+/// files or search index entry. Use it to check why a site extracts poorly
+/// before capturing it. Without `source_type` the URL is classified as
+/// `list_browser_tabs` would.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { gist, timing_ms } = await invoke('preview_extraction', {
+///   url: 'https://example.com/article',
+/// });
+/// console.log(gist.source_type, timing_ms, gist.content_excerpt?.length);
+/// ```
+#[tauri::command]
+pub async fn preview_extraction(
+    url: String,
+    source_type: Option<String>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+) -> Result<ExtractionPreview, String> {
+    let st = match source_type {
+        Some(source_type) => serde_json::from_str(&format!("\"{}\"", source_type))
+            .map_err(|_| format!("Unknown source type '{}'", source_type))?,
+        None => crate::browser::tabs::classify_url(&url),
+    };
+    let gist_options = gist_options(&settings_manager)?;
+
+    let started = std::time::Instant::now();
+    let gist = crate::browser::extractors::prepare_gist(&url, &st, &gist_options).await?;
+    Ok(ExtractionPreview {
+        gist,
+        timing_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// Extraction options for captured pages (content limit from
+/// settings.intelligence; content hashing and JS overrides from settings.browser)
+fn gist_options(
+    settings_manager: &Arc<RwLock<SettingsManager>>,
+) -> Result<crate::browser::extractors::GistOptions, String> {
+    let manager = settings_manager.read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+    let settings = manager.get();
+    let browser = settings.browser;
+    Ok(crate::browser::extractors::GistOptions {
+        max_content_chars: settings.intelligence.max_content_chars,
+        content_hash: browser.track_content_changes,
+        domain_js_overrides: browser.domain_js_overrides,
+    })
 }
 
 /// Prepare a gist for a browser tab, including the Claude conversation if detected.
@@ -2432,13 +5442,23 @@ pub async fn prepare_tab_gist(
 pub async fn prepare_tab_gist_with_claude(
     url: String,
     source_type: String,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
 ) -> Result<crate::browser::extractors::PageGist, String> {
     let st: crate::browser::tabs::SourceType =
         serde_json::from_str(&format!("\"{}\"", source_type))
             .unwrap_or(crate::browser::tabs::SourceType::Other);
+    let gist_options = gist_options(&settings_manager)?;
+    let merge_options = crate::browser::extractors::MergeOptions {
+        dedup_paragraphs: settings_manager
+            .read()
+            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+            .get()
+            .browser
+            .dedup_merged_paragraphs,
+    };
 
     let (page_result, claude_result) = tokio::join!(
-        crate::browser::extractors::prepare_gist(&url, &st),
+        crate::browser::extractors::prepare_gist(&url, &st, &gist_options),
         crate::browser::extractors::claude_extension::extract()
     );
 
@@ -2447,7 +5467,7 @@ pub async fn prepare_tab_gist_with_claude(
     match claude_result {
         Ok(claude_gist) => {
             eprintln!("[MergedGist] Merging page gist with Claude conversation");
-            Ok(crate::browser::extractors::merge_gists(page_gist, claude_gist))
+            Ok(crate::browser::extractors::merge_gists(page_gist, claude_gist, &merge_options))
         }
         Err(e) => {
             eprintln!("[MergedGist] Claude extraction failed, returning page-only gist: {}", e);
@@ -2491,6 +5511,7 @@ pub async fn export_gist(title: String, content: String) -> Result<String, Strin
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gems::test_utils::test_gem;
 
     // Note: Full integration tests for command handlers require a running Tauri app
     // and are better suited for end-to-end testing. The validation logic is tested
@@ -2527,6 +5548,179 @@ mod tests {
         }
     }
     
+    #[test]
+    fn test_demo_mode_blocks_writes() {
+        let demo_mode = DemoMode::default();
+        assert!(demo_mode.ensure_writable().is_ok());
+
+        demo_mode.0.store(true, Ordering::SeqCst);
+        assert!(demo_mode.ensure_writable().unwrap_err().contains("demo mode"));
+    }
+
+    #[test]
+    fn test_find_chat_session_logs() {
+        let recordings = tempfile::tempdir().unwrap();
+        let library = tempfile::tempdir().unwrap();
+        let recording_dir = recordings.path().join("recording_1");
+        std::fs::create_dir_all(&recording_dir).unwrap();
+        std::fs::write(recording_dir.join("chat_session_1700000000_ab12cd34.md"), "").unwrap();
+        std::fs::write(recording_dir.join("transcript.md"), "").unwrap();
+        std::fs::write(library.path().join("chat_session_1700000100.md"), "").unwrap();
+
+        let mut logs: Vec<(String, Option<String>)> = find_chat_session_logs(recordings.path(), library.path())
+            .into_iter()
+            .map(|(id, recording, _)| (id, recording))
+            .collect();
+        logs.sort();
+        assert_eq!(logs, vec![
+            ("chat_1700000000_ab12cd34".to_string(), Some("recording_1.pcm".to_string())),
+            ("chat_1700000100".to_string(), None),
+        ]);
+    }
+
+    #[tokio::test]
+    async fn test_cache_gem_image() {
+        use crate::gems::{test_utils::serve_image, SqliteGemStore};
+
+        let gem_store = SqliteGemStore::new_in_memory().unwrap();
+        let id = uuid::Uuid::new_v4().to_string();
+        let url = serve_image("HTTP/1.1 200 OK\r\nContent-Length: 3".to_string(), b"jpg".to_vec()).await;
+        let gem = gem_store.save(Gem { source_meta: serde_json::json!({ "image_url": url }), ..test_gem(&id) }).await.unwrap();
+
+        let cached = cache_gem_image(&gem_store, gem).await;
+        let local = cached.source_meta["image_local"].as_str().expect("image_local recorded");
+        assert_eq!(std::fs::read(local).unwrap(), b"jpg");
+        let stored = gem_store.get(&id).await.unwrap().unwrap();
+        assert_eq!(stored.source_meta["image_local"].as_str(), Some(local));
+        crate::gems::thumbnails::remove_thumbnail(&id).await.unwrap();
+
+        // A failed download leaves the gem relying on the remote URL
+        let url = serve_image("HTTP/1.1 404 Not Found\r\nContent-Length: 0".to_string(), Vec::new()).await;
+        let gem = Gem { source_meta: serde_json::json!({ "image_url": url }), ..test_gem("missing-image") };
+        let unchanged = cache_gem_image(&gem_store, gem.clone()).await;
+        assert_eq!(unchanged.source_meta, gem.source_meta);
+    }
+
+    #[tokio::test]
+    async fn test_preview_extraction_applies_browser_settings() {
+        use crate::gems::test_utils::serve_image;
+        use tauri::Manager;
+
+        let settings_dir = tempfile::tempdir().unwrap();
+        let manager = SettingsManager::new_with_path(settings_dir.path().join("settings.json")).unwrap();
+        let mut settings = manager.get();
+        settings.intelligence.max_content_chars = 1_000;
+        settings.browser.track_content_changes = true;
+        manager.update(settings).unwrap();
+        let app = tauri::test::mock_app();
+        app.manage(Arc::new(RwLock::new(manager)));
+
+        let html = format!(
+            "<html><head><title>Preview me</title></head><body><article><p>{}</p></article></body></html>",
+            "The first paragraph is long enough to be kept. ".repeat(40)
+        );
+        let url = serve_image(
+            format!("HTTP/1.1 200 OK\r\nContent-Type: text/html\r\nContent-Length: {}", html.len()),
+            html.as_bytes().to_vec(),
+        ).await;
+
+        let preview = preview_extraction(url, Some("Article".to_string()), app.state()).await.unwrap();
+        assert_eq!(preview.gist.title, "Preview me");
+        assert_eq!(preview.gist.source_type, crate::browser::tabs::SourceType::Article);
+        let excerpt = preview.gist.content_excerpt.unwrap();
+        assert!(excerpt.ends_with("[truncated]") && excerpt.chars().count() == 1_000);
+        assert_eq!(preview.gist.extra["excerpt_truncated"], true);
+        assert!(preview.gist.extra["content_hash"].is_string());
+
+        let err = preview_extraction("https://example.com".to_string(), Some("Blog".to_string()), app.state())
+            .await
+            .unwrap_err();
+        assert!(err.contains("Unknown source type"));
+    }
+
+    #[tokio::test]
+    async fn test_demo_mode_rejects_gem_edits() {
+        use crate::gems::{test_utils::test_gem, SqliteGemStore};
+        use tauri::Manager;
+
+        let app = tauri::test::mock_app();
+        let gem_store: Arc<dyn GemStore> = Arc::new(SqliteGemStore::new_in_memory().unwrap());
+        gem_store.save(test_gem("gem-1")).await.unwrap();
+        app.manage(gem_store.clone());
+        app.manage(DemoMode::default());
+
+        app.state::<DemoMode>().0.store(true, Ordering::SeqCst);
+        let err = update_gem_title("gem-1".to_string(), "Renamed".to_string(), app.state(), app.state())
+            .await
+            .unwrap_err();
+        assert!(err.contains("demo mode"));
+        assert_eq!(gem_store.get("gem-1").await.unwrap().unwrap().title, "gem-1");
+
+        app.state::<DemoMode>().0.store(false, Ordering::SeqCst);
+        update_gem_title("gem-1".to_string(), "Renamed".to_string(), app.state(), app.state())
+            .await
+            .unwrap();
+        assert_eq!(gem_store.get("gem-1").await.unwrap().unwrap().title, "Renamed");
+    }
+
+    #[tokio::test]
+    async fn test_delete_existing_gems_skips_missing_and_stops_at_errors() {
+        let gem_store = ["a", "b", "c", "d"].into_iter()
+            .fold(MockGemStore::new(), |store, id| store.with_gem(test_gem(id)))
+            .with_failing_delete("c");
+
+        // Missing IDs are skipped, not errors
+        let ids = vec!["a".to_string(), "missing".to_string()];
+        let (deleted, error) = delete_existing_gems(&gem_store, ids).await;
+        assert_eq!(deleted, vec!["a"]);
+        assert!(error.is_none());
+
+        // A storage error stops the batch; earlier deletes stay done
+        let ids = vec!["b".to_string(), "c".to_string(), "d".to_string()];
+        let (deleted, error) = delete_existing_gems(&gem_store, ids).await;
+        assert_eq!(deleted, vec!["b"]);
+        assert!(error.unwrap().contains("disk I/O error"));
+        assert!(gem_store.get("b").await.unwrap().is_none());
+        assert!(gem_store.get("c").await.unwrap().is_some());
+        assert!(gem_store.get("d").await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_backfill_gem_language() {
+        let gem_store = MockGemStore::new()
+            .with_gem(Gem {
+                transcript: Some("This is a recording of our weekly planning meeting where we discussed the roadmap.".to_string()),
+                ..test_gem("english")
+            })
+            .with_gem(Gem { transcript: Some("ok".to_string()), ..test_gem("unclear") });
+
+        let (saved, language) = backfill_gem_language(&gem_store, "english").await.unwrap().unwrap();
+        assert_eq!(language, "en");
+        assert_eq!(saved.transcript_language.as_deref(), Some("en"));
+        assert_eq!(
+            gem_store.get("english").await.unwrap().unwrap().transcript_language.as_deref(),
+            Some("en")
+        );
+
+        // Undetectable languages and missing gems are left alone
+        assert!(backfill_gem_language(&gem_store, "unclear").await.unwrap().is_none());
+        assert!(gem_store.get("unclear").await.unwrap().unwrap().transcript_language.is_none());
+        assert!(backfill_gem_language(&gem_store, "missing").await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_read_transcript_to_copy() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("transcript.md");
+
+        let err = read_transcript_to_copy(&path, "meeting.pcm").await.unwrap_err();
+        assert!(err.contains("'meeting.pcm'") && err.contains("transcribe the recording first"), "{}", err);
+
+        std::fs::write(&path, "# Transcript — meeting\n\nHéllo").unwrap();
+        let content = read_transcript_to_copy(&path, "meeting.pcm").await.unwrap();
+        assert_eq!(content, "# Transcript — meeting\n\nHéllo");
+    }
+
     // Test validation logic for convert_to_wav
     #[test]
     fn test_convert_to_wav_validation() {
@@ -2570,6 +5764,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem);
@@ -2600,6 +5796,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem_filename);
@@ -2623,6 +5821,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem_recording_path);
@@ -2646,6 +5846,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem_file);
@@ -2669,6 +5871,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem_path);
@@ -2693,13 +5897,214 @@ mod tests {
                 "duration": 300
             }),
             captured_at: "2024-03-15T14:30:22Z".to_string(),
-            ai_enrichment: None,
-            transcript: None,
-            transcript_language: None,
+            ai_enrichment: None,
+            transcript: None,
+            transcript_language: None,
+            user_notes: None,
+            label: None,
+        };
+
+        let result = extract_recording_path(&gem);
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn test_validate_source_url() {
+        assert!(validate_source_url("https://example.com/article").is_ok());
+        assert!(validate_source_url("jarvis://recording/20240315_143022.pcm").is_ok());
+        assert!(validate_source_url("file:///Users/me/notes.pdf").is_ok());
+        assert_eq!(validate_source_url("  https://example.com/article\n"), Ok("https://example.com/article"));
+
+        assert!(validate_source_url("").is_err());
+        assert!(validate_source_url("   ").is_err());
+        assert!(validate_source_url("/relative/path").is_err());
+        assert!(validate_source_url("example.com").is_err());
+        assert!(validate_source_url("about:blank").is_err());
+    }
+
+    #[test]
+    fn test_apply_reextracted_gist_keeps_missing_fields() {
+        let mut gem = new_text_gem("Old", "old content", Some(vec!["kept".to_string()])).unwrap();
+        gem.description = Some("old description".to_string());
+        gem.source_meta = serde_json::json!({ "image_url": "old.png", "other": 1 });
+
+        let gist = crate::browser::extractors::PageGist {
+            url: gem.source_url.clone(),
+            title: "New".to_string(),
+            source_type: crate::browser::tabs::SourceType::Article,
+            domain: gem.domain.clone(),
+            author: None,
+            description: None,
+            content_excerpt: Some("new content".to_string()),
+            published_date: Some("2024-01-01".to_string()),
+            image_url: None,
+            extra: serde_json::json!({ "content_hash": "hash-1" }),
+        };
+        // No stored hash yet: compared by content
+        assert!(apply_reextracted_gist(&mut gem, gist.clone()));
+
+        assert_eq!(gem.title, "Old");
+        assert_eq!(gem.content.as_deref(), Some("new content"));
+        assert_eq!(gem.description.as_deref(), Some("old description"));
+        assert_eq!(
+            gem.source_meta,
+            serde_json::json!({
+                "image_url": "old.png",
+                "other": 1,
+                "published_date": "2024-01-01",
+                "content_hash": "hash-1",
+            })
+        );
+        assert_eq!(gem.ai_enrichment.as_ref().unwrap()["tags"], serde_json::json!(["kept"]));
+
+        // Same hash again: nothing changed
+        assert!(!apply_reextracted_gist(&mut gem, gist.clone()));
+
+        // A different full-content hash counts even when the (truncated) excerpt matches
+        let changed_source = crate::browser::extractors::PageGist {
+            extra: serde_json::json!({ "content_hash": "hash-2" }),
+            ..gist
+        };
+        assert!(apply_reextracted_gist(&mut gem, changed_source));
+    }
+
+    #[test]
+    fn test_new_text_gem() {
+        let gem = new_text_gem(
+            "  Idea ",
+            "Some pasted text",
+            Some(vec!["Rust".to_string(), " rust ".to_string(), "".to_string(), "async".to_string()]),
+        ).unwrap();
+
+        assert_eq!(gem.title, "Idea");
+        assert_eq!(gem.source_type, "Note");
+        assert_eq!(gem.source_url, format!("jarvis://note/{}", gem.id));
+        assert_eq!(gem.ai_enrichment.unwrap()["tags"], serde_json::json!(["Rust", "async"]));
+
+        assert!(new_text_gem("Idea", "text", None).unwrap().ai_enrichment.is_none());
+        assert!(new_text_gem(" ", "text", None).is_err());
+        assert!(new_text_gem("Idea", "\n", None).is_err());
+    }
+
+    #[test]
+    fn test_enrichment_text_prefers_transcript_when_enabled() {
+        let mut gem = create_test_gem_with_recording("gem-1", "recording.pcm");
+        gem.content = Some("Video description".to_string());
+        gem.transcript = Some("What was actually said".to_string());
+
+        assert_eq!(enrichment_text(&gem, true), Some("What was actually said"));
+        assert_eq!(enrichment_text(&gem, false), Some("Video description"));
+
+        gem.transcript = Some("   ".to_string());
+        assert_eq!(enrichment_text(&gem, true), Some("Video description"));
+
+        gem.content = None;
+        gem.description = Some("Fallback".to_string());
+        assert_eq!(enrichment_text(&gem, true), Some("Fallback"));
+    }
+
+    #[test]
+    fn test_enrichment_diff_and_history() {
+        let previous = serde_json::json!({ "tags": ["Rust", "async"], "summary": "Old" });
+        let new = serde_json::json!({ "tags": ["rust", "tokio"], "summary": "Old " });
+        assert_eq!(
+            EnrichmentDiff::between(Some(&previous), &new),
+            EnrichmentDiff {
+                tags_added: vec!["tokio".to_string()],
+                tags_removed: vec!["async".to_string()],
+                summary_changed: false,
+            }
+        );
+        let first = EnrichmentDiff::between(None, &new);
+        assert_eq!(first.tags_added, vec!["rust", "tokio"]);
+        assert!(first.summary_changed);
+
+        let mut gem = create_test_gem_with_recording("gem-1", "recording.pcm");
+        for i in 0..ENRICHMENT_HISTORY_LIMIT + 2 {
+            push_enrichment_history(&mut gem, serde_json::json!({ "summary": i }));
+        }
+        let history = gem.source_meta["enrichment_history"].as_array().unwrap();
+        assert_eq!(history.len(), ENRICHMENT_HISTORY_LIMIT);
+        assert_eq!(history[0]["enrichment"]["summary"], 2, "oldest entries are dropped");
+        assert_eq!(gem.source_meta["recording_filename"], "recording.pcm");
+    }
+
+    #[test]
+    fn test_validate_gem_label() {
+        assert_eq!(validate_gem_label("red"), Ok("red"));
+        assert_eq!(validate_gem_label(" Purple "), Ok("purple"));
+        assert!(validate_gem_label("teal").is_err());
+        assert!(validate_gem_label("").is_err());
+    }
+
+    #[test]
+    fn test_truncate_gem_content_at_char_boundary() {
+        let mut gem = Gem {
+            source_url: "https://example.com".to_string(),
+            title: "Long page".to_string(),
+            content: Some("héllo wörld, ".repeat(2)),
+            source_meta: serde_json::json!({ "published_date": "2024-01-01" }),
+            captured_at: "2024-03-15T14:30:22Z".to_string(),
+            ..test_gem("test-id")
         };
 
-        let result = extract_recording_path(&gem);
-        assert!(result.is_none());
+        // Under the limit: unchanged, no metadata
+        truncate_gem_content(&mut gem, 26);
+        assert_eq!(gem.content.as_deref(), Some("héllo wörld, héllo wörld, "));
+        assert!(gem.source_meta.get("content_truncated").is_none());
+
+        // Cut inside multi-byte text without splitting a character; the
+        // marker fits within the limit
+        truncate_gem_content(&mut gem, 21);
+        assert_eq!(gem.content.as_deref(), Some("héllo wö\n\n[truncated]"));
+        assert_eq!(gem.source_meta["content_truncated"], true);
+        assert_eq!(gem.source_meta["original_content_length"], 26);
+        assert_eq!(gem.source_meta["published_date"], "2024-01-01");
+    }
+
+    #[test]
+    fn test_merge_gem_contents() {
+        let recording = |id: &str, captured_at: &str, transcript: &str, tags: serde_json::Value| Gem {
+            source_type: "Other".to_string(),
+            source_url: format!("jarvis://recording/{}.pcm", id),
+            domain: "jarvis-app".to_string(),
+            title: format!("Recording {}", id),
+            source_meta: serde_json::json!({ "recording_filename": format!("{}.pcm", id) }),
+            captured_at: captured_at.to_string(),
+            ai_enrichment: Some(serde_json::json!({ "tags": tags, "summary": id })),
+            transcript: Some(transcript.to_string()),
+            ..test_gem(id)
+        };
+        // The secondary is the first half of the meeting
+        let mut primary = recording("b", "2024-03-15T15:00:00Z", "second half", serde_json::json!(["Budget", "Q3"]));
+        let secondary = recording("a", "2024-03-15T14:00:00Z", "first half", serde_json::json!(["q3", "Hiring"]));
+
+        merge_gem_contents(&mut primary, &secondary);
+
+        assert_eq!(primary.transcript.as_deref(), Some("first half\n\nsecond half"));
+        let enrichment = primary.ai_enrichment.unwrap();
+        assert_eq!(enrichment["tags"], serde_json::json!(["Budget", "Q3", "Hiring"]));
+        assert_eq!(enrichment["summary"], "b");
+        assert_eq!(primary.title, "Recording b");
+        assert_eq!(primary.source_meta["recording_filename"], "b.pcm");
+        assert_eq!(primary.source_meta["merged_recording_filenames"], serde_json::json!(["a.pcm"]));
+    }
+
+    #[test]
+    fn test_to_key_concepts_counts_mentions() {
+        use crate::intelligence::provider::CoPilotConcept;
+
+        let concepts = vec![
+            CoPilotConcept { term: "Whisper".to_string(), context: "Speech model".to_string() },
+            CoPilotConcept { term: "diarization".to_string(), context: "Who spoke when".to_string() },
+        ];
+        let text = "whisper transcribes; Whisper is fast. WHISPER!";
+
+        let key_concepts = to_key_concepts(concepts, text);
+        assert_eq!(key_concepts[0].mention_count, 3);
+        assert_eq!(key_concepts[0].cycle_added, 0);
+        // Not literally in the text, but the model found it: counted once
+        assert_eq!(key_concepts[1].mention_count, 1);
     }
 
     #[test]
@@ -2724,6 +6129,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem_other);
@@ -2747,6 +6154,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem_recording);
@@ -2770,6 +6179,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
 
         let result = extract_recording_path(&gem_youtube);
@@ -2809,6 +6220,8 @@ mod tests {
                 ai_enrichment: None,
                 transcript,
                 transcript_language: None,
+                user_notes: None,
+                label: None,
             }
         })
     }
@@ -2841,6 +6254,8 @@ mod tests {
                 ai_enrichment: None,
                 transcript: None,
                 transcript_language: None,
+                user_notes: None,
+                label: None,
             }
         })
     }
@@ -2892,9 +6307,9 @@ mod tests {
     pub(super) struct MockIntelProvider {
         available: bool,
         availability_reason: Option<String>,
-        transcript_result: Mutex<Option<Result<TranscriptResult, String>>>,
-        tags_result: Mutex<Option<Result<Vec<String>, String>>>,
-        summary_result: Mutex<Option<Result<String, String>>>,
+        transcript_result: Mutex<Option<JarvisResult<TranscriptResult>>>,
+        tags_result: Mutex<Option<JarvisResult<Vec<String>>>>,
+        summary_result: Mutex<Option<JarvisResult<String>>>,
     }
     
     impl MockIntelProvider {
@@ -2915,17 +6330,22 @@ mod tests {
         }
         
         pub(super) fn with_transcript_result(self, result: Result<TranscriptResult, String>) -> Self {
-            *self.transcript_result.lock().unwrap() = Some(result);
+            *self.transcript_result.lock().unwrap() = Some(result.map_err(JarvisError::Provider));
+            self
+        }
+        
+        pub(super) fn with_transcript_error(self, error: JarvisError) -> Self {
+            *self.transcript_result.lock().unwrap() = Some(Err(error));
             self
         }
         
         pub(super) fn with_tags_result(self, result: Result<Vec<String>, String>) -> Self {
-            *self.tags_result.lock().unwrap() = Some(result);
+            *self.tags_result.lock().unwrap() = Some(result.map_err(JarvisError::Provider));
             self
         }
         
         pub(super) fn with_summary_result(self, result: Result<String, String>) -> Self {
-            *self.summary_result.lock().unwrap() = Some(result);
+            *self.summary_result.lock().unwrap() = Some(result.map_err(JarvisError::Provider));
             self
         }
     }
@@ -2939,19 +6359,19 @@ mod tests {
             }
         }
         
-        async fn generate_tags(&self, _content: &str) -> Result<Vec<String>, String> {
+        async fn generate_tags(&self, _content: &str) -> JarvisResult<Vec<String>> {
             self.tags_result.lock().unwrap()
                 .clone()
                 .unwrap_or_else(|| Ok(vec!["test".to_string(), "mock".to_string()]))
         }
         
-        async fn summarize(&self, _content: &str) -> Result<String, String> {
+        async fn summarize(&self, _content: &str) -> JarvisResult<String> {
             self.summary_result.lock().unwrap()
                 .clone()
                 .unwrap_or_else(|| Ok("Mock summary".to_string()))
         }
         
-        async fn generate_transcript(&self, _audio_path: &std::path::Path) -> Result<TranscriptResult, String> {
+        async fn generate_transcript(&self, _audio_path: &std::path::Path) -> JarvisResult<TranscriptResult> {
             self.transcript_result.lock().unwrap()
                 .clone()
                 .unwrap_or_else(|| Ok(TranscriptResult {
@@ -2964,12 +6384,15 @@ mod tests {
     /// Mock GemStore for testing
     pub(super) struct MockGemStore {
         gems: Mutex<HashMap<String, Gem>>,
+        /// `delete` of this ID fails with a storage error
+        failing_delete: Option<String>,
     }
     
     impl MockGemStore {
         pub(super) fn new() -> Self {
             Self {
                 gems: Mutex::new(HashMap::new()),
+                failing_delete: None,
             }
         }
         
@@ -2977,37 +6400,56 @@ mod tests {
             self.gems.lock().unwrap().insert(gem.id.clone(), gem);
             self
         }
+
+        pub(super) fn with_failing_delete(mut self, id: &str) -> Self {
+            self.failing_delete = Some(id.to_string());
+            self
+        }
     }
     
     #[async_trait::async_trait]
     impl GemStore for MockGemStore {
-        async fn save(&self, gem: Gem) -> Result<Gem, String> {
+        async fn save(&self, gem: Gem) -> JarvisResult<Gem> {
             self.gems.lock().unwrap().insert(gem.id.clone(), gem.clone());
             Ok(gem)
         }
         
-        async fn get(&self, id: &str) -> Result<Option<Gem>, String> {
+        async fn get(&self, id: &str) -> JarvisResult<Option<Gem>> {
             Ok(self.gems.lock().unwrap().get(id).cloned())
         }
         
-        async fn list(&self, _limit: usize, _offset: usize) -> Result<Vec<GemPreview>, String> {
+        async fn list(&self, _limit: usize, _offset: usize) -> JarvisResult<Vec<GemPreview>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn list_with_preview(&self, _limit: usize, _offset: usize, _preview_chars: usize) -> JarvisResult<Vec<GemPreview>> {
             unimplemented!("Not needed for transcribe_gem tests")
         }
         
-        async fn search(&self, _query: &str, _limit: usize) -> Result<Vec<GemPreview>, String> {
+        async fn search(&self, _query: &str, _limit: usize) -> JarvisResult<Vec<GemPreview>> {
             unimplemented!("Not needed for transcribe_gem tests")
         }
         
-        async fn filter_by_tag(&self, _tag: &str, _limit: usize, _offset: usize) -> Result<Vec<GemPreview>, String> {
+        async fn filter_by_tag(&self, _tag: &str, _limit: usize, _offset: usize) -> JarvisResult<Vec<GemPreview>> {
             unimplemented!("Not needed for transcribe_gem tests")
         }
         
-        async fn delete(&self, id: &str) -> Result<(), String> {
-            self.gems.lock().unwrap().remove(id);
-            Ok(())
+        async fn delete(&self, id: &str) -> JarvisResult<bool> {
+            if self.failing_delete.as_deref() == Some(id) {
+                return Err(JarvisError::Db("disk I/O error".to_string()));
+            }
+            Ok(self.gems.lock().unwrap().remove(id).is_some())
+        }
+        
+        async fn merge_into(&self, _merged: Gem, _absorbed_id: &str) -> JarvisResult<Gem> {
+            unimplemented!("Not needed for transcribe_gem tests")
         }
         
-        async fn find_by_recording_filename(&self, filename: &str) -> Result<Option<GemPreview>, String> {
+        async fn find_by_recording_filenames(&self, _filenames: &[String]) -> JarvisResult<std::collections::HashMap<String, GemPreview>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn find_by_recording_filename(&self, filename: &str) -> JarvisResult<Option<GemPreview>> {
             // Search through all gems for one with matching recording_filename in source_meta
             let gems = self.gems.lock().unwrap();
             let matching_gem = gems.values()
@@ -3039,10 +6481,124 @@ mod tests {
                 summary: None,
                 enrichment_source: None,
                 transcript_language: gem.transcript_language.clone(),
+                label: gem.label.clone(),
             }))
         }
+
+        async fn find_by_source_url(&self, source_url: &str) -> JarvisResult<Option<GemPreview>> {
+            Ok(self.gems.lock().unwrap().values()
+                .find(|gem| gem.source_url == source_url)
+                .map(|gem| GemPreview {
+                    id: gem.id.clone(),
+                    source_type: gem.source_type.clone(),
+                    source_url: gem.source_url.clone(),
+                    domain: gem.domain.clone(),
+                    title: gem.title.clone(),
+                    author: gem.author.clone(),
+                    description: gem.description.clone(),
+                    content_preview: None,
+                    captured_at: gem.captured_at.clone(),
+                    tags: None,
+                    summary: None,
+                    enrichment_source: None,
+                    transcript_language: gem.transcript_language.clone(),
+                    label: gem.label.clone(),
+                }))
+        }
+
+        async fn search_transcripts(&self, _query: &str, _limit: usize) -> JarvisResult<Vec<TranscriptMatch>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn filter_by_language(&self, _language: &str, _limit: usize, _offset: usize) -> JarvisResult<Vec<GemPreview>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn list_languages(&self) -> JarvisResult<Vec<LanguageCount>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn tag_cooccurrence(&self, _min_count: usize) -> JarvisResult<Vec<TagCooccurrence>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn find_missing_transcript_language(&self) -> JarvisResult<Vec<String>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn update_title(&self, id: &str, title: &str) -> JarvisResult<()> {
+            match self.gems.lock().unwrap().get_mut(id) {
+                Some(gem) => {
+                    gem.title = title.to_string();
+                    Ok(())
+                }
+                None => Err(JarvisError::NotFound(format!("Gem with id '{}' not found", id))),
+            }
+        }
+
+        async fn update_notes(&self, id: &str, notes: Option<&str>) -> JarvisResult<()> {
+            match self.gems.lock().unwrap().get_mut(id) {
+                Some(gem) => {
+                    gem.user_notes = notes.map(str::to_string);
+                    Ok(())
+                }
+                None => Err(JarvisError::NotFound(format!("Gem with id '{}' not found", id))),
+            }
+        }
+
+        async fn suggest(&self, _prefix: &str, _limit: usize) -> JarvisResult<Vec<String>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn update_label(&self, _id: &str, _label: Option<&str>) -> JarvisResult<()> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn rename_tag(&self, _old: &str, _new: Option<&str>) -> JarvisResult<Vec<String>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn filter_by_label(&self, _label: &str, _limit: usize, _offset: usize) -> JarvisResult<Vec<GemPreview>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn repair_search_index(&self) -> JarvisResult<crate::gems::SearchIndexRepair> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn rebuild_fts_index(&self) -> JarvisResult<usize> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn mark_viewed(&self, _id: &str) -> JarvisResult<()> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn list_recently_viewed(&self, _limit: usize) -> JarvisResult<Vec<crate::gems::ViewedGem>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn list_most_viewed(&self, _limit: usize) -> JarvisResult<Vec<crate::gems::ViewedGem>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn compact(&self) -> JarvisResult<crate::gems::CompactionResult> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn backup_to(&self, _path: &std::path::Path) -> JarvisResult<()> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn restore_from(&self, _path: &std::path::Path) -> JarvisResult<()> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
+
+        async fn list_action_items(&self) -> JarvisResult<Vec<crate::gems::GemActionItems>> {
+            unimplemented!("Not needed for transcribe_gem tests")
+        }
     }
-    
+
     // Helper function to create a test gem with recording metadata
     pub(super) fn create_test_gem_with_recording(id: &str, filename: &str) -> Gem {
         Gem {
@@ -3061,6 +6617,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         }
     }
     
@@ -3082,6 +6640,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         }
     }
 }
@@ -3131,6 +6691,7 @@ pub fn check_claude_panel() -> ClaudePanelStatus {
     #[cfg(target_os = "macos")]
     {
         use crate::browser::accessibility::AccessibilityReader;
+        use crate::browser::extractors::claude_extension::CLAUDE_WEB_AREA_TITLES;
 
         let not_detected = ClaudePanelStatus { detected: false, active_tab_url: None, needs_accessibility: false };
 
@@ -3143,16 +6704,11 @@ pub fn check_claude_panel() -> ClaudePanelStatus {
             Err(_) => return not_detected,
         };
 
-        let web_areas = match AccessibilityReader::find_web_areas(pid) {
-            Ok(areas) => areas,
+        let detected = match AccessibilityReader::find_web_areas_matching(pid, CLAUDE_WEB_AREA_TITLES) {
+            Ok(areas) => !areas.is_empty(),
             Err(_) => return not_detected,
         };
 
-        let detected = web_areas.iter().any(|wa| {
-            let lower = wa.title.to_lowercase();
-            lower.contains("claude") || lower.contains("anthropic") || lower.contains("side panel")
-        });
-
         if detected {
             let active_tab_url = crate::browser::adapters::chrome::get_active_tab_url_sync().ok();
             eprintln!("[ClaudePanel] Detected. Active tab URL: {:?}", active_tab_url);
@@ -3168,6 +6724,76 @@ pub fn check_claude_panel() -> ClaudePanelStatus {
     }
 }
 
+/// Capture a ChatGPT conversation via macOS Accessibility APIs
+///
+/// Reads the conversation from the ChatGPT desktop app if it is running,
+/// otherwise from a ChatGPT page in Chrome. Use this when the DOM-based
+/// ChatGPT extractor can't see the messages (client-rendered content).
+///
+/// # Returns
+///
+/// * `Ok(PageGist)` - The extracted conversation as a PageGist
+/// * `Err(String)` - Error message if extraction fails
+///
+/// # Errors
+///
+/// * "Accessibility permission not granted" - User needs to grant accessibility permission
+/// * "Neither the ChatGPT desktop app nor Chrome is running"
+/// * "No ChatGPT conversation found" - No conversation is open
+/// * "ChatGPT conversation capture is only available on macOS" - Non-macOS platforms
+///
+/// # Example
+///
+/// ```typescript
+/// const gist = await invoke('capture_chatgpt_conversation');
+/// ```
+#[tauri::command]
+pub async fn capture_chatgpt_conversation() -> Result<crate::browser::extractors::PageGist, String> {
+    crate::browser::extractors::chatgpt_accessibility::extract().await
+}
+
+/// Status of ChatGPT conversation detection via accessibility
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatGptPanelStatus {
+    pub detected: bool,
+    /// "desktop_app" or "chrome" when detected
+    pub surface: Option<String>,
+    pub needs_accessibility: bool,
+}
+
+/// Check if a ChatGPT conversation is visible to the accessibility capture
+///
+/// Looks for the ChatGPT desktop app first, then for a ChatGPT web area in Chrome.
+#[tauri::command]
+pub fn check_chatgpt_panel() -> ChatGptPanelStatus {
+    #[cfg(target_os = "macos")]
+    {
+        use crate::browser::accessibility::AccessibilityReader;
+        use crate::browser::extractors::chatgpt_accessibility::detect_surface;
+
+        if !AccessibilityReader::check_permission() {
+            return ChatGptPanelStatus { detected: false, surface: None, needs_accessibility: true };
+        }
+
+        match detect_surface() {
+            Some(surface) => {
+                eprintln!("[ChatGptPanel] Detected via {}", surface.as_str());
+                ChatGptPanelStatus {
+                    detected: true,
+                    surface: Some(surface.as_str().to_string()),
+                    needs_accessibility: false,
+                }
+            }
+            None => ChatGptPanelStatus { detected: false, surface: None, needs_accessibility: false },
+        }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        ChatGptPanelStatus { detected: false, surface: None, needs_accessibility: false }
+    }
+}
+
 /// Check if accessibility permission is granted
 ///
 /// This command checks if the app has accessibility permission on macOS,
@@ -3200,6 +6826,43 @@ pub fn check_accessibility_permission() -> bool {
     }
 }
 
+/// Ask the user to grant accessibility permission
+///
+/// Shows the macOS accessibility prompt (which links to System Settings) and,
+/// if permission is still missing, opens Privacy & Security → Accessibility
+/// directly. Permission usually takes effect only after the user toggles the
+/// app on, so callers should re-check with `check_accessibility_permission`.
+///
+/// # Returns
+///
+/// * `Ok(true)` - Permission is granted
+/// * `Ok(false)` - Permission is not yet granted; the prompt/settings pane was shown
+/// * `Err(String)` - Settings could not be opened, or the platform is not macOS
+///
+/// # Example
+///
+/// ```typescript
+/// const granted = await invoke<boolean>('request_accessibility_permission');
+/// ```
+#[tauri::command]
+pub fn request_accessibility_permission() -> Result<bool, String> {
+    #[cfg(target_os = "macos")]
+    {
+        use crate::browser::accessibility::AccessibilityReader;
+
+        if AccessibilityReader::request_permission() {
+            return Ok(true);
+        }
+        PlatformDetector::open_accessibility_settings()?;
+        Ok(AccessibilityReader::check_permission())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        Err("Accessibility permission is only available on macOS".to_string())
+    }
+}
+
 /// List all LLM models with their status
 ///
 /// This command returns all models from the LLM catalog with their current
@@ -3259,7 +6922,10 @@ pub async fn list_llm_models(
 pub async fn download_llm_model(
     model_id: String,
     llm_manager: State<'_, Arc<LlmModelManager>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     llm_manager.download_model(model_id).await
 }
 
@@ -3327,7 +6993,10 @@ pub async fn delete_llm_model(
     model_id: String,
     llm_manager: State<'_, Arc<LlmModelManager>>,
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     // Check if this is the active model
     let active_model = {
         let manager = settings_manager.read()
@@ -3380,7 +7049,10 @@ pub async fn switch_llm_model(
     llm_manager: State<'_, Arc<LlmModelManager>>,
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
     mlx_provider: State<'_, Arc<tokio::sync::Mutex<Option<Arc<crate::intelligence::MlxProvider>>>>>,
+    demo_mode: State<'_, DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     // Verify model is downloaded
     let model_path = llm_manager.model_path(&model_id);
     if !model_path.exists() {
@@ -3460,6 +7132,113 @@ pub async fn switch_llm_model(
     }
 }
 
+/// An API endpoint as listed by `list_api_endpoints`, which reports whether
+/// a key is set rather than the key itself
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ApiEndpointSummary {
+    pub name: String,
+    pub base_url: String,
+    pub model: String,
+
+    /// Whether an API key is configured
+    pub has_key: bool,
+}
+
+impl From<crate::settings::ApiEndpointConfig> for ApiEndpointSummary {
+    fn from(endpoint: crate::settings::ApiEndpointConfig) -> Self {
+        Self {
+            has_key: endpoint.api_key.is_some_and(|key| !key.is_empty()),
+            name: endpoint.name,
+            base_url: endpoint.base_url,
+            model: endpoint.model,
+        }
+    }
+}
+
+/// Configured endpoints for the "api" provider, from `list_api_endpoints`
+#[derive(Debug, Clone, Serialize)]
+pub struct ApiEndpoints {
+    pub endpoints: Vec<ApiEndpointSummary>,
+
+    /// Name of the endpoint in use, if one is selected
+    pub active: Option<String>,
+}
+
+/// List the configured API endpoints and which one is active
+#[tauri::command]
+pub fn list_api_endpoints(
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+) -> Result<ApiEndpoints, String> {
+    let intelligence = settings_manager
+        .read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence;
+    Ok(ApiEndpoints {
+        endpoints: intelligence.api_endpoints.into_iter().map(ApiEndpointSummary::from).collect(),
+        active: intelligence.active_api_endpoint,
+    })
+}
+
+/// Add an endpoint for the "api" provider
+///
+/// The first endpoint added becomes the active one. Names must be unique.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// await invoke('add_api_endpoint', {
+///   endpoint: { name: 'ollama', base_url: 'http://localhost:11434/v1', api_key: null, model: 'llama3' },
+/// });
+/// ```
+#[tauri::command]
+pub fn add_api_endpoint(
+    endpoint: crate::settings::ApiEndpointConfig,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
+    let manager = settings_manager
+        .read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+    let mut settings = manager.get();
+    if settings.intelligence.api_endpoints.iter().any(|e| e.name == endpoint.name) {
+        return Err(format!("An API endpoint named '{}' already exists", endpoint.name));
+    }
+    if settings.intelligence.active_api_endpoint.is_none() {
+        settings.intelligence.active_api_endpoint = Some(endpoint.name.clone());
+    }
+    settings.intelligence.api_endpoints.push(endpoint);
+    manager.update(settings)
+}
+
+/// Select which configured endpoint the "api" provider uses
+///
+/// Note: the API provider is not implemented yet (selecting "api" falls
+/// back to NoOpProvider), so there is no running provider to rebuild; the
+/// selection is saved and read when the provider is created.
+#[tauri::command]
+pub fn set_active_api_endpoint(
+    name: String,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
+    let manager = settings_manager
+        .read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+    let mut settings = manager.get();
+    if !settings.intelligence.api_endpoints.iter().any(|e| e.name == name) {
+        return Err(format!("No API endpoint named '{}'", name));
+    }
+    settings.intelligence.active_api_endpoint = Some(name);
+    manager.update(settings)
+}
+
     // Unit tests for transcribe_gem command
     
     // Note: These tests cannot be run as standard #[tokio::test] because transcribe_gem
@@ -3539,7 +7318,7 @@ pub async fn switch_llm_model(
             assert!(recording_path.is_some());
             
             // 4. Transcript should be generated successfully
-            let transcript_result: Result<TranscriptResult, String> = provider.generate_transcript(&test_file).await;
+            let transcript_result: JarvisResult<TranscriptResult> = provider.generate_transcript(&test_file).await;
             assert!(transcript_result.is_ok());
             let transcript = transcript_result.unwrap();
             assert_eq!(transcript.language, "en");
@@ -3620,13 +7399,12 @@ pub async fn switch_llm_model(
             
             let provider = Arc::new(
                 MockIntelProvider::new()
-                    .with_transcript_result(Err("Transcript generation not supported by this provider".to_string()))
+                    .with_transcript_error(JarvisError::Unsupported("Transcript generation not supported by this provider".to_string()))
             ) as Arc<dyn IntelProvider>;
             
             // Verify transcription is not supported
             let result = provider.generate_transcript(&test_file).await;
-            assert!(result.is_err());
-            assert!(result.unwrap_err().contains("not supported"));
+            assert!(matches!(result, Err(JarvisError::Unsupported(_))));
             
             // In the actual command, this would return:
             // Err("Current AI provider does not support transcription")
@@ -3782,7 +7560,7 @@ pub async fn switch_llm_model(
                 }));
 
             // Call the actual helper function
-            let result = transcribe_recording_inner(filename, &provider).await;
+            let result = transcribe_recording_inner(filename, &provider, 180).await;
             
             assert!(result.is_ok(), "Expected success, got error: {:?}", result.err());
             let transcript = result.unwrap();
@@ -3804,7 +7582,7 @@ pub async fn switch_llm_model(
                 }));
 
             // Call the actual helper function
-            let result = transcribe_recording_inner(filename, &provider).await;
+            let result = transcribe_recording_inner(filename, &provider, 180).await;
             
             assert!(result.is_err(), "Expected error for missing file");
             let error = result.unwrap_err();
@@ -3821,7 +7599,7 @@ pub async fn switch_llm_model(
                 .with_availability(false, Some("Provider not ready".to_string()));
 
             // Call the actual helper function
-            let result = transcribe_recording_inner(filename, &provider).await;
+            let result = transcribe_recording_inner(filename, &provider, 180).await;
             
             assert!(result.is_err(), "Expected error for unavailable provider");
             let error = result.unwrap_err();
@@ -3846,7 +7624,7 @@ pub async fn switch_llm_model(
 
             for filename in invalid_filenames {
                 // Call the actual helper function
-                let result = transcribe_recording_inner(filename, &provider).await;
+                let result = transcribe_recording_inner(filename, &provider, 180).await;
                 
                 assert!(result.is_err(), "Expected error for invalid filename: {}", filename);
                 let error = result.unwrap_err();
@@ -3862,10 +7640,10 @@ pub async fn switch_llm_model(
             let file_path = create_test_recording(filename);
 
             let provider = tests::MockIntelProvider::new()
-                .with_transcript_result(Err("Transcript generation not supported by this provider".to_string()));
+                .with_transcript_error(JarvisError::Unsupported("Transcript generation not supported by this provider".to_string()));
 
             // Call the actual helper function
-            let result = transcribe_recording_inner(filename, &provider).await;
+            let result = transcribe_recording_inner(filename, &provider, 180).await;
             
             assert!(result.is_err(), "Expected error for unsupported provider");
             let error = result.unwrap_err();
@@ -3885,7 +7663,7 @@ pub async fn switch_llm_model(
                 .with_transcript_result(Err("Transcription timeout after 120 seconds".to_string()));
 
             // Call the actual helper function
-            let result = transcribe_recording_inner(filename, &provider).await;
+            let result = transcribe_recording_inner(filename, &provider, 180).await;
             
             assert!(result.is_err(), "Expected error");
             let error = result.unwrap_err();
@@ -4006,6 +7784,8 @@ pub async fn switch_llm_model(
                 ai_enrichment: None,
                 transcript: Some(transcript.clone()),
                 transcript_language: Some(language.clone()),
+                user_notes: None,
+                label: None,
             };
 
             // Verify gem structure
@@ -4333,6 +8113,8 @@ pub async fn chat_with_recording(
         let queue_clone = intel_queue.inner().clone();
         let app_clone = app_handle.clone();
         let filename_clone = recording_filename.clone();
+        let notify_handle = app_handle.clone();
+        let notify_filename = recording_filename.clone();
 
         tokio::spawn(async move {
             let source = match RecordingChatSource::new(app_clone, filename_clone) {
@@ -4343,9 +8125,17 @@ pub async fn chat_with_recording(
                 }
             };
             // get_context() generates transcript and emits chat-status events
-            if let Err(e) = source.get_context(&queue_clone).await {
-                eprintln!("Chat: Background preparation failed: {}", e);
-                source.on_preparation_status("error", &format!("Preparation failed: {}", e));
+            match source.get_context(&queue_clone).await {
+                Ok(_) => crate::notifications::notify_job_completed(
+                    &notify_handle,
+                    "Transcription",
+                    &format!("{} is ready to chat", notify_filename),
+                ),
+                Err(e) => {
+                    eprintln!("Chat: Background preparation failed: {}", e);
+                    source.on_preparation_status("error", &format!("Preparation failed: {}", e));
+                    crate::notifications::notify_job_failed(&notify_handle, "Transcription", &e);
+                }
             }
         });
     }
@@ -4388,15 +8178,87 @@ pub async fn chat_send_message(
     intel_queue: State<'_, Arc<IntelQueue>>,
     app_handle: AppHandle,
 ) -> Result<String, String> {
-    // Recreate RecordingChatSource (stateless, cheap to construct)
-    let source = RecordingChatSource::new(app_handle.clone(), recording_filename)?;
+    // Recreate RecordingChatSource (stateless, cheap to construct)
+    let source = RecordingChatSource::new(app_handle.clone(), recording_filename)?;
+
+    // Get chatbot from managed state
+    let chatbot_state = app_handle.state::<tokio::sync::Mutex<Chatbot>>();
+    let mut chatbot = chatbot_state.lock().await;
+
+    // Fit transcript context to the active model's context window
+    fit_chat_to_active_model(&mut chatbot, &app_handle)?;
+
+    // Send message
+    chatbot.send_message(&session_id, &message, &source, &*intel_queue).await
+}
+
+/// Size the chatbot's context to the active model's context window (unknown
+/// models keep the current size)
+fn fit_chat_to_active_model(chatbot: &mut Chatbot, app_handle: &AppHandle) -> Result<(), String> {
+    let active_model = app_handle.state::<Arc<RwLock<SettingsManager>>>()
+        .read()
+        .map_err(|e| format!("Failed to acquire settings lock: {}", e))?
+        .get()
+        .intelligence
+        .active_model;
+    if let Some(context_length) = LlmModelManager::context_length(&active_model) {
+        chatbot.set_model_context_length(context_length);
+    }
+    Ok(())
+}
+
+/// Start a chat session over the whole gem library
+///
+/// Each message searches the library for the question and answers from the
+/// top matching gems, citing them by number and title. Use
+/// `chat_library_send_message` to send messages; `chat_get_history` and
+/// `chat_end_session` work as for recording chats.
+///
+/// # Returns
+///
+/// The new session ID
+#[tauri::command]
+pub async fn chat_with_library(
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    search_provider: State<'_, Arc<dyn SearchResultProvider>>,
+    app_handle: AppHandle,
+) -> Result<String, String> {
+    let source = LibraryChatSource::new(gem_store.inner().clone(), search_provider.inner().clone());
+
+    let chatbot_state = app_handle.state::<tokio::sync::Mutex<Chatbot>>();
+    let mut chatbot = chatbot_state.lock().await;
+    chatbot.start_session(&source).await
+}
+
+/// Send a message in a library chat session
+///
+/// The library is searched for `message` and the top matching gems become the
+/// context for this answer.
+///
+/// # Returns
+///
+/// The assistant's response text, citing the gems it used
+///
+/// # Errors
+///
+/// Returns an error if the session is not found, the search fails, or
+/// message sending fails
+#[tauri::command]
+pub async fn chat_library_send_message(
+    session_id: String,
+    message: String,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    search_provider: State<'_, Arc<dyn SearchResultProvider>>,
+    intel_queue: State<'_, Arc<IntelQueue>>,
+    app_handle: AppHandle,
+) -> Result<String, String> {
+    let source = LibraryChatSource::new(gem_store.inner().clone(), search_provider.inner().clone());
 
-    // Get chatbot from managed state
     let chatbot_state = app_handle.state::<tokio::sync::Mutex<Chatbot>>();
     let mut chatbot = chatbot_state.lock().await;
+    fit_chat_to_active_model(&mut chatbot, &app_handle)?;
 
-    // Send message
-    chatbot.send_message(&session_id, &message, &source, &*intel_queue).await
+    chatbot.send_message(&session_id, &message, &source, &intel_queue).await
 }
 
 /// Get the message history for a chat session
@@ -4432,7 +8294,8 @@ pub async fn chat_get_history(
 
 /// End a chat session
 ///
-/// Removes the session from memory. The session log file remains on disk.
+/// Removes the session from memory. The session log file remains on disk,
+/// so the session can be continued later with `chat_resume_session`.
 ///
 /// # Arguments
 ///
@@ -4460,6 +8323,183 @@ pub async fn chat_end_session(
     Ok(())
 }
 
+/// A saved chat session, as listed by `list_chat_sessions`
+#[derive(Debug, Clone, Serialize)]
+pub struct ChatSessionSummary {
+    pub session_id: String,
+
+    /// Recording the session chats with (pass to `chat_send_message`);
+    /// None for library chats (use `chat_library_send_message`)
+    pub recording_filename: Option<String>,
+
+    /// Local time the session started, from the log header
+    pub started_at: Option<String>,
+
+    pub message_count: usize,
+
+    /// Start of the first user message
+    pub preview: Option<String>,
+
+    /// Whether the session is loaded (no need to resume)
+    pub active: bool,
+}
+
+/// A session restored by `chat_resume_session`
+#[derive(Debug, Clone, Serialize)]
+pub struct ResumedChatSession {
+    pub session_id: String,
+    pub recording_filename: Option<String>,
+    pub messages: Vec<ChatMessage>,
+}
+
+/// Characters of the first user message shown in `ChatSessionSummary::preview`
+const CHAT_PREVIEW_CHARS: usize = 100;
+
+/// Session logs under the per-recording folders and the library chat
+/// folder: (session ID, recording filename or None for library chats, log path)
+fn find_chat_session_logs(
+    recordings_dir: &std::path::Path,
+    library_sessions_dir: &std::path::Path,
+) -> Vec<(String, Option<String>, PathBuf)> {
+    let mut logs: Vec<_> = session_logs_in(library_sessions_dir)
+        .into_iter()
+        .map(|(session_id, path)| (session_id, None, path))
+        .collect();
+
+    let Ok(entries) = std::fs::read_dir(recordings_dir) else {
+        return logs;
+    };
+    for dir in entries.flatten().map(|e| e.path()).filter(|p| p.is_dir()) {
+        let Some(stem) = dir.file_name().map(|n| n.to_string_lossy().to_string()) else {
+            continue;
+        };
+        for (session_id, path) in session_logs_in(&dir) {
+            logs.push((session_id, Some(format!("{}.pcm", stem)), path));
+        }
+    }
+    logs
+}
+
+/// Session logs directly in `dir`: (session ID, log path)
+fn session_logs_in(dir: &std::path::Path) -> Vec<(String, PathBuf)> {
+    let Ok(files) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    files.flatten()
+        .map(|e| e.path())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_string_lossy().to_string();
+            let session_id = crate::agents::chatbot::session_id_from_log_filename(&name)?;
+            Some((session_id, path))
+        })
+        .collect()
+}
+
+/// List saved chat sessions that can be resumed, newest first
+///
+/// Scans the per-recording folders and the library chat folder for session
+/// logs; sessions with no messages are skipped. The logs are read without
+/// holding the chatbot, so listing never delays a chat in progress.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const sessions: ChatSessionSummary[] = await invoke('list_chat_sessions');
+/// ```
+#[tauri::command]
+pub async fn list_chat_sessions(
+    file_manager: State<'_, FileManager>,
+    app_handle: AppHandle,
+) -> Result<Vec<ChatSessionSummary>, String> {
+    let logs = find_chat_session_logs(
+        file_manager.get_recordings_dir(),
+        &crate::agents::library_chat::library_session_dir(),
+    );
+
+    let mut sessions = Vec::new();
+    for (session_id, recording_filename, path) in logs {
+        let Ok(log) = tokio::fs::read_to_string(&path).await else {
+            continue;
+        };
+        let messages = crate::agents::chatbot::parse_session_log(&log);
+        if messages.is_empty() {
+            continue;
+        }
+        sessions.push(ChatSessionSummary {
+            active: false,
+            started_at: log.lines()
+                .find_map(|l| l.strip_prefix("**Started:** "))
+                .map(str::to_string),
+            message_count: messages.len(),
+            preview: messages.iter()
+                .find(|m| m.role == "user")
+                .map(|m| m.content.chars().take(CHAT_PREVIEW_CHARS).collect()),
+            session_id,
+            recording_filename,
+        });
+    }
+
+    {
+        let chatbot_state = app_handle.state::<tokio::sync::Mutex<Chatbot>>();
+        let chatbot = chatbot_state.lock().await;
+        for session in &mut sessions {
+            session.active = chatbot.has_session(&session.session_id);
+        }
+    }
+
+    sessions.sort_by_key(|s| {
+        std::cmp::Reverse(crate::agents::chatbot::session_timestamp(&s.session_id).unwrap_or(0))
+    });
+    Ok(sessions)
+}
+
+/// Resume a saved chat session after a restart
+///
+/// Reloads the session's history from its log into the chatbot; continue
+/// with `chat_send_message` using the returned `recording_filename`, or with
+/// `chat_library_send_message` when it is null (a library chat). New
+/// messages are appended to the same log. Resuming a session that is
+/// already loaded just returns its history.
+///
+/// # Errors
+///
+/// Returns an error if no log exists for `session_id` or it can't be read.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const { recording_filename, messages } = await invoke('chat_resume_session', { sessionId });
+/// ```
+#[tauri::command]
+pub async fn chat_resume_session(
+    session_id: String,
+    file_manager: State<'_, FileManager>,
+    app_handle: AppHandle,
+) -> Result<ResumedChatSession, String> {
+    let logs = find_chat_session_logs(
+        file_manager.get_recordings_dir(),
+        &crate::agents::library_chat::library_session_dir(),
+    );
+    let (_, recording_filename, log_path) = logs
+        .into_iter()
+        .find(|(id, _, _)| *id == session_id)
+        .ok_or_else(|| format!("No saved chat session '{}'", session_id))?;
+
+    let chatbot_state = app_handle.state::<tokio::sync::Mutex<Chatbot>>();
+    let mut chatbot = chatbot_state.lock().await;
+    let messages = chatbot.resume_session(&session_id, &log_path).await?;
+
+    Ok(ResumedChatSession {
+        session_id,
+        recording_filename,
+        messages,
+    })
+}
+
 /// Check if a saved transcript exists on disk for a recording and return it.
 ///
 /// Looks in the per-recording folder: `recordings/{stem}/transcript.md`
@@ -4480,3 +8520,437 @@ pub async fn get_saved_transcript(
         Ok(None)
     }
 }
+
+/// Copy a recording's saved transcript to the system clipboard.
+///
+/// Reads `recordings/{stem}/transcript.md` and returns the number of
+/// characters copied. Errors if the recording hasn't been transcribed yet.
+#[tauri::command]
+pub async fn copy_transcript(
+    recording_filename: String,
+    app_handle: AppHandle,
+) -> Result<usize, String> {
+    use tauri_plugin_clipboard_manager::ClipboardExt;
+
+    let source = RecordingChatSource::new(app_handle.clone(), recording_filename.clone())?;
+    let content = read_transcript_to_copy(&source.transcript_path(), &recording_filename).await?;
+    let char_count = content.chars().count();
+
+    app_handle.clipboard().write_text(content)
+        .map_err(|e| format!("Failed to copy transcript to clipboard: {}", e))?;
+
+    Ok(char_count)
+}
+
+/// Saved transcript for `copy_transcript`, with a clear error if the
+/// recording hasn't been transcribed
+async fn read_transcript_to_copy(transcript_path: &std::path::Path, recording_filename: &str) -> Result<String, String> {
+    if !transcript_path.exists() {
+        return Err(format!(
+            "No saved transcript for '{}' yet — transcribe the recording first",
+            recording_filename
+        ));
+    }
+    tokio::fs::read_to_string(transcript_path).await
+        .map_err(|e| format!("Failed to read transcript: {}", e))
+}
+
+// ============================================================================
+// Log Commands
+// ============================================================================
+
+/// Return the last `lines` lines of the current app log
+///
+/// Reads the log file written by this launch (see `logging::current_log_file`)
+/// from the end, so large logs are never loaded in full. `lines` must be
+/// between 1 and `MAX_RECENT_LOG_LINES` (5000).
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const lines: string[] = await invoke('read_recent_logs', { lines: 200 });
+/// ```
+#[tauri::command]
+pub async fn read_recent_logs(lines: usize) -> Result<Vec<String>, String> {
+    use crate::logging::MAX_RECENT_LOG_LINES;
+
+    if lines == 0 || lines > MAX_RECENT_LOG_LINES {
+        return Err(format!("lines must be between 1 and {}", MAX_RECENT_LOG_LINES));
+    }
+
+    let logs_dir = crate::logging::logs_dir()
+        .ok_or_else(|| "Failed to resolve logs directory".to_string())?;
+    let log_path = crate::logging::current_log_file(&logs_dir)
+        .ok_or_else(|| "No log file found".to_string())?;
+
+    tokio::task::spawn_blocking(move || crate::logging::read_recent_lines(&log_path, lines))
+        .await
+        .map_err(|e| format!("Log read task failed: {}", e))?
+        .map_err(|e| format!("Failed to read log file: {}", e))
+}
+
+/// Return the last `lines` lines of the MLX sidecar log
+///
+/// The sidecar's stderr (Python tracebacks, dependency errors) and any
+/// non-protocol stdout are written to `mlx_sidecar.log` with a marker each
+/// time the sidecar starts. Returns an empty list if the sidecar has never
+/// run. `lines` must be between 1 and `MAX_RECENT_LOG_LINES` (5000).
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const lines: string[] = await invoke('read_mlx_sidecar_log', { lines: 100 });
+/// ```
+#[tauri::command]
+pub async fn read_mlx_sidecar_log(lines: usize) -> Result<Vec<String>, String> {
+    use crate::logging::MAX_RECENT_LOG_LINES;
+
+    if lines == 0 || lines > MAX_RECENT_LOG_LINES {
+        return Err(format!("lines must be between 1 and {}", MAX_RECENT_LOG_LINES));
+    }
+
+    let log_path = crate::intelligence::mlx_provider::sidecar_log_path()
+        .ok_or_else(|| "Failed to resolve logs directory".to_string())?;
+    if !log_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    tokio::task::spawn_blocking(move || crate::logging::read_recent_lines(&log_path, lines))
+        .await
+        .map_err(|e| format!("Log read task failed: {}", e))?
+        .map_err(|e| format!("Failed to read sidecar log: {}", e))
+}
+
+/// List log files in the logs directory, newest first
+///
+/// Includes the rotated `jarvis-*.log` launch logs and auxiliary logs such as
+/// `gem_save.log`. Returns an empty list if the directory does not exist yet.
+#[tauri::command]
+pub async fn list_log_files() -> Result<Vec<crate::logging::LogFileInfo>, String> {
+    let logs_dir = crate::logging::logs_dir()
+        .ok_or_else(|| "Failed to resolve logs directory".to_string())?;
+    if !logs_dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    crate::logging::list_log_files(&logs_dir)
+        .map_err(|e| format!("Failed to list log files: {}", e))
+}
+
+// ============================================================================
+// Diagnostics Commands
+// ============================================================================
+
+/// Result of a single diagnostic check
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticCheck {
+    pub ok: bool,
+    pub detail: Option<String>,
+}
+
+impl DiagnosticCheck {
+    fn from_result<T, E: std::fmt::Display>(result: Result<T, E>) -> Self {
+        match result {
+            Ok(_) => Self { ok: true, detail: None },
+            Err(e) => Self { ok: false, detail: Some(e.to_string()) },
+        }
+    }
+}
+
+/// Aggregated health report returned by `run_diagnostics`
+#[derive(Debug, Clone, Serialize)]
+pub struct DiagnosticsReport {
+    pub generated_at: String,
+    pub app_version: String,
+    /// OS and architecture, e.g. "macos aarch64"
+    pub os: String,
+    pub platform_supported: bool,
+    pub accessibility_permission: bool,
+    /// Configured intelligence provider name (settings.intelligence.provider)
+    pub intel_provider: String,
+    pub intel_availability: crate::intelligence::AvailabilityResult,
+    /// MLX sidecar lifecycle state; None when MLX is not the active provider
+    pub mlx_sidecar: Option<crate::intelligence::SidecarState>,
+    pub venv_status: String,
+    pub venv_python_path: Option<String>,
+    /// Configured transcription engine (settings.transcription.transcription_engine)
+    pub transcription_engine: String,
+    /// None when the transcription manager failed to initialize
+    pub transcription_status: Option<TranscriptionStatus>,
+    pub whisperkit: WhisperKitStatus,
+    /// Path to the QMD binary, if installed
+    pub qmd_path: Option<String>,
+    pub search_availability: crate::intelligence::AvailabilityResult,
+    pub database: DiagnosticCheck,
+    pub recordings_dir: String,
+    pub recordings_free_bytes: Option<u64>,
+}
+
+/// Run a self-diagnostic health check
+///
+/// Composes the individual checks (platform support, accessibility permission,
+/// intel provider and venv, transcription engine, WhisperKit, QMD and search
+/// availability, database reachability, recordings disk space) into a single
+/// report the user can copy into a bug report. Individual check failures are
+/// recorded in the report rather than failing the command.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report = await invoke('run_diagnostics');
+/// await navigator.clipboard.writeText(JSON.stringify(report, null, 2));
+/// ```
+#[tauri::command]
+pub async fn run_diagnostics(
+    app_handle: AppHandle,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    intel_provider: State<'_, Arc<dyn IntelProvider>>,
+    venv_manager: State<'_, Arc<VenvManager>>,
+    search_provider: State<'_, Arc<dyn SearchResultProvider>>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    file_manager: State<'_, FileManager>,
+) -> Result<DiagnosticsReport, String> {
+    let settings = {
+        let manager = settings_manager.read()
+            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+        manager.get()
+    };
+
+    let venv_status = serde_json::to_value(venv_manager.status())
+        .ok()
+        .and_then(|v| v.as_str().map(String::from))
+        .unwrap_or_else(|| "unknown".to_string());
+
+    let transcription_status = match app_handle.try_state::<tokio::sync::Mutex<TranscriptionManager>>() {
+        Some(manager) => Some(manager.lock().await.get_status().await),
+        None => None,
+    };
+
+    let mlx_provider = match app_handle.try_state::<Arc<tokio::sync::Mutex<Option<Arc<crate::intelligence::MlxProvider>>>>>() {
+        Some(mlx) => mlx.lock().await.clone(),
+        None => None,
+    };
+    let mlx_sidecar = match mlx_provider {
+        Some(provider) => Some(provider.sidecar_state().await),
+        None => None,
+    };
+
+    let (intel_availability, search_availability, qmd_path, database) = tokio::join!(
+        intel_provider.check_availability(),
+        search_provider.check_availability(),
+        crate::search::QmdResultProvider::find_qmd_binary(),
+        gem_store.list(1, 0),
+    );
+
+    // A failed probe is part of the report, not a reason to abort it
+    let whisperkit = check_whisperkit_status().unwrap_or_else(|e| {
+        eprintln!("Diagnostics: WhisperKit status check failed: {}", e);
+        WhisperKitStatus {
+            available: false,
+            reason: Some(format!("Status check failed: {}", e)),
+        }
+    });
+
+    let recordings_free_bytes = match file_manager.available_space() {
+        Ok(bytes) => Some(bytes),
+        Err(e) => {
+            eprintln!("Diagnostics: {}", e);
+            None
+        }
+    };
+
+    Ok(DiagnosticsReport {
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        app_version: app_handle.package_info().version.to_string(),
+        os: format!("{} {}", std::env::consts::OS, std::env::consts::ARCH),
+        platform_supported: PlatformDetector::is_supported(),
+        accessibility_permission: check_accessibility_permission(),
+        intel_provider: settings.intelligence.provider,
+        intel_availability,
+        mlx_sidecar,
+        venv_status,
+        venv_python_path: venv_manager.venv_python_path().map(|p| p.to_string_lossy().to_string()),
+        transcription_engine: settings.transcription.transcription_engine,
+        transcription_status,
+        whisperkit,
+        qmd_path: qmd_path.map(|p| p.to_string_lossy().to_string()),
+        search_availability,
+        database: DiagnosticCheck::from_result(database),
+        recordings_dir: file_manager.get_recordings_dir().to_string_lossy().to_string(),
+        recordings_free_bytes,
+    })
+}
+
+// ============================================================================
+// Activity Commands
+// ============================================================================
+
+/// Stop all background activity (panic button)
+///
+/// Stops the recording, the browser observer, and the Co-Pilot agent, cancels
+/// in-flight MLX inference, and cancels active model downloads. Each step is
+/// best-effort; failures are listed in the report's `errors`.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const report: ActivityStopReport = await invoke('stop_all_activity');
+/// console.log(`Cancelled downloads: ${report.downloads_cancelled.join(', ')}`);
+/// ```
+#[tauri::command]
+pub async fn stop_all_activity(
+    app_handle: AppHandle,
+) -> Result<crate::shutdown::ActivityStopReport, String> {
+    Ok(crate::shutdown::stop_all_activity(&app_handle).await)
+}
+
+// ============================================================================
+// VAD Calibration Commands
+// ============================================================================
+
+/// Longest ambient capture `calibrate_vad` accepts
+const MAX_VAD_CALIBRATION_SECONDS: u64 = 30;
+
+/// Result of `calibrate_vad`
+#[derive(Debug, Clone, Serialize)]
+pub struct VadCalibration {
+    /// Seconds of ambient audio analyzed
+    pub duration_seconds: f32,
+    /// Median level of the ambient audio (dBFS)
+    pub noise_floor_dbfs: f32,
+    /// Loudest sample in the capture (dBFS)
+    pub peak_dbfs: f32,
+    /// 95th percentile speech probability Silero assigned to the ambient audio
+    /// (None when the VAD model isn't available)
+    pub ambient_speech_probability: Option<f32>,
+    pub current_threshold: f32,
+    pub suggested_threshold: f32,
+    /// True if the suggestion was written to settings
+    pub applied: bool,
+}
+
+/// Measure ambient noise and suggest a VAD threshold
+///
+/// Records `duration_seconds` (1–30) of ambient audio through the JarvisListen
+/// sidecar, measures the noise floor and how speech-like Silero VAD finds the
+/// noise, and suggests a `vad_threshold`. The room should be quiet (no one
+/// talking) during calibration. With `apply: true` the suggestion is saved to
+/// settings. Fails while a recording is in progress.
+///
+/// # Examples
+///
+/// ```typescript
+/// import { invoke } from '@tauri-apps/api/core';
+///
+/// const result: VadCalibration = await invoke('calibrate_vad', { durationSeconds: 5, apply: true });
+/// console.log(`Suggested threshold: ${result.suggested_threshold}`);
+/// ```
+#[tauri::command]
+pub async fn calibrate_vad(
+    duration_seconds: u64,
+    apply: Option<bool>,
+    recording_state: State<'_, Mutex<RecordingManager>>,
+    settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, DemoMode>,
+) -> Result<VadCalibration, String> {
+    use nix::sys::signal::{kill, Signal};
+    use nix::unistd::Pid;
+
+    demo_mode.ensure_writable()?;
+
+    if duration_seconds == 0 || duration_seconds > MAX_VAD_CALIBRATION_SECONDS {
+        return Err(format!(
+            "duration_seconds must be between 1 and {}, got {}",
+            MAX_VAD_CALIBRATION_SECONDS, duration_seconds
+        ));
+    }
+
+    let capture_path = std::env::temp_dir()
+        .join(format!("jarvis_vad_calibration_{}.pcm", uuid::Uuid::new_v4()));
+
+    // `_capture_guard` keeps recordings from starting until calibration ends
+    let crate::recording::SampleCapture { child, guard: _capture_guard } = {
+        let recording_manager = recording_state
+            .lock()
+            .map_err(|e| format!("Failed to acquire lock on RecordingManager: {}", e))?;
+        recording_manager.start_sample_capture(&capture_path)?
+    };
+    let pid = Pid::from_raw(child.pid() as i32);
+
+    tokio::time::sleep(std::time::Duration::from_secs(duration_seconds)).await;
+
+    // SIGTERM lets JarvisListen flush its buffers; SIGKILL if it doesn't exit
+    let _ = kill(pid, Signal::SIGTERM);
+    let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(3);
+    while kill(pid, None).is_ok() && tokio::time::Instant::now() < deadline {
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+    if kill(pid, None).is_ok() {
+        let _ = child.kill();
+    }
+
+    let pcm = tokio::fs::read(&capture_path).await;
+    let _ = tokio::fs::remove_file(&capture_path).await;
+    let pcm = pcm.map_err(|e| format!("Failed to read calibration audio: {}", e))?;
+
+    let samples: Vec<f32> = pcm
+        .chunks_exact(2)
+        .map(|chunk| i16::from_le_bytes([chunk[0], chunk[1]]) as f32 / 32768.0)
+        .collect();
+    if samples.len() < 8_000 {
+        return Err("Captured too little audio to calibrate — check microphone permission".to_string());
+    }
+
+    let current_threshold = {
+        let manager = settings_manager.read()
+            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+        manager.get().transcription.vad_threshold
+    };
+
+    // Silero inference is CPU-bound; keep it off the async runtime
+    let (chunk_levels, ambient_speech_probability, peak) = tokio::task::spawn_blocking(move || {
+        use crate::transcription::vad;
+
+        let chunk_levels: Vec<f32> = samples.chunks(512).map(vad::rms_dbfs).collect();
+        let peak = samples.iter().fold(0.0f32, |max, s| max.max(s.abs()));
+        let mut silero = crate::transcription::SileroVad::new(None, current_threshold);
+        let ambient_speech_probability = silero
+            .speech_probabilities(&samples)
+            .and_then(|probabilities| vad::quantile(&probabilities, 0.95));
+        (chunk_levels, ambient_speech_probability, peak)
+    })
+    .await
+    .map_err(|e| format!("VAD calibration task failed: {}", e))?;
+
+    let noise_floor_dbfs = crate::transcription::vad::quantile(&chunk_levels, 0.5).unwrap_or(-100.0);
+    let peak_dbfs = if peak > 0.0 { (20.0 * peak.log10()).max(-100.0) } else { -100.0 };
+    let suggested_threshold =
+        crate::transcription::vad::suggest_vad_threshold(noise_floor_dbfs, ambient_speech_probability);
+
+    let applied = apply.unwrap_or(false);
+    if applied {
+        let manager = settings_manager.read()
+            .map_err(|e| format!("Failed to acquire settings lock: {}", e))?;
+        let mut settings = manager.get();
+        settings.transcription.vad_threshold = suggested_threshold;
+        manager.update(settings)?;
+    }
+
+    Ok(VadCalibration {
+        duration_seconds: (pcm.len() / 2) as f32 / 16_000.0,
+        noise_floor_dbfs,
+        peak_dbfs,
+        ambient_speech_probability,
+        current_threshold,
+        suggested_threshold,
+        applied,
+    })
+}
@@ -1,56 +1,187 @@
 use std::fmt::{self, Display, Formatter};
 
-/// Application error types for JarvisApp
-#[derive(Debug)]
-pub enum AppError {
-    /// Failed to spawn the JarvisListen sidecar process
-    SidecarSpawnFailed(String),
-    
-    /// The JarvisListen sidecar process crashed during recording
-    SidecarCrashed(String),
-    
+use serde::Serialize;
+
+/// Crate-wide error type for JarvisApp
+///
+/// Internal modules (gems, intelligence, recording) return `JarvisError` so
+/// callers can match on the kind of failure. Tauri commands still return
+/// `Result<_, String>`; `?` converts through `From<JarvisError> for String`.
+///
+/// Serializes as `{"kind": "not_found", "message": "..."}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", content = "message", rename_all = "snake_case")]
+pub enum JarvisError {
+    /// SQLite query or connection failure
+    Db(String),
+
     /// File I/O operation failed (read, write, delete)
-    FileIOError(String),
-    
+    Io(String),
+
+    /// JSON (de)serialization failed, or a model response could not be parsed
+    Parse(String),
+
+    /// HTTP request failed (image download, web search, model download)
+    Network(String),
+
+    /// Intelligence provider failed or is unavailable
+    Provider(String),
+
+    /// The active provider doesn't support the operation (e.g. transcription)
+    Unsupported(String),
+
+    /// The requested gem, recording, or other entity does not exist
+    NotFound(String),
+
+    /// The request was rejected before doing any work (bad input, wrong state)
+    Validation(String),
+
+    /// A sidecar process (JarvisListen, MLX server) failed to start, crashed,
+    /// or its pipe broke
+    Sidecar(String),
+
+    /// The operation was cancelled (e.g. in-flight inference)
+    Cancelled(String),
+
+    /// The operation didn't finish within its configured time limit
+    Timeout(String),
+
     /// Permission denied (Screen Recording or Microphone access)
     PermissionDenied(String),
-    
+
     /// Platform is not supported for recording
     PlatformNotSupported,
-    
-    /// Recording file is invalid or corrupted
-    InvalidRecording(String),
-    
+
     /// Attempted to start recording while already recording
     ConcurrentRecording,
+
+    /// Anything without a more specific kind
+    Other(String),
 }
 
-impl Display for AppError {
+/// Result alias for internal code
+pub type JarvisResult<T> = Result<T, JarvisError>;
+
+impl Display for JarvisError {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // The messages are already user-facing, so they're shown without a
+        // kind prefix — commands surface exactly what they did before.
         match self {
-            AppError::SidecarSpawnFailed(msg) => {
-                write!(f, "Failed to start audio capture: {}", msg)
-            }
-            AppError::SidecarCrashed(msg) => {
-                write!(f, "Audio capture crashed unexpectedly: {}", msg)
-            }
-            AppError::FileIOError(msg) => {
-                write!(f, "File operation failed: {}", msg)
-            }
-            AppError::PermissionDenied(msg) => {
+            JarvisError::Db(msg)
+            | JarvisError::Io(msg)
+            | JarvisError::Parse(msg)
+            | JarvisError::Network(msg)
+            | JarvisError::Provider(msg)
+            | JarvisError::Unsupported(msg)
+            | JarvisError::NotFound(msg)
+            | JarvisError::Validation(msg)
+            | JarvisError::Sidecar(msg)
+            | JarvisError::Cancelled(msg)
+            | JarvisError::Timeout(msg)
+            | JarvisError::Other(msg) => write!(f, "{}", msg),
+            JarvisError::PermissionDenied(msg) => {
                 write!(f, "Permission denied: {}", msg)
             }
-            AppError::PlatformNotSupported => {
+            JarvisError::PlatformNotSupported => {
                 write!(f, "Recording is not yet supported on this platform. Currently only macOS is supported.")
             }
-            AppError::InvalidRecording(msg) => {
-                write!(f, "Invalid recording: {}", msg)
-            }
-            AppError::ConcurrentRecording => {
+            JarvisError::ConcurrentRecording => {
                 write!(f, "A recording is already in progress")
             }
         }
     }
 }
 
-impl std::error::Error for AppError {}
+impl std::error::Error for JarvisError {}
+
+impl From<rusqlite::Error> for JarvisError {
+    fn from(e: rusqlite::Error) -> Self {
+        JarvisError::Db(e.to_string())
+    }
+}
+
+impl From<std::io::Error> for JarvisError {
+    fn from(e: std::io::Error) -> Self {
+        JarvisError::Io(e.to_string())
+    }
+}
+
+impl From<serde_json::Error> for JarvisError {
+    fn from(e: serde_json::Error) -> Self {
+        JarvisError::Parse(e.to_string())
+    }
+}
+
+impl From<reqwest::Error> for JarvisError {
+    fn from(e: reqwest::Error) -> Self {
+        JarvisError::Network(e.to_string())
+    }
+}
+
+/// Untyped errors from code that still returns `Result<_, String>`
+impl From<String> for JarvisError {
+    fn from(msg: String) -> Self {
+        JarvisError::Other(msg)
+    }
+}
+
+/// Command boundary: Tauri commands return `Result<_, String>`
+impl From<JarvisError> for String {
+    fn from(e: JarvisError) -> Self {
+        e.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display_is_the_message() {
+        let err = JarvisError::NotFound("Gem with id abc not found".to_string());
+        assert_eq!(err.to_string(), "Gem with id abc not found");
+        assert_eq!(String::from(err), "Gem with id abc not found");
+        assert_eq!(
+            JarvisError::ConcurrentRecording.to_string(),
+            "A recording is already in progress"
+        );
+    }
+
+    #[test]
+    fn test_serializes_kind_and_message() {
+        let json = serde_json::to_value(JarvisError::Sidecar("broken pipe".to_string())).unwrap();
+        assert_eq!(json, serde_json::json!({"kind": "sidecar", "message": "broken pipe"}));
+
+        let json = serde_json::to_value(JarvisError::Unsupported("no chat".to_string())).unwrap();
+        assert_eq!(json, serde_json::json!({"kind": "unsupported", "message": "no chat"}));
+
+        let json = serde_json::to_value(JarvisError::PlatformNotSupported).unwrap();
+        assert_eq!(json, serde_json::json!({"kind": "platform_not_supported"}));
+    }
+
+    #[test]
+    fn test_from_impls() {
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing.pcm");
+        assert!(matches!(JarvisError::from(io), JarvisError::Io(_)));
+
+        let parse = serde_json::from_str::<Vec<String>>("not json").unwrap_err();
+        assert!(matches!(JarvisError::from(parse), JarvisError::Parse(_)));
+
+        assert!(matches!(
+            JarvisError::from(rusqlite::Error::QueryReturnedNoRows),
+            JarvisError::Db(_)
+        ));
+    }
+
+    #[test]
+    fn test_question_mark_converts_at_command_boundary() {
+        fn internal() -> JarvisResult<()> {
+            Err(JarvisError::Validation("Title cannot be empty".to_string()))
+        }
+        fn command() -> Result<(), String> {
+            internal()?;
+            Ok(())
+        }
+        assert_eq!(command(), Err("Title cannot be empty".to_string()));
+    }
+}
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 /// Audio format constants for PCM recordings
 pub const SAMPLE_RATE: u32 = 16000;
@@ -22,6 +22,80 @@ pub struct RecordingMetadata {
     pub duration_seconds: f64,
 }
 
+/// Size-based sanity check of a PCM recording (see `FileManager::verify_recording`)
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct RecordingIntegrity {
+    /// The file exists on disk
+    pub exists: bool,
+
+    /// Size of the file in bytes (0 if it doesn't exist)
+    pub size_bytes: u64,
+
+    /// The file has at least one byte of audio
+    pub nonempty: bool,
+
+    /// Duration calculated from the size, as in `RecordingMetadata`
+    pub estimated_duration_seconds: f64,
+
+    /// Nonempty and a whole number of sample frames; a partial trailing frame
+    /// means the write was cut off
+    pub likely_valid: bool,
+}
+
+impl RecordingIntegrity {
+    /// Inspect the PCM file at `path`
+    pub fn check(path: &Path) -> Self {
+        let size_bytes = std::fs::metadata(path)
+            .ok()
+            .filter(|m| m.is_file())
+            .map(|m| m.len());
+        let exists = size_bytes.is_some();
+        let size_bytes = size_bytes.unwrap_or(0);
+        let frame_bytes = (BYTES_PER_SAMPLE * CHANNELS) as u64;
+
+        Self {
+            exists,
+            size_bytes,
+            nonempty: size_bytes > 0,
+            estimated_duration_seconds: FileManager::calculate_duration(size_bytes),
+            likely_valid: size_bytes > 0 && size_bytes.is_multiple_of(frame_bytes),
+        }
+    }
+}
+
+/// Whether the file at `path` starts with a RIFF (WAV) header rather than raw PCM
+pub fn has_wav_header(path: &Path) -> bool {
+    use std::io::Read;
+
+    let mut header = [0u8; 4];
+    std::fs::File::open(path)
+        .and_then(|mut f| f.read_exact(&mut header))
+        .is_ok_and(|_| &header == b"RIFF")
+}
+
+/// Criteria for `FileManager::filter_recordings`; unset fields don't filter
+///
+/// All bounds are inclusive. Dates are Unix timestamps in seconds, compared
+/// against `RecordingMetadata::created_at`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct RecordingFilter {
+    pub min_duration_seconds: Option<f64>,
+    pub max_duration_seconds: Option<f64>,
+    pub created_after: Option<u64>,
+    pub created_before: Option<u64>,
+}
+
+impl RecordingFilter {
+    /// True if `recording` satisfies every set bound
+    pub fn matches(&self, recording: &RecordingMetadata) -> bool {
+        self.min_duration_seconds.is_none_or(|min| recording.duration_seconds >= min)
+            && self.max_duration_seconds.is_none_or(|max| recording.duration_seconds <= max)
+            && self.created_after.is_none_or(|after| recording.created_at >= after)
+            && self.created_before.is_none_or(|before| recording.created_at <= before)
+    }
+}
+
 /// Application configuration
 #[derive(Serialize, Deserialize, Debug)]
 pub struct AppConfig {
@@ -42,6 +116,7 @@ pub struct AppConfig {
 }
 
 /// Manages recording file storage and operations
+#[derive(Clone)]
 pub struct FileManager {
     recordings_dir: PathBuf,
 }
@@ -76,6 +151,24 @@ impl FileManager {
     pub fn get_recordings_dir(&self) -> &std::path::Path {
         &self.recordings_dir
     }
+
+    /// Free disk space (bytes available to this user) on the recordings volume
+    pub fn available_space(&self) -> Result<u64, String> {
+        use std::os::unix::ffi::OsStrExt;
+
+        let path = std::ffi::CString::new(self.recordings_dir.as_os_str().as_bytes())
+            .map_err(|e| format!("Invalid recordings path: {}", e))?;
+        let mut stat: libc::statvfs = unsafe { std::mem::zeroed() };
+        if unsafe { libc::statvfs(path.as_ptr(), &mut stat) } != 0 {
+            return Err(format!(
+                "Failed to query disk space: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+
+        #[allow(clippy::unnecessary_cast)] // field widths differ between macOS and Linux
+        Ok(stat.f_bavail as u64 * stat.f_frsize as u64)
+    }
     
     /// Calculate the duration of a recording from its file size
     /// 
@@ -172,6 +265,135 @@ impl FileManager {
         Ok(recordings)
     }
     
+    /// List recordings matching `filter`, newest first
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a minimum bound is greater than its maximum, or if
+    /// `list_recordings` fails.
+    pub fn filter_recordings(&self, filter: &RecordingFilter) -> Result<Vec<RecordingMetadata>, String> {
+        if let (Some(min), Some(max)) = (filter.min_duration_seconds, filter.max_duration_seconds) {
+            if min > max {
+                return Err(format!("min_duration_seconds ({}) is greater than max_duration_seconds ({})", min, max));
+            }
+        }
+        if let (Some(after), Some(before)) = (filter.created_after, filter.created_before) {
+            if after > before {
+                return Err(format!("created_after ({}) is later than created_before ({})", after, before));
+            }
+        }
+
+        let mut recordings = self.list_recordings()?;
+        recordings.retain(|recording| filter.matches(recording));
+        Ok(recordings)
+    }
+    
+    /// Check that a recording's PCM file exists, is nonempty, and holds a
+    /// whole number of sample frames
+    ///
+    /// A missing file is reported (`exists: false`), not an error.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the filename is empty or contains path separators.
+    pub fn verify_recording(&self, filename: &str) -> Result<RecordingIntegrity, String> {
+        Self::validate_filename(filename)?;
+        Ok(RecordingIntegrity::check(&self.recordings_dir.join(filename)))
+    }
+
+    /// Concatenate recordings, in order, into a new recording
+    ///
+    /// Recordings are uniform raw PCM (16kHz mono s16le), so this is a
+    /// byte-level append with no re-encoding. `output_name` gets a `.pcm`
+    /// extension if it has none.
+    ///
+    /// # Returns
+    ///
+    /// The new recording's filename
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if fewer than two recordings are given, any filename
+    /// is invalid or missing, a recording isn't raw PCM (WAV header or a
+    /// partial trailing sample) or is being appended to, or a recording named
+    /// `output_name` already exists.
+    pub fn concat_recordings(&self, filenames: &[String], output_name: &str) -> Result<String, String> {
+        use std::io::Write;
+
+        if filenames.len() < 2 {
+            return Err("Select at least two recordings to concatenate".to_string());
+        }
+
+        let output_name = if output_name.ends_with(".pcm") {
+            output_name.to_string()
+        } else {
+            format!("{}.pcm", output_name)
+        };
+        Self::validate_filename(&output_name)?;
+
+        let mut inputs = Vec::with_capacity(filenames.len());
+        let mut _reading = Vec::with_capacity(filenames.len());
+        for filename in filenames {
+            Self::validate_filename(filename)?;
+            // Blocks appending to the input until it has been copied
+            _reading.push(crate::recording::RecordingReadGuard::acquire(filename)?);
+            let path = self.recordings_dir.join(filename);
+            let integrity = RecordingIntegrity::check(&path);
+            if !integrity.exists {
+                return Err(format!("Recording '{}' not found in recordings directory", filename));
+            }
+            if integrity.nonempty && !integrity.likely_valid {
+                return Err(format!("Recording '{}' ends with a partial sample", filename));
+            }
+            if has_wav_header(&path) {
+                return Err(format!("Recording '{}' is not raw PCM (has a WAV header)", filename));
+            }
+            inputs.push(path);
+        }
+
+        let output_path = self.recordings_dir.join(&output_name);
+        let mut output = std::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&output_path)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => format!("Recording '{}' already exists", output_name),
+                _ => format!("Failed to create recording '{}': {}", output_name, e),
+            })?;
+
+        let written = inputs.iter().try_for_each(|path| {
+            let mut input = std::fs::File::open(path)?;
+            std::io::copy(&mut input, &mut output)?;
+            Ok::<_, std::io::Error>(())
+        }).and_then(|_| output.flush());
+
+        if let Err(e) = written {
+            let _ = std::fs::remove_file(&output_path);
+            return Err(format!("Failed to write recording '{}': {}", output_name, e));
+        }
+
+        Ok(output_name)
+    }
+
+    /// Reject empty filenames and ones that could escape the recordings directory
+    fn validate_filename(filename: &str) -> Result<(), String> {
+        // Validate filename is not empty
+        if filename.is_empty() {
+            return Err("Filename cannot be empty".to_string());
+        }
+        
+        // Validate filename doesn't contain path separators or parent directory references
+        // This prevents path traversal attacks like "../../../etc/passwd"
+        if filename.contains('/') || filename.contains('\\') || filename.contains("..") {
+            return Err(format!(
+                "Invalid filename '{}': path traversal not allowed",
+                filename
+            ));
+        }
+
+        Ok(())
+    }
+    
     /// Delete a recording by filename
     /// 
     /// Validates the filename to prevent path traversal attacks, then deletes
@@ -213,19 +435,7 @@ impl FileManager {
     /// # Ok::<(), String>(())
     /// ```
     pub fn delete_recording(&self, filename: &str) -> Result<(), String> {
-        // Validate filename is not empty
-        if filename.is_empty() {
-            return Err("Filename cannot be empty".to_string());
-        }
-        
-        // Validate filename doesn't contain path separators or parent directory references
-        // This prevents path traversal attacks like "../../../etc/passwd"
-        if filename.contains('/') || filename.contains('\\') || filename.contains("..") {
-            return Err(format!(
-                "Invalid filename '{}': path traversal not allowed",
-                filename
-            ));
-        }
+        Self::validate_filename(filename)?;
         
         // Construct the full path
         let file_path = self.recordings_dir.join(filename);
@@ -294,6 +504,14 @@ mod tests {
         assert_eq!(duration, 1.5);
     }
 
+    #[test]
+    fn test_available_space_reports_free_bytes() {
+        let file_manager = FileManager {
+            recordings_dir: std::env::temp_dir(),
+        };
+        assert!(file_manager.available_space().unwrap() > 0);
+    }
+
     #[test]
     fn test_list_recordings_empty_directory() {
         use std::time::{SystemTime, UNIX_EPOCH};
@@ -423,6 +641,103 @@ mod tests {
         std::fs::remove_dir_all(&temp_dir).ok();
     }
 
+    #[test]
+    fn test_recording_filter_matches() {
+        let recording = RecordingMetadata {
+            filename: "20240315_143022.pcm".to_string(),
+            size_bytes: 32000 * 600,
+            created_at: 1_710_513_022,
+            duration_seconds: 600.0,
+        };
+
+        assert!(RecordingFilter::default().matches(&recording));
+        assert!(RecordingFilter { min_duration_seconds: Some(600.0), ..Default::default() }.matches(&recording));
+        assert!(!RecordingFilter { min_duration_seconds: Some(601.0), ..Default::default() }.matches(&recording));
+        assert!(!RecordingFilter { max_duration_seconds: Some(599.0), ..Default::default() }.matches(&recording));
+        assert!(RecordingFilter {
+            created_after: Some(1_710_500_000),
+            created_before: Some(1_710_513_022),
+            ..Default::default()
+        }
+        .matches(&recording));
+        assert!(!RecordingFilter { created_after: Some(1_710_513_023), ..Default::default() }.matches(&recording));
+    }
+
+    #[test]
+    fn test_filter_recordings_rejects_inverted_bounds() {
+        let file_manager = FileManager {
+            recordings_dir: std::env::temp_dir(),
+        };
+        let filter = RecordingFilter {
+            min_duration_seconds: Some(60.0),
+            max_duration_seconds: Some(30.0),
+            ..Default::default()
+        };
+        assert!(file_manager.filter_recordings(&filter).is_err());
+    }
+
+    #[test]
+    fn test_verify_recording() {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let temp_dir = std::env::temp_dir().join(format!("jarvis_test_verify_{}", timestamp));
+        std::fs::create_dir_all(&temp_dir).unwrap();
+        std::fs::write(temp_dir.join("good.pcm"), vec![0u8; 32000]).unwrap();
+        std::fs::write(temp_dir.join("truncated.pcm"), vec![0u8; 32001]).unwrap();
+        std::fs::write(temp_dir.join("empty.pcm"), Vec::<u8>::new()).unwrap();
+
+        let file_manager = FileManager {
+            recordings_dir: temp_dir.clone(),
+        };
+
+        let good = file_manager.verify_recording("good.pcm").unwrap();
+        assert!(good.exists && good.nonempty && good.likely_valid);
+        assert_eq!(good.estimated_duration_seconds, 1.0);
+
+        let truncated = file_manager.verify_recording("truncated.pcm").unwrap();
+        assert!(truncated.nonempty);
+        assert!(!truncated.likely_valid);
+
+        let empty = file_manager.verify_recording("empty.pcm").unwrap();
+        assert!(empty.exists && !empty.nonempty && !empty.likely_valid);
+
+        let missing = file_manager.verify_recording("missing.pcm").unwrap();
+        assert!(!missing.exists);
+        assert_eq!(missing.size_bytes, 0);
+
+        assert!(file_manager.verify_recording("../good.pcm").is_err());
+
+        std::fs::remove_dir_all(&temp_dir).ok();
+    }
+
+    #[test]
+    fn test_concat_recordings() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        std::fs::write(temp_dir.path().join("a.pcm"), [1u8, 0, 2, 0]).unwrap();
+        std::fs::write(temp_dir.path().join("b.pcm"), [3u8, 0]).unwrap();
+        std::fs::write(temp_dir.path().join("odd.pcm"), [1u8, 2, 3]).unwrap();
+        std::fs::write(temp_dir.path().join("wav.pcm"), b"RIFF\0\0\0\0WAVE").unwrap();
+
+        let file_manager = FileManager {
+            recordings_dir: temp_dir.path().to_path_buf(),
+        };
+        let names = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+
+        let output = file_manager.concat_recordings(&names(&["b.pcm", "a.pcm"]), "joined").unwrap();
+        assert_eq!(output, "joined.pcm");
+        assert_eq!(std::fs::read(temp_dir.path().join("joined.pcm")).unwrap(), vec![3, 0, 1, 0, 2, 0]);
+
+        // Output must be new, and inputs must be valid raw PCM
+        assert!(file_manager.concat_recordings(&names(&["a.pcm", "b.pcm"]), "joined.pcm").is_err());
+        assert!(file_manager.concat_recordings(&names(&["a.pcm", "odd.pcm"]), "x").is_err());
+        assert!(file_manager.concat_recordings(&names(&["a.pcm", "wav.pcm"]), "x").is_err());
+        assert!(file_manager.concat_recordings(&names(&["a.pcm", "missing.pcm"]), "x").is_err());
+        assert!(file_manager.concat_recordings(&names(&["a.pcm"]), "x").is_err());
+        assert!(file_manager.concat_recordings(&names(&["a.pcm", "b.pcm"]), "../x").is_err());
+        assert!(!temp_dir.path().join("x.pcm").exists());
+    }
+
     #[test]
     fn test_delete_recording_success() {
         use std::io::Write;
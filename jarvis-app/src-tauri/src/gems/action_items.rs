@@ -0,0 +1,307 @@
+// Action items extracted from recording gems
+//
+// A recording gem stores its items in `source_meta.action_items`; items the
+// user has completed are also listed in `source_meta.done_action_items`, and
+// the rest are open.
+//
+// The export turns the per-gem open lists from `GemStore::list_action_items`
+// into a task file for a todo app: a Markdown checklist, an iCalendar file of
+// VTODOs, or CSV. Items are grouped by source gem and link back to it via its
+// source_url.
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use super::store::GemActionItems;
+
+/// Output format for `export_action_items`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ActionItemFormat {
+    Markdown,
+    Ics,
+    Csv,
+}
+
+impl ActionItemFormat {
+    /// File extension (without the dot)
+    pub fn extension(self) -> &'static str {
+        match self {
+            ActionItemFormat::Markdown => "md",
+            ActionItemFormat::Ics => "ics",
+            ActionItemFormat::Csv => "csv",
+        }
+    }
+}
+
+/// Store freshly extracted `items`, keeping the done state of items that
+/// were extracted again and dropping it for items that were not
+pub fn replace_action_items(source_meta: &mut Value, items: &[String]) {
+    let done: Vec<String> = string_list(source_meta, "done_action_items")
+        .into_iter()
+        .filter(|item| items.contains(item))
+        .collect();
+    source_meta["action_items"] = serde_json::json!(items);
+    source_meta["done_action_items"] = serde_json::json!(done);
+}
+
+/// Mark one of the stored action items done (or open again)
+///
+/// Returns the items that are still open.
+pub fn set_action_item_done(source_meta: &mut Value, item: &str, done: bool) -> Result<Vec<String>, String> {
+    let items = string_list(source_meta, "action_items");
+    if !items.iter().any(|i| i == item) {
+        return Err(format!("'{}' is not one of this gem's action items", item));
+    }
+
+    let mut done_items = string_list(source_meta, "done_action_items");
+    done_items.retain(|i| i != item);
+    if done {
+        done_items.push(item.to_string());
+    }
+    source_meta["done_action_items"] = serde_json::json!(done_items);
+
+    Ok(items.into_iter().filter(|i| !done_items.contains(i)).collect())
+}
+
+fn string_list(source_meta: &Value, key: &str) -> Vec<String> {
+    source_meta.get(key)
+        .and_then(|v| v.as_array())
+        .map(|items| items.iter().filter_map(|i| i.as_str().map(String::from)).collect())
+        .unwrap_or_default()
+}
+
+/// Render the action item groups in `format`
+///
+/// `now` is the export time; it becomes the DTSTAMP of iCalendar entries.
+pub fn format_action_items(
+    groups: &[GemActionItems],
+    format: ActionItemFormat,
+    now: chrono::DateTime<chrono::Utc>,
+) -> String {
+    match format {
+        ActionItemFormat::Markdown => to_markdown(groups),
+        ActionItemFormat::Ics => to_ics(groups, now),
+        ActionItemFormat::Csv => to_csv(groups),
+    }
+}
+
+fn to_markdown(groups: &[GemActionItems]) -> String {
+    let mut out = String::from("# Action Items\n");
+    for group in groups {
+        out.push_str(&format!(
+            "\n## [{}]({}) — {}\n\n",
+            escape_markdown(&group.title),
+            group.source_url.replace(' ', "%20").replace('(', "%28").replace(')', "%29"),
+            display_date(&group.captured_at)
+        ));
+        for item in &group.action_items {
+            out.push_str(&format!("- [ ] {}\n", escape_markdown(item)));
+        }
+    }
+    out
+}
+
+fn to_ics(groups: &[GemActionItems], now: chrono::DateTime<chrono::Utc>) -> String {
+    let dtstamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let mut lines = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Jarvis//Action Items//EN".to_string(),
+    ];
+    for group in groups {
+        for (i, item) in group.action_items.iter().enumerate() {
+            lines.push("BEGIN:VTODO".to_string());
+            lines.push(format!("UID:{}-{}@jarvis", group.gem_id, i));
+            lines.push(format!("DTSTAMP:{}", dtstamp));
+            lines.push(format!("SUMMARY:{}", escape_ics_text(item)));
+            lines.push(format!("DESCRIPTION:{}", escape_ics_text(&format!("From \"{}\"", group.title))));
+            lines.push(format!("CATEGORIES:{}", escape_ics_text(&group.title)));
+            lines.push(format!("URL:{}", group.source_url));
+            lines.push("STATUS:NEEDS-ACTION".to_string());
+            lines.push("END:VTODO".to_string());
+        }
+    }
+    lines.push("END:VCALENDAR".to_string());
+
+    // RFC 5545: CRLF line endings, content lines folded at 75 octets
+    lines.iter().map(|line| fold_ics_line(line)).collect::<Vec<_>>().join("\r\n") + "\r\n"
+}
+
+fn to_csv(groups: &[GemActionItems]) -> String {
+    let mut out = String::from("gem_title,captured_at,source_url,action_item\n");
+    for group in groups {
+        for item in &group.action_items {
+            out.push_str(&format!(
+                "{},{},{},{}\n",
+                escape_csv(&group.title),
+                escape_csv(&group.captured_at),
+                escape_csv(&group.source_url),
+                escape_csv(item)
+            ));
+        }
+    }
+    out
+}
+
+/// RFC 3339 timestamp → "YYYY-MM-DD HH:MM" in local time (unchanged if unparseable)
+fn display_date(captured_at: &str) -> String {
+    chrono::DateTime::parse_from_rfc3339(captured_at)
+        .map(|dt| dt.with_timezone(&chrono::Local).format("%Y-%m-%d %H:%M").to_string())
+        .unwrap_or_else(|_| captured_at.to_string())
+}
+
+fn escape_ics_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace("\r\n", "\\n")
+        .replace('\n', "\\n")
+}
+
+/// Split a content line into 75-octet pieces joined by CRLF + space,
+/// never splitting a UTF-8 character
+fn fold_ics_line(line: &str) -> String {
+    const MAX_OCTETS: usize = 75;
+    let mut out = String::new();
+    let mut current = 0;
+    for c in line.chars() {
+        // Continuation lines start with a space, which counts toward the limit
+        if current + c.len_utf8() > MAX_OCTETS {
+            out.push_str("\r\n ");
+            current = 1;
+        }
+        out.push(c);
+        current += c.len_utf8();
+    }
+    out
+}
+
+/// Escape Markdown syntax so the text renders literally, and fold line
+/// breaks so it stays on its heading or checklist line
+fn escape_markdown(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | '`' | '*' | '_' | '[' | ']' | '(' | ')' | '<' | '>' | '#' | '|' | '!' => {
+                out.push('\\');
+                out.push(c);
+            }
+            '\r' | '\n' => out.push(' '),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn groups() -> Vec<GemActionItems> {
+        vec![GemActionItems {
+            gem_id: "gem-1".to_string(),
+            title: "Standup, Monday".to_string(),
+            source_url: "jarvis://recording/standup.pcm".to_string(),
+            captured_at: "not a date".to_string(),
+            action_items: vec!["Alice to send the deck".to_string(), "Review \"Q3\" plan".to_string()],
+        }]
+    }
+
+    fn now() -> chrono::DateTime<chrono::Utc> {
+        chrono::DateTime::parse_from_rfc3339("2024-03-15T14:30:22Z").unwrap().with_timezone(&chrono::Utc)
+    }
+
+    #[test]
+    fn test_set_action_item_done() {
+        let mut meta = serde_json::json!({ "action_items": ["Send deck", "Book room"] });
+
+        assert_eq!(set_action_item_done(&mut meta, "Send deck", true).unwrap(), vec!["Book room"]);
+        assert_eq!(meta["done_action_items"], serde_json::json!(["Send deck"]));
+        assert!(set_action_item_done(&mut meta, "Unknown", true).is_err());
+
+        assert_eq!(
+            set_action_item_done(&mut meta, "Send deck", false).unwrap(),
+            vec!["Send deck", "Book room"]
+        );
+        assert_eq!(meta["done_action_items"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_replace_action_items_keeps_done_state_of_repeated_items() {
+        let mut meta = serde_json::json!({
+            "action_items": ["Send deck", "Book room"],
+            "done_action_items": ["Send deck", "Book room"]
+        });
+
+        replace_action_items(&mut meta, &["Book room".to_string(), "Email Bob".to_string()]);
+        assert_eq!(meta["action_items"], serde_json::json!(["Book room", "Email Bob"]));
+        assert_eq!(meta["done_action_items"], serde_json::json!(["Book room"]));
+    }
+
+    #[test]
+    fn test_markdown_checklist_grouped_by_gem() {
+        let md = format_action_items(&groups(), ActionItemFormat::Markdown, now());
+        assert_eq!(
+            md,
+            "# Action Items\n\n## [Standup, Monday](jarvis://recording/standup.pcm) — not a date\n\n\
+             - [ ] Alice to send the deck\n- [ ] Review \"Q3\" plan\n"
+        );
+    }
+
+    #[test]
+    fn test_markdown_escapes_titles_and_items() {
+        let groups = vec![GemActionItems {
+            title: "Sync [draft](evil) <b>*now*</b>".to_string(),
+            source_url: "jarvis://recording/team sync (1).pcm".to_string(),
+            action_items: vec!["Fix `build`\n# not a heading".to_string()],
+            ..groups().remove(0)
+        }];
+
+        let md = format_action_items(&groups, ActionItemFormat::Markdown, now());
+        assert!(md.contains(
+            "## [Sync \\[draft\\]\\(evil\\) \\<b\\>\\*now\\*\\</b\\>](jarvis://recording/team%20sync%20%281%29.pcm)"
+        ));
+        assert!(md.contains("- [ ] Fix \\`build\\` \\# not a heading\n"));
+    }
+
+    #[test]
+    fn test_ics_todos() {
+        let ics = format_action_items(&groups(), ActionItemFormat::Ics, now());
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VTODO").count(), 2);
+        assert!(ics.contains("UID:gem-1-0@jarvis\r\n"));
+        assert!(ics.contains("DTSTAMP:20240315T143022Z\r\n"));
+        assert!(ics.contains("CATEGORIES:Standup\\, Monday\r\n"));
+        assert!(ics.contains("URL:jarvis://recording/standup.pcm\r\n"));
+    }
+
+    #[test]
+    fn test_ics_folds_long_lines() {
+        let folded = fold_ics_line(&format!("SUMMARY:{}", "é".repeat(60)));
+        for line in folded.split("\r\n") {
+            assert!(line.len() <= 75, "line too long: {}", line.len());
+        }
+        assert_eq!(folded.replace("\r\n ", ""), format!("SUMMARY:{}", "é".repeat(60)));
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        let csv = format_action_items(&groups(), ActionItemFormat::Csv, now());
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "gem_title,captured_at,source_url,action_item");
+        assert_eq!(
+            lines[2],
+            "\"Standup, Monday\",not a date,jarvis://recording/standup.pcm,\"Review \"\"Q3\"\" plan\""
+        );
+    }
+}
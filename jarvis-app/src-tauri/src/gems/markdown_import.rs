@@ -0,0 +1,200 @@
+// Markdown note parsing for `import_markdown_dir`
+//
+// Reads the optional YAML-style frontmatter block (`---` ... `---`) for
+// `title`, `tags` and `date`. Only simple `key: value` lines are understood;
+// tags may be an inline list (`[a, b]`), comma-separated (`a, b`) or a
+// block list (`- a`). Without a frontmatter title, the first `# ` heading
+// is used, then the filename.
+
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// A Markdown file split into metadata and body
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownNote {
+    pub title: String,
+    pub tags: Vec<String>,
+
+    /// RFC 3339 timestamp from the frontmatter `date`, if it parsed
+    pub date: Option<String>,
+
+    /// Text after the frontmatter
+    pub body: String,
+}
+
+impl MarkdownNote {
+    /// Parse `text`, falling back to `file_name` (without extension) for the title
+    pub fn parse(file_name: &str, text: &str) -> Self {
+        let (frontmatter, body) = split_frontmatter(text);
+
+        let mut title = None;
+        let mut tags = Vec::new();
+        let mut date = None;
+        let mut current_key = "";
+
+        for line in frontmatter.lines() {
+            if let Some(item) = line.trim_start().strip_prefix("- ") {
+                if current_key == "tags" {
+                    tags.push(unquote(item).to_string());
+                }
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            current_key = key.trim();
+            let value = value.trim();
+            match current_key {
+                "title" if !value.is_empty() => title = Some(unquote(value).to_string()),
+                "tags" => tags.extend(
+                    value
+                        .trim_start_matches('[')
+                        .trim_end_matches(']')
+                        .split(',')
+                        .map(|t| unquote(t.trim()).to_string()),
+                ),
+                "date" => date = parse_date(unquote(value)),
+                _ => {}
+            }
+        }
+        tags.retain(|t| !t.is_empty());
+
+        let title = title
+            .or_else(|| {
+                body.lines()
+                    .find_map(|l| l.strip_prefix("# "))
+                    .map(|h| h.trim().to_string())
+                    .filter(|h| !h.is_empty())
+            })
+            .unwrap_or_else(|| {
+                Path::new(file_name)
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .unwrap_or(file_name)
+                    .to_string()
+            });
+
+        Self { title, tags, date, body: body.trim().to_string() }
+    }
+}
+
+/// `.md` files under `dir`, recursively and sorted, skipping hidden entries
+/// and symlinked directories (a link to a parent would never finish)
+pub fn find_markdown_files(dir: &Path) -> std::io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            let hidden = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.starts_with('.'));
+            if hidden {
+                continue;
+            }
+            // The entry's own type, unlike `path.is_dir()`, doesn't follow symlinks
+            if entry.file_type()?.is_dir() {
+                pending.push(path);
+            } else if path.extension().is_some_and(|e| e.eq_ignore_ascii_case("md")) && path.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Stable `jarvis://note/{hash}` URL for an imported file, so re-importing
+/// the same file finds the existing gem
+pub fn note_url_for_path(path: &Path) -> String {
+    let digest = Sha256::digest(path.to_string_lossy().as_bytes());
+    let hash: String = digest.iter().take(16).map(|b| format!("{:02x}", b)).collect();
+    format!("jarvis://note/{}", hash)
+}
+
+/// Split off a leading `---` frontmatter block, returning (frontmatter, body)
+fn split_frontmatter(text: &str) -> (&str, &str) {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let Some(rest) = text.strip_prefix("---\n").or_else(|| text.strip_prefix("---\r\n")) else {
+        return ("", text);
+    };
+    let mut offset = 0;
+    for line in rest.split_inclusive('\n') {
+        if line.trim_end() == "---" {
+            return (&rest[..offset], &rest[offset + line.len()..]);
+        }
+        offset += line.len();
+    }
+    // Unclosed block: treat the whole file as body
+    ("", text)
+}
+
+fn unquote(value: &str) -> &str {
+    value.trim().trim_matches('"').trim_matches('\'')
+}
+
+/// RFC 3339 timestamps, or `YYYY-MM-DD` (midnight UTC)
+fn parse_date(value: &str) -> Option<String> {
+    if let Ok(dt) = chrono::DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&chrono::Utc).to_rfc3339());
+    }
+    chrono::NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|d| d.and_hms_opt(0, 0, 0))
+        .map(|dt| dt.and_utc().to_rfc3339())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_frontmatter() {
+        let text = "---\ntitle: \"Weekly sync\"\ntags: [rust, 'async']\ndate: 2024-03-15\n---\n# Heading\n\nBody text\n";
+        let note = MarkdownNote::parse("sync.md", text);
+
+        assert_eq!(note.title, "Weekly sync");
+        assert_eq!(note.tags, vec!["rust", "async"]);
+        assert_eq!(note.date.as_deref(), Some("2024-03-15T00:00:00+00:00"));
+        assert_eq!(note.body, "# Heading\n\nBody text");
+    }
+
+    #[test]
+    fn test_parse_block_tag_list_and_title_fallbacks() {
+        let note = MarkdownNote::parse("a.md", "---\ntags:\n  - one\n  - two\n---\n# From heading\ntext");
+        assert_eq!(note.title, "From heading");
+        assert_eq!(note.tags, vec!["one", "two"]);
+        assert!(note.date.is_none());
+
+        let note = MarkdownNote::parse("plain-note.md", "no heading here");
+        assert_eq!(note.title, "plain-note");
+        assert_eq!(note.body, "no heading here");
+    }
+
+    #[test]
+    fn test_find_markdown_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(dir.path().join("sub")).unwrap();
+        std::fs::create_dir_all(dir.path().join(".obsidian")).unwrap();
+        for name in ["a.md", "sub/b.MD", "c.txt", ".obsidian/d.md"] {
+            std::fs::write(dir.path().join(name), "x").unwrap();
+        }
+        // A link back to the vault root would otherwise be walked forever
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("sub/up")).unwrap();
+
+        let files = find_markdown_files(dir.path()).unwrap();
+        assert_eq!(files, vec![dir.path().join("a.md"), dir.path().join("sub/b.MD")]);
+    }
+
+    #[test]
+    fn test_note_url_is_stable() {
+        let a = note_url_for_path(Path::new("/notes/a.md"));
+        assert_eq!(a, note_url_for_path(Path::new("/notes/a.md")));
+        assert_ne!(a, note_url_for_path(Path::new("/notes/b.md")));
+        assert!(a.starts_with("jarvis://note/"));
+    }
+}
@@ -1,5 +1,11 @@
 mod store;
 mod sqlite_store;
+pub mod search_query;
+pub mod markdown_import;
+pub mod action_items;
+pub mod thumbnails;
+#[cfg(test)]
+pub mod test_utils;
 
-pub use store::{Gem, GemPreview, GemStore};
+pub use store::{list_all_gems, CompactionResult, Gem, GemActionItems, GemPreview, GemStore, LanguageCount, SearchIndexRepair, TagCooccurrence, TranscriptMatch, ViewedGem, DEFAULT_PREVIEW_CHARS, GEM_LABELS};
 pub use sqlite_store::SqliteGemStore;
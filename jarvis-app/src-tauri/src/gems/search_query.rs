@@ -0,0 +1,121 @@
+// Field-scoped search syntax for keyword search
+//
+// A query like `source_type:YouTube tag:rust async runtime` is split into
+// field filters (applied as SQL WHERE clauses) and free text (passed to the
+// FTS5 MATCH). Supported fields: `source_type`, `domain`, `author`, `tag`.
+// Values containing spaces can be quoted: `author:"Jane Doe"`. Filters are
+// ANDed; matching is case-insensitive and exact.
+//
+// Anything that isn't a supported `field:value` token stays in the free
+// text untouched, so FTS5's own column filters (`title:rust`) keep working.
+
+/// Field a search filter applies to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchField {
+    SourceType,
+    Domain,
+    Author,
+    Tag,
+}
+
+impl SearchField {
+    fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "source_type" => Some(SearchField::SourceType),
+            "domain" => Some(SearchField::Domain),
+            "author" => Some(SearchField::Author),
+            "tag" => Some(SearchField::Tag),
+            _ => None,
+        }
+    }
+}
+
+/// A search query split into field filters and free text
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchQuery {
+    /// `field:value` filters, in the order they appeared
+    pub filters: Vec<(SearchField, String)>,
+
+    /// Remaining text for the FTS5 MATCH (may be empty)
+    pub text: String,
+}
+
+impl SearchQuery {
+    pub fn parse(query: &str) -> Self {
+        let mut parsed = SearchQuery::default();
+        let mut text_tokens = Vec::new();
+
+        for token in tokenize(query) {
+            let filter = token.split_once(':').and_then(|(name, value)| {
+                let field = SearchField::from_name(name)?;
+                let value = value.trim_matches('"');
+                (!value.is_empty()).then(|| (field, value.to_string()))
+            });
+            match filter {
+                Some(filter) => parsed.filters.push(filter),
+                None => text_tokens.push(token),
+            }
+        }
+
+        parsed.text = text_tokens.join(" ");
+        parsed
+    }
+}
+
+/// Split on whitespace, keeping double-quoted runs (and any prefix glued to
+/// them, e.g. `author:"Jane Doe"`) together as one token
+fn tokenize(query: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_quotes = false;
+
+    for c in query.chars() {
+        if c == '"' {
+            in_quotes = !in_quotes;
+            current.push(c);
+        } else if c.is_whitespace() && !in_quotes {
+            if !current.is_empty() {
+                tokens.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_extracts_field_filters() {
+        let q = SearchQuery::parse("source_type:YouTube rust  async TAG:tokio");
+        assert_eq!(
+            q.filters,
+            vec![
+                (SearchField::SourceType, "YouTube".to_string()),
+                (SearchField::Tag, "tokio".to_string()),
+            ]
+        );
+        assert_eq!(q.text, "rust async");
+    }
+
+    #[test]
+    fn test_parse_quoted_values_and_passthrough() {
+        let q = SearchQuery::parse(r#"author:"Jane Doe" "exact phrase" title:rust domain:"#);
+        assert_eq!(q.filters, vec![(SearchField::Author, "Jane Doe".to_string())]);
+        // Unknown fields and empty values are left for FTS5
+        assert_eq!(q.text, r#""exact phrase" title:rust domain:"#);
+    }
+
+    #[test]
+    fn test_parse_plain_query_is_unchanged() {
+        let q = SearchQuery::parse("plain words");
+        assert!(q.filters.is_empty());
+        assert_eq!(q.text, "plain words");
+    }
+}
@@ -1,25 +1,75 @@
 use rusqlite::Connection;
+use std::collections::HashMap;
+use std::path::Path;
 use std::sync::{Arc, Mutex};
 
+/// Columns of the gems_fts index, in order
+const FTS_COLUMNS: &str = "title, description, content, transcript, user_notes";
+
+/// gems columns read by `fts_values`; only updates to these re-index a gem
+const FTS_SOURCE_COLUMNS: &str = "title, description, content, ai_enrichment, transcript, user_notes";
+
+/// Values indexed in gems_fts for a gems row (`row` is `new`, `old`, or `gems`)
+///
+/// The `content` column also carries the AI summary and tags, so searching a
+/// topic finds gems that were only tagged with it.
+fn fts_values(row: &str) -> String {
+    format!(
+        "{r}.title,
+        {r}.description,
+        COALESCE({r}.content, '') || ' ' || COALESCE(json_extract({r}.ai_enrichment, '$.summary'), '')
+            || ' ' || COALESCE((SELECT group_concat(value, ' ') FROM json_each({r}.ai_enrichment, '$.tags')), ''),
+        COALESCE({r}.transcript, ''),
+        COALESCE({r}.user_notes, '')",
+        r = row
+    )
+}
+
+/// `tags` with every case-insensitive match of `old` replaced by `new` (or
+/// dropped when `new` is None), keeping the first occurrence of each tag
+fn replace_tag(tags: &[String], old: &str, new: Option<&str>) -> Vec<String> {
+    let old = old.to_lowercase();
+    let mut result: Vec<String> = Vec::with_capacity(tags.len());
+    for tag in tags {
+        let tag = if tag.to_lowercase() == old {
+            match new {
+                Some(new) => new,
+                None => continue,
+            }
+        } else {
+            tag.as_str()
+        };
+        if !result.iter().any(|t| t.to_lowercase() == tag.to_lowercase()) {
+            result.push(tag.to_string());
+        }
+    }
+    result
+}
+
 pub struct SqliteGemStore {
     conn: Arc<Mutex<Connection>>,
 }
 
 impl SqliteGemStore {
     /// Initialize store at ~/.jarvis/gems.db
-    pub fn new() -> Result<Self, String> {
+    pub fn new() -> JarvisResult<Self> {
         let home = dirs::home_dir()
-            .ok_or("Could not find home directory")?;
+            .ok_or_else(|| JarvisError::Io("Could not find home directory".to_string()))?;
         let db_path = home.join(".jarvis").join("gems.db");
         
         // Ensure parent directory exists
         if let Some(parent) = db_path.parent() {
             std::fs::create_dir_all(parent)
-                .map_err(|e| format!("Failed to create .jarvis directory: {}", e))?;
+                .map_err(|e| JarvisError::Io(format!("Failed to create .jarvis directory: {}", e)))?;
         }
         
-        let conn = Connection::open(&db_path)
-            .map_err(|e| format!("Failed to open database: {}", e))?;
+        Self::open(&db_path)
+    }
+
+    /// Open (creating if needed) the database at `db_path`
+    pub(crate) fn open(db_path: &Path) -> JarvisResult<Self> {
+        let conn = Connection::open(db_path)
+            .map_err(|e| JarvisError::Db(format!("Failed to open database: {}", e)))?;
         
         let store = Self {
             conn: Arc::new(Mutex::new(conn)),
@@ -31,9 +81,9 @@ impl SqliteGemStore {
     
     /// Initialize in-memory store for testing
     #[cfg(test)]
-    pub fn new_in_memory() -> Result<Self, String> {
+    pub fn new_in_memory() -> JarvisResult<Self> {
         let conn = Connection::open_in_memory()
-            .map_err(|e| format!("Failed to open in-memory database: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to open in-memory database: {}", e)))?;
         
         let store = Self {
             conn: Arc::new(Mutex::new(conn)),
@@ -48,9 +98,9 @@ impl SqliteGemStore {
         self.conn.clone()
     }
     
-    fn initialize_schema(&self) -> Result<(), String> {
+    fn initialize_schema(&self) -> JarvisResult<()> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
         
         // Main gems table
         conn.execute(
@@ -68,57 +118,79 @@ impl SqliteGemStore {
                 ai_enrichment TEXT
             )",
             [],
-        ).map_err(|e| format!("Failed to create gems table: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to create gems table: {}", e)))?;
         
         // Migration: Add ai_enrichment column if it doesn't exist
         let mut stmt = conn.prepare("PRAGMA table_info(gems)")
-            .map_err(|e| format!("Failed to prepare PRAGMA: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to prepare PRAGMA: {}", e)))?;
         let columns: Vec<String> = stmt
             .query_map([], |row| row.get::<_, String>(1))
-            .map_err(|e| format!("Failed to query columns: {}", e))?
+            .map_err(|e| JarvisError::Db(format!("Failed to query columns: {}", e)))?
             .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| format!("Failed to collect columns: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to collect columns: {}", e)))?;
         
         if !columns.contains(&"ai_enrichment".to_string()) {
             // Add the column
             conn.execute("ALTER TABLE gems ADD COLUMN ai_enrichment TEXT", [])
-                .map_err(|e| format!("Failed to add ai_enrichment column: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to add ai_enrichment column: {}", e)))?;
             
             // Drop old triggers
             conn.execute("DROP TRIGGER IF EXISTS gems_ai", [])
-                .map_err(|e| format!("Failed to drop gems_ai trigger: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to drop gems_ai trigger: {}", e)))?;
             conn.execute("DROP TRIGGER IF EXISTS gems_ad", [])
-                .map_err(|e| format!("Failed to drop gems_ad trigger: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to drop gems_ad trigger: {}", e)))?;
             conn.execute("DROP TRIGGER IF EXISTS gems_au", [])
-                .map_err(|e| format!("Failed to drop gems_au trigger: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to drop gems_au trigger: {}", e)))?;
         }
         
         // Migration: Add transcript column if it doesn't exist
         if !columns.contains(&"transcript".to_string()) {
             conn.execute("ALTER TABLE gems ADD COLUMN transcript TEXT", [])
-                .map_err(|e| format!("Failed to add transcript column: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to add transcript column: {}", e)))?;
             // Drop old FTS table so it gets recreated with the transcript column
             conn.execute("DROP TABLE IF EXISTS gems_fts", [])
-                .map_err(|e| format!("Failed to drop old FTS table: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to drop old FTS table: {}", e)))?;
         }
 
         // Migration: Add transcript_language column if it doesn't exist
         if !columns.contains(&"transcript_language".to_string()) {
             conn.execute("ALTER TABLE gems ADD COLUMN transcript_language TEXT", [])
-                .map_err(|e| format!("Failed to add transcript_language column: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to add transcript_language column: {}", e)))?;
+        }
+
+        // Migration: Add user_notes column if it doesn't exist
+        if !columns.contains(&"user_notes".to_string()) {
+            conn.execute("ALTER TABLE gems ADD COLUMN user_notes TEXT", [])
+                .map_err(|e| JarvisError::Db(format!("Failed to add user_notes column: {}", e)))?;
+        }
+
+        // Migration: Add label column if it doesn't exist
+        if !columns.contains(&"label".to_string()) {
+            conn.execute("ALTER TABLE gems ADD COLUMN label TEXT", [])
+                .map_err(|e| JarvisError::Db(format!("Failed to add label column: {}", e)))?;
+        }
+
+        // Migration: Add view tracking columns if they don't exist
+        if !columns.contains(&"last_viewed_at".to_string()) {
+            conn.execute("ALTER TABLE gems ADD COLUMN last_viewed_at TEXT", [])
+                .map_err(|e| JarvisError::Db(format!("Failed to add last_viewed_at column: {}", e)))?;
+        }
+        if !columns.contains(&"view_count".to_string()) {
+            conn.execute("ALTER TABLE gems ADD COLUMN view_count INTEGER NOT NULL DEFAULT 0", [])
+                .map_err(|e| JarvisError::Db(format!("Failed to add view_count column: {}", e)))?;
         }
 
-        // Ensure FTS table schema is up-to-date (handles case where transcript column
-        // was added to gems table but FTS wasn't recreated)
+        // Ensure FTS table schema is up-to-date (handles case where the transcript or
+        // user_notes column was added to gems table but FTS wasn't recreated)
         let fts_needs_rebuild: bool = conn.query_row(
             "SELECT sql FROM sqlite_master WHERE type='table' AND name='gems_fts'",
             [],
             |row| row.get::<_, String>(0),
-        ).ok().map_or(false, |sql| !sql.contains("transcript"));
+        ).ok().map_or(false, |sql| !sql.contains("transcript") || !sql.contains("user_notes"));
 
         if fts_needs_rebuild {
             conn.execute("DROP TABLE IF EXISTS gems_fts", [])
-                .map_err(|e| format!("Failed to drop outdated FTS table: {}", e))?;
+                .map_err(|e| JarvisError::Db(format!("Failed to drop outdated FTS table: {}", e)))?;
         }
 
         // FTS5 virtual table for full-text search
@@ -128,76 +200,95 @@ impl SqliteGemStore {
                 description,
                 content,
                 transcript,
+                user_notes,
                 content=gems,
                 content_rowid=rowid
             )",
             [],
-        ).map_err(|e| format!("Failed to create FTS5 table: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to create FTS5 table: {}", e)))?;
 
-        // Rebuild FTS index if we had to drop an outdated FTS table
-        if fts_needs_rebuild {
-            conn.execute("INSERT INTO gems_fts(gems_fts) VALUES('rebuild')", [])
-                .map_err(|e| format!("Failed to rebuild FTS index: {}", e))?;
+        // Indexed content predates tags being searchable if the old insert trigger
+        // doesn't reference them (checked before the triggers are recreated below)
+        let tags_not_indexed: bool = conn.query_row(
+            "SELECT sql FROM sqlite_master WHERE type='trigger' AND name='gems_ai'",
+            [],
+            |row| row.get::<_, String>(0),
+        ).ok().is_some_and(|sql| !sql.contains("$.tags"));
+
+        // Repopulate the FTS index if we had to drop an outdated FTS table or the
+        // indexed expressions changed
+        if fts_needs_rebuild || tags_not_indexed {
+            Self::repopulate_fts(&conn)?;
         }
 
         // Triggers to keep FTS5 in sync (always recreate to ensure they have the latest logic)
+        Self::create_fts_triggers(&conn)?;
+        
+        Ok(())
+    }
+
+    /// Clear the FTS index and re-insert every gem
+    ///
+    /// FTS5's 'rebuild' would copy the raw gems columns and lose the summary and
+    /// tags folded into `content`, so rows are re-inserted with the same
+    /// expressions the triggers use.
+    fn repopulate_fts(conn: &Connection) -> JarvisResult<()> {
+        conn.execute("INSERT INTO gems_fts(gems_fts) VALUES('delete-all')", [])
+            .map_err(|e| JarvisError::Db(format!("Failed to clear FTS index: {}", e)))?;
+        conn.execute(
+            &format!(
+                "INSERT INTO gems_fts(rowid, {}) SELECT rowid, {} FROM gems",
+                FTS_COLUMNS,
+                fts_values("gems")
+            ),
+            [],
+        ).map_err(|e| JarvisError::Db(format!("Failed to rebuild FTS index: {}", e)))?;
+        Ok(())
+    }
+
+    /// (Re)create the triggers that keep gems_fts in sync with gems
+    fn create_fts_triggers(conn: &Connection) -> JarvisResult<()> {
         conn.execute("DROP TRIGGER IF EXISTS gems_ai", [])
-            .map_err(|e| format!("Failed to drop gems_ai trigger: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to drop gems_ai trigger: {}", e)))?;
         conn.execute(
-            "CREATE TRIGGER gems_ai AFTER INSERT ON gems BEGIN
-                INSERT INTO gems_fts(rowid, title, description, content, transcript)
-                VALUES (
-                    new.rowid,
-                    new.title,
-                    new.description,
-                    COALESCE(new.content, '') || ' ' || COALESCE(json_extract(new.ai_enrichment, '$.summary'), ''),
-                    COALESCE(new.transcript, '')
-                );
-            END",
+            &format!(
+                "CREATE TRIGGER gems_ai AFTER INSERT ON gems BEGIN
+                    INSERT INTO gems_fts(rowid, {cols}) VALUES (new.rowid, {new});
+                END",
+                cols = FTS_COLUMNS,
+                new = fts_values("new")
+            ),
             [],
-        ).map_err(|e| format!("Failed to create insert trigger: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to create insert trigger: {}", e)))?;
         
         conn.execute("DROP TRIGGER IF EXISTS gems_ad", [])
-            .map_err(|e| format!("Failed to drop gems_ad trigger: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to drop gems_ad trigger: {}", e)))?;
         conn.execute(
-            "CREATE TRIGGER gems_ad AFTER DELETE ON gems BEGIN
-                INSERT INTO gems_fts(gems_fts, rowid, title, description, content, transcript)
-                VALUES(
-                    'delete',
-                    old.rowid,
-                    old.title,
-                    old.description,
-                    COALESCE(old.content, '') || ' ' || COALESCE(json_extract(old.ai_enrichment, '$.summary'), ''),
-                    COALESCE(old.transcript, '')
-                );
-            END",
+            &format!(
+                "CREATE TRIGGER gems_ad AFTER DELETE ON gems BEGIN
+                    INSERT INTO gems_fts(gems_fts, rowid, {cols}) VALUES ('delete', old.rowid, {old});
+                END",
+                cols = FTS_COLUMNS,
+                old = fts_values("old")
+            ),
             [],
-        ).map_err(|e| format!("Failed to create delete trigger: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to create delete trigger: {}", e)))?;
         
         conn.execute("DROP TRIGGER IF EXISTS gems_au", [])
-            .map_err(|e| format!("Failed to drop gems_au trigger: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to drop gems_au trigger: {}", e)))?;
         conn.execute(
-            "CREATE TRIGGER gems_au AFTER UPDATE ON gems BEGIN
-                INSERT INTO gems_fts(gems_fts, rowid, title, description, content, transcript)
-                VALUES(
-                    'delete',
-                    old.rowid,
-                    old.title,
-                    old.description,
-                    COALESCE(old.content, '') || ' ' || COALESCE(json_extract(old.ai_enrichment, '$.summary'), ''),
-                    COALESCE(old.transcript, '')
-                );
-                INSERT INTO gems_fts(rowid, title, description, content, transcript)
-                VALUES (
-                    new.rowid,
-                    new.title,
-                    new.description,
-                    COALESCE(new.content, '') || ' ' || COALESCE(json_extract(new.ai_enrichment, '$.summary'), ''),
-                    COALESCE(new.transcript, '')
-                );
-            END",
+            &format!(
+                "CREATE TRIGGER gems_au AFTER UPDATE OF {watched} ON gems BEGIN
+                    INSERT INTO gems_fts(gems_fts, rowid, {cols}) VALUES ('delete', old.rowid, {old});
+                    INSERT INTO gems_fts(rowid, {cols}) VALUES (new.rowid, {new});
+                END",
+                watched = FTS_SOURCE_COLUMNS,
+                cols = FTS_COLUMNS,
+                old = fts_values("old"),
+                new = fts_values("new")
+            ),
             [],
-        ).map_err(|e| format!("Failed to create update trigger: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to create update trigger: {}", e)))?;
         
         Ok(())
     }
@@ -205,9 +296,177 @@ impl SqliteGemStore {
 
 use async_trait::async_trait;
 use rusqlite::{params, OptionalExtension};
-use crate::gems::store::{Gem, GemPreview, GemStore};
+use crate::error::{JarvisError, JarvisResult};
+use crate::gems::search_query::{SearchField, SearchQuery};
+use crate::gems::store::{CompactionResult, Gem, GemActionItems, GemPreview, GemStore, LanguageCount, SearchIndexRepair, TagCooccurrence, TranscriptMatch, ViewedGem, DEFAULT_PREVIEW_CHARS};
 
 impl SqliteGemStore {
+    /// Check that `path` is an intact SQLite database with a gems table
+    fn validate_snapshot(path: &Path) -> JarvisResult<()> {
+        let snapshot = Connection::open_with_flags(path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| JarvisError::Db(format!("Failed to open database snapshot: {}", e)))?;
+
+        let integrity: String = snapshot.query_row("PRAGMA integrity_check", [], |row| row.get(0))
+            .map_err(|e| JarvisError::Db(format!("Database snapshot is unreadable: {}", e)))?;
+        if integrity != "ok" {
+            return Err(JarvisError::Validation(format!("Database snapshot failed its integrity check: {}", integrity)));
+        }
+
+        let has_gems: bool = snapshot.query_row(
+            "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'gems')",
+            [],
+            |row| row.get(0),
+        ).map_err(|e| JarvisError::Db(format!("Database snapshot is unreadable: {}", e)))?;
+        if !has_gems {
+            return Err(JarvisError::Validation("Database snapshot has no gems table".to_string()));
+        }
+        Ok(())
+    }
+
+    /// Replace the database file at `db_path` with `replacement` by renaming,
+    /// closing and reopening the shared connection around it. With
+    /// `keep_current_as`, the current file is moved there instead of being
+    /// overwritten.
+    fn swap_database(&self, db_path: &Path, replacement: &Path, keep_current_as: Option<&Path>) -> JarvisResult<()> {
+        let mut conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        // Close the live connection (checkpointing any WAL) before moving files under it
+        let placeholder = Connection::open_in_memory()
+            .map_err(|e| JarvisError::Db(format!("Failed to open placeholder database: {}", e)))?;
+        std::mem::replace(&mut *conn, placeholder)
+            .close()
+            .map_err(|(_, e)| JarvisError::Db(format!("Failed to close database: {}", e)))?;
+        for suffix in ["-wal", "-shm"] {
+            let _ = std::fs::remove_file(format!("{}{}", db_path.display(), suffix));
+        }
+
+        let swapped = keep_current_as
+            .map_or(Ok(()), |keep| std::fs::rename(db_path, keep))
+            .and_then(|_| std::fs::rename(replacement, db_path));
+        if swapped.is_err() {
+            if let Some(keep) = keep_current_as.filter(|_| !db_path.exists()) {
+                let _ = std::fs::rename(keep, db_path);
+            }
+        }
+
+        // Reopen even if the swap failed, so the store keeps working
+        *conn = Connection::open(db_path)
+            .map_err(|e| JarvisError::Db(format!("Failed to reopen database: {}", e)))?;
+        // Shared with SqliteProjectStore, which relies on CASCADE
+        conn.execute("PRAGMA foreign_keys = ON", [])
+            .map_err(|e| JarvisError::Db(format!("Failed to enable foreign keys: {}", e)))?;
+        swapped.map_err(|e| JarvisError::Io(format!("Failed to replace database: {}", e)))
+    }
+
+    /// Create or migrate the tables of every store sharing this database
+    /// (gems, projects, search history)
+    fn initialize_shared_schema(&self) -> JarvisResult<()> {
+        self.initialize_schema()?;
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        crate::projects::SqliteProjectStore::initialize_schema_on(&conn).map_err(JarvisError::Db)?;
+        crate::search::SearchHistory::initialize_schema_on(&conn)
+    }
+
+    /// Current database size in bytes (page_count * page_size)
+    fn database_size(conn: &Connection) -> JarvisResult<u64> {
+        let page_count: i64 = conn.query_row("PRAGMA page_count", [], |row| row.get(0))
+            .map_err(|e| JarvisError::Db(format!("Failed to read page count: {}", e)))?;
+        let page_size: i64 = conn.query_row("PRAGMA page_size", [], |row| row.get(0))
+            .map_err(|e| JarvisError::Db(format!("Failed to read page size: {}", e)))?;
+
+        Ok((page_count * page_size) as u64)
+    }
+
+    /// Viewed gems (view_count > 0) sorted by `order_by`
+    fn list_viewed(&self, order_by: &str, limit: usize) -> JarvisResult<Vec<ViewedGem>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        // Only the preview's columns: content is cut one character past the
+        // preview length (SQLite's substr counts characters) so it still gets
+        // its ellipsis, and source_meta, transcript and notes aren't read
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, source_type, source_url, domain, title, author,
+                description, substr(content, 1, ?2), '{{}}', captured_at, ai_enrichment, NULL, transcript_language, NULL, label,
+                last_viewed_at, view_count
+            FROM gems
+            WHERE view_count > 0 AND last_viewed_at IS NOT NULL
+            ORDER BY {}
+            LIMIT ?1",
+            order_by
+        )).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+
+        let viewed = stmt.query_map(params![limit, DEFAULT_PREVIEW_CHARS + 1], |row| {
+            Ok(ViewedGem {
+                gem: Self::gem_to_preview(&Self::row_to_gem(row)?),
+                last_viewed_at: row.get(15)?,
+                view_count: row.get(16)?,
+            })
+        })
+        .map_err(|e| JarvisError::Db(format!("Failed to query viewed gems: {}", e)))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| JarvisError::Db(format!("Failed to collect viewed gems: {}", e)))?;
+
+        Ok(viewed)
+    }
+
+    /// Insert or update `gem` (upsert by source_url) and read the stored row back
+    fn upsert_gem(conn: &Connection, gem: &Gem) -> JarvisResult<Gem> {
+        // Serialize ai_enrichment to JSON string (or NULL if None)
+        let ai_enrichment_str = gem.ai_enrichment.as_ref()
+            .map(|v| v.to_string());
+        
+        conn.execute(
+            "INSERT INTO gems (id, source_type, source_url, domain, title, author, 
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)
+            ON CONFLICT(source_url) DO UPDATE SET
+                source_type = excluded.source_type,
+                title = excluded.title,
+                author = excluded.author,
+                description = excluded.description,
+                content = excluded.content,
+                source_meta = excluded.source_meta,
+                captured_at = excluded.captured_at,
+                ai_enrichment = excluded.ai_enrichment,
+                transcript = excluded.transcript,
+                transcript_language = excluded.transcript_language,
+                user_notes = COALESCE(excluded.user_notes, gems.user_notes),
+                label = COALESCE(excluded.label, gems.label)",
+            params![
+                gem.id,
+                gem.source_type,
+                gem.source_url,
+                gem.domain,
+                gem.title,
+                gem.author,
+                gem.description,
+                gem.content,
+                gem.source_meta.to_string(),
+                gem.captured_at,
+                ai_enrichment_str,
+                gem.transcript,
+                gem.transcript_language,
+                gem.user_notes,
+                gem.label,
+            ],
+        ).map_err(|e| JarvisError::Db(format!("Failed to save gem: {}", e)))?;
+        
+        // Query back the actual row to get the correct ID (in case of conflict, the original ID is kept)
+        let mut stmt = conn.prepare(
+            "SELECT id, source_type, source_url, domain, title, author, 
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
+            FROM gems WHERE source_url = ?1"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare query: {}", e)))?;
+        
+        let saved_gem = stmt.query_row(params![gem.source_url], Self::row_to_gem)
+            .map_err(|e| JarvisError::Db(format!("Failed to query saved gem: {}", e)))?;
+        
+        Ok(saved_gem)
+    }
+
     fn row_to_gem(row: &rusqlite::Row) -> rusqlite::Result<Gem> {
         // Read ai_enrichment as Option<String> and deserialize to Option<Value>
         let ai_enrichment: Option<serde_json::Value> = row.get::<_, Option<String>>(10)?
@@ -228,10 +487,16 @@ impl SqliteGemStore {
             ai_enrichment,
             transcript: row.get(11)?,
             transcript_language: row.get(12)?,
+            user_notes: row.get(13)?,
+            label: row.get(14)?,
         })
     }
     
     fn gem_to_preview(gem: &Gem) -> GemPreview {
+        Self::gem_to_preview_with_length(gem, DEFAULT_PREVIEW_CHARS)
+    }
+
+    fn gem_to_preview_with_length(gem: &Gem, preview_chars: usize) -> GemPreview {
         // Extract tags, summary, and enrichment source from ai_enrichment JSON
         let (tags, summary, enrichment_source) = if let Some(ai_enrichment) = &gem.ai_enrichment {
             let tags = ai_enrichment
@@ -267,8 +532,8 @@ impl SqliteGemStore {
             description: gem.description.clone(),
             content_preview: gem.content.as_ref().map(|c| {
                 // Safe UTF-8 truncation by character count, not byte offset
-                if c.chars().count() > 200 {
-                    format!("{}...", c.chars().take(200).collect::<String>())
+                if c.chars().count() > preview_chars {
+                    format!("{}...", c.chars().take(preview_chars).collect::<String>())
                 } else {
                     c.clone()
                 }
@@ -278,84 +543,43 @@ impl SqliteGemStore {
             summary,
             enrichment_source,
             transcript_language: gem.transcript_language.clone(),
+            label: gem.label.clone(),
         }
     }
 }
 
 #[async_trait]
 impl GemStore for SqliteGemStore {
-    async fn save(&self, gem: Gem) -> Result<Gem, String> {
+    async fn save(&self, gem: Gem) -> JarvisResult<Gem> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
-        
-        // Serialize ai_enrichment to JSON string (or NULL if None)
-        let ai_enrichment_str = gem.ai_enrichment.as_ref()
-            .map(|v| v.to_string());
-        
-        conn.execute(
-            "INSERT INTO gems (id, source_type, source_url, domain, title, author, 
-                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)
-            ON CONFLICT(source_url) DO UPDATE SET
-                title = excluded.title,
-                author = excluded.author,
-                description = excluded.description,
-                content = excluded.content,
-                source_meta = excluded.source_meta,
-                captured_at = excluded.captured_at,
-                ai_enrichment = excluded.ai_enrichment,
-                transcript = excluded.transcript,
-                transcript_language = excluded.transcript_language",
-            params![
-                gem.id,
-                gem.source_type,
-                gem.source_url,
-                gem.domain,
-                gem.title,
-                gem.author,
-                gem.description,
-                gem.content,
-                gem.source_meta.to_string(),
-                gem.captured_at,
-                ai_enrichment_str,
-                gem.transcript,
-                gem.transcript_language,
-            ],
-        ).map_err(|e| format!("Failed to save gem: {}", e))?;
-        
-        // Query back the actual row to get the correct ID (in case of conflict, the original ID is kept)
-        let mut stmt = conn.prepare(
-            "SELECT id, source_type, source_url, domain, title, author, 
-                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language
-            FROM gems WHERE source_url = ?1"
-        ).map_err(|e| format!("Failed to prepare query: {}", e))?;
-        
-        let saved_gem = stmt.query_row(params![gem.source_url], Self::row_to_gem)
-            .map_err(|e| format!("Failed to query saved gem: {}", e))?;
-        
-        Ok(saved_gem)
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        Self::upsert_gem(&conn, &gem)
     }
     
-    async fn get(&self, id: &str) -> Result<Option<Gem>, String> {
+    async fn get(&self, id: &str) -> JarvisResult<Option<Gem>> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
         
         let mut stmt = conn.prepare(
             "SELECT id, source_type, source_url, domain, title, author, 
-                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
             FROM gems WHERE id = ?1"
-        ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare query: {}", e)))?;
         
         let result = stmt.query_row(params![id], Self::row_to_gem)
             .optional()
-            .map_err(|e| format!("Failed to query gem: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to query gem: {}", e)))?;
         
         Ok(result)
     }
     
-    async fn list(&self, limit: usize, offset: usize) -> Result<Vec<GemPreview>, String> {
+    async fn list(&self, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>> {
+        self.list_with_preview(limit, offset, DEFAULT_PREVIEW_CHARS).await
+    }
+
+    async fn list_with_preview(&self, limit: usize, offset: usize, preview_chars: usize) -> JarvisResult<Vec<GemPreview>> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
         
         // NOTE: This fetches full content and truncates in Rust. For large gems (50K+ chars),
         // this is wasteful. Could optimize with SUBSTR(content, 1, 600) in SQL (600 bytes ~= 200 UTF-8 chars),
@@ -363,56 +587,86 @@ impl GemStore for SqliteGemStore {
         // Current approach prioritizes correctness over performance.
         let mut stmt = conn.prepare(
             "SELECT id, source_type, source_url, domain, title, author, 
-                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
             FROM gems
             ORDER BY captured_at DESC
             LIMIT ?1 OFFSET ?2"
-        ).map_err(|e| format!("Failed to prepare statement: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
         
         let gems = stmt.query_map(params![limit, offset], Self::row_to_gem)
-            .map_err(|e| format!("Failed to query gems: {}", e))?
+            .map_err(|e| JarvisError::Db(format!("Failed to query gems: {}", e)))?
             .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| format!("Failed to collect gems: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to collect gems: {}", e)))?;
         
-        Ok(gems.iter().map(Self::gem_to_preview).collect())
+        Ok(gems.iter().map(|gem| Self::gem_to_preview_with_length(gem, preview_chars)).collect())
     }
     
-    async fn search(&self, query: &str, limit: usize) -> Result<Vec<GemPreview>, String> {
+    async fn search(&self, query: &str, limit: usize) -> JarvisResult<Vec<GemPreview>> {
         // Handle empty query by delegating to list()
         if query.trim().is_empty() {
             return self.list(limit, 0).await;
         }
         
+        // `field:value` tokens become WHERE filters; the rest goes to FTS5
+        let parsed = SearchQuery::parse(query);
+        
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
         
-        // NOTE: Same performance consideration as list() - fetches full content for truncation.
-        let mut stmt = conn.prepare(
+        let mut sql = String::from(
             "SELECT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
-                g.description, g.content, g.source_meta, g.captured_at, g.ai_enrichment, g.transcript, g.transcript_language
-            FROM gems g
-            INNER JOIN gems_fts fts ON g.rowid = fts.rowid
-            WHERE gems_fts MATCH ?1
-            ORDER BY rank
-            LIMIT ?2"
-        ).map_err(|e| {
+                g.description, g.content, g.source_meta, g.captured_at, g.ai_enrichment, g.transcript, g.transcript_language, g.user_notes, g.label
+            FROM gems g"
+        );
+        let mut conditions: Vec<&str> = Vec::new();
+        let mut sql_params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
+        
+        if !parsed.text.is_empty() {
+            sql.push_str("\n            INNER JOIN gems_fts fts ON g.rowid = fts.rowid");
+            conditions.push("gems_fts MATCH ?");
+            sql_params.push(Box::new(parsed.text.clone()));
+        }
+        for (field, value) in &parsed.filters {
+            conditions.push(match field {
+                SearchField::SourceType => "g.source_type = ? COLLATE NOCASE",
+                SearchField::Domain => "g.domain = ? COLLATE NOCASE",
+                SearchField::Author => "g.author = ? COLLATE NOCASE",
+                SearchField::Tag => "EXISTS (SELECT 1 FROM json_each(g.ai_enrichment, '$.tags') WHERE json_each.value = ? COLLATE NOCASE)",
+            });
+            sql_params.push(Box::new(value.clone()));
+        }
+        if !conditions.is_empty() {
+            sql.push_str(&format!("\n            WHERE {}", conditions.join(" AND ")));
+        }
+        // Filter-only queries have no relevance rank; show the most recent first
+        if parsed.text.is_empty() {
+            sql.push_str("\n            ORDER BY g.captured_at DESC");
+        } else {
+            sql.push_str("\n            ORDER BY rank");
+        }
+        sql.push_str("\n            LIMIT ?");
+        sql_params.push(Box::new(limit as i64));
+        
+        // NOTE: Same performance consideration as list() - fetches full content for truncation.
+        let mut stmt = conn.prepare(&sql).map_err(|e| {
             // Handle FTS5 syntax errors with user-friendly messages
             let error_msg = e.to_string();
             if error_msg.contains("fts5: syntax error") || error_msg.contains("unterminated string") {
-                format!("Invalid search query syntax. Please check for unmatched quotes or special characters.")
+                JarvisError::Validation("Invalid search query syntax. Please check for unmatched quotes or special characters.".to_string())
             } else {
-                format!("Failed to prepare search statement: {}", e)
+                JarvisError::Db(format!("Failed to prepare search statement: {}", e))
             }
         })?;
         
-        let gems = stmt.query_map(params![query, limit], Self::row_to_gem)
+        let param_refs: Vec<&dyn rusqlite::types::ToSql> = sql_params.iter().map(|p| p.as_ref()).collect();
+        let gems = stmt.query_map(param_refs.as_slice(), Self::row_to_gem)
             .map_err(|e| {
                 // Handle FTS5 query execution errors
                 let error_msg = e.to_string();
                 if error_msg.contains("fts5: syntax error") || error_msg.contains("unterminated string") {
-                    format!("Invalid search query syntax. Please check for unmatched quotes or special characters.")
+                    JarvisError::Validation("Invalid search query syntax. Please check for unmatched quotes or special characters.".to_string())
                 } else {
-                    format!("Failed to search gems: {}", e)
+                    JarvisError::Db(format!("Failed to search gems: {}", e))
                 }
             })?
             .collect::<Result<Vec<_>, _>>()
@@ -420,92 +674,655 @@ impl GemStore for SqliteGemStore {
                 // Handle FTS5 errors during result collection
                 let error_msg = e.to_string();
                 if error_msg.contains("fts5: syntax error") || error_msg.contains("unterminated string") {
-                    format!("Invalid search query syntax. Please check for unmatched quotes or special characters.")
+                    JarvisError::Validation("Invalid search query syntax. Please check for unmatched quotes or special characters.".to_string())
                 } else {
-                    format!("Failed to collect search results: {}", e)
+                    JarvisError::Db(format!("Failed to collect search results: {}", e))
                 }
             })?;
         
         Ok(gems.iter().map(Self::gem_to_preview).collect())
     }
     
-    async fn filter_by_tag(&self, tag: &str, limit: usize, offset: usize) -> Result<Vec<GemPreview>, String> {
+    async fn suggest(&self, prefix: &str, limit: usize) -> JarvisResult<Vec<String>> {
+        let prefix = prefix.trim();
+        if prefix.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+        
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        
+        // Quoted so FTS5 operators in the prefix are taken literally; the trailing *
+        // makes the last word a prefix match
+        let fts_query = format!("title : \"{}\" *", prefix.replace('"', "\"\""));
+        let mut stmt = conn.prepare(
+            "SELECT g.title, COUNT(*)
+            FROM gems g
+            INNER JOIN gems_fts fts ON g.rowid = fts.rowid
+            WHERE gems_fts MATCH ?1
+            GROUP BY g.title"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare title suggestions: {}", e)))?;
+        let titles = stmt.query_map(params![fts_query], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| JarvisError::Db(format!("Failed to query title suggestions: {}", e)))?;
+        
+        let mut stmt = conn.prepare(
+            "SELECT json_each.value, COUNT(*)
+            FROM gems g, json_each(json_extract(g.ai_enrichment, '$.tags'))
+            WHERE substr(lower(json_each.value), 1, length(?1)) = lower(?1)
+            GROUP BY json_each.value"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare tag suggestions: {}", e)))?;
+        let tags = stmt.query_map(params![prefix], |row| Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?)))
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| JarvisError::Db(format!("Failed to query tag suggestions: {}", e)))?;
+        
+        // A title and a tag with the same text become one suggestion with summed counts
+        let mut counts: std::collections::HashMap<String, i64> = std::collections::HashMap::new();
+        for (text, count) in titles.into_iter().chain(tags) {
+            *counts.entry(text).or_default() += count;
+        }
+        let mut suggestions: Vec<(String, i64)> = counts.into_iter().collect();
+        suggestions.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        
+        Ok(suggestions.into_iter().take(limit).map(|(text, _)| text).collect())
+    }
+    
+    async fn search_transcripts(&self, query: &str, limit: usize) -> JarvisResult<Vec<TranscriptMatch>> {
+        if query.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        
+        // Parentheses or a column filter outside a phrase could close the
+        // transcript filter below and match other columns
+        let mut in_phrase = false;
+        for c in query.chars() {
+            match c {
+                '"' => in_phrase = !in_phrase,
+                '(' | ')' | ':' if !in_phrase => {
+                    return Err(JarvisError::Validation(
+                        "Transcript search doesn't support parentheses or column filters. Quote the text to search for it literally.".to_string()
+                    ));
+                }
+                _ => {}
+            }
+        }
+        
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        
+        // Column filter restricts matching to the transcript column; snippet()
+        // column 3 is transcript (title, description, content, transcript, user_notes)
+        let fts_query = format!("transcript : ({})", query);
+        let mut stmt = conn.prepare(
+            "SELECT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
+                g.description, g.content, g.source_meta, g.captured_at, g.ai_enrichment, g.transcript, g.transcript_language, g.user_notes, g.label,
+                snippet(gems_fts, 3, '**', '**', '…', 24)
+            FROM gems g
+            INNER JOIN gems_fts fts ON g.rowid = fts.rowid
+            WHERE gems_fts MATCH ?1
+            ORDER BY rank
+            LIMIT ?2"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare transcript search statement: {}", e)))?;
+        
+        let rows = stmt.query_map(params![fts_query, limit], |row| {
+                Ok((Self::row_to_gem(row)?, row.get::<_, String>(15)?))
+            })
+            .and_then(|rows| rows.collect::<Result<Vec<_>, _>>())
+            .map_err(|e| {
+                let error_msg = e.to_string();
+                if error_msg.contains("fts5: syntax error") || error_msg.contains("unterminated string") {
+                    JarvisError::Validation("Invalid search query syntax. Please check for unmatched quotes or special characters.".to_string())
+                } else {
+                    JarvisError::Db(format!("Failed to search transcripts: {}", e))
+                }
+            })?;
+        
+        Ok(rows
+            .into_iter()
+            .map(|(gem, transcript_snippet)| TranscriptMatch {
+                gem: Self::gem_to_preview(&gem),
+                transcript_snippet,
+            })
+            .collect())
+    }
+    
+    async fn rename_tag(&self, old: &str, new: Option<&str>) -> JarvisResult<Vec<String>> {
+        let mut conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        let tx = conn.transaction()
+            .map_err(|e| JarvisError::Db(format!("Failed to start transaction: {}", e)))?;
+
+        let tagged: Vec<(String, String)> = {
+            let mut stmt = tx.prepare(
+                "SELECT id, ai_enrichment FROM gems
+                 WHERE EXISTS (SELECT 1 FROM json_each(ai_enrichment, '$.tags') WHERE json_each.value = ?1 COLLATE NOCASE)"
+            ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+            let rows = stmt.query_map(params![old], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(|e| JarvisError::Db(format!("Failed to query tagged gems: {}", e)))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| JarvisError::Db(format!("Failed to collect tagged gems: {}", e)))?;
+            rows
+        };
+
+        let mut changed = Vec::new();
+        for (id, ai_enrichment) in tagged {
+            let mut enrichment: serde_json::Value = serde_json::from_str(&ai_enrichment)
+                .map_err(|e| JarvisError::Parse(format!("Invalid ai_enrichment on gem {}: {}", id, e)))?;
+            let tags: Vec<String> = enrichment["tags"]
+                .as_array()
+                .map(|tags| tags.iter().filter_map(|t| t.as_str().map(String::from)).collect())
+                .unwrap_or_default();
+            let replaced = replace_tag(&tags, old, new);
+            if replaced == tags {
+                continue;
+            }
+            enrichment["tags"] = serde_json::json!(replaced);
+
+            tx.execute(
+                "UPDATE gems SET ai_enrichment = ?1 WHERE id = ?2",
+                params![enrichment.to_string(), id],
+            ).map_err(|e| JarvisError::Db(format!("Failed to update gem tags: {}", e)))?;
+            changed.push(id);
+        }
+
+        tx.commit()
+            .map_err(|e| JarvisError::Db(format!("Failed to commit tag change: {}", e)))?;
+        Ok(changed)
+    }
+
+    async fn filter_by_tag(&self, tag: &str, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
         
         let mut stmt = conn.prepare(
             "SELECT DISTINCT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
-                g.description, g.content, g.source_meta, g.captured_at, g.ai_enrichment, g.transcript, g.transcript_language
+                g.description, g.content, g.source_meta, g.captured_at, g.ai_enrichment, g.transcript, g.transcript_language, g.user_notes, g.label
              FROM gems g, json_each(json_extract(g.ai_enrichment, '$.tags'))
              WHERE json_each.value = ?1
              ORDER BY g.captured_at DESC
              LIMIT ?2 OFFSET ?3"
-        ).map_err(|e| format!("Failed to prepare statement: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
         
         let gems = stmt.query_map(params![tag, limit, offset], Self::row_to_gem)
-            .map_err(|e| format!("Failed to query gems by tag: {}", e))?
+            .map_err(|e| JarvisError::Db(format!("Failed to query gems by tag: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect gems: {}", e)))?;
+        
+        Ok(gems.iter().map(Self::gem_to_preview).collect())
+    }
+    
+    async fn filter_by_language(&self, language: &str, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        
+        let mut stmt = conn.prepare(
+            "SELECT id, source_type, source_url, domain, title, author, 
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
+            FROM gems
+            WHERE COALESCE(NULLIF(transcript_language, ''), json_extract(source_meta, '$.content_language')) = ?1
+            ORDER BY captured_at DESC
+            LIMIT ?2 OFFSET ?3"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+        
+        let gems = stmt.query_map(params![language, limit, offset], Self::row_to_gem)
+            .map_err(|e| JarvisError::Db(format!("Failed to query gems by language: {}", e)))?
             .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| format!("Failed to collect gems: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to collect gems: {}", e)))?;
         
         Ok(gems.iter().map(Self::gem_to_preview).collect())
     }
+
+    async fn list_languages(&self) -> JarvisResult<Vec<LanguageCount>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        
+        let mut stmt = conn.prepare(
+            "SELECT COALESCE(NULLIF(transcript_language, ''), json_extract(source_meta, '$.content_language')) AS language,
+                COUNT(*)
+            FROM gems
+            WHERE language IS NOT NULL AND language != ''
+            GROUP BY language
+            ORDER BY COUNT(*) DESC, language ASC"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+        
+        let languages = stmt.query_map([], |row| {
+            Ok(LanguageCount {
+                language: row.get(0)?,
+                count: row.get::<_, i64>(1)? as usize,
+            })
+        })
+            .map_err(|e| JarvisError::Db(format!("Failed to query languages: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect languages: {}", e)))?;
+        
+        Ok(languages)
+    }
+    
+    async fn tag_cooccurrence(&self, min_count: usize) -> JarvisResult<Vec<TagCooccurrence>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        // Expand each gem's tags twice and keep each unordered pair once
+        let mut stmt = conn.prepare(
+            "SELECT lower(a.value), lower(b.value), COUNT(DISTINCT g.id)
+            FROM gems g, json_each(g.ai_enrichment, '$.tags') a, json_each(g.ai_enrichment, '$.tags') b
+            WHERE lower(a.value) < lower(b.value)
+            GROUP BY lower(a.value), lower(b.value)
+            HAVING COUNT(DISTINCT g.id) >= ?1
+            ORDER BY COUNT(DISTINCT g.id) DESC, lower(a.value) ASC, lower(b.value) ASC"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+
+        let pairs = stmt.query_map(params![min_count.max(1)], |row| {
+            Ok(TagCooccurrence {
+                tag_a: row.get(0)?,
+                tag_b: row.get(1)?,
+                count: row.get::<_, i64>(2)? as usize,
+            })
+        })
+            .map_err(|e| JarvisError::Db(format!("Failed to query tag pairs: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect tag pairs: {}", e)))?;
+
+        Ok(pairs)
+    }
+
+    async fn find_missing_transcript_language(&self) -> JarvisResult<Vec<String>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        
+        let mut stmt = conn.prepare(
+            "SELECT id FROM gems
+            WHERE transcript IS NOT NULL AND TRIM(transcript) != ''
+                AND (transcript_language IS NULL OR TRIM(transcript_language) = '')
+            ORDER BY captured_at DESC"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+        
+        let ids = stmt.query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| JarvisError::Db(format!("Failed to query gems missing language: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect gem ids: {}", e)))?;
+        
+        Ok(ids)
+    }
     
-    async fn delete(&self, id: &str) -> Result<(), String> {
+    async fn delete(&self, id: &str) -> JarvisResult<bool> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
         
         let rows_affected = conn.execute(
             "DELETE FROM gems WHERE id = ?1",
             params![id],
-        ).map_err(|e| format!("Failed to delete gem: {}", e))?;
-        
-        if rows_affected == 0 {
-            return Err(format!("Gem with id '{}' not found", id));
-        }
+        ).map_err(|e| JarvisError::Db(format!("Failed to delete gem: {}", e)))?;
         
-        Ok(())
+        Ok(rows_affected > 0)
     }
     
-    async fn find_by_recording_filename(&self, filename: &str) -> Result<Option<GemPreview>, String> {
+    async fn merge_into(&self, merged: Gem, absorbed_id: &str) -> JarvisResult<Gem> {
+        let mut conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        let tx = conn.transaction()
+            .map_err(|e| JarvisError::Db(format!("Failed to start transaction: {}", e)))?;
+
+        let saved = Self::upsert_gem(&tx, &merged)?;
+        tx.execute("DELETE FROM gems WHERE id = ?1", params![absorbed_id])
+            .map_err(|e| JarvisError::Db(format!("Failed to delete gem: {}", e)))?;
+
+        tx.commit()
+            .map_err(|e| JarvisError::Db(format!("Failed to commit merge: {}", e)))?;
+        Ok(saved)
+    }
+    
+    async fn find_by_recording_filename(&self, filename: &str) -> JarvisResult<Option<GemPreview>> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
         
         let mut stmt = conn.prepare(
             "SELECT id, source_type, source_url, domain, title, author, 
-                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
             FROM gems
             WHERE json_extract(source_meta, '$.recording_filename') = ?1
             ORDER BY captured_at DESC
             LIMIT 1"
-        ).map_err(|e| format!("Failed to prepare query: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare query: {}", e)))?;
         
         let result = stmt.query_row(params![filename], Self::row_to_gem)
             .optional()
-            .map_err(|e| format!("Failed to query gem: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to query gem: {}", e)))?;
         
         Ok(result.map(|gem| Self::gem_to_preview(&gem)))
     }
 
-    async fn update_title(&self, id: &str, title: &str) -> Result<(), String> {
+    async fn find_by_recording_filenames(&self, filenames: &[String]) -> JarvisResult<HashMap<String, GemPreview>> {
+        if filenames.is_empty() {
+            return Ok(HashMap::new());
+        }
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        // Oldest first, so the newest gem for a recording wins
+        let placeholders = vec!["?"; filenames.len()].join(", ");
+        let mut stmt = conn.prepare(&format!(
+            "SELECT id, source_type, source_url, domain, title, author,
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
+            FROM gems
+            WHERE json_extract(source_meta, '$.recording_filename') IN ({})
+            ORDER BY captured_at ASC",
+            placeholders
+        )).map_err(|e| JarvisError::Db(format!("Failed to prepare query: {}", e)))?;
+
+        let gems = stmt.query_map(rusqlite::params_from_iter(filenames), Self::row_to_gem)
+            .map_err(|e| JarvisError::Db(format!("Failed to query gems: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect gems: {}", e)))?;
+
+        Ok(gems.iter()
+            .filter_map(|gem| {
+                let filename = gem.source_meta.get("recording_filename")?.as_str()?;
+                Some((filename.to_string(), Self::gem_to_preview(gem)))
+            })
+            .collect())
+    }
+
+    async fn find_by_source_url(&self, source_url: &str) -> JarvisResult<Option<GemPreview>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, source_type, source_url, domain, title, author,
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
+            FROM gems
+            WHERE source_url = ?1"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare query: {}", e)))?;
+
+        let result = stmt.query_row(params![source_url], Self::row_to_gem)
+            .optional()
+            .map_err(|e| JarvisError::Db(format!("Failed to query gem: {}", e)))?;
+
+        Ok(result.map(|gem| Self::gem_to_preview(&gem)))
+    }
+
+    async fn list_action_items(&self) -> JarvisResult<Vec<GemActionItems>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, title, source_url, captured_at, json_extract(source_meta, '$.action_items'),
+                json_extract(source_meta, '$.done_action_items')
+            FROM gems
+            WHERE json_extract(source_meta, '$.recording_filename') IS NOT NULL
+                AND json_type(source_meta, '$.action_items') = 'array'
+                AND json_array_length(source_meta, '$.action_items') > 0
+            ORDER BY captured_at DESC"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?,
+                row.get::<_, String>(2)?,
+                row.get::<_, String>(3)?,
+                row.get::<_, String>(4)?,
+                row.get::<_, Option<String>>(5)?,
+            ))
+        })
+            .map_err(|e| JarvisError::Db(format!("Failed to query action items: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect action items: {}", e)))?;
+
+        let mut groups = Vec::new();
+        for (gem_id, title, source_url, captured_at, items, done) in rows {
+            let items: Vec<String> = serde_json::from_str(&items)
+                .map_err(|e| JarvisError::Parse(format!("Invalid action_items on gem {}: {}", gem_id, e)))?;
+            let done: Vec<String> = match done {
+                Some(done) => serde_json::from_str(&done)
+                    .map_err(|e| JarvisError::Parse(format!("Invalid done_action_items on gem {}: {}", gem_id, e)))?,
+                None => Vec::new(),
+            };
+
+            let action_items: Vec<String> = items.into_iter().filter(|item| !done.contains(item)).collect();
+            if !action_items.is_empty() {
+                groups.push(GemActionItems { gem_id, title, source_url, captured_at, action_items });
+            }
+        }
+        Ok(groups)
+    }
+
+    async fn update_title(&self, id: &str, title: &str) -> JarvisResult<()> {
         let conn = self.conn.lock()
-            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
 
         let rows_affected = conn.execute(
             "UPDATE gems SET title = ?1 WHERE id = ?2",
             params![title, id],
-        ).map_err(|e| format!("Failed to update gem title: {}", e))?;
+        ).map_err(|e| JarvisError::Db(format!("Failed to update gem title: {}", e)))?;
+
+        if rows_affected == 0 {
+            return Err(JarvisError::NotFound(format!("Gem with id '{}' not found", id)));
+        }
+
+        Ok(())
+    }
+
+    async fn update_notes(&self, id: &str, notes: Option<&str>) -> JarvisResult<()> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let rows_affected = conn.execute(
+            "UPDATE gems SET user_notes = ?1 WHERE id = ?2",
+            params![notes, id],
+        ).map_err(|e| JarvisError::Db(format!("Failed to update gem notes: {}", e)))?;
+
+        if rows_affected == 0 {
+            return Err(JarvisError::NotFound(format!("Gem with id '{}' not found", id)));
+        }
+
+        Ok(())
+    }
+
+    async fn update_label(&self, id: &str, label: Option<&str>) -> JarvisResult<()> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let rows_affected = conn.execute(
+            "UPDATE gems SET label = ?1 WHERE id = ?2",
+            params![label, id],
+        ).map_err(|e| JarvisError::Db(format!("Failed to update gem label: {}", e)))?;
+
+        if rows_affected == 0 {
+            return Err(JarvisError::NotFound(format!("Gem with id '{}' not found", id)));
+        }
+
+        Ok(())
+    }
+
+    async fn mark_viewed(&self, id: &str) -> JarvisResult<()> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let rows_affected = conn.execute(
+            "UPDATE gems SET last_viewed_at = ?1, view_count = view_count + 1 WHERE id = ?2",
+            params![chrono::Utc::now().to_rfc3339(), id],
+        ).map_err(|e| JarvisError::Db(format!("Failed to record gem view: {}", e)))?;
 
         if rows_affected == 0 {
-            return Err(format!("Gem with id '{}' not found", id));
+            return Err(JarvisError::NotFound(format!("Gem with id '{}' not found", id)));
+        }
+
+        Ok(())
+    }
+
+    async fn list_recently_viewed(&self, limit: usize) -> JarvisResult<Vec<ViewedGem>> {
+        self.list_viewed("last_viewed_at DESC", limit)
+    }
+
+    async fn list_most_viewed(&self, limit: usize) -> JarvisResult<Vec<ViewedGem>> {
+        self.list_viewed("view_count DESC, last_viewed_at DESC", limit)
+    }
+
+    async fn filter_by_label(&self, label: &str, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let mut stmt = conn.prepare(
+            "SELECT id, source_type, source_url, domain, title, author,
+                description, content, source_meta, captured_at, ai_enrichment, transcript, transcript_language, user_notes, label
+            FROM gems
+            WHERE label = ?1
+            ORDER BY captured_at DESC
+            LIMIT ?2 OFFSET ?3"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+
+        let gems = stmt.query_map(params![label, limit, offset], Self::row_to_gem)
+            .map_err(|e| JarvisError::Db(format!("Failed to query gems by label: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect gems: {}", e)))?;
+
+        Ok(gems.iter().map(Self::gem_to_preview).collect())
+    }
+
+    async fn repair_search_index(&self) -> JarvisResult<SearchIndexRepair> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let trigger_count: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master
+            WHERE type = 'trigger' AND name IN ('gems_ai', 'gems_ad', 'gems_au')",
+            [],
+            |row| row.get(0),
+        ).map_err(|e| JarvisError::Db(format!("Failed to check FTS triggers: {}", e)))?;
+        let triggers_restored = trigger_count < 3;
+        if triggers_restored {
+            Self::create_fts_triggers(&conn)?;
+        }
+
+        // fts5vocab exposes which rowids the index actually holds (a plain SELECT
+        // on gems_fts would read through to the gems table instead)
+        conn.execute(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS temp.gems_fts_instances USING fts5vocab(main, gems_fts, instance)",
+            [],
+        ).map_err(|e| JarvisError::Db(format!("Failed to inspect FTS index: {}", e)))?;
+        let counts = conn.query_row(
+            "SELECT
+                (SELECT COUNT(DISTINCT doc) FROM temp.gems_fts_instances
+                    WHERE doc NOT IN (SELECT rowid FROM gems)),
+                (SELECT COUNT(*) FROM gems
+                    WHERE rowid NOT IN (SELECT doc FROM temp.gems_fts_instances))",
+            [],
+            |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+        );
+        conn.execute("DROP TABLE IF EXISTS temp.gems_fts_instances", [])
+            .map_err(|e| JarvisError::Db(format!("Failed to inspect FTS index: {}", e)))?;
+        let (orphaned_entries, missing_entries) =
+            counts.map_err(|e| JarvisError::Db(format!("Failed to inspect FTS index: {}", e)))?;
+
+        let index_rebuilt = orphaned_entries > 0 || missing_entries > 0;
+        if index_rebuilt {
+            Self::repopulate_fts(&conn)?;
         }
 
+        Ok(SearchIndexRepair {
+            triggers_restored,
+            orphaned_entries: orphaned_entries as usize,
+            missing_entries: missing_entries as usize,
+            index_rebuilt,
+        })
+    }
+
+    async fn rebuild_fts_index(&self) -> JarvisResult<usize> {
+        let mut conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let tx = conn.transaction()
+            .map_err(|e| JarvisError::Db(format!("Failed to start transaction: {}", e)))?;
+        Self::create_fts_triggers(&tx)?;
+        Self::repopulate_fts(&tx)?;
+        let count: i64 = tx.query_row("SELECT COUNT(*) FROM gems", [], |row| row.get(0))
+            .map_err(|e| JarvisError::Db(format!("Failed to count gems: {}", e)))?;
+        tx.commit()
+            .map_err(|e| JarvisError::Db(format!("Failed to commit FTS rebuild: {}", e)))?;
+        Ok(count as usize)
+    }
+
+    async fn backup_to(&self, path: &Path) -> JarvisResult<()> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        if path.exists() {
+            return Err(JarvisError::Validation(format!("Backup target already exists: {}", path.display())));
+        }
+        // Holding the lock keeps every writer (gems, projects, search history) out
+        conn.execute("VACUUM INTO ?1", params![path.to_string_lossy()])
+            .map_err(|e| JarvisError::Db(format!("Failed to snapshot database: {}", e)))?;
         Ok(())
     }
+
+    async fn restore_from(&self, path: &Path) -> JarvisResult<()> {
+        let db_path = {
+            let conn = self.conn.lock()
+                .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+            conn.path()
+                .filter(|p| !p.is_empty())
+                .map(std::path::PathBuf::from)
+                .ok_or_else(|| JarvisError::Validation("An in-memory database can't be restored".to_string()))?
+        };
+        let file_name = db_path.file_name().unwrap_or_default().to_string_lossy().to_string();
+        let incoming = db_path.with_file_name(format!("{}.restore", file_name));
+        let previous = db_path.with_file_name(format!("{}.pre-restore", file_name));
+
+        // Copy next to the live database (same filesystem, so the swap below
+        // is a rename) and check the copy before touching the live file
+        let prepared = std::fs::copy(path, &incoming)
+            .map_err(|e| JarvisError::Io(format!("Failed to copy database snapshot: {}", e)))
+            .and_then(|_| Self::validate_snapshot(&incoming));
+        if let Err(e) = prepared {
+            let _ = std::fs::remove_file(&incoming);
+            return Err(e);
+        }
+
+        self.swap_database(&db_path, &incoming, Some(&previous))?;
+
+        // Bring an older snapshot up to the current schema, for every store
+        // sharing the database; put the previous database back if that fails
+        match self.initialize_shared_schema() {
+            Ok(()) => {
+                let _ = std::fs::remove_file(&previous);
+                Ok(())
+            }
+            Err(e) => {
+                eprintln!("Gems: Restored database failed to migrate ({}), rolling back", e);
+                self.swap_database(&db_path, &previous, None)?;
+                Err(e)
+            }
+        }
+    }
+
+    async fn compact(&self) -> JarvisResult<CompactionResult> {
+        // NOTE: The connection lock is held for the whole VACUUM, so every other
+        // gem/project query waits until compaction finishes. On large libraries
+        // this can take several seconds.
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let size_before_bytes = Self::database_size(&conn)?;
+
+        conn.execute("INSERT INTO gems_fts(gems_fts) VALUES('optimize')", [])
+            .map_err(|e| JarvisError::Db(format!("Failed to optimize FTS index: {}", e)))?;
+        conn.execute("VACUUM", [])
+            .map_err(|e| JarvisError::Db(format!("Failed to vacuum database: {}", e)))?;
+
+        let size_after_bytes = Self::database_size(&conn)?;
+
+        Ok(CompactionResult {
+            size_before_bytes,
+            size_after_bytes,
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::gems::test_utils::test_gem;
     use proptest::prelude::*;
 
     #[test]
@@ -537,7 +1354,9 @@ mod tests {
         
         let expected_columns = vec![
             "id", "source_type", "source_url", "domain", "title",
-            "author", "description", "content", "source_meta", "captured_at", "ai_enrichment"
+            "author", "description", "content", "source_meta", "captured_at", "ai_enrichment",
+            "transcript", "transcript_language", "user_notes", "label",
+            "last_viewed_at", "view_count"
         ];
         
         assert_eq!(columns, expected_columns, "gems table should have correct columns");
@@ -608,6 +1427,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         let saved1 = store.save(gem1.clone()).await
@@ -628,6 +1449,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         let saved2 = store.save(gem2.clone()).await
@@ -697,6 +1520,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         let gem2 = Gem {
@@ -713,6 +1538,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         let gem3 = Gem {
@@ -729,6 +1556,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         // Save in random order
@@ -767,6 +1596,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
             };
             store.save(gem).await.expect("Save should succeed");
         }
@@ -785,6 +1616,26 @@ mod tests {
         assert_eq!(results_beyond.len(), 0, "Should return empty list when offset exceeds count");
     }
 
+    #[tokio::test]
+    async fn test_list_in_pages_returns_every_gem() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        for i in 0..5 {
+            store.save(test_gem(&format!("gem-{}", i))).await.unwrap();
+        }
+
+        // Pages of 2 and an exact multiple (the last page comes back empty)
+        for page_size in [2, 5] {
+            let mut ids: Vec<String> = crate::gems::store::list_in_pages(&store, page_size).await
+                .unwrap()
+                .into_iter()
+                .map(|gem| gem.id)
+                .collect();
+            ids.sort();
+            assert_eq!(ids, vec!["gem-0", "gem-1", "gem-2", "gem-3", "gem-4"]);
+        }
+    }
+
     #[tokio::test]
     async fn test_list_truncates_content_to_200_chars() {
         let store = SqliteGemStore::new_in_memory()
@@ -806,6 +1657,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         store.save(gem).await.expect("Save should succeed");
@@ -846,6 +1699,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         store.save(gem).await.expect("Save should succeed");
@@ -882,6 +1737,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         store.save(gem).await.expect("Save should succeed");
@@ -900,6 +1757,32 @@ mod tests {
         assert!(std::str::from_utf8(preview_content.as_bytes()).is_ok(), "Should be valid UTF-8");
     }
 
+    #[tokio::test]
+    async fn test_list_with_preview_uses_custom_length() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let utf8_content = "Hello 世界 🌍 ".repeat(50);
+        let gem = Gem {
+            source_url: "https://example.com/utf8-custom".to_string(),
+            title: "UTF-8 Content Gem".to_string(),
+            content: Some(utf8_content.clone()),
+            ..test_gem(&uuid::Uuid::new_v4().to_string())
+        };
+
+        store.save(gem).await.expect("Save should succeed");
+
+        // Odd length lands in the middle of the multi-byte run
+        let results = store.list_with_preview(10, 0, 457).await.expect("List should succeed");
+        let preview_content = results[0].content_preview.as_ref().unwrap();
+        assert_eq!(preview_content.chars().count(), 460, "Should be 457 chars + '...'");
+        assert!(utf8_content.starts_with(preview_content.trim_end_matches("...")));
+
+        // Length beyond content returns the full content untouched
+        let results = store.list_with_preview(10, 0, 10_000).await.expect("List should succeed");
+        assert_eq!(results[0].content_preview.as_deref(), Some(utf8_content.as_str()));
+    }
+
     // Property 1: Save-Retrieve Round Trip
     // Validates: Requirements 3.1, 3.6
     proptest! {
@@ -933,6 +1816,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
                 };
                 
                 let saved = store.save(gem.clone()).await.unwrap();
@@ -977,6 +1862,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         let gem2 = Gem {
@@ -993,76 +1880,272 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         store.save(gem1.clone()).await.expect("Save gem1");
         store.save(gem2.clone()).await.expect("Save gem2");
         
-        // Search for "Rust"
-        let results = store.search("Rust", 10).await.expect("Search should succeed");
+        // Search for "Rust"
+        let results = store.search("Rust", 10).await.expect("Search should succeed");
+        
+        assert_eq!(results.len(), 1, "Should find one gem");
+        assert_eq!(results[0].title, "Introduction to Rust Programming");
+    }
+
+    #[tokio::test]
+    async fn test_search_finds_gems_by_content() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        
+        let gem = Gem {
+            id: uuid::Uuid::new_v4().to_string(),
+            source_type: "Article".to_string(),
+            source_url: "https://example.com/async".to_string(),
+            domain: "example.com".to_string(),
+            title: "Programming Concepts".to_string(),
+            author: None,
+            description: None,
+            content: Some("Async programming with tokio is powerful".to_string()),
+            source_meta: serde_json::json!({}),
+            captured_at: chrono::Utc::now().to_rfc3339(),
+            ai_enrichment: None,
+            transcript: None,
+            transcript_language: None,
+            user_notes: None,
+            label: None,
+        };
+        
+        store.save(gem.clone()).await.expect("Save gem");
+        
+        // Search for "tokio" which is only in content
+        let results = store.search("tokio", 10).await.expect("Search should succeed");
+        
+        assert_eq!(results.len(), 1, "Should find gem by content");
+        assert_eq!(results[0].title, "Programming Concepts");
+    }
+
+    #[tokio::test]
+    async fn test_search_finds_gems_by_description() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        
+        let gem = Gem {
+            id: uuid::Uuid::new_v4().to_string(),
+            source_type: "Article".to_string(),
+            source_url: "https://example.com/oauth".to_string(),
+            domain: "example.com".to_string(),
+            title: "Security Guide".to_string(),
+            author: None,
+            description: Some("OAuth token authentication explained".to_string()),
+            content: Some("Security is important".to_string()),
+            source_meta: serde_json::json!({}),
+            captured_at: chrono::Utc::now().to_rfc3339(),
+            ai_enrichment: None,
+            transcript: None,
+            transcript_language: None,
+            user_notes: None,
+            label: None,
+        };
+        
+        store.save(gem.clone()).await.expect("Save gem");
+        
+        // Search for "OAuth" which is only in description
+        let results = store.search("OAuth", 10).await.expect("Search should succeed");
+        
+        assert_eq!(results.len(), 1, "Should find gem by description");
+        assert_eq!(results[0].title, "Security Guide");
+    }
+
+    #[tokio::test]
+    async fn test_search_with_field_filters() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        
+        // (source_type, domain, author, tags, content)
+        let cases = [
+            ("YouTube", "youtube.com", Some("Jane Doe"), vec!["rust"], "async runtimes compared"),
+            ("Article", "blog.rust-lang.org", None, vec!["rust"], "async closures"),
+            ("YouTube", "youtube.com", None, vec!["cooking"], "async cooking"),
+        ];
+        for (i, (source_type, domain, author, tags, content)) in cases.iter().enumerate() {
+            let gem = Gem {
+                source_type: source_type.to_string(),
+                source_url: format!("https://{}/{}", domain, i),
+                domain: domain.to_string(),
+                title: format!("Gem {}", i),
+                author: author.map(|a| a.to_string()),
+                content: Some(content.to_string()),
+                captured_at: format!("2024-01-0{}T00:00:00Z", i + 1),
+                ai_enrichment: Some(serde_json::json!({"tags": tags})),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            store.save(gem).await.expect("Save gem");
+        }
+        
+        let titles = |results: Vec<GemPreview>| -> Vec<String> {
+            let mut titles: Vec<String> = results.into_iter().map(|g| g.title).collect();
+            titles.sort();
+            titles
+        };
+        
+        let results = store.search("source_type:youtube async", 10).await.expect("Search should succeed");
+        assert_eq!(titles(results), vec!["Gem 0", "Gem 2"]);
+        
+        let results = store.search("source_type:YouTube tag:rust async", 10).await.expect("Search should succeed");
+        assert_eq!(titles(results), vec!["Gem 0"]);
+        
+        let results = store.search("author:\"jane doe\"", 10).await.expect("Search should succeed");
+        assert_eq!(titles(results), vec!["Gem 0"]);
+        
+        // Filter-only queries are ordered most recent first
+        let results = store.search("domain:youtube.com", 10).await.expect("Search should succeed");
+        let ordered: Vec<String> = results.into_iter().map(|g| g.title).collect();
+        assert_eq!(ordered, vec!["Gem 2", "Gem 0"]);
+    }
+
+    #[tokio::test]
+    async fn test_suggest_ranks_titles_and_tags_by_frequency() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        
+        // (title, tags)
+        let cases = [
+            ("Rust async book", vec!["rust", "async"]),
+            ("Rust async book", vec!["rust"]),
+            ("Learning Rustlings", vec!["rust", "exercises"]),
+            ("Cooking pasta", vec!["food"]),
+        ];
+        for (i, (title, tags)) in cases.iter().enumerate() {
+            let gem = Gem {
+                source_url: format!("https://example.com/suggest-{}", i),
+                title: title.to_string(),
+                ai_enrichment: Some(serde_json::json!({"tags": tags})),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            store.save(gem).await.expect("Save gem");
+        }
+        
+        let suggestions = store.suggest("ru", 10).await.expect("Suggest should succeed");
+        assert_eq!(suggestions, vec!["rust", "Rust async book", "Learning Rustlings"]);
+        
+        let suggestions = store.suggest("ru", 1).await.expect("Suggest should succeed");
+        assert_eq!(suggestions, vec!["rust"]);
+        
+        // Quotes and FTS operators in the prefix don't break the query
+        assert!(store.suggest("\"ru", 10).await.is_ok());
+        assert!(store.suggest("  ", 10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_repair_search_index() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        
+        for i in 0..2 {
+            let gem = Gem {
+                source_url: format!("https://example.com/repair-{}", i),
+                title: format!("Kubernetes operators {}", i),
+                ..test_gem(&format!("gem-{}", i))
+            };
+            store.save(gem).await.expect("Save gem");
+        }
+        
+        // A healthy index is left alone
+        let report = store.repair_search_index().await.expect("Repair should succeed");
+        assert!(!report.triggers_restored && !report.index_rebuilt);
+        
+        // Lose the delete trigger, delete a gem (leaving a stale entry), and
+        // insert a gem without indexing it
+        {
+            let conn = store.conn.lock().unwrap();
+            conn.execute("DROP TRIGGER gems_ad", []).unwrap();
+            conn.execute("DROP TRIGGER gems_ai", []).unwrap();
+            conn.execute("DELETE FROM gems WHERE id = 'gem-0'", []).unwrap();
+            conn.execute(
+                "INSERT INTO gems (id, source_type, source_url, domain, title, source_meta, captured_at)
+                VALUES ('gem-2', 'Article', 'https://example.com/repair-2', 'example.com', 'Kubernetes networking', '{}', '2024-01-01T00:00:00Z')",
+                [],
+            ).unwrap();
+        }
+        
+        let report = store.repair_search_index().await.expect("Repair should succeed");
+        assert!(report.triggers_restored);
+        assert_eq!(report.orphaned_entries, 1);
+        assert_eq!(report.missing_entries, 1);
+        assert!(report.index_rebuilt);
         
-        assert_eq!(results.len(), 1, "Should find one gem");
-        assert_eq!(results[0].title, "Introduction to Rust Programming");
+        let mut ids: Vec<String> = store.search("kubernetes", 10).await.unwrap().into_iter().map(|g| g.id).collect();
+        ids.sort();
+        assert_eq!(ids, vec!["gem-1", "gem-2"]);
+        
+        // Running it again finds nothing to fix
+        let report = store.repair_search_index().await.expect("Repair should succeed");
+        assert!(!report.triggers_restored && !report.index_rebuilt);
     }
 
     #[tokio::test]
-    async fn test_search_finds_gems_by_content() {
+    async fn test_rebuild_fts_index() {
         let store = SqliteGemStore::new_in_memory()
             .expect("Failed to create in-memory store");
-        
+
         let gem = Gem {
-            id: uuid::Uuid::new_v4().to_string(),
-            source_type: "Article".to_string(),
-            source_url: "https://example.com/async".to_string(),
-            domain: "example.com".to_string(),
-            title: "Programming Concepts".to_string(),
-            author: None,
-            description: None,
-            content: Some("Async programming with tokio is powerful".to_string()),
-            source_meta: serde_json::json!({}),
-            captured_at: chrono::Utc::now().to_rfc3339(),
-            ai_enrichment: None,
-            transcript: None,
-            transcript_language: None,
+            source_url: "https://example.com/rebuild".to_string(),
+            title: "Borrow checker".to_string(),
+            content: Some("Lifetimes explained".to_string()),
+            ai_enrichment: Some(serde_json::json!({ "tags": ["ownership"], "summary": "How rustc checks references" })),
+            ..test_gem("gem-0")
         };
-        
-        store.save(gem.clone()).await.expect("Save gem");
-        
-        // Search for "tokio" which is only in content
-        let results = store.search("tokio", 10).await.expect("Search should succeed");
-        
-        assert_eq!(results.len(), 1, "Should find gem by content");
-        assert_eq!(results[0].title, "Programming Concepts");
+        store.save(gem).await.expect("Save gem");
+
+        // Wipe the index, as manual DB edits might
+        store.conn.lock().unwrap()
+            .execute("INSERT INTO gems_fts(gems_fts) VALUES('delete-all')", [])
+            .unwrap();
+        assert!(store.search("borrow", 10).await.unwrap().is_empty());
+
+        assert_eq!(store.rebuild_fts_index().await.expect("Rebuild should succeed"), 1);
+        // Title, and the summary and tags folded into content, are searchable again
+        for query in ["borrow", "rustc", "ownership"] {
+            assert_eq!(store.search(query, 10).await.unwrap().len(), 1, "query {}", query);
+        }
     }
 
     #[tokio::test]
-    async fn test_search_finds_gems_by_description() {
+    async fn test_search_finds_gems_by_tag() {
         let store = SqliteGemStore::new_in_memory()
             .expect("Failed to create in-memory store");
         
         let gem = Gem {
-            id: uuid::Uuid::new_v4().to_string(),
-            source_type: "Article".to_string(),
-            source_url: "https://example.com/oauth".to_string(),
-            domain: "example.com".to_string(),
-            title: "Security Guide".to_string(),
-            author: None,
-            description: Some("OAuth token authentication explained".to_string()),
-            content: Some("Security is important".to_string()),
-            source_meta: serde_json::json!({}),
-            captured_at: chrono::Utc::now().to_rfc3339(),
-            ai_enrichment: None,
-            transcript: None,
-            transcript_language: None,
+            source_url: "https://example.com/ownership".to_string(),
+            title: "Ownership and borrowing".to_string(),
+            content: Some("Each value has a single owner".to_string()),
+            ai_enrichment: Some(serde_json::json!({"tags": ["rust", "memory-safety"], "summary": "How ownership works"})),
+            ..test_gem(&uuid::Uuid::new_v4().to_string())
         };
         
-        store.save(gem.clone()).await.expect("Save gem");
-        
-        // Search for "OAuth" which is only in description
-        let results = store.search("OAuth", 10).await.expect("Search should succeed");
+        store.save(gem).await.expect("Save gem");
         
-        assert_eq!(results.len(), 1, "Should find gem by description");
-        assert_eq!(results[0].title, "Security Guide");
+        // "rust" only appears in the tags
+        let results = store.search("rust", 10).await.expect("Search should succeed");
+        assert_eq!(results.len(), 1, "Should find gem by tag");
+        assert_eq!(results[0].title, "Ownership and borrowing");
+
+        // An index built before tags were searchable is repopulated on startup
+        {
+            let conn = store.conn.lock().unwrap();
+            conn.execute("INSERT INTO gems_fts(gems_fts) VALUES('delete-all')", []).unwrap();
+            conn.execute("DROP TRIGGER gems_ai", []).unwrap();
+            conn.execute("CREATE TRIGGER gems_ai AFTER INSERT ON gems BEGIN SELECT 1; END", []).unwrap();
+        }
+        assert!(store.search("rust", 10).await.unwrap().is_empty());
+
+        store.initialize_schema().expect("Re-initialize schema");
+        let results = store.search("rust", 10).await.expect("Search should succeed");
+        assert_eq!(results.len(), 1, "Rebuilt index should include tags");
+        let results = store.search("ownership", 10).await.expect("Search should succeed");
+        assert_eq!(results.len(), 1, "Rebuilt index should include summary and content");
     }
 
     #[tokio::test]
@@ -1086,6 +2169,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
             };
             store.save(gem).await.expect("Save should succeed");
         }
@@ -1117,6 +2202,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         store.save(gem).await.expect("Save should succeed");
         
@@ -1147,6 +2234,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
             };
             store.save(gem).await.expect("Save should succeed");
         }
@@ -1176,6 +2265,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         store.save(gem).await.expect("Save should succeed");
         
@@ -1194,7 +2285,9 @@ mod tests {
         let result = store.search("\"unmatched", 10).await;
         
         assert!(result.is_err(), "Should return error for unmatched quotes");
-        let error_msg = result.unwrap_err();
+        let error = result.unwrap_err();
+        assert!(matches!(error, JarvisError::Validation(_)), "Syntax errors are validation errors: {:?}", error);
+        let error_msg = error.to_string();
         println!("Actual error message: {}", error_msg);
         assert!(error_msg.contains("Invalid search query syntax"), "Error should be user-friendly: {}", error_msg);
     }
@@ -1220,6 +2313,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         store.save(gem).await.expect("Save should succeed");
@@ -1257,6 +2352,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         let saved = store.save(gem).await.expect("Save should succeed");
@@ -1266,8 +2363,8 @@ mod tests {
         assert!(retrieved.is_some(), "Gem should exist before delete");
         
         // Delete the gem
-        let delete_result = store.delete(&saved.id).await;
-        assert!(delete_result.is_ok(), "Delete should succeed");
+        let deleted = store.delete(&saved.id).await.expect("Delete should succeed");
+        assert!(deleted, "Delete should report the gem as deleted");
         
         // Verify gem no longer exists
         let after_delete = store.get(&saved.id).await.expect("Get should succeed");
@@ -1275,19 +2372,63 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_delete_non_existent_gem_returns_error() {
+    async fn test_delete_non_existent_gem_returns_false() {
         let store = SqliteGemStore::new_in_memory()
             .expect("Failed to create in-memory store");
         
         let non_existent_id = uuid::Uuid::new_v4().to_string();
         
-        // Try to delete non-existent gem
-        let result = store.delete(&non_existent_id).await;
-        
-        assert!(result.is_err(), "Delete should return error for non-existent gem");
-        let error_msg = result.unwrap_err();
-        assert!(error_msg.contains("not found"), "Error should mention gem not found");
-        assert!(error_msg.contains(&non_existent_id), "Error should include the gem ID");
+        // Deleting a missing gem is not an error
+        let deleted = store.delete(&non_existent_id).await
+            .expect("Delete of a missing gem should not error");
+        assert!(!deleted, "Delete should report nothing was deleted");
+    }
+
+    #[tokio::test]
+    async fn test_find_by_recording_filenames() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        let recording_gem = |id: &str, filename: &str, captured_at: &str| Gem {
+            source_meta: serde_json::json!({ "recording_filename": filename }),
+            captured_at: captured_at.to_string(),
+            ..test_gem(id)
+        };
+        for gem in [
+            recording_gem("old", "a.pcm", "2024-01-01T00:00:00Z"),
+            recording_gem("new", "a.pcm", "2024-02-01T00:00:00Z"),
+            recording_gem("b", "b.pcm", "2024-01-01T00:00:00Z"),
+            recording_gem("other", "c.pcm", "2024-01-01T00:00:00Z"),
+        ] {
+            store.save(gem).await.unwrap();
+        }
+
+        let filenames = vec!["a.pcm".to_string(), "b.pcm".to_string(), "missing.pcm".to_string()];
+        let found = store.find_by_recording_filenames(&filenames).await.unwrap();
+        let mut ids: Vec<(&str, &str)> = found.iter().map(|(f, gem)| (f.as_str(), gem.id.as_str())).collect();
+        ids.sort();
+        assert_eq!(ids, vec![("a.pcm", "new"), ("b.pcm", "b")]);
+
+        assert!(store.find_by_recording_filenames(&[]).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_merge_into_saves_and_deletes_together() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        for id in ["primary", "secondary", "other"] {
+            store.save(test_gem(id)).await.unwrap();
+        }
+
+        let merged = Gem { title: "Merged".to_string(), ..test_gem("primary") };
+        let saved = store.merge_into(merged, "secondary").await.expect("Merge should succeed");
+        assert_eq!(saved.title, "Merged");
+        assert!(store.get("secondary").await.unwrap().is_none());
+
+        // A failed save (id taken by another gem) must not delete the absorbed gem
+        store.save(test_gem("secondary")).await.unwrap();
+        let clashing = Gem { source_url: "https://example.com/elsewhere".to_string(), ..test_gem("other") };
+        assert!(store.merge_into(clashing, "secondary").await.is_err());
+        assert!(store.get("secondary").await.unwrap().is_some());
     }
 
     #[tokio::test]
@@ -1310,6 +2451,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         let saved = store.save(gem).await.expect("Save should succeed");
@@ -1326,6 +2469,469 @@ mod tests {
         assert_eq!(search_after.len(), 0, "Gem should not be searchable after delete");
     }
 
+    #[tokio::test]
+    async fn test_filter_by_language_and_list_languages() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let languages = [Some("en"), Some("zh"), Some("en"), None, Some("")];
+        for (i, language) in languages.iter().enumerate() {
+            let gem = Gem {
+                source_type: "Other".to_string(),
+                source_url: format!("jarvis://recording/lang-{}", i),
+                domain: "jarvis-app".to_string(),
+                title: format!("Recording {}", i),
+                captured_at: format!("2024-01-0{}T00:00:00Z", i + 1),
+                transcript: Some("transcript".to_string()),
+                transcript_language: language.map(|l| l.to_string()),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            store.save(gem).await.expect("Save should succeed");
+        }
+
+        let english = store.filter_by_language("en", 10, 0).await.expect("Filter should succeed");
+        assert_eq!(english.len(), 2);
+        assert_eq!(english[0].title, "Recording 2", "Most recent first");
+        assert!(english.iter().all(|g| g.transcript_language.as_deref() == Some("en")));
+
+        let paged = store.filter_by_language("en", 1, 1).await.expect("Filter should succeed");
+        assert_eq!(paged.len(), 1);
+        assert_eq!(paged[0].title, "Recording 0");
+
+        let counts = store.list_languages().await.expect("List languages should succeed");
+        let counts: Vec<(String, usize)> = counts.into_iter().map(|c| (c.language, c.count)).collect();
+        assert_eq!(counts, vec![("en".to_string(), 2), ("zh".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_resave_updates_source_type() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+        let saved = store.save(test_gem("reclassified")).await.expect("Save should succeed");
+        assert_eq!(saved.source_type, "Article");
+
+        let gem = Gem { source_type: "Paper".to_string(), ..saved };
+        let resaved = store.save(gem).await.expect("Resave should succeed");
+        assert_eq!(resaved.source_type, "Paper");
+
+        let stored = store.get("reclassified").await.expect("Get should succeed").expect("Gem exists");
+        assert_eq!(stored.source_type, "Paper");
+    }
+
+    #[tokio::test]
+    async fn test_search_transcripts_ignores_other_columns() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        // (title, transcript)
+        let cases = [
+            ("Roadmap sync", Some("We agreed the launch slips to March because of the pricing review.")),
+            ("Launch retrospective", Some("Nothing notable was said here.")),
+            ("Pricing notes", None),
+        ];
+        for (i, (title, transcript)) in cases.iter().enumerate() {
+            let gem = Gem {
+                source_type: "Other".to_string(),
+                source_url: format!("jarvis://recording/quote-{}", i),
+                domain: "jarvis-app".to_string(),
+                title: title.to_string(),
+                description: Some("launch pricing".to_string()),
+                captured_at: format!("2024-01-0{}T00:00:00Z", i + 1),
+                transcript: transcript.map(|t| t.to_string()),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            store.save(gem).await.expect("Save should succeed");
+        }
+
+        let results = store.search_transcripts("launch", 10).await.expect("Search should succeed");
+        assert_eq!(results.len(), 1, "Title/description matches must not count");
+        assert_eq!(results[0].gem.title, "Roadmap sync");
+        assert!(results[0].transcript_snippet.contains("**launch**"));
+
+        let results = store.search_transcripts("\"pricing review\"", 10).await.expect("Phrase search should succeed");
+        assert_eq!(results.len(), 1);
+
+        // Breaking out of the transcript filter would match "Launch retrospective" by title
+        let err = store.search_transcripts("nothing) OR (title : retrospective", 10).await.unwrap_err();
+        assert!(matches!(err, JarvisError::Validation(_)));
+        let results = store.search_transcripts("\"title : retrospective\"", 10).await.expect("Quoted colons are literal");
+        assert!(results.is_empty(), "Title-only matches must not count");
+
+        let results = store.search_transcripts("  ", 10).await.expect("Empty search should succeed");
+        assert!(results.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_user_notes_are_searchable_and_survive_resave() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let gem = Gem {
+            source_url: "https://example.com/notes".to_string(),
+            title: "Some article".to_string(),
+            content: Some("Body text".to_string()),
+            captured_at: "2024-01-01T00:00:00Z".to_string(),
+            ..test_gem(&uuid::Uuid::new_v4().to_string())
+        };
+        let saved = store.save(gem.clone()).await.expect("Save should succeed");
+
+        store.update_notes(&saved.id, Some("follow up with marmalade team")).await
+            .expect("Update notes should succeed");
+        let results = store.search("marmalade", 10).await.expect("Search should succeed");
+        assert_eq!(results.len(), 1);
+
+        // Re-capturing the same URL (no notes on the incoming gem) keeps the notes
+        let resaved = store.save(gem).await.expect("Re-save should succeed");
+        assert_eq!(resaved.user_notes.as_deref(), Some("follow up with marmalade team"));
+
+        store.update_notes(&saved.id, None).await.expect("Clearing notes should succeed");
+        let results = store.search("marmalade", 10).await.expect("Search should succeed");
+        assert!(results.is_empty());
+
+        let err = store.update_notes("missing", Some("x")).await.unwrap_err();
+        assert!(matches!(err, JarvisError::NotFound(_)));
+    }
+
+    #[test]
+    fn test_replace_tag() {
+        let tags: Vec<String> = ["ML", "rust", "machine-learning"].iter().map(|t| t.to_string()).collect();
+        assert_eq!(replace_tag(&tags, "ml", Some("machine-learning")), vec!["machine-learning", "rust"]);
+        assert_eq!(replace_tag(&tags, "Rust", None), vec!["ML", "machine-learning"]);
+        assert_eq!(replace_tag(&tags, "go", Some("golang")), tags);
+    }
+
+    #[tokio::test]
+    async fn test_rename_and_delete_tag() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let mut ids = Vec::new();
+        for (i, tags) in [vec!["ML", "rust"], vec!["python"], vec!["ml", "ai"]].into_iter().enumerate() {
+            let gem = Gem {
+                source_url: format!("https://example.com/tags-{}", i),
+                title: format!("Article {}", i),
+                captured_at: format!("2024-01-0{}T00:00:00Z", i + 1),
+                ai_enrichment: Some(serde_json::json!({ "tags": tags, "summary": "s" })),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            ids.push(store.save(gem).await.expect("Save should succeed").id);
+        }
+
+        let mut changed = store.rename_tag("ml", Some("ai")).await.expect("Rename should succeed");
+        changed.sort();
+        let mut expected = vec![ids[0].clone(), ids[2].clone()];
+        expected.sort();
+        assert_eq!(changed, expected);
+
+        let gem = store.get(&ids[2]).await.unwrap().unwrap();
+        assert_eq!(gem.ai_enrichment.as_ref().unwrap()["tags"], serde_json::json!(["ai"]));
+        assert_eq!(gem.ai_enrichment.as_ref().unwrap()["summary"], "s");
+        assert_eq!(store.filter_by_tag("ai", 10, 0).await.unwrap().len(), 2);
+
+        assert_eq!(store.rename_tag("rust", None).await.unwrap(), vec![ids[0].clone()]);
+        assert!(store.filter_by_tag("rust", 10, 0).await.unwrap().is_empty());
+        assert!(store.rename_tag("missing", None).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_tag_cooccurrence() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let tag_sets = [vec!["Rust", "async", "tokio"], vec!["rust", "Async"], vec!["rust"], vec![]];
+        for (i, tags) in tag_sets.into_iter().enumerate() {
+            let gem = Gem {
+                source_url: format!("https://example.com/pairs-{}", i),
+                title: format!("Article {}", i),
+                captured_at: format!("2024-01-0{}T00:00:00Z", i + 1),
+                ai_enrichment: Some(serde_json::json!({ "tags": tags })),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            store.save(gem).await.expect("Save should succeed");
+        }
+
+        let pair = |a: &str, b: &str, count| TagCooccurrence { tag_a: a.into(), tag_b: b.into(), count };
+        assert_eq!(
+            store.tag_cooccurrence(0).await.unwrap(),
+            vec![pair("async", "rust", 2), pair("async", "tokio", 1), pair("rust", "tokio", 1)]
+        );
+        assert_eq!(store.tag_cooccurrence(2).await.unwrap(), vec![pair("async", "rust", 2)]);
+    }
+
+    #[tokio::test]
+    async fn test_backup_and_restore() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = SqliteGemStore::open(&dir.path().join("gems.db")).unwrap();
+
+        let gem = Gem {
+            source_url: "https://example.com/backed-up".to_string(),
+            title: "Backed up".to_string(),
+            content: Some("kept".to_string()),
+            captured_at: "2024-01-01T00:00:00Z".to_string(),
+            ..test_gem(&uuid::Uuid::new_v4().to_string())
+        };
+        let saved = store.save(gem.clone()).await.unwrap();
+
+        let snapshot = dir.path().join("snapshot.db");
+        store.backup_to(&snapshot).await.unwrap();
+        assert!(store.backup_to(&snapshot).await.is_err(), "existing target is not overwritten");
+
+        store.delete(&saved.id).await.unwrap();
+        let later = Gem { id: uuid::Uuid::new_v4().to_string(), source_url: "https://example.com/later".to_string(), ..gem };
+        store.save(later).await.unwrap();
+
+        store.restore_from(&snapshot).await.unwrap();
+        let gems = store.list(10, 0).await.unwrap();
+        assert_eq!(gems.len(), 1);
+        assert_eq!(gems[0].id, saved.id);
+        assert_eq!(store.search("kept", 10).await.unwrap().len(), 1, "FTS index comes back too");
+
+        let exists = |table: &str| -> bool {
+            store.conn.lock().unwrap().query_row(
+                "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = ?1)",
+                params![table],
+                |row| row.get(0),
+            ).unwrap()
+        };
+        assert!(exists("projects") && exists("search_history"), "other stores' tables are recreated");
+
+        let not_a_db = dir.path().join("not-a-db");
+        std::fs::write(&not_a_db, "hello").unwrap();
+        assert!(store.restore_from(&not_a_db).await.is_err());
+        assert_eq!(store.list(10, 0).await.unwrap().len(), 1);
+
+        // Nothing is left behind next to the live database
+        let mut files: Vec<String> = std::fs::read_dir(dir.path()).unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().to_string())
+            .filter(|name| name.starts_with("gems.db"))
+            .collect();
+        files.sort();
+        assert_eq!(files, vec!["gems.db"]);
+    }
+
+    #[tokio::test]
+    async fn test_update_label_and_filter_by_label() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let gem = Gem {
+                source_url: format!("https://example.com/label-{}", i),
+                title: format!("Article {}", i),
+                captured_at: format!("2024-01-0{}T00:00:00Z", i + 1),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            ids.push(store.save(gem).await.expect("Save should succeed").id);
+        }
+
+        store.update_label(&ids[0], Some("red")).await.expect("Update label should succeed");
+        store.update_label(&ids[2], Some("red")).await.expect("Update label should succeed");
+        store.update_label(&ids[1], Some("blue")).await.expect("Update label should succeed");
+
+        let red = store.filter_by_label("red", 10, 0).await.expect("Filter should succeed");
+        let titles: Vec<&str> = red.iter().map(|g| g.title.as_str()).collect();
+        assert_eq!(titles, vec!["Article 2", "Article 0"]);
+        assert_eq!(red[0].label.as_deref(), Some("red"));
+
+        // Relabeling replaces the previous label
+        store.update_label(&ids[0], Some("blue")).await.expect("Update label should succeed");
+        store.update_label(&ids[2], None).await.expect("Clearing label should succeed");
+        assert!(store.filter_by_label("red", 10, 0).await.unwrap().is_empty());
+        assert_eq!(store.filter_by_label("blue", 10, 0).await.unwrap().len(), 2);
+
+        let err = store.update_label("missing", Some("red")).await.unwrap_err();
+        assert!(matches!(err, JarvisError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_view_tracking() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let mut ids = Vec::new();
+        for i in 0..3 {
+            let gem = Gem {
+                source_url: format!("https://example.com/viewed-{}", i),
+                title: format!("Article {}", i),
+                content: Some("é".repeat(DEFAULT_PREVIEW_CHARS + 50)),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            ids.push(store.save(gem).await.expect("Save should succeed").id);
+        }
+
+        // Article 0 opened twice, then Article 1 once; Article 2 never
+        for id in [&ids[0], &ids[0], &ids[1]] {
+            store.mark_viewed(id).await.expect("Mark viewed should succeed");
+            std::thread::sleep(std::time::Duration::from_millis(2));
+        }
+
+        let recent = store.list_recently_viewed(10).await.unwrap();
+        let titles: Vec<&str> = recent.iter().map(|v| v.gem.title.as_str()).collect();
+        assert_eq!(titles, vec!["Article 1", "Article 0"]);
+
+        let most = store.list_most_viewed(1).await.unwrap();
+        assert_eq!(most[0].gem.title, "Article 0");
+        assert_eq!(most[0].view_count, 2);
+        let preview = most[0].gem.content_preview.as_deref().unwrap();
+        assert_eq!(preview, format!("{}...", "é".repeat(DEFAULT_PREVIEW_CHARS)));
+
+        // A view only writes the gems row, not the FTS index
+        let changes_before = store.conn.lock().unwrap().total_changes();
+        store.mark_viewed(&ids[2]).await.unwrap();
+        assert_eq!(store.conn.lock().unwrap().total_changes() - changes_before, 1);
+
+        // Re-saving a gem (e.g. re-enrichment) keeps its view stats
+        let gem = store.get(&ids[0]).await.unwrap().unwrap();
+        store.save(gem).await.unwrap();
+        assert_eq!(store.list_most_viewed(1).await.unwrap()[0].view_count, 2);
+
+        let err = store.mark_viewed("missing").await.unwrap_err();
+        assert!(matches!(err, JarvisError::NotFound(_)));
+    }
+
+    #[tokio::test]
+    async fn test_filter_by_content_language() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        // (transcript_language, source_meta.content_language)
+        let cases = [(None, Some("fr")), (Some("en"), Some("fr")), (None, None)];
+        for (i, (transcript_language, content_language)) in cases.iter().enumerate() {
+            let gem = Gem {
+                source_url: format!("https://example.com/{}", i),
+                title: format!("Article {}", i),
+                content: Some("content".to_string()),
+                source_meta: match content_language {
+                    Some(lang) => serde_json::json!({ "content_language": lang }),
+                    None => serde_json::json!({}),
+                },
+                captured_at: format!("2024-01-0{}T00:00:00Z", i + 1),
+                transcript: transcript_language.map(|_| "transcript".to_string()),
+                transcript_language: transcript_language.map(|l| l.to_string()),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            store.save(gem).await.expect("Save should succeed");
+        }
+
+        // Transcript language wins over content language
+        let french = store.filter_by_language("fr", 10, 0).await.expect("Filter should succeed");
+        assert_eq!(french.iter().map(|g| g.title.as_str()).collect::<Vec<_>>(), vec!["Article 0"]);
+
+        let counts = store.list_languages().await.expect("List languages should succeed");
+        let counts: Vec<(String, usize)> = counts.into_iter().map(|c| (c.language, c.count)).collect();
+        assert_eq!(counts, vec![("en".to_string(), 1), ("fr".to_string(), 1)]);
+    }
+
+    #[tokio::test]
+    async fn test_find_missing_transcript_language() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        // (transcript, transcript_language)
+        let cases = [
+            (Some("hello there"), None),
+            (Some("hello there"), Some("")),
+            (Some("hello there"), Some("en")),
+            (None, None),
+            (Some("   "), None),
+        ];
+        let mut ids = Vec::new();
+        for (i, (transcript, language)) in cases.iter().enumerate() {
+            let gem = Gem {
+                source_type: "Other".to_string(),
+                source_url: format!("jarvis://recording/missing-{}", i),
+                domain: "jarvis-app".to_string(),
+                title: format!("Recording {}", i),
+                transcript: transcript.map(|t| t.to_string()),
+                transcript_language: language.map(|l| l.to_string()),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            ids.push(store.save(gem).await.expect("Save should succeed").id);
+        }
+
+        let mut missing = store.find_missing_transcript_language().await
+            .expect("Query should succeed");
+        missing.sort();
+        let mut expected = vec![ids[0].clone(), ids[1].clone()];
+        expected.sort();
+        assert_eq!(missing, expected);
+    }
+
+    #[tokio::test]
+    async fn test_compact_reclaims_space_after_deletes() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        // Save enough large gems to allocate many pages
+        let mut ids = Vec::new();
+        for i in 0..20 {
+            let gem = Gem {
+                source_url: format!("https://example.com/compact/{}", i),
+                title: format!("Compaction test {}", i),
+                content: Some(format!("compactable words {} ", i).repeat(500)),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            ids.push(store.save(gem).await.expect("Save should succeed").id);
+        }
+
+        for id in &ids[1..] {
+            store.delete(id).await.expect("Delete should succeed");
+        }
+
+        let result = store.compact().await.expect("Compact should succeed");
+        assert!(
+            result.size_after_bytes < result.size_before_bytes,
+            "Compaction should shrink the database ({} -> {})",
+            result.size_before_bytes,
+            result.size_after_bytes
+        );
+
+        // Remaining gem is still searchable after the FTS optimize
+        let results = store.search("compactable", 10).await.expect("Search should succeed");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, ids[0]);
+    }
+
+    #[tokio::test]
+    async fn test_list_action_items_only_open_items_of_recordings() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let now = chrono::Utc::now();
+        let cases = [
+            ("older", serde_json::json!({ "recording_filename": "a.pcm", "action_items": ["Send notes"] }), 2),
+            ("newer", serde_json::json!({
+                "recording_filename": "b.pcm",
+                "action_items": ["Book room", "Email Bob", "Order lunch"],
+                "done_action_items": ["Email Bob"]
+            }), 1),
+            ("all done", serde_json::json!({
+                "recording_filename": "d.pcm",
+                "action_items": ["Ship it"],
+                "done_action_items": ["Ship it"]
+            }), 3),
+            ("empty", serde_json::json!({ "recording_filename": "c.pcm", "action_items": [] }), 0),
+            ("article", serde_json::json!({ "action_items": ["Not a recording"] }), 0),
+        ];
+        for (title, source_meta, hours_ago) in cases {
+            let gem = Gem {
+                source_url: format!("jarvis://recording/{}", title),
+                title: title.to_string(),
+                source_meta,
+                captured_at: (now - chrono::Duration::hours(hours_ago)).to_rfc3339(),
+                ..test_gem(&uuid::Uuid::new_v4().to_string())
+            };
+            store.save(gem).await.expect("Save should succeed");
+        }
+
+        let items = store.list_action_items().await.expect("Query should succeed");
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[0].title, "newer");
+        assert_eq!(items[0].action_items, vec!["Book room", "Order lunch"]);
+        assert_eq!(items[1].title, "older");
+    }
+
     // Phase 1 Tests: find_by_recording_filename
 
     #[tokio::test]
@@ -1352,6 +2958,8 @@ mod tests {
             ai_enrichment: None,
             transcript: Some("MLX Omni transcript".to_string()),
             transcript_language: Some("en".to_string()),
+            user_notes: None,
+            label: None,
         };
         
         store.save(gem.clone()).await.expect("Save should succeed");
@@ -1367,6 +2975,23 @@ mod tests {
         assert_eq!(preview.transcript_language, Some("en".to_string()));
     }
 
+    #[tokio::test]
+    async fn test_find_by_source_url() {
+        let store = SqliteGemStore::new_in_memory()
+            .expect("Failed to create in-memory store");
+
+        let gem = Gem {
+            source_url: "https://example.com/post".to_string(),
+            title: "Post".to_string(),
+            ..test_gem(&uuid::Uuid::new_v4().to_string())
+        };
+        store.save(gem.clone()).await.expect("Save should succeed");
+
+        let found = store.find_by_source_url("https://example.com/post").await.unwrap();
+        assert_eq!(found.map(|p| p.id), Some(gem.id));
+        assert!(store.find_by_source_url("https://example.com/other").await.unwrap().is_none());
+    }
+
     #[tokio::test]
     async fn test_find_by_recording_filename_with_no_gem() {
         let store = SqliteGemStore::new_in_memory()
@@ -1404,6 +3029,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         // Create second gem (newer)
@@ -1423,6 +3050,8 @@ mod tests {
             ai_enrichment: None,
             transcript: None,
             transcript_language: None,
+            user_notes: None,
+            label: None,
         };
         
         store.save(gem1).await.expect("Save gem1 should succeed");
@@ -1470,6 +3099,8 @@ mod tests {
                         ai_enrichment: None,
                         transcript: None,
                         transcript_language: None,
+                        user_notes: None,
+                        label: None,
                     };
                     expected_gem_id = Some(gem.id.clone());
                     store.save(gem).await.expect("Save should succeed");
@@ -1493,6 +3124,8 @@ mod tests {
                         ai_enrichment: None,
                         transcript: None,
                         transcript_language: None,
+                        user_notes: None,
+                        label: None,
                     };
                     store.save(other_gem).await.expect("Save should succeed");
                 }
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+use std::path::Path;
+
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
+use crate::error::JarvisResult;
+
 /// Backend-agnostic gem representation
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Gem {
@@ -48,6 +53,16 @@ pub struct Gem {
     /// ISO 639-1 language code (e.g., "en", "zh", "es")
     /// NULL when no transcript has been generated
     pub transcript_language: Option<String>,
+
+    /// Free-form notes written by the user (editable, searchable)
+    /// Kept across re-enrichment and re-capture; NULL when the user hasn't added any
+    #[serde(default)]
+    pub user_notes: Option<String>,
+
+    /// Color label for at-a-glance triage (one of `GEM_LABELS`)
+    /// Unlike tags, a gem has at most one; NULL when unlabeled
+    #[serde(default)]
+    pub label: Option<String>,
 }
 
 /// Lightweight gem for list/search results
@@ -61,7 +76,7 @@ pub struct GemPreview {
     pub author: Option<String>,
     pub description: Option<String>,
     
-    /// Content truncated to 200 characters
+    /// Content truncated to `preview_chars` characters (default 200)
     pub content_preview: Option<String>,
     
     pub captured_at: String,
@@ -79,28 +94,167 @@ pub struct GemPreview {
     /// ISO 639-1 language code (e.g., "en", "zh", "es")
     /// Note: transcript text itself is NOT included in preview (too large for list views)
     pub transcript_language: Option<String>,
+
+    /// Color label (one of `GEM_LABELS`), NULL when unlabeled
+    pub label: Option<String>,
+}
+
+/// Default number of content characters included in a GemPreview
+pub const DEFAULT_PREVIEW_CHARS: usize = 200;
+
+/// Fixed palette of gem color labels
+pub const GEM_LABELS: &[&str] = &["red", "orange", "yellow", "green", "blue", "purple", "gray"];
+
+/// Number of gems whose transcript is in a given language
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LanguageCount {
+    /// ISO 639-1 language code (e.g., "en", "zh", "es")
+    pub language: String,
+
+    /// Number of gems with this transcript_language
+    pub count: usize,
+}
+
+/// Two tags that appear together on gems
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagCooccurrence {
+    /// Lowercased tag; sorts before `tag_b`
+    pub tag_a: String,
+
+    /// Lowercased tag
+    pub tag_b: String,
+
+    /// Number of gems carrying both tags
+    pub count: usize,
+}
+
+/// A gem whose transcript matched a transcript-only search
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TranscriptMatch {
+    /// The matching gem
+    #[serde(flatten)]
+    pub gem: GemPreview,
+
+    /// Excerpt of the transcript around the match, with matched terms wrapped in `**`
+    pub transcript_snippet: String,
+}
+
+/// A gem with its view statistics, for "recently viewed" and "most viewed" lists
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ViewedGem {
+    /// The viewed gem
+    #[serde(flatten)]
+    pub gem: GemPreview,
+
+    /// RFC 3339 timestamp of the last `mark_gem_viewed`
+    pub last_viewed_at: String,
+
+    /// Number of times the gem was opened
+    pub view_count: u64,
+}
+
+/// Open action items stored on a recording gem (`source_meta.action_items`
+/// not yet marked done)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GemActionItems {
+    pub gem_id: String,
+    pub title: String,
+    /// The gem's `jarvis://recording/...` URL
+    pub source_url: String,
+    pub captured_at: String,
+    pub action_items: Vec<String>,
+}
+
+/// Result of a database compaction (sizes in bytes)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactionResult {
+    /// Database size before compaction
+    pub size_before_bytes: u64,
+
+    /// Database size after compaction
+    pub size_after_bytes: u64,
+}
+
+/// Result of checking and repairing the full-text search index
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SearchIndexRepair {
+    /// Sync triggers were missing and had to be recreated
+    pub triggers_restored: bool,
+
+    /// Index entries whose gem no longer exists
+    pub orphaned_entries: usize,
+
+    /// Gems that were missing from the index
+    pub missing_entries: usize,
+
+    /// The index was cleared and repopulated from the gems table
+    pub index_rebuilt: bool,
 }
 
 /// Storage interface for gems - implementations are swappable
 #[async_trait]
 pub trait GemStore: Send + Sync {
     /// Save or update a gem (upsert by source_url)
-    async fn save(&self, gem: Gem) -> Result<Gem, String>;
+    async fn save(&self, gem: Gem) -> JarvisResult<Gem>;
     
     /// Get a gem by ID
-    async fn get(&self, id: &str) -> Result<Option<Gem>, String>;
+    async fn get(&self, id: &str) -> JarvisResult<Option<Gem>>;
     
     /// List gems with pagination (ordered by captured_at DESC)
-    async fn list(&self, limit: usize, offset: usize) -> Result<Vec<GemPreview>, String>;
+    async fn list(&self, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>>;
+
+    /// List gems with pagination, truncating content previews to `preview_chars` characters
+    async fn list_with_preview(&self, limit: usize, offset: usize, preview_chars: usize) -> JarvisResult<Vec<GemPreview>>;
+    
+    /// Search gems by keyword (FTS on title, description, content, AI summary and tags, transcript, user notes)
+    ///
+    /// Supports `field:value` filters (`source_type`, `domain`, `author`, `tag`);
+    /// see `search_query` for the syntax.
+    async fn search(&self, query: &str, limit: usize) -> JarvisResult<Vec<GemPreview>>;
+    
+    /// Type-ahead completions for `prefix`: distinct gem titles (FTS prefix match
+    /// on the title) and enrichment tags starting with it, most frequent first
+    async fn suggest(&self, prefix: &str, limit: usize) -> JarvisResult<Vec<String>>;
     
-    /// Search gems by keyword (FTS on title, description, content)
-    async fn search(&self, query: &str, limit: usize) -> Result<Vec<GemPreview>, String>;
+    /// Search only transcripts by keyword (FTS restricted to the transcript column)
+    ///
+    /// Rejects parentheses and column filters outside quoted phrases.
+    async fn search_transcripts(&self, query: &str, limit: usize) -> JarvisResult<Vec<TranscriptMatch>>;
     
+    /// Replace tag `old` (case-insensitive) with `new` in every gem's
+    /// ai_enrichment.tags, de-duplicating; `None` removes the tag. Runs in a
+    /// single transaction. Returns the IDs of the gems that changed.
+    async fn rename_tag(&self, old: &str, new: Option<&str>) -> JarvisResult<Vec<String>>;
+
     /// Filter gems by tag (exact match on ai_enrichment.tags array)
-    async fn filter_by_tag(&self, tag: &str, limit: usize, offset: usize) -> Result<Vec<GemPreview>, String>;
+    async fn filter_by_tag(&self, tag: &str, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>>;
+    
+    /// Filter gems by language: transcript_language when set, else the
+    /// detected `source_meta.content_language` of web content (exact match)
+    async fn filter_by_language(&self, language: &str, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>>;
+
+    /// List distinct gem languages (as used by `filter_by_language`) with
+    /// gem counts (most common first)
+    async fn list_languages(&self) -> JarvisResult<Vec<LanguageCount>>;
+    
+    /// Pairs of tags appearing together on at least `min_count` gems, most
+    /// frequent first (tags compared case-insensitively)
+    async fn tag_cooccurrence(&self, min_count: usize) -> JarvisResult<Vec<TagCooccurrence>>;
+
+    /// IDs of gems that have a transcript but no (or an empty) transcript_language
+    async fn find_missing_transcript_language(&self) -> JarvisResult<Vec<String>>;
     
     /// Delete a gem by ID
-    async fn delete(&self, id: &str) -> Result<(), String>;
+    ///
+    /// Returns `Ok(false)` if no gem has that ID, so batch callers can skip
+    /// missing gems; `Err` is reserved for real storage failures.
+    async fn delete(&self, id: &str) -> JarvisResult<bool>;
+    
+    /// Save `merged` and delete the gem `absorbed_id` in one transaction
+    ///
+    /// Either both changes are stored or neither is, so a failed merge never
+    /// leaves the merged content duplicated or the absorbed gem lost.
+    async fn merge_into(&self, merged: Gem, absorbed_id: &str) -> JarvisResult<Gem>;
     
     /// Find a gem by recording filename
     /// 
@@ -113,9 +267,87 @@ pub trait GemStore: Send + Sync {
     /// # Returns
     /// * `Ok(Some(GemPreview))` - If a gem with matching recording_filename is found
     /// * `Ok(None)` - If no gem with matching recording_filename exists
-    /// * `Err(String)` - If the query fails
-    async fn find_by_recording_filename(&self, filename: &str) -> Result<Option<GemPreview>, String>;
+    /// * `Err(JarvisError::Db)` - If the query fails
+    async fn find_by_recording_filename(&self, filename: &str) -> JarvisResult<Option<GemPreview>>;
+
+    /// `find_by_recording_filename` for many recordings in one query
+    ///
+    /// Returns a map of filename to gem preview, holding only the recordings
+    /// that have a gem.
+    async fn find_by_recording_filenames(&self, filenames: &[String]) -> JarvisResult<HashMap<String, GemPreview>>;
+
+    /// Find the gem saved from `source_url`, if any (source URLs are unique)
+    async fn find_by_source_url(&self, source_url: &str) -> JarvisResult<Option<GemPreview>>;
+
+    /// Open action items of recording gems: `source_meta.action_items` minus
+    /// those in `source_meta.done_action_items`. Gems with no open items are
+    /// left out (ordered by captured_at DESC)
+    async fn list_action_items(&self) -> JarvisResult<Vec<GemActionItems>>;
 
     /// Update a gem's title
-    async fn update_title(&self, id: &str, title: &str) -> Result<(), String>;
+    async fn update_title(&self, id: &str, title: &str) -> JarvisResult<()>;
+
+    /// Set or clear (`None`) a gem's user notes
+    async fn update_notes(&self, id: &str, notes: Option<&str>) -> JarvisResult<()>;
+
+    /// Set or clear (`None`) a gem's color label
+    async fn update_label(&self, id: &str, label: Option<&str>) -> JarvisResult<()>;
+
+    /// Filter gems by color label (ordered by captured_at DESC)
+    async fn filter_by_label(&self, label: &str, limit: usize, offset: usize) -> JarvisResult<Vec<GemPreview>>;
+
+    /// Record that a gem was opened (bumps view_count, sets last_viewed_at)
+    async fn mark_viewed(&self, id: &str) -> JarvisResult<()>;
+
+    /// Viewed gems, most recently viewed first
+    async fn list_recently_viewed(&self, limit: usize) -> JarvisResult<Vec<ViewedGem>>;
+
+    /// Viewed gems, most often viewed first (ties broken by recency)
+    async fn list_most_viewed(&self, limit: usize) -> JarvisResult<Vec<ViewedGem>>;
+
+    /// Check the search index against the gems table and fix it
+    ///
+    /// Recreates missing sync triggers and repopulates the index if it has
+    /// entries for deleted gems or is missing gems. Safe to run repeatedly.
+    async fn repair_search_index(&self) -> JarvisResult<SearchIndexRepair>;
+
+    /// Clear the search index and re-index every gem, returning the number
+    /// of gems indexed
+    ///
+    /// Unconditional, unlike `repair_search_index`; also recreates the sync
+    /// triggers.
+    async fn rebuild_fts_index(&self) -> JarvisResult<usize>;
+
+    /// Reclaim unused space and optimize the search index
+    ///
+    /// Blocks all other store operations until compaction finishes.
+    async fn compact(&self) -> JarvisResult<CompactionResult>;
+
+    /// Write a consistent snapshot of the whole database (gems, projects,
+    /// search history) to `path`, which must not exist yet
+    async fn backup_to(&self, path: &Path) -> JarvisResult<()>;
+
+    /// Replace the whole database with the snapshot at `path` (as written by
+    /// `backup_to`), migrating it to the current schema
+    async fn restore_from(&self, path: &Path) -> JarvisResult<()>;
+}
+
+/// Gems fetched per `list` call by `list_all_gems`
+const LIST_PAGE_SIZE: usize = 500;
+
+/// Previews of every gem, newest first, fetched a page at a time
+pub async fn list_all_gems(store: &dyn GemStore) -> JarvisResult<Vec<GemPreview>> {
+    list_in_pages(store, LIST_PAGE_SIZE).await
+}
+
+pub(crate) async fn list_in_pages(store: &dyn GemStore, page_size: usize) -> JarvisResult<Vec<GemPreview>> {
+    let mut gems = Vec::new();
+    loop {
+        let page = store.list(page_size, gems.len()).await?;
+        let done = page.len() < page_size;
+        gems.extend(page);
+        if done {
+            return Ok(gems);
+        }
+    }
 }
@@ -0,0 +1,44 @@
+// Shared fixtures for gem tests
+
+use super::Gem;
+
+/// A bare Article gem for tests, with a source URL unique to `id`
+///
+/// Override what a test cares about with struct update syntax:
+/// `Gem { title: "Tokio".to_string(), ..test_gem("a") }`
+pub fn test_gem(id: &str) -> Gem {
+    Gem {
+        id: id.to_string(),
+        source_type: "Article".to_string(),
+        source_url: format!("https://example.com/{}", id),
+        domain: "example.com".to_string(),
+        title: id.to_string(),
+        author: None,
+        description: None,
+        content: None,
+        source_meta: serde_json::json!({}),
+        captured_at: chrono::Utc::now().to_rfc3339(),
+        ai_enrichment: None,
+        transcript: None,
+        transcript_language: None,
+        user_notes: None,
+        label: None,
+    }
+}
+
+/// Serve one raw HTTP response (`head` without the blank line) on a local
+/// port, returning an image URL on it
+pub async fn serve_image(head: String, body: Vec<u8>) -> String {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut buf = vec![0u8; 8192];
+        let _ = socket.read(&mut buf).await;
+        let _ = socket.write_all(format!("{}\r\nConnection: close\r\n\r\n", head).as_bytes()).await;
+        let _ = socket.write_all(&body).await;
+    });
+    format!("http://{}/og.jpg", addr)
+}
@@ -0,0 +1,123 @@
+// Local thumbnail cache for gem OG images
+//
+// Remote image URLs rot and every render leaks a request to the source site,
+// so when `BrowserSettings::cache_gem_images` is on, save_gem downloads the
+// image once into ~/.jarvis/thumbnails/{gem_id}.jpg.
+
+use std::path::PathBuf;
+
+use crate::error::{JarvisError, JarvisResult};
+
+/// Images larger than this are not cached
+const MAX_THUMBNAIL_BYTES: usize = 10 * 1024 * 1024;
+
+/// ~/.jarvis/thumbnails
+pub fn thumbnails_dir() -> JarvisResult<PathBuf> {
+    let home = dirs::home_dir().ok_or_else(|| JarvisError::Io("Could not find home directory".to_string()))?;
+    Ok(home.join(".jarvis").join("thumbnails"))
+}
+
+/// Path of the cached thumbnail for a gem (the file may not exist)
+pub fn thumbnail_path(gem_id: &str) -> JarvisResult<PathBuf> {
+    if gem_id.is_empty() || gem_id.contains('/') || gem_id.contains('\\') || gem_id.contains("..") {
+        return Err(JarvisError::Validation(format!("Invalid gem id '{}'", gem_id)));
+    }
+    Ok(thumbnails_dir()?.join(format!("{}.jpg", gem_id)))
+}
+
+/// Download `image_url` into the thumbnail cache, returning the local path
+pub async fn cache_thumbnail(gem_id: &str, image_url: &str) -> JarvisResult<PathBuf> {
+    let path = thumbnail_path(gem_id)?;
+    let bytes = download_image(image_url, MAX_THUMBNAIL_BYTES).await?;
+
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent)
+            .await
+            .map_err(|e| JarvisError::Io(format!("Failed to create thumbnails directory: {}", e)))?;
+    }
+    tokio::fs::write(&path, &bytes)
+        .await
+        .map_err(|e| JarvisError::Io(format!("Failed to write thumbnail: {}", e)))?;
+
+    Ok(path)
+}
+
+/// Fetch an image body, refusing it as soon as it is known to exceed
+/// `max_bytes` (from Content-Length, or while streaming when that is absent)
+async fn download_image(image_url: &str, max_bytes: usize) -> JarvisResult<Vec<u8>> {
+    let mut response = crate::browser::http::send(crate::browser::http::get("thumbnails", image_url))
+        .await
+        .map_err(|e| JarvisError::Network(format!("Failed to fetch image: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(JarvisError::Network(format!("Image request failed with status {}", response.status())));
+    }
+    if let Some(length) = response.content_length().filter(|&length| length > max_bytes as u64) {
+        return Err(JarvisError::Validation(format!("Image too large ({} bytes)", length)));
+    }
+
+    let mut bytes = Vec::new();
+    while let Some(chunk) = response
+        .chunk()
+        .await
+        .map_err(|e| JarvisError::Network(format!("Failed to read image body: {}", e)))?
+    {
+        if bytes.len() + chunk.len() > max_bytes {
+            return Err(JarvisError::Validation(format!("Image too large (over {} bytes)", max_bytes)));
+        }
+        bytes.extend_from_slice(&chunk);
+    }
+
+    if bytes.is_empty() {
+        return Err(JarvisError::Validation("Image response was empty".to_string()));
+    }
+    Ok(bytes)
+}
+
+/// Delete a gem's cached thumbnail, if any
+pub async fn remove_thumbnail(gem_id: &str) -> JarvisResult<()> {
+    let path = thumbnail_path(gem_id)?;
+    match tokio::fs::remove_file(&path).await {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(JarvisError::Io(format!("Failed to delete thumbnail: {}", e))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gems::test_utils::serve_image;
+
+    #[test]
+    fn test_thumbnail_path_rejects_traversal() {
+        assert!(thumbnail_path("../gems").is_err());
+        assert!(thumbnail_path("a/b").is_err());
+        assert!(thumbnail_path("").is_err());
+
+        let path = thumbnail_path("0f8c2d1e").unwrap();
+        assert!(path.ends_with("thumbnails/0f8c2d1e.jpg"));
+    }
+
+    #[tokio::test]
+    async fn test_download_image() {
+        let body = vec![0xFFu8; 64];
+        let url = serve_image(format!("HTTP/1.1 200 OK\r\nContent-Length: {}", body.len()), body.clone()).await;
+        assert_eq!(download_image(&url, 1024).await.unwrap(), body);
+    }
+
+    #[tokio::test]
+    async fn test_download_image_rejects_large_content_length() {
+        // The declared size alone is enough to refuse; no body is sent
+        let url = serve_image("HTTP/1.1 200 OK\r\nContent-Length: 999999999".to_string(), Vec::new()).await;
+        let err = download_image(&url, 1024).await.unwrap_err();
+        assert!(matches!(err, JarvisError::Validation(_)), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_download_image_caps_body_without_content_length() {
+        let url = serve_image("HTTP/1.1 200 OK".to_string(), vec![0u8; 4096]).await;
+        let err = download_image(&url, 1024).await.unwrap_err();
+        assert!(err.to_string().contains("over 1024 bytes"), "{}", err);
+    }
+}
@@ -10,6 +10,7 @@ use tokio::process::{Child, Command};
 use tokio::sync::Mutex;
 
 use super::provider::{AvailabilityResult, IntelProvider};
+use crate::error::{JarvisError, JarvisResult};
 use super::utils::split_content;
 
 /// Max characters per chunk to stay within Apple Foundation Models' 4096-token context window.
@@ -76,7 +77,7 @@ impl IntelligenceKitProvider {
     /// (no target-triple suffix, no binaries/ prefix). We find it next to the main executable.
     ///
     /// Dev mode: Binary is at src-tauri/binaries/IntelligenceKit-<target-triple>.
-    fn resolve_binary_path() -> Result<std::path::PathBuf, String> {
+    fn resolve_binary_path() -> JarvisResult<std::path::PathBuf> {
         // Production: look next to the running executable (Contents/MacOS/)
         if let Ok(exe) = std::env::current_exe() {
             if let Some(exe_dir) = exe.parent() {
@@ -108,14 +109,14 @@ impl IntelligenceKitProvider {
             return Ok(dev_path);
         }
 
-        Err(format!(
+        Err(JarvisError::Sidecar(format!(
             "IntelligenceKit binary not found. Checked:\n  - next to executable\n  - {:?}",
             dev_path
-        ))
+        )))
     }
 
     /// Create a new provider and spawn the sidecar using tokio::process::Command
-    pub async fn new(app_handle: tauri::AppHandle) -> Result<Self, String> {
+    pub async fn new(app_handle: tauri::AppHandle) -> JarvisResult<Self> {
         // Resolve IntelligenceKit binary path.
         //
         // Production: Tauri bundles externalBin into Contents/MacOS/ (no target triple suffix).
@@ -130,21 +131,21 @@ impl IntelligenceKitProvider {
             .stdout(Stdio::piped())
             .stderr(Stdio::piped())
             .spawn()
-            .map_err(|e| format!("Failed to spawn IntelligenceKit: {}", e))?;
+            .map_err(|e| JarvisError::Sidecar(format!("Failed to spawn IntelligenceKit: {}", e)))?;
 
         // Take ownership of stdio handles
         let stdin = child
             .stdin
             .take()
-            .ok_or("Failed to get stdin handle")?;
+            .ok_or_else(|| JarvisError::Sidecar("Failed to get stdin handle".to_string()))?;
         let stdout = child
             .stdout
             .take()
-            .ok_or("Failed to get stdout handle")?;
+            .ok_or_else(|| JarvisError::Sidecar("Failed to get stdout handle".to_string()))?;
         let stderr = child
             .stderr
             .take()
-            .ok_or("Failed to get stderr handle")?;
+            .ok_or_else(|| JarvisError::Sidecar("Failed to get stderr handle".to_string()))?;
 
         // Spawn stderr monitoring task
         tokio::spawn(async move {
@@ -189,16 +190,16 @@ impl IntelligenceKitProvider {
     }
 
     /// Send a command and receive a response (with 30s timeout)
-    async fn send_command(&self, cmd: NdjsonCommand) -> Result<NdjsonResponse, String> {
+    async fn send_command(&self, cmd: NdjsonCommand) -> JarvisResult<NdjsonResponse> {
         let mut state = self.state.lock().await;
 
         // Serialize command to JSON + newline
         let json = serde_json::to_string(&cmd)
-            .map_err(|e| format!("Failed to serialize command: {}", e))?;
+            .map_err(|e| JarvisError::Parse(format!("Failed to serialize command: {}", e)))?;
 
         // Write to stdin with 30s timeout
         {
-            let stdin = state.stdin.as_mut().ok_or("Stdin not available")?;
+            let stdin = state.stdin.as_mut().ok_or_else(|| JarvisError::Sidecar("Stdin not available".to_string()))?;
             let write_future = async {
                 stdin.write_all(json.as_bytes()).await?;
                 stdin.write_all(b"\n").await?;
@@ -208,29 +209,29 @@ impl IntelligenceKitProvider {
 
             tokio::time::timeout(std::time::Duration::from_secs(30), write_future)
                 .await
-                .map_err(|_| "Command write timeout".to_string())?
-                .map_err(|e| format!("Failed to write command: {}", e))?;
+                .map_err(|_| JarvisError::Sidecar("Command write timeout".to_string()))?
+                .map_err(|e| JarvisError::Sidecar(format!("Failed to write command: {}", e)))?;
         }
 
         // Read one line from stdout with 30s timeout
         let mut response_line = String::new();
         {
-            let stdout = state.stdout.as_mut().ok_or("Stdout not available")?;
+            let stdout = state.stdout.as_mut().ok_or_else(|| JarvisError::Sidecar("Stdout not available".to_string()))?;
             let read_future = stdout.read_line(&mut response_line);
 
             tokio::time::timeout(std::time::Duration::from_secs(30), read_future)
                 .await
-                .map_err(|_| "Command read timeout".to_string())?
-                .map_err(|e| format!("Failed to read response: {}", e))?;
+                .map_err(|_| JarvisError::Sidecar("Command read timeout".to_string()))?
+                .map_err(|e| JarvisError::Sidecar(format!("Failed to read response: {}", e)))?;
         }
 
         // Deserialize response
         serde_json::from_str(&response_line)
-            .map_err(|e| format!("Failed to parse response: {}", e))
+            .map_err(|e| JarvisError::Parse(format!("Failed to parse response: {}", e)))
     }
 
     /// Internal availability check (sends check-availability command)
-    async fn check_availability_internal(&self) -> Result<AvailabilityResult, String> {
+    async fn check_availability_internal(&self) -> JarvisResult<AvailabilityResult> {
         let cmd = NdjsonCommand {
             command: "check-availability".to_string(),
             session_id: None,
@@ -256,7 +257,7 @@ impl IntelligenceKitProvider {
     }
 
     /// Open a new session
-    async fn open_session(&self) -> Result<String, String> {
+    async fn open_session(&self) -> JarvisResult<String> {
         let cmd = NdjsonCommand {
             command: "open-session".to_string(),
             session_id: None,
@@ -272,12 +273,12 @@ impl IntelligenceKitProvider {
         let response = self.send_command(cmd).await?;
 
         if !response.ok {
-            return Err(response
+            return Err(JarvisError::Provider(response
                 .error
-                .unwrap_or_else(|| "Failed to open session".to_string()));
+                .unwrap_or_else(|| "Failed to open session".to_string())));
         }
 
-        let session_id = response.session_id.ok_or("No session_id in response")?;
+        let session_id = response.session_id.ok_or_else(|| JarvisError::Provider("No session_id in response".to_string()))?;
 
         {
             let mut state = self.state.lock().await;
@@ -288,7 +289,7 @@ impl IntelligenceKitProvider {
     }
 
     /// Ensure a session is open (create if needed)
-    async fn ensure_session(&self) -> Result<String, String> {
+    async fn ensure_session(&self) -> JarvisResult<String> {
         let state = self.state.lock().await;
         if let Some(session_id) = &state.session_id {
             return Ok(session_id.clone());
@@ -327,7 +328,7 @@ impl IntelligenceKitProvider {
     }
 
     /// Internal helper for generate_tags (no retry logic)
-    async fn generate_tags_internal(&self, content: &str) -> Result<Vec<String>, String> {
+    async fn generate_tags_internal(&self, content: &str) -> JarvisResult<Vec<String>> {
         let session_id = self.ensure_session().await?;
 
         let cmd = NdjsonCommand {
@@ -345,19 +346,19 @@ impl IntelligenceKitProvider {
         let response = self.send_command(cmd).await?;
 
         if !response.ok {
-            return Err(response
+            return Err(JarvisError::Provider(response
                 .error
-                .unwrap_or_else(|| "Tag generation failed".to_string()));
+                .unwrap_or_else(|| "Tag generation failed".to_string())));
         }
 
         let tags: Vec<String> = serde_json::from_value(
             response.result.unwrap_or(serde_json::Value::Array(vec![])),
         )
-        .map_err(|e| format!("Failed to parse tags: {}", e))?;
+        .map_err(|e| JarvisError::Parse(format!("Failed to parse tags: {}", e)))?;
 
         // Validate and trim tags
         if tags.is_empty() {
-            return Err("Model returned no tags".to_string());
+            return Err(JarvisError::Provider("Model returned no tags".to_string()));
         }
 
         // Trim to max 5 tags if model returned more
@@ -367,9 +368,9 @@ impl IntelligenceKitProvider {
     }
 
     /// Generate tags with session-expired retry (single chunk, no chunking logic)
-    async fn generate_tags_with_retry(&self, content: &str) -> Result<Vec<String>, String> {
+    async fn generate_tags_with_retry(&self, content: &str) -> JarvisResult<Vec<String>> {
         match self.generate_tags_internal(content).await {
-            Err(e) if e.contains("session_not_found") => {
+            Err(JarvisError::Provider(e)) if e.contains("session_not_found") => {
                 self.open_session().await?;
                 self.generate_tags_internal(content).await
             }
@@ -378,9 +379,9 @@ impl IntelligenceKitProvider {
     }
 
     /// Summarize with session-expired retry (single chunk, no chunking logic)
-    async fn summarize_with_retry(&self, content: &str) -> Result<String, String> {
+    async fn summarize_with_retry(&self, content: &str) -> JarvisResult<String> {
         match self.summarize_internal(content).await {
-            Err(e) if e.contains("session_not_found") => {
+            Err(JarvisError::Provider(e)) if e.contains("session_not_found") => {
                 self.open_session().await?;
                 self.summarize_internal(content).await
             }
@@ -389,7 +390,7 @@ impl IntelligenceKitProvider {
     }
 
     /// Internal helper for summarize (no retry logic)
-    async fn summarize_internal(&self, content: &str) -> Result<String, String> {
+    async fn summarize_internal(&self, content: &str) -> JarvisResult<String> {
         let session_id = self.ensure_session().await?;
 
         let cmd = NdjsonCommand {
@@ -407,9 +408,9 @@ impl IntelligenceKitProvider {
         let response = self.send_command(cmd).await?;
 
         if !response.ok {
-            return Err(response
+            return Err(JarvisError::Provider(response
                 .error
-                .unwrap_or_else(|| "Summarization failed".to_string()));
+                .unwrap_or_else(|| "Summarization failed".to_string())));
         }
 
         let summary: String = serde_json::from_value(
@@ -417,10 +418,10 @@ impl IntelligenceKitProvider {
                 .result
                 .unwrap_or(serde_json::Value::String(String::new())),
         )
-        .map_err(|e| format!("Failed to parse summary: {}", e))?;
+        .map_err(|e| JarvisError::Parse(format!("Failed to parse summary: {}", e)))?;
 
         if summary.is_empty() {
-            return Err("Model returned empty summary".to_string());
+            return Err(JarvisError::Provider("Model returned empty summary".to_string()));
         }
 
         Ok(summary)
@@ -434,7 +435,7 @@ impl IntelProvider for IntelligenceKitProvider {
         state.availability.clone()
     }
 
-    async fn generate_tags(&self, content: &str) -> Result<Vec<String>, String> {
+    async fn generate_tags(&self, content: &str) -> JarvisResult<Vec<String>> {
         let chunks = split_content(content, MAX_CONTENT_CHARS);
 
         if chunks.len() == 1 {
@@ -481,13 +482,13 @@ impl IntelProvider for IntelligenceKitProvider {
         all_tags.truncate(5);
 
         if all_tags.is_empty() {
-            return Err("No tags generated from any content chunk".to_string());
+            return Err(JarvisError::Provider("No tags generated from any content chunk".to_string()));
         }
 
         Ok(all_tags)
     }
 
-    async fn summarize(&self, content: &str) -> Result<String, String> {
+    async fn summarize(&self, content: &str) -> JarvisResult<String> {
         let chunks = split_content(content, MAX_CONTENT_CHARS);
 
         if chunks.len() == 1 {
@@ -528,7 +529,7 @@ impl IntelProvider for IntelligenceKitProvider {
         }
 
         if chunk_summaries.is_empty() {
-            return Err("No summaries generated from any content chunk".to_string());
+            return Err(JarvisError::Provider("No summaries generated from any content chunk".to_string()));
         }
 
         if chunk_summaries.len() == 1 {
@@ -12,6 +12,7 @@ use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
 use tokio::sync::Mutex as TokioMutex;
 use tokio_util::sync::CancellationToken;
 
+use crate::notifications;
 use crate::settings::ModelStatus;
 
 /// Static metadata for an LLM model in the catalog
@@ -23,6 +24,7 @@ struct LlmModelEntry {
     size_estimate: &'static str,
     quality_tier: &'static str,
     capabilities: &'static [&'static str],
+    context_length: usize,
 }
 
 /// Information about an LLM model (returned to frontend)
@@ -36,6 +38,8 @@ pub struct LlmModelInfo {
     pub quality_tier: String,
     pub status: ModelStatus,
     pub capabilities: Vec<String>,
+    /// Maximum context window in tokens
+    pub context_length: usize,
 }
 
 /// Internal state for an in-progress download
@@ -68,6 +72,7 @@ impl LlmModelManager {
             size_estimate: "~2 GB",
             quality_tier: "basic",
             capabilities: &["text"],
+            context_length: 131_072,
         },
         LlmModelEntry {
             id: "qwen3-4b-4bit",
@@ -77,6 +82,7 @@ impl LlmModelManager {
             size_estimate: "~3 GB",
             quality_tier: "good",
             capabilities: &["text"],
+            context_length: 32_768,
         },
         LlmModelEntry {
             id: "qwen3-8b-4bit",
@@ -86,6 +92,7 @@ impl LlmModelManager {
             size_estimate: "~5 GB",
             quality_tier: "great",
             capabilities: &["text"],
+            context_length: 32_768,
         },
         LlmModelEntry {
             id: "qwen3-14b-4bit",
@@ -95,6 +102,7 @@ impl LlmModelManager {
             size_estimate: "~9 GB",
             quality_tier: "best",
             capabilities: &["text"],
+            context_length: 32_768,
         },
         LlmModelEntry {
             id: "qwen-omni-3b-8bit",
@@ -104,6 +112,7 @@ impl LlmModelManager {
             size_estimate: "~5 GB",
             quality_tier: "good",
             capabilities: &["audio", "text"],
+            context_length: 32_768,
         },
         LlmModelEntry {
             id: "qwen-omni-7b-4bit",
@@ -113,6 +122,7 @@ impl LlmModelManager {
             size_estimate: "~8 GB",
             quality_tier: "better",
             capabilities: &["audio", "text"],
+            context_length: 32_768,
         },
     ];
 
@@ -157,6 +167,11 @@ impl LlmModelManager {
         Self::LLM_MODEL_CATALOG.iter().find(|e| e.id == model_id)
     }
 
+    /// Context window (in tokens) of a catalog model, or None for unknown IDs
+    pub fn context_length(model_id: &str) -> Option<usize> {
+        Self::catalog_entry(model_id).map(|e| e.context_length)
+    }
+
     /// Validates that a downloaded model directory contains config.json
     fn validate_model(&self, model_id: &str) -> bool {
         self.model_path(model_id).join("config.json").exists()
@@ -203,6 +218,7 @@ impl LlmModelManager {
                 quality_tier: entry.quality_tier.to_string(),
                 status,
                 capabilities: entry.capabilities.iter().map(|s| s.to_string()).collect(),
+                context_length: entry.context_length,
             });
         }
 
@@ -287,16 +303,20 @@ impl LlmModelManager {
                         "llm-model-download-complete",
                         serde_json::json!({ "model_id": model_id }),
                     );
+                    notifications::notify_job_completed(&app_handle, "Model download", &model_id);
                 }
                 Err(e) => {
                     // Clean up partial download
                     let _ = std::fs::remove_dir_all(&download_dest);
 
-                    if e != "Download cancelled" {
+                    if e == "Download cancelled" {
+                        notifications::notify_job_cancelled(&app_handle, "Model download", &model_id);
+                    } else {
                         error_states_clone
                             .lock()
                             .await
                             .insert(model_id.clone(), e.clone());
+                        notifications::notify_job_failed(&app_handle, "Model download", &format!("{}: {}", model_id, e));
                     }
                     let _ = app_handle.emit(
                         "llm-model-download-error",
@@ -575,6 +595,19 @@ impl LlmModelManager {
         Ok(())
     }
 
+    /// Cancels every in-progress download, returning the cancelled model IDs.
+    pub async fn cancel_all_downloads(&self) -> Vec<String> {
+        let model_ids: Vec<String> = self.download_queue.lock().await.keys().cloned().collect();
+        let mut cancelled = Vec::new();
+        for model_id in model_ids {
+            // A download may finish between listing and cancelling; skip those
+            if self.cancel_download(model_id.clone()).await.is_ok() {
+                cancelled.push(model_id);
+            }
+        }
+        cancelled
+    }
+
     /// Deletes a downloaded model from disk.
     ///
     /// Note: Active model protection (preventing deletion of the currently active model)
@@ -2,20 +2,80 @@
 
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::process::Stdio;
-use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
 
 use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, BufWriter};
-use tokio::process::{Child, Command};
+use tokio::process::{Child, ChildStdin, ChildStdout, Command};
 use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
 
-use super::provider::{AvailabilityResult, IntelProvider};
-use super::utils::split_content;
+use super::provider::{
+    answer_system_message, parse_action_items, parse_concepts, AvailabilityResult, CoPilotConcept,
+    IntelProvider, ProviderCapabilities, ACTION_ITEMS_PROMPT, CONCEPTS_PROMPT, MAX_ANSWER_CONTEXT_TOKENS,
+};
+use super::utils::{split_content, tail_within_tokens};
+use crate::error::{JarvisError, JarvisResult};
 
 /// Max characters per chunk for MLX models (15,000 chars ~= 6,000 tokens)
 const MAX_CONTENT_CHARS: usize = 15_000;
 
+/// Sidecar stderr (and stray stdout) log, in the logs directory next to the
+/// app logs; read it with `read_mlx_sidecar_log`
+pub const SIDECAR_LOG_FILE: &str = "mlx_sidecar.log";
+
+/// Path of `SIDECAR_LOG_FILE`
+pub fn sidecar_log_path() -> Option<PathBuf> {
+    crate::logging::logs_dir().map(|dir| dir.join(SIDECAR_LOG_FILE))
+}
+
+/// `[timestamp] [stream] line` for each line of `text`
+fn format_sidecar_log(timestamp: &str, stream: &str, text: &str) -> String {
+    text.lines()
+        .map(|line| format!("[{}] [{}] {}\n", timestamp, stream, line))
+        .collect()
+}
+
+/// Append `text` from `stream` ("stderr", "stdout" or "jarvis") to the sidecar log
+async fn log_sidecar_output(stream: &str, text: &str) {
+    let Some(path) = sidecar_log_path() else {
+        return;
+    };
+    if let Some(dir) = path.parent() {
+        let _ = tokio::fs::create_dir_all(dir).await;
+    }
+    let entry = format_sidecar_log(&chrono::Utc::now().to_rfc3339(), stream, text);
+    let file = tokio::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .await;
+    if let Ok(mut file) = file {
+        let _ = file.write_all(entry.as_bytes()).await;
+    }
+}
+
+/// Start a new sidecar section in the log, first clearing a log that has
+/// grown past the app log size limit
+async fn start_sidecar_log(python_path: &str, sidecar_path: &Path) {
+    if let Some(path) = sidecar_log_path() {
+        let too_big = tokio::fs::metadata(&path)
+            .await
+            .is_ok_and(|m| m.len() >= crate::logging::MAX_LOG_FILE_BYTES);
+        if too_big {
+            let _ = tokio::fs::remove_file(&path).await;
+        }
+    }
+    log_sidecar_output(
+        "jarvis",
+        &format!("--- Starting sidecar: {} {} ---", python_path, sidecar_path.display()),
+    )
+    .await;
+}
+
 /// NDJSON command structure for MLX sidecar protocol
 #[derive(Serialize)]
 struct NdjsonCommand {
@@ -36,6 +96,8 @@ struct NdjsonCommand {
     context: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     messages: Option<Vec<serde_json::Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    focus: Option<String>,
 }
 
 /// NDJSON response structure from MLX sidecar
@@ -87,6 +149,20 @@ struct NdjsonResponse {
     response: Option<String>,
 }
 
+/// Lifecycle state of the MLX sidecar process
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SidecarState {
+    /// Process is running (model loaded)
+    Running,
+    /// Shut down after the idle timeout; respawned on the next request
+    Idle,
+    /// Shut down explicitly; not respawned
+    Stopped,
+    /// Respawning or reloading the model failed; retried on the next request
+    Failed,
+}
+
 /// MLX provider state
 struct ProviderState {
     /// Child process handle
@@ -96,15 +172,30 @@ struct ProviderState {
     /// Cached availability result
     availability: AvailabilityResult,
     /// Stdin writer (buffered for efficiency)
-    stdin: Option<BufWriter<tokio::process::ChildStdin>>,
+    stdin: Option<BufWriter<ChildStdin>>,
     /// Stdout reader (buffered for line reading)
-    stdout: Option<BufReader<tokio::process::ChildStdout>>,
+    stdout: Option<BufReader<ChildStdout>>,
+    /// Python interpreter used to (re)spawn the sidecar
+    python_path: String,
+    /// Loaded model, reloaded when the sidecar is respawned after going idle
+    model_path: Option<PathBuf>,
+    /// When the last command finished (drives the idle timeout)
+    last_used: Instant,
+    sidecar_state: SidecarState,
+    /// A command was written but its response never read (the caller's
+    /// future was dropped, e.g. by an outer timeout). The late reply is still
+    /// on stdout, so the sidecar must be restarted before the next command.
+    response_pending: bool,
 }
 
 /// MLX provider - manages sidecar lifecycle and NDJSON communication
 pub struct MlxProvider {
     /// Shared state protected by mutex (only one command in-flight at a time)
     state: Arc<Mutex<ProviderState>>,
+    /// Cancels in-flight and queued commands; replaced after each cancellation
+    cancel_token: std::sync::Mutex<CancellationToken>,
+    /// Whether the loaded model accepts audio (transcription, Co-Pilot)
+    supports_audio: AtomicBool,
 }
 
 impl MlxProvider {
@@ -112,7 +203,7 @@ impl MlxProvider {
     ///
     /// Production (bundled .app): Tauri places resources at Contents/Resources/
     /// Dev mode: Script is at src-tauri/sidecars/mlx-server/server.py
-    fn resolve_sidecar_path() -> Result<PathBuf, String> {
+    fn resolve_sidecar_path() -> JarvisResult<PathBuf> {
         // Production: look in Resources directory
         if let Ok(exe) = std::env::current_exe() {
             if let Some(exe_dir) = exe.parent() {
@@ -136,14 +227,14 @@ impl MlxProvider {
             return Ok(dev_path);
         }
 
-        Err(format!(
+        Err(JarvisError::Sidecar(format!(
             "MLX sidecar script not found. Checked:\n  - Contents/Resources/sidecars/mlx-server/server.py\n  - {:?}",
             dev_path
-        ))
+        )))
     }
 
     /// Check if Python is installed and accessible
-    async fn check_python_installed(python_path: &str) -> Result<(), String> {
+    async fn check_python_installed(python_path: &str) -> JarvisResult<()> {
         let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/tmp"));
         let output = Command::new(python_path)
             .arg("--version")
@@ -152,35 +243,34 @@ impl MlxProvider {
             .await
             .map_err(|e| {
                 if e.kind() == std::io::ErrorKind::NotFound {
-                    format!("Python not found at '{}'. Please install Python 3.10+ or update the python_path in settings.", python_path)
+                    JarvisError::Sidecar(format!("Python not found at '{}'. Please install Python 3.10+ or update the python_path in settings.", python_path))
                 } else {
-                    format!("Failed to check Python version: {}", e)
+                    JarvisError::Sidecar(format!("Failed to check Python version: {}", e))
                 }
             })?;
 
         if !output.status.success() {
-            return Err(format!("Python check failed: {}", String::from_utf8_lossy(&output.stderr)));
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            log_sidecar_output("jarvis", &format!("Python check failed for '{}'", python_path)).await;
+            log_sidecar_output("stderr", &stderr).await;
+            return Err(JarvisError::Sidecar(format!("Python check failed: {}", stderr)));
         }
 
         eprintln!("MLX: Python found: {}", String::from_utf8_lossy(&output.stdout).trim());
         Ok(())
     }
 
-    /// Create a new provider and spawn the sidecar
-    pub async fn new(
-        _app_handle: tauri::AppHandle,
-        model_path: PathBuf,
-        python_path: String,
-    ) -> Result<Self, String> {
-        // Check if Python is installed before attempting to spawn sidecar
-        Self::check_python_installed(&python_path).await?;
-
+    /// Spawn the Python sidecar and return the process with buffered stdio
+    async fn spawn_sidecar(
+        python_path: &str,
+    ) -> JarvisResult<(Child, BufWriter<ChildStdin>, BufReader<ChildStdout>)> {
         let sidecar_path = Self::resolve_sidecar_path()?;
 
         // Spawn Python sidecar using tokio::process::Command
         // Set current_dir to home to avoid inheriting a stale/deleted cwd from the parent process
         let home = dirs::home_dir().unwrap_or_else(|| PathBuf::from("/tmp"));
-        let mut child = Command::new(&python_path)
+        start_sidecar_log(python_path, &sidecar_path).await;
+        let mut child = Command::new(python_path)
             .arg(&sidecar_path)
             .current_dir(&home)
             .stdin(Stdio::piped())
@@ -188,24 +278,24 @@ impl MlxProvider {
             .stderr(Stdio::piped())
             .spawn()
             .map_err(|e| {
-                format!("Failed to spawn MLX sidecar (Python found but spawn failed): {}", e)
+                JarvisError::Sidecar(format!("Failed to spawn MLX sidecar (Python found but spawn failed): {}", e))
             })?;
 
         // Take ownership of stdio handles
         let stdin = child
             .stdin
             .take()
-            .ok_or("Failed to get stdin handle")?;
+            .ok_or_else(|| JarvisError::Sidecar("Failed to get stdin handle".to_string()))?;
         let stdout = child
             .stdout
             .take()
-            .ok_or("Failed to get stdout handle")?;
+            .ok_or_else(|| JarvisError::Sidecar("Failed to get stdout handle".to_string()))?;
         let stderr = child
             .stderr
             .take()
-            .ok_or("Failed to get stderr handle")?;
+            .ok_or_else(|| JarvisError::Sidecar("Failed to get stderr handle".to_string()))?;
 
-        // Spawn stderr monitoring task
+        // Spawn stderr monitoring task (app log + sidecar log)
         tokio::spawn(async move {
             let mut reader = BufReader::new(stderr);
             let mut line = String::new();
@@ -214,10 +304,31 @@ impl MlxProvider {
                     break;
                 }
                 eprint!("[MLX] {}", line);
+                log_sidecar_output("stderr", &line).await;
                 line.clear();
             }
+            log_sidecar_output("jarvis", "--- Sidecar stderr closed (process exited) ---").await;
         });
 
+        Ok((child, BufWriter::new(stdin), BufReader::new(stdout)))
+    }
+
+    /// Create a new provider and spawn the sidecar
+    ///
+    /// With `idle_timeout_secs > 0` the sidecar is shut down after that many
+    /// seconds without a request (freeing the model's memory) and respawned with
+    /// the same model on the next request. 0 keeps it alive until `shutdown`.
+    pub async fn new(
+        _app_handle: tauri::AppHandle,
+        model_path: PathBuf,
+        python_path: String,
+        idle_timeout_secs: u64,
+    ) -> JarvisResult<Self> {
+        // Check if Python is installed before attempting to spawn sidecar
+        Self::check_python_installed(&python_path).await?;
+
+        let (child, stdin, stdout) = Self::spawn_sidecar(&python_path).await?;
+
         let state = ProviderState {
             child: Some(child),
             model_name: None,
@@ -225,12 +336,19 @@ impl MlxProvider {
                 available: false,
                 reason: None,
             },
-            stdin: Some(BufWriter::new(stdin)),
-            stdout: Some(BufReader::new(stdout)),
+            stdin: Some(stdin),
+            stdout: Some(stdout),
+            python_path,
+            model_path: None,
+            last_used: Instant::now(),
+            sidecar_state: SidecarState::Running,
+            response_pending: false,
         };
 
         let provider = Self {
             state: Arc::new(Mutex::new(state)),
+            cancel_token: std::sync::Mutex::new(CancellationToken::new()),
+            supports_audio: AtomicBool::new(false),
         };
 
         // Check availability with 15s timeout (allows for model loading)
@@ -239,7 +357,7 @@ impl MlxProvider {
             provider.check_availability_internal(),
         )
         .await
-        .map_err(|_| "MLX availability check timeout (15s). The sidecar may be unresponsive.".to_string())??;
+        .map_err(|_| JarvisError::Sidecar("MLX availability check timeout (15s). The sidecar may be unresponsive.".to_string()))??;
 
         {
             let mut state = provider.state.lock().await;
@@ -250,12 +368,12 @@ impl MlxProvider {
             let reason = availability.reason.unwrap_or_else(|| "Unknown reason".to_string());
             // Provide helpful error messages based on the reason
             if reason.contains("mlx") || reason.contains("import") {
-                return Err(format!(
+                return Err(JarvisError::Provider(format!(
                     "MLX dependencies not installed: {}. Please install mlx and mlx-lm: pip install mlx mlx-lm",
                     reason
-                ));
+                )));
             } else {
-                return Err(format!("MLX not available: {}", reason));
+                return Err(JarvisError::Provider(format!("MLX not available: {}", reason)));
             }
         }
 
@@ -265,27 +383,215 @@ impl MlxProvider {
             provider.load_model_internal(model_path),
         )
         .await
-        .map_err(|_| "MLX model load timeout (15s)".to_string())??;
+        .map_err(|_| JarvisError::Sidecar("MLX model load timeout (15s)".to_string()))??;
+
+        if idle_timeout_secs > 0 {
+            Self::spawn_idle_monitor(
+                Arc::downgrade(&provider.state),
+                Duration::from_secs(idle_timeout_secs),
+            );
+        }
 
         Ok(provider)
     }
 
+    /// Shut the sidecar down once it has been idle for `idle_timeout`.
+    ///
+    /// Runs until the provider is dropped or explicitly shut down. A command in
+    /// flight holds the state lock, so the sidecar is never stopped mid-request.
+    fn spawn_idle_monitor(state: Weak<Mutex<ProviderState>>, idle_timeout: Duration) {
+        let check_interval = idle_timeout.min(Duration::from_secs(30));
+        tokio::spawn(async move {
+            loop {
+                tokio::time::sleep(check_interval).await;
+                let Some(state) = state.upgrade() else {
+                    break;
+                };
+                let mut state = state.lock().await;
+                match state.sidecar_state {
+                    SidecarState::Stopped => break,
+                    SidecarState::Running if state.last_used.elapsed() >= idle_timeout => {
+                        eprintln!(
+                            "MLX: Sidecar idle for {}s, shutting down to free memory",
+                            idle_timeout.as_secs()
+                        );
+                        Self::stop_sidecar(&mut state).await;
+                        state.sidecar_state = SidecarState::Idle;
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    /// Respawn an idle sidecar and reload its model
+    ///
+    /// On failure the new process is killed and the state becomes `Failed`,
+    /// so later requests retry the respawn instead of reaching a sidecar
+    /// without a model.
+    async fn respawn_sidecar(state: &mut ProviderState) -> JarvisResult<()> {
+        eprintln!("MLX: Respawning idle sidecar");
+        let result = Self::spawn_and_reload(state).await;
+        if result.is_err() {
+            Self::discard_sidecar(state).await;
+            state.sidecar_state = SidecarState::Failed;
+        }
+        result
+    }
+
+    async fn spawn_and_reload(state: &mut ProviderState) -> JarvisResult<()> {
+        let (child, stdin, stdout) = Self::spawn_sidecar(&state.python_path).await?;
+        state.child = Some(child);
+        state.stdin = Some(stdin);
+        state.stdout = Some(stdout);
+        state.sidecar_state = SidecarState::Running;
+
+        if let Some(model_path) = state.model_path.clone() {
+            let cmd = Self::load_model_command(&model_path);
+            let response = Self::exchange(state, &cmd, 60).await?;
+            if response.response_type == "error" || !response.success.unwrap_or(false) {
+                let err = response.error.unwrap_or_else(|| "Model load failed".to_string());
+                eprintln!("MLX: Failed to reload model after respawn: {}", err);
+                return Err(JarvisError::Provider(format!("Failed to reload model after respawn: {}", err)));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Gracefully stop the sidecar process (shutdown command, then kill after 3s)
+    async fn stop_sidecar(state: &mut ProviderState) {
+        let cmd = NdjsonCommand {
+            command: "shutdown".to_string(),
+            model_path: None,
+            content: None,
+            repo_id: None,
+            destination: None,
+            audio_path: None,
+            capabilities: None,
+            context: None,
+            messages: None,
+            focus: None,
+        };
+
+        // Try to send shutdown command (ignore errors)
+        if state.child.is_some() {
+            let _ = Self::exchange(state, &cmd, 5).await;
+        }
+        state.stdin = None;
+        state.stdout = None;
+        state.response_pending = false;
+
+        // Wait up to 3 seconds for graceful exit
+        if let Some(mut child) = state.child.take() {
+            let wait_future = child.wait();
+            if tokio::time::timeout(Duration::from_secs(3), wait_future)
+                .await
+                .is_err()
+            {
+                // Timeout - send SIGTERM
+                let _ = child.kill().await;
+            }
+        }
+    }
+
+    /// Current lifecycle state of the sidecar process
+    pub async fn sidecar_state(&self) -> SidecarState {
+        self.state.lock().await.sidecar_state
+    }
+
     /// Send a command and receive a response with configurable timeout.
     ///
     /// `timeout_secs` controls both the write and read timeout for this command.
     /// Use shorter timeouts (60s) for quick operations like tags/summary,
     /// and longer timeouts (600s) for audio transcription of large files.
-    async fn send_command(&self, cmd: NdjsonCommand, timeout_secs: u64) -> Result<NdjsonResponse, String> {
+    ///
+    /// Respawns the sidecar first if it was shut down for being idle.
+    /// Returns "Inference cancelled" if `cancel_inference` is called meanwhile.
+    async fn send_command(&self, cmd: NdjsonCommand, timeout_secs: u64) -> JarvisResult<NdjsonResponse> {
+        let cancel_token = self
+            .cancel_token
+            .lock()
+            .map_err(|e| JarvisError::Other(format!("Failed to acquire cancel token lock: {}", e)))?
+            .clone();
+
+        let mut state = tokio::select! {
+            state = self.state.lock() => state,
+            _ = cancel_token.cancelled() => return Err(JarvisError::Cancelled("Inference cancelled".to_string())),
+        };
+
+        if state.response_pending {
+            eprintln!("MLX: Previous command was abandoned before its response, restarting sidecar");
+            Self::discard_sidecar(&mut state).await;
+        }
+        if matches!(state.sidecar_state, SidecarState::Idle | SidecarState::Failed) {
+            Self::respawn_sidecar(&mut state).await?;
+        }
+
+        // Stays set if this future is dropped mid-exchange (e.g. `with_timeout`)
+        state.response_pending = true;
+        let result = tokio::select! {
+            result = Self::exchange(&mut state, &cmd, timeout_secs) => Some(result),
+            _ = cancel_token.cancelled() => None,
+        };
+        state.last_used = Instant::now();
+
+        match result {
+            Some(Err(JarvisError::Timeout(msg))) => {
+                eprintln!("MLX: Command '{}' timed out, restarting sidecar", cmd.command);
+                Self::discard_sidecar(&mut state).await;
+                Err(JarvisError::Timeout(msg))
+            }
+            Some(result) => {
+                state.response_pending = false;
+                result
+            }
+            None => {
+                eprintln!("MLX: Command '{}' cancelled, restarting sidecar", cmd.command);
+                Self::discard_sidecar(&mut state).await;
+                Err(JarvisError::Cancelled("Inference cancelled".to_string()))
+            }
+        }
+    }
+
+    /// Kill a sidecar whose response is still pending on stdout (the pipe
+    /// can't be reused) and let the next command respawn it
+    async fn discard_sidecar(state: &mut ProviderState) {
+        state.stdin = None;
+        state.stdout = None;
+        state.response_pending = false;
+        if let Some(mut child) = state.child.take() {
+            let _ = child.kill().await;
+        }
+        if state.sidecar_state == SidecarState::Running {
+            state.sidecar_state = SidecarState::Idle;
+        }
+    }
+
+    /// Cancel the in-flight command and any commands waiting behind it
+    ///
+    /// Returns true if a command was in flight. The sidecar is restarted (and
+    /// the model reloaded) on the next request.
+    pub fn cancel_inference(&self) -> bool {
+        let in_flight = self.state.try_lock().is_err();
+        if let Ok(mut token) = self.cancel_token.lock() {
+            token.cancel();
+            *token = CancellationToken::new();
+        }
+        in_flight
+    }
+
+    /// Write one command to the sidecar and read one response line
+    async fn exchange(state: &mut ProviderState, cmd: &NdjsonCommand, timeout_secs: u64) -> JarvisResult<NdjsonResponse> {
         let command_name = cmd.command.clone();
-        let mut state = self.state.lock().await;
 
         // Serialize command to JSON + newline
-        let json = serde_json::to_string(&cmd)
-            .map_err(|e| format!("Failed to serialize command: {}", e))?;
+        let json = serde_json::to_string(cmd)
+            .map_err(|e| JarvisError::Parse(format!("Failed to serialize command: {}", e)))?;
 
         // Write to stdin
         {
-            let stdin = state.stdin.as_mut().ok_or("Stdin not available")?;
+            let stdin = state.stdin.as_mut().ok_or_else(|| JarvisError::Sidecar("Stdin not available".to_string()))?;
             let write_future = async {
                 stdin.write_all(json.as_bytes()).await?;
                 stdin.write_all(b"\n").await?;
@@ -297,15 +603,15 @@ impl MlxProvider {
                 .await
                 .map_err(|_| {
                     eprintln!("MLX: Write timeout after {}s for command '{}'. The sidecar may be unresponsive.", timeout_secs, command_name);
-                    format!("Command write timeout ({}s) for '{}'", timeout_secs, command_name)
+                    JarvisError::Timeout(format!("Command write timeout ({}s) for '{}'", timeout_secs, command_name))
                 })?
-                .map_err(|e| format!("Failed to write command: {}", e))?;
+                .map_err(|e| JarvisError::Sidecar(format!("Failed to write command: {}", e)))?;
         }
 
         // Read one line from stdout
         let mut response_line = String::new();
         {
-            let stdout = state.stdout.as_mut().ok_or("Stdout not available")?;
+            let stdout = state.stdout.as_mut().ok_or_else(|| JarvisError::Sidecar("Stdout not available".to_string()))?;
             let read_future = stdout.read_line(&mut response_line);
 
             tokio::time::timeout(std::time::Duration::from_secs(timeout_secs), read_future)
@@ -316,23 +622,28 @@ impl MlxProvider {
                         For audio transcription, larger files need more time.",
                         timeout_secs, command_name
                     );
-                    format!("Command read timeout ({}s) for '{}'. Try a shorter audio file or increase timeout.", timeout_secs, command_name)
+                    JarvisError::Timeout(format!("Command read timeout ({}s) for '{}'. Try a shorter audio file or increase timeout.", timeout_secs, command_name))
                 })?
-                .map_err(|e| format!("Failed to read response: {}", e))?;
+                .map_err(|e| JarvisError::Sidecar(format!("Failed to read response: {}", e)))?;
         }
 
         if response_line.is_empty() {
             eprintln!("MLX: Sidecar closed connection (broken pipe) during command '{}'", command_name);
-            return Err("Sidecar closed connection (broken pipe)".to_string());
+            return Err(JarvisError::Sidecar("Sidecar closed connection (broken pipe)".to_string()));
         }
 
-        // Deserialize response
-        serde_json::from_str(&response_line)
-            .map_err(|e| format!("Failed to parse response: {}", e))
+        // Deserialize response; anything else on stdout (e.g. a stray print) goes to the sidecar log
+        match serde_json::from_str(&response_line) {
+            Ok(response) => Ok(response),
+            Err(e) => {
+                log_sidecar_output("stdout", &response_line).await;
+                Err(JarvisError::Parse(format!("Failed to parse response: {}", e)))
+            }
+        }
     }
 
     /// Internal availability check (sends check-availability command)
-    async fn check_availability_internal(&self) -> Result<AvailabilityResult, String> {
+    async fn check_availability_internal(&self) -> JarvisResult<AvailabilityResult> {
         let cmd = NdjsonCommand {
             command: "check-availability".to_string(),
             model_path: None,
@@ -343,6 +654,7 @@ impl MlxProvider {
             capabilities: None,
             context: None,
             messages: None,
+            focus: None,
         };
 
         let response = self.send_command(cmd, 15).await?;
@@ -361,52 +673,66 @@ impl MlxProvider {
     }
 
     /// Load a model from disk
-    async fn load_model_internal(&self, model_path: PathBuf) -> Result<(), String> {
-        // Look up model capabilities from catalog
-        // Extract model ID from path: ~/.jarvis/models/llm/Qwen3-8B-4bit → "Qwen3-8B-4bit"
-        let model_id = model_path
-            .file_name()
-            .and_then(|n| n.to_str())
-            .ok_or("Invalid model path")?;
-        
-        // Map directory name to catalog ID
-        // The catalog uses kebab-case IDs like "qwen3-8b-4bit"
-        // The directory names from HuggingFace use PascalCase like "Qwen3-8B-4bit"
-        // We need to match them by converting to lowercase and comparing
-        let capabilities = Self::lookup_capabilities(model_id);
-        
-        let cmd = NdjsonCommand {
-            command: "load-model".to_string(),
-            model_path: Some(model_path.to_string_lossy().to_string()),
-            content: None,
-            repo_id: None,
-            destination: None,
-            audio_path: None,
-            capabilities: Some(capabilities),
-            context: None,
-            messages: None,
-        };
+    async fn load_model_internal(&self, model_path: PathBuf) -> JarvisResult<()> {
+        if model_path.file_name().and_then(|n| n.to_str()).is_none() {
+            return Err(JarvisError::Validation("Invalid model path".to_string()));
+        }
+
+        let cmd = Self::load_model_command(&model_path);
+        let supports_audio = cmd
+            .capabilities
+            .as_ref()
+            .is_some_and(|caps| caps.iter().any(|c| c == "audio"));
 
         let response = self.send_command(cmd, 60).await?;
 
         if response.response_type == "error" {
-            return Err(response
+            return Err(JarvisError::Provider(response
                 .error
-                .unwrap_or_else(|| "Failed to load model".to_string()));
+                .unwrap_or_else(|| "Failed to load model".to_string())));
         }
 
         if !response.success.unwrap_or(false) {
-            return Err("Model load failed".to_string());
+            return Err(JarvisError::Provider("Model load failed".to_string()));
         }
 
         // Update state with model name
         {
             let mut state = self.state.lock().await;
             state.model_name = response.model_name;
+            state.model_path = Some(model_path);
         }
+        self.supports_audio.store(supports_audio, Ordering::Relaxed);
 
         Ok(())
     }
+
+    /// Build the load-model command for a model directory
+    fn load_model_command(model_path: &Path) -> NdjsonCommand {
+        // Look up model capabilities from catalog
+        // Extract model ID from path: ~/.jarvis/models/llm/Qwen3-8B-4bit → "Qwen3-8B-4bit"
+        // The catalog uses kebab-case IDs like "qwen3-8b-4bit"
+        // The directory names from HuggingFace use PascalCase like "Qwen3-8B-4bit"
+        // We need to match them by converting to lowercase and comparing
+        let model_id = model_path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        let capabilities = Self::lookup_capabilities(model_id);
+
+        NdjsonCommand {
+            command: "load-model".to_string(),
+            model_path: Some(model_path.to_string_lossy().to_string()),
+            content: None,
+            repo_id: None,
+            destination: None,
+            audio_path: None,
+            capabilities: Some(capabilities),
+            context: None,
+            messages: None,
+            focus: None,
+        }
+    }
     
     /// Look up model capabilities from the catalog
     /// 
@@ -444,7 +770,7 @@ impl MlxProvider {
     }
 
     /// Switch to a different model
-    pub async fn switch_model(&self, model_path: PathBuf) -> Result<(), String> {
+    pub async fn switch_model(&self, model_path: PathBuf) -> JarvisResult<()> {
         // Save previous model name in case we need to rollback
         let previous_model = {
             let state = self.state.lock().await;
@@ -462,39 +788,15 @@ impl MlxProvider {
         }
     }
 
-    /// Shutdown the sidecar gracefully
+    /// Shutdown the sidecar gracefully (it is not respawned afterwards)
     pub async fn shutdown(&self) {
-        let cmd = NdjsonCommand {
-            command: "shutdown".to_string(),
-            model_path: None,
-            content: None,
-            repo_id: None,
-            destination: None,
-            audio_path: None,
-            capabilities: None,
-            context: None,
-            messages: None,
-        };
-
-        // Try to send shutdown command (ignore errors)
-        let _ = self.send_command(cmd, 5).await;
-
-        // Wait up to 3 seconds for graceful exit
         let mut state = self.state.lock().await;
-        if let Some(mut child) = state.child.take() {
-            let wait_future = child.wait();
-            if tokio::time::timeout(std::time::Duration::from_secs(3), wait_future)
-                .await
-                .is_err()
-            {
-                // Timeout - send SIGTERM
-                let _ = child.kill().await;
-            }
-        }
+        Self::stop_sidecar(&mut state).await;
+        state.sidecar_state = SidecarState::Stopped;
     }
 
     /// Generate tags for a single chunk
-    async fn generate_tags_chunk(&self, content: &str) -> Result<Vec<String>, String> {
+    async fn generate_tags_chunk(&self, content: &str) -> JarvisResult<Vec<String>> {
         let cmd = NdjsonCommand {
             command: "generate-tags".to_string(),
             model_path: None,
@@ -505,29 +807,30 @@ impl MlxProvider {
             capabilities: None,
             context: None,
             messages: None,
+            focus: None,
         };
 
         let response = self.send_command(cmd, 60).await?;
 
         if response.response_type == "error" {
-            return Err(response
+            return Err(JarvisError::Provider(response
                 .error
-                .unwrap_or_else(|| "Tag generation failed".to_string()));
+                .unwrap_or_else(|| "Tag generation failed".to_string())));
         }
 
         let tags = response
             .tags
-            .ok_or_else(|| "No tags in response".to_string())?;
+            .ok_or_else(|| JarvisError::Provider("No tags in response".to_string()))?;
 
         if tags.is_empty() {
-            return Err("Model returned no tags".to_string());
+            return Err(JarvisError::Provider("Model returned no tags".to_string()));
         }
 
         Ok(tags)
     }
 
     /// Summarize a single chunk
-    async fn summarize_chunk(&self, content: &str) -> Result<String, String> {
+    async fn summarize_chunk(&self, content: &str) -> JarvisResult<String> {
         let cmd = NdjsonCommand {
             command: "summarize".to_string(),
             model_path: None,
@@ -538,22 +841,23 @@ impl MlxProvider {
             capabilities: None,
             context: None,
             messages: None,
+            focus: None,
         };
 
         let response = self.send_command(cmd, 60).await?;
 
         if response.response_type == "error" {
-            return Err(response
+            return Err(JarvisError::Provider(response
                 .error
-                .unwrap_or_else(|| "Summarization failed".to_string()));
+                .unwrap_or_else(|| "Summarization failed".to_string())));
         }
 
         let summary = response
             .summary
-            .ok_or_else(|| "No summary in response".to_string())?;
+            .ok_or_else(|| JarvisError::Provider("No summary in response".to_string()))?;
 
         if summary.is_empty() {
-            return Err("Model returned empty summary".to_string());
+            return Err(JarvisError::Provider("Model returned empty summary".to_string()));
         }
 
         Ok(summary)
@@ -563,7 +867,7 @@ impl MlxProvider {
     ///
     /// Uses a 600s timeout to support large audio files (10+ minutes).
     /// The send_command itself also uses 600s so neither layer cuts off early.
-    async fn generate_transcript_internal(&self, audio_path: &std::path::Path) -> Result<super::provider::TranscriptResult, String> {
+    async fn generate_transcript_internal(&self, audio_path: &std::path::Path) -> JarvisResult<super::provider::TranscriptResult> {
         let audio_path_str = audio_path.to_string_lossy().to_string();
         eprintln!("MLX: Starting transcript generation for '{}'", audio_path_str);
 
@@ -577,6 +881,7 @@ impl MlxProvider {
             capabilities: None,
             context: None,
             messages: None,
+            focus: None,
         };
 
         // 600s (10 min) timeout for transcript generation — large audio files need time
@@ -589,11 +894,11 @@ impl MlxProvider {
         if response.response_type == "error" {
             let err = response.error.unwrap_or_else(|| "Transcript generation failed".to_string());
             eprintln!("MLX: Transcript generation error for '{}': {}", audio_path_str, err);
-            return Err(err);
+            return Err(JarvisError::Provider(err));
         }
 
-        let language = response.language.ok_or("No language in response")?;
-        let transcript = response.transcript.ok_or("No transcript in response")?;
+        let language = response.language.ok_or_else(|| JarvisError::Provider("No language in response".to_string()))?;
+        let transcript = response.transcript.ok_or_else(|| JarvisError::Provider("No transcript in response".to_string()))?;
 
         eprintln!("MLX: Transcript generation complete for '{}' (language: {})", audio_path_str, language);
         Ok(super::provider::TranscriptResult { language, transcript })
@@ -606,7 +911,8 @@ impl MlxProvider {
         &self,
         audio_path: &std::path::Path,
         context: &str,
-    ) -> Result<super::provider::CoPilotCycleResult, String> {
+        focus: Option<&str>,
+    ) -> JarvisResult<super::provider::CoPilotCycleResult> {
         let audio_path_str = audio_path.to_string_lossy().to_string();
         eprintln!("MLX: Starting Co-Pilot analysis for '{}'", audio_path_str);
 
@@ -620,6 +926,7 @@ impl MlxProvider {
             capabilities: None,
             context: Some(context.to_string()),
             messages: None,
+            focus: focus.map(str::to_string),
         };
 
         // 120s timeout for Co-Pilot analysis (R11.2)
@@ -632,7 +939,7 @@ impl MlxProvider {
         if response.response_type == "error" {
             let err = response.error.unwrap_or_else(|| "Co-Pilot analysis failed".to_string());
             eprintln!("MLX: Co-Pilot analysis error for '{}': {}", audio_path_str, err);
-            return Err(err);
+            return Err(JarvisError::Provider(err));
         }
 
         // Parse response with graceful handling of missing fields (R2.7)
@@ -694,10 +1001,57 @@ impl MlxProvider {
         })
     }
     
+    /// Run a list-extraction prompt over `text` in MAX_CONTENT_CHARS chunks and
+    /// concatenate the parsed results
+    ///
+    /// Failed chunks are logged and skipped; errors only if every chunk failed.
+    async fn extract_list_in_chunks<T>(
+        &self,
+        prompt: &str,
+        text: &str,
+        task: &str,
+        parse: fn(&str) -> JarvisResult<Vec<T>>,
+    ) -> JarvisResult<Vec<T>> {
+        let chunks = split_content(text, MAX_CONTENT_CHARS);
+        if chunks.len() > 1 {
+            eprintln!(
+                "MLX: Content too large ({} chars), splitting into {} chunks for {}",
+                text.len(),
+                chunks.len(),
+                task
+            );
+        }
+
+        let mut results = Vec::new();
+        let mut last_error = None;
+        let mut succeeded = false;
+        for (i, chunk) in chunks.iter().enumerate() {
+            let raw = self.chat_internal(&[
+                ("system".to_string(), prompt.to_string()),
+                ("user".to_string(), chunk.to_string()),
+            ]).await;
+            match raw.and_then(|raw| parse(&raw)) {
+                Ok(found) => {
+                    succeeded = true;
+                    results.extend(found);
+                }
+                Err(e) => {
+                    eprintln!("MLX: Chunk {}/{} failed: {}", i + 1, chunks.len(), e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match (succeeded, last_error) {
+            (false, Some(e)) => Err(e),
+            _ => Ok(results),
+        }
+    }
+
     /// Send a multi-turn conversation to the LLM and receive a text response.
     ///
     /// Uses a 120s timeout as specified in requirements.
-    async fn chat_internal(&self, messages: &[(String, String)]) -> Result<String, String> {
+    async fn chat_internal(&self, messages: &[(String, String)]) -> JarvisResult<String> {
         eprintln!("MLX: Starting chat with {} messages", messages.len());
         
         let cmd = NdjsonCommand {
@@ -720,6 +1074,7 @@ impl MlxProvider {
                     })
                     .collect()
             ),
+            focus: None,
         };
         
         // 120s timeout for chat (as specified in requirements)
@@ -732,12 +1087,12 @@ impl MlxProvider {
         if response.response_type == "error" {
             let err = response.error.unwrap_or_else(|| "Chat failed".to_string());
             eprintln!("MLX: Chat error: {}", err);
-            return Err(err);
+            return Err(JarvisError::Provider(err));
         }
         
         let response_text = response
             .response
-            .ok_or_else(|| "No response field in chat result".to_string())?;
+            .ok_or_else(|| JarvisError::Provider("No response field in chat result".to_string()))?;
         
         eprintln!("MLX: Chat complete");
         Ok(response_text)
@@ -751,7 +1106,17 @@ impl IntelProvider for MlxProvider {
         state.availability.clone()
     }
 
-    async fn generate_tags(&self, content: &str) -> Result<Vec<String>, String> {
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            tags: true,
+            summary: true,
+            transcript: self.supports_audio.load(Ordering::Relaxed),
+            translate: false,
+            chat: true,
+        }
+    }
+
+    async fn generate_tags(&self, content: &str) -> JarvisResult<Vec<String>> {
         let chunks = split_content(content, MAX_CONTENT_CHARS);
 
         if chunks.len() == 1 {
@@ -788,13 +1153,13 @@ impl IntelProvider for MlxProvider {
         all_tags.truncate(5);
 
         if all_tags.is_empty() {
-            return Err("No tags generated from any content chunk".to_string());
+            return Err(JarvisError::Provider("No tags generated from any content chunk".to_string()));
         }
 
         Ok(all_tags)
     }
 
-    async fn summarize(&self, content: &str) -> Result<String, String> {
+    async fn summarize(&self, content: &str) -> JarvisResult<String> {
         let chunks = split_content(content, MAX_CONTENT_CHARS);
 
         if chunks.len() == 1 {
@@ -821,7 +1186,7 @@ impl IntelProvider for MlxProvider {
         }
 
         if chunk_summaries.is_empty() {
-            return Err("No summaries generated from any content chunk".to_string());
+            return Err(JarvisError::Provider("No summaries generated from any content chunk".to_string()));
         }
 
         if chunk_summaries.len() == 1 {
@@ -839,7 +1204,7 @@ impl IntelProvider for MlxProvider {
         }
     }
 
-    async fn generate_transcript(&self, audio_path: &std::path::Path) -> Result<super::provider::TranscriptResult, String> {
+    async fn generate_transcript(&self, audio_path: &std::path::Path) -> JarvisResult<super::provider::TranscriptResult> {
         self.generate_transcript_internal(audio_path).await
     }
     
@@ -847,14 +1212,220 @@ impl IntelProvider for MlxProvider {
         &self,
         audio_path: &std::path::Path,
         context: &str,
-    ) -> Result<super::provider::CoPilotCycleResult, String> {
-        self.copilot_analyze_internal(audio_path, context).await
+        focus: Option<&str>,
+    ) -> JarvisResult<super::provider::CoPilotCycleResult> {
+        self.copilot_analyze_internal(audio_path, context, focus).await
     }
     
     async fn chat(
         &self,
         messages: &[(String, String)],
-    ) -> Result<String, String> {
+    ) -> JarvisResult<String> {
         self.chat_internal(messages).await
     }
+
+    async fn answer(&self, question: &str, context: &str) -> JarvisResult<String> {
+        // Keep the most recent context that fits the model's window
+        let fitted = tail_within_tokens(context, MAX_ANSWER_CONTEXT_TOKENS);
+        if fitted.len() < context.len() {
+            eprintln!(
+                "MLX: Answer context too large ({} chars), using the last {} chars",
+                context.len(),
+                fitted.len()
+            );
+        }
+        self.chat_internal(&[
+            ("system".to_string(), answer_system_message(fitted)),
+            ("user".to_string(), question.to_string()),
+        ])
+        .await
+    }
+
+    async fn extract_concepts(&self, text: &str) -> JarvisResult<Vec<CoPilotConcept>> {
+        let found = self
+            .extract_list_in_chunks(CONCEPTS_PROMPT, text, "concept extraction", parse_concepts)
+            .await?;
+
+        // Keep the first explanation of a term seen in several chunks
+        let mut concepts: Vec<CoPilotConcept> = Vec::new();
+        for concept in found {
+            if !concepts.iter().any(|c| c.term.eq_ignore_ascii_case(&concept.term)) {
+                concepts.push(concept);
+            }
+        }
+        Ok(concepts)
+    }
+
+    async fn extract_action_items(&self, transcript: &str) -> JarvisResult<Vec<String>> {
+        let mut items = self
+            .extract_list_in_chunks(ACTION_ITEMS_PROMPT, transcript, "action item extraction", parse_action_items)
+            .await?;
+        let mut seen = std::collections::HashSet::new();
+        items.retain(|item| seen.insert(item.to_lowercase()));
+        Ok(items)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Provider wrapping `program` as a stand-in sidecar
+    fn provider_with_fake_sidecar(program: &str, args: &[&str]) -> (MlxProvider, i32) {
+        let mut child = Command::new(program)
+            .args(args)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("failed to spawn cat");
+        let pid = child.id().expect("child has no pid") as i32;
+        let stdin = child.stdin.take().map(BufWriter::new);
+        let stdout = child.stdout.take().map(BufReader::new);
+
+        let state = ProviderState {
+            child: Some(child),
+            model_name: None,
+            availability: AvailabilityResult {
+                available: true,
+                reason: None,
+            },
+            stdin,
+            stdout,
+            python_path: "python3".to_string(),
+            model_path: None,
+            last_used: Instant::now(),
+            sidecar_state: SidecarState::Running,
+            response_pending: false,
+        };
+        let provider = MlxProvider {
+            state: Arc::new(Mutex::new(state)),
+            cancel_token: std::sync::Mutex::new(CancellationToken::new()),
+            supports_audio: AtomicBool::new(false),
+        };
+        (provider, pid)
+    }
+
+    #[test]
+    fn test_format_sidecar_log_prefixes_each_line() {
+        let entry = format_sidecar_log("2026-01-01T00:00:00Z", "stderr", "Traceback:\n  File \"server.py\"\n");
+        assert_eq!(
+            entry,
+            "[2026-01-01T00:00:00Z] [stderr] Traceback:\n[2026-01-01T00:00:00Z] [stderr]   File \"server.py\"\n"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_reaps_sidecar_process() {
+        // `cat` exits on stdin EOF, like server.py
+        let (provider, pid) = provider_with_fake_sidecar("cat", &[]);
+        // Process exists before shutdown
+        assert_eq!(unsafe { libc::kill(pid, 0) }, 0);
+
+        provider.shutdown().await;
+
+        assert_eq!(provider.sidecar_state().await, SidecarState::Stopped);
+        assert!(provider.state.lock().await.child.is_none());
+        // Reaped (not a zombie): signalling the PID fails
+        assert_eq!(unsafe { libc::kill(pid, 0) }, -1);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_inference_aborts_in_flight_command() {
+        // `sleep` never answers, so the command blocks until cancelled
+        let (provider, _pid) = provider_with_fake_sidecar("sleep", &["30"]);
+        let provider = Arc::new(provider);
+        assert!(!provider.cancel_inference(), "nothing in flight yet");
+
+        let in_flight = {
+            let provider = provider.clone();
+            tokio::spawn(async move { provider.generate_tags("some content").await })
+        };
+        tokio::time::sleep(Duration::from_millis(200)).await;
+
+        assert!(provider.cancel_inference());
+        let result = in_flight.await.unwrap();
+        assert!(matches!(result.unwrap_err(), JarvisError::Cancelled(_)));
+        assert_eq!(provider.sidecar_state().await, SidecarState::Idle);
+        assert!(provider.state.lock().await.child.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_outer_timeout_restarts_sidecar_before_next_command() {
+        let (provider, pid) = provider_with_fake_sidecar("sleep", &["30"]);
+        provider.state.lock().await.python_path = "/nonexistent/python3".to_string();
+
+        // The caller gives up while the command is still waiting for its reply
+        let result = crate::intelligence::utils::with_timeout(1, "Tag generation", provider.generate_tags("content")).await;
+        assert!(matches!(result.unwrap_err(), JarvisError::Timeout(_)));
+        assert!(provider.state.lock().await.response_pending);
+
+        // The next command must not read the abandoned reply: the sidecar is
+        // killed first (respawning then fails here, as there is no interpreter)
+        let _ = provider.generate_tags("content").await;
+        let state = provider.state.lock().await;
+        assert!(!state.response_pending);
+        assert_ne!(state.sidecar_state, SidecarState::Running);
+        assert_eq!(unsafe { libc::kill(pid, 0) }, -1);
+    }
+
+    /// Executable standing in for the Python interpreter: answers every
+    /// command with `reply`
+    fn fake_interpreter(dir: &tempfile::TempDir, reply: &str) -> String {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.path().join("python3");
+        std::fs::write(&path, format!("#!/bin/sh\nwhile read line; do echo '{}'; done\n", reply)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path.to_string_lossy().into_owned()
+    }
+
+    #[tokio::test]
+    async fn test_idle_monitor_unloads_sidecar() {
+        let (provider, pid) = provider_with_fake_sidecar("cat", &[]);
+        MlxProvider::spawn_idle_monitor(Arc::downgrade(&provider.state), Duration::from_millis(100));
+
+        tokio::time::sleep(Duration::from_millis(500)).await;
+
+        assert_eq!(provider.sidecar_state().await, SidecarState::Idle);
+        assert!(provider.state.lock().await.child.is_none());
+        assert_eq!(unsafe { libc::kill(pid, 0) }, -1);
+    }
+
+    #[tokio::test]
+    async fn test_respawn_reloads_model() {
+        let dir = tempfile::tempdir().unwrap();
+        let (provider, _pid) = provider_with_fake_sidecar("cat", &[]);
+        {
+            let mut state = provider.state.lock().await;
+            MlxProvider::stop_sidecar(&mut state).await;
+            state.sidecar_state = SidecarState::Idle;
+            state.python_path = fake_interpreter(&dir, r#"{"type":"model_loaded","success":true}"#);
+            state.model_path = Some(PathBuf::from("/models/test"));
+        }
+
+        MlxProvider::respawn_sidecar(&mut *provider.state.lock().await).await.unwrap();
+
+        assert_eq!(provider.sidecar_state().await, SidecarState::Running);
+        assert!(provider.state.lock().await.child.is_some());
+        provider.shutdown().await;
+    }
+
+    #[tokio::test]
+    async fn test_failed_reload_leaves_sidecar_failed() {
+        let dir = tempfile::tempdir().unwrap();
+        let (provider, _pid) = provider_with_fake_sidecar("cat", &[]);
+        {
+            let mut state = provider.state.lock().await;
+            MlxProvider::stop_sidecar(&mut state).await;
+            state.sidecar_state = SidecarState::Idle;
+            state.python_path = fake_interpreter(&dir, r#"{"type":"error","error":"Model not found"}"#);
+            state.model_path = Some(PathBuf::from("/models/missing"));
+        }
+
+        let err = provider.generate_tags("content").await.unwrap_err();
+        assert!(err.to_string().contains("Failed to reload model"), "{}", err);
+        let state = provider.state.lock().await;
+        assert_eq!(state.sidecar_state, SidecarState::Failed);
+        assert!(state.child.is_none());
+    }
 }
@@ -6,17 +6,21 @@ pub mod llm_model_manager;
 pub mod mlx_provider;
 pub mod noop_provider;
 pub mod queue;
+pub mod timed_provider;
+pub mod timings;
 pub mod utils;
 pub mod venv_manager;
 
-pub use provider::{AvailabilityResult, IntelProvider};
+pub use provider::{AvailabilityResult, IntelProvider, ProviderCapabilities};
 pub use intelligencekit_provider::IntelligenceKitProvider;
 pub use llm_model_manager::{LlmModelInfo, LlmModelManager};
-pub use mlx_provider::MlxProvider;
+pub use mlx_provider::{MlxProvider, SidecarState};
 pub use noop_provider::NoOpProvider;
 pub use queue::{IntelCommand, IntelQueue, IntelResponse};
+pub use timed_provider::TimedProvider;
 pub use venv_manager::VenvManager;
 
+use crate::error::JarvisError;
 use crate::settings::Settings;
 use std::sync::Arc;
 
@@ -67,7 +71,13 @@ pub async fn create_provider(
         }
         
         // Try to create MlxProvider
-        match MlxProvider::new(app_handle.clone(), model_path, python_path).await {
+        match MlxProvider::new(
+            app_handle.clone(),
+            model_path,
+            python_path,
+            settings.intelligence.sidecar_idle_seconds,
+        )
+        .await {
             Ok(provider) => {
                 eprintln!("Intelligence: MlxProvider initialized successfully with model '{}'", model_id);
                 let provider_arc = Arc::new(provider);
@@ -77,15 +87,20 @@ pub async fn create_provider(
                 eprintln!("Intelligence: Failed to initialize MlxProvider: {}", e);
                 
                 // Provide specific guidance based on error type
-                if e.contains("Python not found") {
-                    eprintln!("Intelligence: Python is not installed or not in PATH");
-                    eprintln!("Intelligence: Install Python 3.10+ or update python_path in Settings");
-                } else if e.contains("MLX dependencies not installed") {
-                    eprintln!("Intelligence: MLX Python packages are not installed");
-                    eprintln!("Intelligence: Use 'Setup MLX Environment' in Settings to auto-install");
-                } else if e.contains("model") {
-                    eprintln!("Intelligence: Model loading failed - the model may be corrupted");
-                    eprintln!("Intelligence: Try deleting and re-downloading the model in Settings");
+                match &e {
+                    JarvisError::Sidecar(msg) if msg.contains("Python not found") => {
+                        eprintln!("Intelligence: Python is not installed or not in PATH");
+                        eprintln!("Intelligence: Install Python 3.10+ or update python_path in Settings");
+                    }
+                    JarvisError::Provider(msg) if msg.contains("MLX dependencies not installed") => {
+                        eprintln!("Intelligence: MLX Python packages are not installed");
+                        eprintln!("Intelligence: Use 'Setup MLX Environment' in Settings to auto-install");
+                    }
+                    JarvisError::Provider(msg) | JarvisError::Sidecar(msg) if msg.contains("model") => {
+                        eprintln!("Intelligence: Model loading failed - the model may be corrupted");
+                        eprintln!("Intelligence: Try deleting and re-downloading the model in Settings");
+                    }
+                    _ => {}
                 }
                 
                 eprintln!("Intelligence: Falling back to IntelligenceKit");
@@ -122,7 +137,7 @@ async fn try_intelligencekit_fallback(
         Err(e) => {
             eprintln!("Intelligence: Failed to initialize IntelligenceKitProvider: {}", e);
             eprintln!("Intelligence: Using NoOpProvider (AI enrichment disabled)");
-            (Arc::new(NoOpProvider::new(e)), None)
+            (Arc::new(NoOpProvider::new(e.to_string())), None)
         }
     }
 }
@@ -2,7 +2,8 @@
 
 use async_trait::async_trait;
 
-use super::provider::{AvailabilityResult, IntelProvider};
+use super::provider::{AvailabilityResult, IntelProvider, ProviderCapabilities};
+use crate::error::{JarvisError, JarvisResult};
 
 /// No-op provider that always returns unavailable
 /// 
@@ -28,11 +29,21 @@ impl IntelProvider for NoOpProvider {
         }
     }
 
-    async fn generate_tags(&self, _content: &str) -> Result<Vec<String>, String> {
-        Err("IntelligenceKit unavailable".to_string())
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            tags: false,
+            summary: false,
+            transcript: false,
+            translate: false,
+            chat: false,
+        }
+    }
+
+    async fn generate_tags(&self, _content: &str) -> JarvisResult<Vec<String>> {
+        Err(JarvisError::Provider("IntelligenceKit unavailable".to_string()))
     }
 
-    async fn summarize(&self, _content: &str) -> Result<String, String> {
-        Err("IntelligenceKit unavailable".to_string())
+    async fn summarize(&self, _content: &str) -> JarvisResult<String> {
+        Err(JarvisError::Provider("IntelligenceKit unavailable".to_string()))
     }
 }
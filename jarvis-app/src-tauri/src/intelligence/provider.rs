@@ -3,6 +3,8 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 
+use crate::error::{JarvisError, JarvisResult};
+
 /// Result of an availability check
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AvailabilityResult {
@@ -44,6 +46,133 @@ pub struct CoPilotConcept {
     pub context: String,
 }
 
+/// Operations a provider supports
+///
+/// Lets the frontend hide actions (e.g. "Transcribe") the active provider
+/// can't perform instead of surfacing a "not supported" error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProviderCapabilities {
+    pub tags: bool,
+    pub summary: bool,
+    pub transcript: bool,
+    /// No provider translates yet; reserved so the frontend can rely on the field
+    pub translate: bool,
+    pub chat: bool,
+}
+
+/// Instructions for answering a question from supplied context
+const ANSWER_PROMPT: &str = "You are a helpful assistant. Answer questions using only the following context, \
+     giving specific answers grounded in it. Be concise and accurate. If the answer isn't in the \
+     context, say so.";
+
+/// Token budget for the context passed to `IntelProvider::answer`; providers
+/// with a fixed window (MLX) cut longer context from the start
+pub const MAX_ANSWER_CONTEXT_TOKENS: usize = 6_000;
+
+/// System message for question answering over `context`
+///
+/// Shared by `IntelProvider::answer` and multi-turn chat (`Chatbot`), which
+/// adds conversation history after it.
+pub fn answer_system_message(context: &str) -> String {
+    format!("{}\n\n--- CONTEXT ---\n{}", ANSWER_PROMPT, context)
+}
+
+/// `answer_system_message` with source-specific `instructions` between the
+/// answer prompt and the context
+pub fn answer_system_message_with(instructions: &str, context: &str) -> String {
+    format!("{}\n\n{}\n\n--- CONTEXT ---\n{}", ANSWER_PROMPT, instructions, context)
+}
+
+/// Prompt for `IntelProvider::copilot_analyze` over `context` (the running
+/// summary; empty on the first cycle) and an optional `focus`
+///
+/// The MLX sidecar builds the same prompt itself; the Co-Pilot log records
+/// this copy.
+pub fn copilot_prompt(context: &str, focus: Option<&str>) -> String {
+    let prompt = if context.is_empty() {
+        "This is the start of a conversation. Analyze the audio and provide:\n\
+         1. What was discussed\n\
+         2. Summary of the conversation\n\
+         3. Key points mentioned\n\
+         4. Any decisions made\n\
+         5. Action items identified\n\
+         6. Open questions raised\n\
+         7. Suggested questions to ask next (with reasons)\n\
+         8. Key concepts (technical terms, names, topics) with brief context\n\n\
+         Respond in JSON format.".to_string()
+    } else {
+        format!(
+            "Previous conversation summary:\n{}\n\n\
+             Analyze the new audio segment and provide:\n\
+             1. What new content was discussed\n\
+             2. Updated summary of the entire conversation so far\n\
+             3. Key points mentioned\n\
+             4. Any decisions made\n\
+             5. Action items identified\n\
+             6. Open questions raised\n\
+             7. Suggested questions to ask next (with reasons)\n\
+             8. Key concepts (technical terms, names, topics) with brief context\n\n\
+             Respond in JSON format.",
+            context
+        )
+    };
+    match focus {
+        Some(focus) => format!(
+            "Focus for this conversation: {}\n\
+             Prioritize key points and suggested questions related to this focus.\n\n{}",
+            focus, prompt
+        ),
+        None => prompt,
+    }
+}
+
+/// Instructions for `IntelProvider::extract_concepts`
+pub(crate) const CONCEPTS_PROMPT: &str = "Extract the key concepts from the text: terms, names, and ideas a reader \
+     would want in a glossary. Return ONLY a JSON array of at most 15 objects of the form \
+     {\"term\": \"...\", \"context\": \"one-sentence explanation from the text\"}.";
+
+/// Instructions for `IntelProvider::extract_action_items`
+pub(crate) const ACTION_ITEMS_PROMPT: &str = "Extract the action items from this meeting transcript: \
+     tasks someone committed to or was asked to do. Return ONLY a JSON array of strings, each a \
+     concise to-do that names the owner if mentioned. Return [] if there are none.";
+
+/// Parse the JSON array in a model response, tolerating markdown code fences
+/// and text around it. `what` names the items in error messages.
+fn parse_json_array<T: serde::de::DeserializeOwned>(raw: &str, what: &str) -> JarvisResult<Vec<T>> {
+    let start = raw.find('[');
+    let end = raw.rfind(']');
+    let json = match (start, end) {
+        (Some(start), Some(end)) if start < end => &raw[start..=end],
+        _ => return Err(JarvisError::Parse(format!("No JSON array in {} response: {}", what, raw))),
+    };
+
+    serde_json::from_str(json)
+        .map_err(|e| JarvisError::Parse(format!("Failed to parse {}: {} — raw: {}", what, e, raw)))
+}
+
+/// Parse a JSON array of `{term, context}` objects from a model response
+///
+/// Entries with an empty term are dropped.
+pub fn parse_concepts(raw: &str) -> JarvisResult<Vec<CoPilotConcept>> {
+    let concepts: Vec<CoPilotConcept> = parse_json_array(raw, "concepts")?;
+    Ok(concepts
+        .into_iter()
+        .filter(|c| !c.term.trim().is_empty())
+        .collect())
+}
+
+/// Parse a JSON array of action item strings from a model response
+///
+/// Items are trimmed and empty ones dropped.
+pub fn parse_action_items(raw: &str) -> JarvisResult<Vec<String>> {
+    let items: Vec<String> = parse_json_array(raw, "action items")?;
+    Ok(items
+        .into_iter()
+        .map(|item| item.trim().to_string())
+        .filter(|item| !item.is_empty())
+        .collect())
+}
+
 /// Backend-agnostic intelligence provider interface
 /// 
 /// This trait abstracts the intelligence backend, enabling swappable implementations
@@ -53,18 +182,33 @@ pub struct CoPilotConcept {
 pub trait IntelProvider: Send + Sync {
     /// Check if the provider is available and ready to process requests
     async fn check_availability(&self) -> AvailabilityResult;
+
+    /// Operations this provider supports
+    ///
+    /// The default covers the required methods only (tags and summary).
+    /// Providers that override `generate_transcript` or `chat` should
+    /// override this too.
+    fn capabilities(&self) -> ProviderCapabilities {
+        ProviderCapabilities {
+            tags: true,
+            summary: true,
+            transcript: false,
+            translate: false,
+            chat: false,
+        }
+    }
     
     /// Generate topic tags from content
     /// 
     /// Returns a vector of short topic strings (1-3 words each).
     /// Implementation should request 3-5 tags but accept 1-10, trimming to max 5.
     /// Returns error if model returns empty array.
-    async fn generate_tags(&self, content: &str) -> Result<Vec<String>, String>;
+    async fn generate_tags(&self, content: &str) -> JarvisResult<Vec<String>>;
     
     /// Generate a one-sentence summary from content
     /// 
     /// Returns a single sentence capturing the key idea.
-    async fn summarize(&self, content: &str) -> Result<String, String>;
+    async fn summarize(&self, content: &str) -> JarvisResult<String>;
     
     /// Generate transcript from audio file
     /// 
@@ -78,9 +222,9 @@ pub trait IntelProvider: Send + Sync {
     /// # Returns
     /// 
     /// * `Ok(TranscriptResult)` - Transcript with detected language
-    /// * `Err(String)` - Error message if transcription fails or is not supported
-    async fn generate_transcript(&self, _audio_path: &std::path::Path) -> Result<TranscriptResult, String> {
-        Err("Transcript generation not supported by this provider".to_string())
+    /// * `Err(JarvisError)` - If transcription fails or is not supported
+    async fn generate_transcript(&self, _audio_path: &std::path::Path) -> JarvisResult<TranscriptResult> {
+        Err(JarvisError::Unsupported("Transcript generation not supported by this provider".to_string()))
     }
     
     /// Analyze an audio chunk with running context for Co-Pilot
@@ -93,17 +237,20 @@ pub trait IntelProvider: Send + Sync {
     ///
     /// * `audio_path` - Path to the audio chunk file (.wav format)
     /// * `context` - Running context (previous cycle's summary, empty for first cycle)
+    /// * `focus` - Optional freeform hint (e.g., "architecture decisions") that the
+    ///   analysis should prioritize; None for general analysis
     ///
     /// # Returns
     ///
     /// * `Ok(CoPilotCycleResult)` - Structured analysis of the audio chunk
-    /// * `Err(String)` - Error message if analysis fails or is not supported
+    /// * `Err(JarvisError)` - If analysis fails or is not supported
     async fn copilot_analyze(
         &self,
         _audio_path: &std::path::Path,
         _context: &str,
-    ) -> Result<CoPilotCycleResult, String> {
-        Err("Co-Pilot analysis not supported by this provider".to_string())
+        _focus: Option<&str>,
+    ) -> JarvisResult<CoPilotCycleResult> {
+        Err(JarvisError::Unsupported("Co-Pilot analysis not supported by this provider".to_string()))
     }
     
     /// Send a multi-turn conversation to the LLM and receive a text response
@@ -118,11 +265,156 @@ pub trait IntelProvider: Send + Sync {
     /// # Returns
     ///
     /// * `Ok(String)` - The assistant's response text
-    /// * `Err(String)` - Error message if chat fails or is not supported
+    /// * `Err(JarvisError)` - If chat fails or is not supported
     async fn chat(
         &self,
         _messages: &[(String, String)],
-    ) -> Result<String, String> {
-        Err("Chat not supported by this provider".to_string())
+    ) -> JarvisResult<String> {
+        Err(JarvisError::Unsupported("Chat not supported by this provider".to_string()))
+    }
+
+    /// Answer a question using only the given context
+    ///
+    /// The default builds a system message from `context` (see
+    /// `answer_system_message`) and sends it with the question via `chat`.
+    /// Providers can override this to fit the context to their model.
+    ///
+    /// # Arguments
+    ///
+    /// * `question` - The user's question
+    /// * `context` - Material the answer should be grounded in
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The answer text
+    /// * `Err(JarvisError)` - If chat fails or is not supported
+    async fn answer(&self, question: &str, context: &str) -> JarvisResult<String> {
+        self.chat(&[
+            ("system".to_string(), answer_system_message(context)),
+            ("user".to_string(), question.to_string()),
+        ])
+        .await
+    }
+
+    /// Extract key concepts (glossary terms with a short explanation) from text
+    ///
+    /// The default asks `chat` for a JSON array and parses it with
+    /// `parse_concepts`. Providers with small context windows should override
+    /// this to process long text in chunks.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<CoPilotConcept>)` - Concepts in the order the model listed them
+    /// * `Err(JarvisError)` - If chat fails, is not supported, or the
+    ///   response isn't a JSON array (`JarvisError::Parse`)
+    async fn extract_concepts(&self, text: &str) -> JarvisResult<Vec<CoPilotConcept>> {
+        let raw = self
+            .chat(&[
+                ("system".to_string(), CONCEPTS_PROMPT.to_string()),
+                ("user".to_string(), text.to_string()),
+            ])
+            .await?;
+        parse_concepts(&raw)
+    }
+
+    /// Extract action items (to-dos) from a meeting transcript
+    ///
+    /// Same shape as `CoPilotCycleResult::action_items`. The default asks
+    /// `chat` for a JSON array of strings and parses it with `parse_action_items`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<String>)` - Action items; empty if the transcript has none
+    /// * `Err(JarvisError)` - If chat fails, is not supported, or the
+    ///   response isn't a JSON array (`JarvisError::Parse`)
+    async fn extract_action_items(&self, transcript: &str) -> JarvisResult<Vec<String>> {
+        let raw = self
+            .chat(&[
+                ("system".to_string(), ACTION_ITEMS_PROMPT.to_string()),
+                ("user".to_string(), transcript.to_string()),
+            ])
+            .await?;
+        parse_action_items(&raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Provider whose chat echoes the messages it received
+    struct EchoProvider;
+
+    #[async_trait]
+    impl IntelProvider for EchoProvider {
+        async fn check_availability(&self) -> AvailabilityResult {
+            AvailabilityResult { available: true, reason: None }
+        }
+
+        async fn generate_tags(&self, _content: &str) -> JarvisResult<Vec<String>> {
+            Ok(Vec::new())
+        }
+
+        async fn summarize(&self, _content: &str) -> JarvisResult<String> {
+            Ok(String::new())
+        }
+
+        async fn chat(&self, messages: &[(String, String)]) -> JarvisResult<String> {
+            Ok(messages
+                .iter()
+                .map(|(role, content)| format!("{}: {}", role, content))
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_default_answer_sends_context_and_question() {
+        let echoed = EchoProvider.answer("Who won?", "The blue team won.").await.unwrap();
+
+        assert!(echoed.starts_with("system: You are a helpful assistant."));
+        assert!(echoed.contains("grounded in it"));
+        assert!(echoed.contains("--- CONTEXT ---\nThe blue team won."));
+        assert!(echoed.ends_with("user: Who won?"));
+    }
+
+    #[test]
+    fn test_copilot_prompt() {
+        let first = copilot_prompt("", None);
+        assert!(first.starts_with("This is the start of a conversation."));
+
+        let later = copilot_prompt("They agreed on Friday.", Some("pricing"));
+        assert!(later.starts_with("Focus for this conversation: pricing\n"));
+        assert!(later.contains("Previous conversation summary:\nThey agreed on Friday.\n"));
+    }
+
+    #[test]
+    fn test_default_capabilities_cover_required_methods() {
+        let caps = EchoProvider.capabilities();
+
+        assert!(caps.tags && caps.summary);
+        assert!(!caps.transcript && !caps.translate && !caps.chat);
+    }
+
+    #[test]
+    fn test_parse_concepts() {
+        let raw = "Here you go:\n```json\n[{\"term\": \"VAD\", \"context\": \"Voice activity detection\"}, \
+                   {\"term\": \" \", \"context\": \"blank\"}]\n```";
+        let concepts = parse_concepts(raw).unwrap();
+        assert_eq!(concepts.len(), 1);
+        assert_eq!(concepts[0].term, "VAD");
+        assert_eq!(concepts[0].context, "Voice activity detection");
+
+        assert!(parse_concepts("no concepts").is_err());
+    }
+
+    #[test]
+    fn test_parse_action_items() {
+        let raw = "```json\n[\"Alice to send the deck\", \"  \", \" Book the room \"]\n```";
+        assert_eq!(
+            parse_action_items(raw).unwrap(),
+            vec!["Alice to send the deck".to_string(), "Book the room".to_string()]
+        );
+        assert!(parse_action_items("[]").unwrap().is_empty());
     }
 }
@@ -5,11 +5,12 @@ use std::sync::Arc;
 use tokio::sync::{mpsc, oneshot};
 
 use super::provider::{CoPilotCycleResult, IntelProvider, TranscriptResult};
+use crate::error::{JarvisError, JarvisResult};
 
 /// Request sent to the IntelQueue worker
 pub struct IntelRequest {
     pub command: IntelCommand,
-    pub reply_tx: oneshot::Sender<Result<IntelResponse, String>>,
+    pub reply_tx: oneshot::Sender<JarvisResult<IntelResponse>>,
 }
 
 /// Commands that can be submitted to the IntelQueue
@@ -24,6 +25,7 @@ pub enum IntelCommand {
     CopilotAnalyze {
         audio_path: PathBuf,
         context: String,
+        focus: Option<String>,
     },
     GenerateTags {
         content: String,
@@ -80,9 +82,9 @@ impl IntelQueue {
                             .await
                             .map(IntelResponse::Transcript)
                     }
-                    IntelCommand::CopilotAnalyze { audio_path, context } => {
+                    IntelCommand::CopilotAnalyze { audio_path, context, focus } => {
                         provider
-                            .copilot_analyze(&audio_path, &context)
+                            .copilot_analyze(&audio_path, &context, focus.as_deref())
                             .await
                             .map(IntelResponse::CopilotAnalysis)
                     }
@@ -117,17 +119,18 @@ impl IntelQueue {
     /// # Returns
     ///
     /// * `Ok(IntelResponse)` - The response from the provider
-    /// * `Err(String)` - Error if the queue is closed or the worker dropped
-    pub async fn submit(&self, command: IntelCommand) -> Result<IntelResponse, String> {
+    /// * `Err(JarvisError)` - The provider's error, or `Other` if the queue is
+    ///   closed or the worker dropped
+    pub async fn submit(&self, command: IntelCommand) -> JarvisResult<IntelResponse> {
         let (reply_tx, reply_rx) = oneshot::channel();
 
         self.tx
             .send(IntelRequest { command, reply_tx })
             .await
-            .map_err(|_| "Queue closed".to_string())?;
+            .map_err(|_| JarvisError::Other("Queue closed".to_string()))?;
 
         reply_rx
             .await
-            .map_err(|_| "Worker dropped".to_string())?
+            .map_err(|_| JarvisError::Other("Worker dropped".to_string()))?
     }
 }
@@ -0,0 +1,136 @@
+// TimedProvider - records per-call durations for the wrapped IntelProvider
+//
+// Wraps the active provider at startup so `generate_tags`, `summarize` and
+// `generate_transcript` calls feed `timings` whichever backend is in use.
+// Every other method is forwarded unchanged, so provider overrides of the
+// trait's default methods still apply.
+
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Instant;
+
+use async_trait::async_trait;
+
+use super::provider::{
+    AvailabilityResult, CoPilotConcept, CoPilotCycleResult, IntelProvider, ProviderCapabilities,
+    TranscriptResult,
+};
+use super::timings::{self, TimedOperation};
+use crate::error::JarvisResult;
+
+pub struct TimedProvider {
+    inner: Arc<dyn IntelProvider>,
+}
+
+impl TimedProvider {
+    pub fn new(inner: Arc<dyn IntelProvider>) -> Self {
+        Self { inner }
+    }
+}
+
+/// Await `call`, recording its duration (or failure) under `op`
+async fn timed<T>(op: TimedOperation, call: impl Future<Output = JarvisResult<T>>) -> JarvisResult<T> {
+    let started = Instant::now();
+    let result = call.await;
+    match &result {
+        Ok(_) => timings::record(op, started.elapsed()),
+        Err(_) => timings::record_failure(op),
+    }
+    result
+}
+
+#[async_trait]
+impl IntelProvider for TimedProvider {
+    async fn check_availability(&self) -> AvailabilityResult {
+        self.inner.check_availability().await
+    }
+
+    fn capabilities(&self) -> ProviderCapabilities {
+        self.inner.capabilities()
+    }
+
+    async fn generate_tags(&self, content: &str) -> JarvisResult<Vec<String>> {
+        timed(TimedOperation::Tags, self.inner.generate_tags(content)).await
+    }
+
+    async fn summarize(&self, content: &str) -> JarvisResult<String> {
+        timed(TimedOperation::Summarize, self.inner.summarize(content)).await
+    }
+
+    async fn generate_transcript(&self, audio_path: &Path) -> JarvisResult<TranscriptResult> {
+        timed(TimedOperation::Transcribe, self.inner.generate_transcript(audio_path)).await
+    }
+
+    async fn copilot_analyze(
+        &self,
+        audio_path: &Path,
+        context: &str,
+        focus: Option<&str>,
+    ) -> JarvisResult<CoPilotCycleResult> {
+        self.inner.copilot_analyze(audio_path, context, focus).await
+    }
+
+    async fn chat(&self, messages: &[(String, String)]) -> JarvisResult<String> {
+        self.inner.chat(messages).await
+    }
+
+    async fn answer(&self, question: &str, context: &str) -> JarvisResult<String> {
+        self.inner.answer(question, context).await
+    }
+
+    async fn extract_concepts(&self, text: &str) -> JarvisResult<Vec<CoPilotConcept>> {
+        self.inner.extract_concepts(text).await
+    }
+
+    async fn extract_action_items(&self, transcript: &str) -> JarvisResult<Vec<String>> {
+        self.inner.extract_action_items(transcript).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::JarvisError;
+
+    /// Provider whose summarize always fails
+    struct StubProvider;
+
+    #[async_trait]
+    impl IntelProvider for StubProvider {
+        async fn check_availability(&self) -> AvailabilityResult {
+            AvailabilityResult { available: true, reason: None }
+        }
+
+        async fn generate_tags(&self, _content: &str) -> JarvisResult<Vec<String>> {
+            Ok(vec!["tag".to_string()])
+        }
+
+        async fn summarize(&self, _content: &str) -> JarvisResult<String> {
+            Err(JarvisError::Provider("boom".to_string()))
+        }
+    }
+
+    fn metrics_for(op: TimedOperation) -> (u64, u64) {
+        timings::snapshot()
+            .into_iter()
+            .find(|m| m.operation == op)
+            .map(|m| (m.count, m.failures))
+            .unwrap_or_default()
+    }
+
+    #[tokio::test]
+    async fn test_records_calls_and_failures() {
+        let provider = TimedProvider::new(Arc::new(StubProvider));
+        let (tags_before, _) = metrics_for(TimedOperation::Tags);
+        let (summaries_before, failures_before) = metrics_for(TimedOperation::Summarize);
+
+        assert_eq!(provider.generate_tags("text").await.unwrap(), vec!["tag"]);
+        assert!(provider.summarize("text").await.is_err());
+
+        assert!(metrics_for(TimedOperation::Tags).0 > tags_before);
+        let (summaries, failures) = metrics_for(TimedOperation::Summarize);
+        assert!(summaries > summaries_before && failures > failures_before);
+        assert!(timings::average(TimedOperation::Tags).is_some());
+    }
+}
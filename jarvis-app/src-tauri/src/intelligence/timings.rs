@@ -0,0 +1,146 @@
+// Rolling per-item timings for AI operations
+//
+// Provider calls (`TimedProvider`) and whole-gem enrichment record how long
+// each call took. The last `WINDOW` successful samples per operation are
+// averaged for `provider_metrics` and to estimate batch jobs
+// (`estimate_batch`). Process-wide and in-memory only: metrics start empty
+// after a restart and fill in as items are processed.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use serde::Serialize;
+
+/// Samples kept per operation
+const WINDOW: usize = 20;
+
+/// Operations whose per-call time is tracked
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimedOperation {
+    /// Tags, summary and (with mlx-omni) transcript for one gem (`enrich_content`)
+    Enrich,
+
+    /// `IntelProvider::generate_tags`
+    Tags,
+
+    /// `IntelProvider::summarize`
+    Summarize,
+
+    /// `IntelProvider::generate_transcript`
+    Transcribe,
+}
+
+/// Metrics for one operation, as returned by `provider_metrics`
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationMetrics {
+    pub operation: TimedOperation,
+
+    /// Calls since the app started, successful or not
+    pub count: u64,
+
+    /// Failed calls since the app started
+    pub failures: u64,
+
+    /// Average duration of the last (up to 20) successful calls
+    pub average_ms: Option<u64>,
+
+    /// Duration of the most recent successful call
+    pub last_ms: Option<u64>,
+}
+
+#[derive(Default)]
+struct OperationStats {
+    count: u64,
+    failures: u64,
+    recent: VecDeque<Duration>,
+}
+
+static STATS: LazyLock<Mutex<HashMap<TimedOperation, OperationStats>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Record how long one successful call of `op` took
+pub fn record(op: TimedOperation, elapsed: Duration) {
+    if let Ok(mut stats) = STATS.lock() {
+        let entry = stats.entry(op).or_default();
+        entry.count += 1;
+        push_sample(&mut entry.recent, elapsed);
+    }
+}
+
+/// Record a failed call of `op`; failures don't affect the averages
+pub fn record_failure(op: TimedOperation) {
+    if let Ok(mut stats) = STATS.lock() {
+        let entry = stats.entry(op).or_default();
+        entry.count += 1;
+        entry.failures += 1;
+    }
+}
+
+/// Average time per call over recent samples, or `None` before any were recorded
+pub fn average(op: TimedOperation) -> Option<Duration> {
+    STATS.lock().ok().and_then(|stats| stats.get(&op).and_then(|s| mean(&s.recent)))
+}
+
+/// Metrics for every operation recorded so far, in a stable order
+pub fn snapshot() -> Vec<OperationMetrics> {
+    let Ok(stats) = STATS.lock() else {
+        return Vec::new();
+    };
+    [
+        TimedOperation::Enrich,
+        TimedOperation::Tags,
+        TimedOperation::Summarize,
+        TimedOperation::Transcribe,
+    ]
+    .into_iter()
+    .filter_map(|op| {
+        let s = stats.get(&op)?;
+        Some(OperationMetrics {
+            operation: op,
+            count: s.count,
+            failures: s.failures,
+            average_ms: mean(&s.recent).map(|d| d.as_millis() as u64),
+            last_ms: s.recent.back().map(|d| d.as_millis() as u64),
+        })
+    })
+    .collect()
+}
+
+fn push_sample(window: &mut VecDeque<Duration>, elapsed: Duration) {
+    if window.len() == WINDOW {
+        window.pop_front();
+    }
+    window.push_back(elapsed);
+}
+
+fn mean(window: &VecDeque<Duration>) -> Option<Duration> {
+    if window.is_empty() {
+        return None;
+    }
+    Some(window.iter().sum::<Duration>() / window.len() as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rolling_average_keeps_recent_samples() {
+        let mut window = VecDeque::new();
+        assert_eq!(mean(&window), None);
+
+        for _ in 0..WINDOW {
+            push_sample(&mut window, Duration::from_secs(10));
+        }
+        assert_eq!(mean(&window), Some(Duration::from_secs(10)));
+
+        // Old samples roll off as new ones arrive
+        for _ in 0..WINDOW / 2 {
+            push_sample(&mut window, Duration::from_secs(30));
+        }
+        assert_eq!(window.len(), WINDOW);
+        assert_eq!(mean(&window), Some(Duration::from_secs(20)));
+    }
+}
@@ -1,5 +1,41 @@
 // Shared utilities for intelligence providers
 
+use std::future::Future;
+use std::time::Duration;
+
+use crate::error::{JarvisError, JarvisResult};
+use crate::settings::IntelligenceSettings;
+
+/// Per-operation time limits for provider calls (from IntelligenceSettings)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProviderTimeouts {
+    pub tags_secs: u64,
+    pub summary_secs: u64,
+    pub transcript_secs: u64,
+}
+
+impl From<&IntelligenceSettings> for ProviderTimeouts {
+    fn from(settings: &IntelligenceSettings) -> Self {
+        Self {
+            tags_secs: settings.tags_timeout_secs,
+            summary_secs: settings.summary_timeout_secs,
+            transcript_secs: settings.transcript_timeout_secs,
+        }
+    }
+}
+
+/// Run a provider call, failing with `JarvisError::Timeout` if it takes longer
+/// than `secs` (e.g. a stalled sidecar) instead of waiting forever
+pub async fn with_timeout<T>(
+    secs: u64,
+    operation: &str,
+    call: impl Future<Output = JarvisResult<T>>,
+) -> JarvisResult<T> {
+    tokio::time::timeout(Duration::from_secs(secs), call)
+        .await
+        .unwrap_or_else(|_| Err(JarvisError::Timeout(format!("{} timed out after {}s", operation, secs))))
+}
+
 /// Snap a byte index down to the nearest valid UTF-8 char boundary.
 pub fn snap_to_char_boundary(s: &str, index: usize) -> usize {
     if index >= s.len() {
@@ -54,10 +90,197 @@ pub fn split_content(content: &str, max_chars: usize) -> Vec<&str> {
     chunks
 }
 
+/// Approximate ASCII characters per token for common LLM tokenizers.
+pub const ASCII_CHARS_PER_TOKEN: usize = 4;
+
+/// Estimate the number of LLM tokens in a text.
+///
+/// Counts ~4 ASCII characters per token and one token per non-ASCII character
+/// (CJK and other scripts tokenize far less efficiently). Errs on the high side
+/// so chunks built from it stay under the model's context window.
+pub fn estimate_tokens(text: &str) -> usize {
+    let (ascii, other) = text.chars().fold((0usize, 0usize), |(ascii, other), c| {
+        if c.is_ascii() { (ascii + 1, other) } else { (ascii, other + 1) }
+    });
+    ascii.div_ceil(ASCII_CHARS_PER_TOKEN) + other
+}
+
+/// Split text into chunks of at most `max_tokens` estimated tokens each,
+/// breaking at paragraph/line/word boundaries where possible (see `split_content`).
+pub fn chunk_text(text: &str, max_tokens: usize) -> Vec<&str> {
+    let max_tokens = max_tokens.max(1);
+    let mut chunks = Vec::new();
+    chunk_into(text, max_tokens, max_tokens * ASCII_CHARS_PER_TOKEN, &mut chunks);
+    chunks
+}
+
+/// Split by byte size, then re-split any chunk whose token estimate is still
+/// over budget (dense non-ASCII text) with a smaller size.
+fn chunk_into<'a>(text: &'a str, max_tokens: usize, max_bytes: usize, out: &mut Vec<&'a str>) {
+    // 4 bytes always holds at least one char, so split_content makes progress
+    let max_bytes = max_bytes.max(4);
+    for chunk in split_content(text, max_bytes) {
+        if max_bytes == 4 || estimate_tokens(chunk) <= max_tokens {
+            out.push(chunk);
+        } else {
+            chunk_into(chunk, max_tokens, max_bytes / 2, out);
+        }
+    }
+}
+
+/// Start of `text` within `max_tokens` estimated tokens, cut at a
+/// paragraph/line/word boundary where possible
+pub fn head_within_tokens(text: &str, max_tokens: usize) -> &str {
+    if estimate_tokens(text) <= max_tokens {
+        return text;
+    }
+    chunk_text(text, max_tokens).first().copied().unwrap_or_default()
+}
+
+/// Return the longest suffix of `text` that fits within `max_tokens`
+/// estimated tokens, starting at a paragraph/line/word boundary near the cut
+/// where possible.
+///
+/// Keeps the most recent content, which is usually most relevant for long
+/// transcripts and running summaries.
+pub fn tail_within_tokens(text: &str, max_tokens: usize) -> &str {
+    if estimate_tokens(text) <= max_tokens {
+        return text;
+    }
+
+    // Walk back from the end while the suffix still fits
+    let (mut ascii, mut other) = (0usize, 0usize);
+    let mut start = text.len();
+    for (i, c) in text.char_indices().rev() {
+        if c.is_ascii() { ascii += 1 } else { other += 1 }
+        if ascii.div_ceil(ASCII_CHARS_PER_TOKEN) + other > max_tokens {
+            break;
+        }
+        start = i;
+    }
+
+    let suffix = &text[start..];
+    if text[..start].ends_with(['\n', ' ']) {
+        return suffix;
+    }
+    // Skip the partial paragraph/line/word at the cut, giving up at most a
+    // quarter of the suffix (and 500 bytes)
+    let window = &suffix[..snap_to_char_boundary(suffix, (suffix.len() / 4).min(500))];
+    let skip = window.find("\n\n").map(|pos| pos + 2)
+        .or_else(|| window.find('\n').map(|pos| pos + 1))
+        .or_else(|| window.find(' ').map(|pos| pos + 1))
+        .unwrap_or(0);
+    &suffix[skip..]
+}
+
+/// Maximum number of bytes inspected by `detect_language`.
+/// Long transcripts are sampled from the start; a few KB is plenty for detection.
+const LANGUAGE_SAMPLE_BYTES: usize = 4_000;
+
+/// Minimum whatlang confidence for `detect_language` to return a result.
+/// whatlang's own `is_reliable()` rejects many short but clear sentences.
+const MIN_LANGUAGE_CONFIDENCE: f64 = 0.5;
+
+/// Detect the language of a text, returning an ISO 639-1 code (e.g., "en", "zh").
+/// Returns None if the text is too short or the detection confidence is low.
+pub fn detect_language(text: &str) -> Option<&'static str> {
+    let sample = &text[..snap_to_char_boundary(text, LANGUAGE_SAMPLE_BYTES)];
+    let info = whatlang::detect(sample)?;
+    if info.confidence() < MIN_LANGUAGE_CONFIDENCE {
+        return None;
+    }
+    Some(iso_639_1(info.lang()))
+}
+
+/// Map a whatlang language (ISO 639-3) to its ISO 639-1 code.
+fn iso_639_1(lang: whatlang::Lang) -> &'static str {
+    use whatlang::Lang;
+    match lang {
+        Lang::Epo => "eo",
+        Lang::Eng => "en",
+        Lang::Rus => "ru",
+        Lang::Cmn => "zh",
+        Lang::Spa => "es",
+        Lang::Por => "pt",
+        Lang::Ita => "it",
+        Lang::Ben => "bn",
+        Lang::Fra => "fr",
+        Lang::Deu => "de",
+        Lang::Ukr => "uk",
+        Lang::Kat => "ka",
+        Lang::Ara => "ar",
+        Lang::Hin => "hi",
+        Lang::Jpn => "ja",
+        Lang::Heb => "he",
+        Lang::Yid => "yi",
+        Lang::Pol => "pl",
+        Lang::Amh => "am",
+        Lang::Jav => "jv",
+        Lang::Kor => "ko",
+        Lang::Nob => "nb",
+        Lang::Dan => "da",
+        Lang::Swe => "sv",
+        Lang::Fin => "fi",
+        Lang::Tur => "tr",
+        Lang::Nld => "nl",
+        Lang::Hun => "hu",
+        Lang::Ces => "cs",
+        Lang::Ell => "el",
+        Lang::Bul => "bg",
+        Lang::Bel => "be",
+        Lang::Mar => "mr",
+        Lang::Kan => "kn",
+        Lang::Ron => "ro",
+        Lang::Slv => "sl",
+        Lang::Hrv => "hr",
+        Lang::Srp => "sr",
+        Lang::Mkd => "mk",
+        Lang::Lit => "lt",
+        Lang::Lav => "lv",
+        Lang::Est => "et",
+        Lang::Tam => "ta",
+        Lang::Vie => "vi",
+        Lang::Urd => "ur",
+        Lang::Tha => "th",
+        Lang::Guj => "gu",
+        Lang::Uzb => "uz",
+        Lang::Pan => "pa",
+        Lang::Aze => "az",
+        Lang::Ind => "id",
+        Lang::Tel => "te",
+        Lang::Pes => "fa",
+        Lang::Mal => "ml",
+        Lang::Ori => "or",
+        Lang::Mya => "my",
+        Lang::Nep => "ne",
+        Lang::Sin => "si",
+        Lang::Khm => "km",
+        Lang::Tuk => "tk",
+        Lang::Aka => "ak",
+        Lang::Zul => "zu",
+        Lang::Sna => "sn",
+        Lang::Afr => "af",
+        Lang::Lat => "la",
+        Lang::Slk => "sk",
+        Lang::Cat => "ca",
+        Lang::Tgl => "tl",
+        Lang::Hye => "hy",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[tokio::test]
+    async fn test_with_timeout() {
+        let ok = with_timeout(5, "Tag generation", async { Ok(vec!["rust".to_string()]) }).await;
+        assert_eq!(ok, Ok(vec!["rust".to_string()]));
+
+        let stalled = with_timeout(0, "Transcription", std::future::pending::<JarvisResult<()>>()).await;
+        assert_eq!(stalled, Err(JarvisError::Timeout("Transcription timed out after 0s".to_string())));
+    }
+
     #[test]
     fn test_snap_to_char_boundary() {
         let s = "Hello 世界";
@@ -103,4 +326,90 @@ mod tests {
         let chunks = split_content(&content, 1000);
         assert_eq!(chunks.join(""), content);
     }
+
+    #[test]
+    fn test_estimate_tokens() {
+        assert_eq!(estimate_tokens(""), 0);
+        assert_eq!(estimate_tokens("abcd"), 1);
+        assert_eq!(estimate_tokens("abcde"), 2);
+        // Non-ASCII characters count one token each
+        assert_eq!(estimate_tokens("世界"), 2);
+    }
+
+    #[test]
+    fn test_chunk_text_respects_token_budget() {
+        let content = "The quick brown fox jumps over the lazy dog.\n".repeat(200);
+        let chunks = chunk_text(&content, 100);
+        assert!(chunks.len() > 1);
+        assert!(chunks.iter().all(|c| estimate_tokens(c) <= 100));
+        assert_eq!(chunks.join(""), content);
+
+        // Dense non-ASCII text needs smaller byte chunks for the same budget
+        let cjk = "这是我们每周计划会议的录音。".repeat(100);
+        let chunks = chunk_text(&cjk, 50);
+        assert!(chunks.iter().all(|c| estimate_tokens(c) <= 50));
+        assert_eq!(chunks.join(""), cjk);
+    }
+
+    #[test]
+    fn test_head_within_tokens() {
+        let summary = "First point.\n\n".repeat(100);
+        let head = head_within_tokens(&summary, 50);
+        assert!(summary.starts_with(head));
+        assert!(!head.is_empty() && estimate_tokens(head) <= 50);
+
+        assert_eq!(head_within_tokens("Short summary.", 50), "Short summary.");
+    }
+
+    #[test]
+    fn test_tail_within_tokens_keeps_recent_content() {
+        let content: String = (0..500).map(|i| format!("Line {}\n", i)).collect();
+        let tail = tail_within_tokens(&content, 200);
+        assert!(estimate_tokens(tail) <= 200);
+        assert!(tail.ends_with("Line 499\n"));
+        assert!(content.ends_with(tail));
+
+        assert_eq!(tail_within_tokens("short", 200), "short");
+    }
+
+    #[test]
+    fn test_tail_within_tokens_just_over_budget_keeps_nearly_all() {
+        // 3,510 tokens against a 3,500 budget
+        let content = "word ".repeat(2_808);
+        let tail = tail_within_tokens(&content, 3_500);
+        assert!(content.ends_with(tail));
+        assert!(tail.starts_with("word "), "cut at a word boundary");
+        let tokens = estimate_tokens(tail);
+        assert!((3_490..=3_500).contains(&tokens), "kept {} tokens", tokens);
+
+        let cjk = "这是我们每周计划会议的录音。".repeat(100);
+        let tail = tail_within_tokens(&cjk, 1_000);
+        assert_eq!(estimate_tokens(tail), 1_000);
+    }
+
+    #[test]
+    fn test_detect_language_common_languages() {
+        assert_eq!(
+            detect_language("This is a recording of our weekly planning meeting where we discussed the roadmap."),
+            Some("en")
+        );
+        assert_eq!(
+            detect_language("Esta es una grabación de nuestra reunión semanal donde hablamos sobre el plan del proyecto."),
+            Some("es")
+        );
+        assert_eq!(detect_language("这是我们每周计划会议的录音，我们讨论了项目的路线图和下一步的工作。"), Some("zh"));
+    }
+
+    #[test]
+    fn test_detect_language_unreliable_returns_none() {
+        assert_eq!(detect_language(""), None);
+        assert_eq!(detect_language("ok"), None);
+    }
+
+    #[test]
+    fn test_detect_language_samples_long_text_safely() {
+        // Multi-byte content longer than the sample window must not panic
+        let content = "这是我们每周计划会议的录音。".repeat(1000);
+        assert_eq!(detect_language(&content), Some("zh"));
+    }
 }
@@ -25,7 +25,10 @@ pub async fn regenerate_gem_knowledge(
     gem_id: String,
     knowledge_store: State<'_, Arc<dyn KnowledgeStore>>,
     gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<KnowledgeEntry, String> {
+    demo_mode.ensure_writable()?;
+
     let gem = gem_store
         .get(&gem_id)
         .await?
@@ -48,3 +51,94 @@ pub async fn get_gem_knowledge_subfile(
 ) -> Result<Option<String>, String> {
     knowledge_store.get_subfile(&gem_id, &filename).await
 }
+
+/// Knowledge folder ids whose gem no longer exists, sorted
+async fn orphaned_knowledge_ids(
+    knowledge_store: &dyn KnowledgeStore,
+    gem_store: &dyn GemStore,
+) -> Result<Vec<String>, String> {
+    let mut orphaned = Vec::new();
+    for gem_id in knowledge_store.list_indexed().await? {
+        if gem_store.get(&gem_id).await?.is_none() {
+            orphaned.push(gem_id);
+        }
+    }
+    orphaned.sort();
+    Ok(orphaned)
+}
+
+/// List knowledge folders left behind by gems that no longer exist
+/// (e.g. after a failed delete or a manual DB edit)
+#[tauri::command]
+pub async fn find_orphaned_knowledge(
+    knowledge_store: State<'_, Arc<dyn KnowledgeStore>>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<String>, String> {
+    orphaned_knowledge_ids(knowledge_store.inner().as_ref(), gem_store.inner().as_ref()).await
+}
+
+/// Delete orphaned knowledge folders, returning the ids that were removed
+///
+/// A folder that fails to delete is logged and skipped, so one bad folder
+/// doesn't block the rest.
+#[tauri::command]
+pub async fn prune_orphaned_knowledge(
+    knowledge_store: State<'_, Arc<dyn KnowledgeStore>>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
+) -> Result<Vec<String>, String> {
+    demo_mode.ensure_writable()?;
+
+    let orphaned = orphaned_knowledge_ids(knowledge_store.inner().as_ref(), gem_store.inner().as_ref()).await?;
+
+    let mut pruned = Vec::new();
+    for gem_id in orphaned {
+        match knowledge_store.delete(&gem_id).await {
+            Ok(()) => pruned.push(gem_id),
+            Err(e) => eprintln!("Knowledge: Failed to prune orphaned folder {}: {}", gem_id, e),
+        }
+    }
+    Ok(pruned)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gems::{test_utils::test_gem, SqliteGemStore};
+    use crate::knowledge::local_store::LocalKnowledgeStore;
+    use crate::knowledge::store::{KnowledgeEvent, KnowledgeEventEmitter};
+    use tauri::Manager;
+
+    struct NoEvents;
+
+    impl KnowledgeEventEmitter for NoEvents {
+        fn emit_progress(&self, _event: KnowledgeEvent) {}
+    }
+
+    #[tokio::test]
+    async fn test_find_and_prune_orphaned_knowledge() {
+        let dir = tempfile::tempdir().unwrap();
+        let knowledge_store: Arc<dyn KnowledgeStore> =
+            Arc::new(LocalKnowledgeStore::new(dir.path().to_path_buf(), Arc::new(NoEvents)));
+        let gem_store: Arc<dyn GemStore> = Arc::new(SqliteGemStore::new_in_memory().unwrap());
+
+        let kept = gem_store.save(test_gem("kept")).await.unwrap();
+        knowledge_store.create(&kept).await.unwrap();
+        for orphan in ["orphan-b", "orphan-a"] {
+            knowledge_store.create(&test_gem(orphan)).await.unwrap();
+        }
+
+        let app = tauri::test::mock_app();
+        app.manage(knowledge_store.clone());
+        app.manage(gem_store);
+        app.manage(crate::commands::DemoMode::default());
+
+        let orphaned = find_orphaned_knowledge(app.state(), app.state()).await.unwrap();
+        assert_eq!(orphaned, vec!["orphan-a", "orphan-b"]);
+
+        let pruned = prune_orphaned_knowledge(app.state(), app.state(), app.state()).await.unwrap();
+        assert_eq!(pruned, orphaned);
+        assert_eq!(knowledge_store.list_indexed().await.unwrap(), vec!["kept"]);
+        assert!(find_orphaned_knowledge(app.state(), app.state()).await.unwrap().is_empty());
+    }
+}
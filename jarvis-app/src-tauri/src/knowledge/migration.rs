@@ -58,8 +58,7 @@ pub async fn check_and_run_migration(
     // Load ALL gems for migration
     // GemStore::list() returns GemPreview (truncated), we need full Gem objects
     // Strategy: list all IDs, then get() each one
-    let previews = gem_store
-        .list(10000, 0)
+    let previews = crate::gems::list_all_gems(gem_store)
         .await
         .map_err(|e| format!("Failed to list gems for migration: {}", e))?;
 
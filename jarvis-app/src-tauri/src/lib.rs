@@ -1,5 +1,7 @@
 // Module declarations
 pub mod agents;
+pub mod autosave;
+pub mod backup;
 pub mod browser;
 pub mod commands;
 pub mod error;
@@ -8,13 +10,16 @@ pub mod gems;
 pub mod intelligence;
 pub mod knowledge;
 pub mod logging;
+pub mod notifications;
 pub mod platform;
 pub mod projects;
 pub mod recording;
 pub mod search;
 pub mod settings;
 pub mod shortcuts;
+pub mod shutdown;
 pub mod transcription;
+pub mod tray;
 pub mod wav;
 
 use std::sync::{Arc, Mutex, RwLock};
@@ -26,7 +31,7 @@ use recording::RecordingManager;
 use search::{FtsResultProvider, QmdResultProvider, SearchResultProvider, TavilyProvider, CompositeSearchProvider};
 use settings::{ModelManager, SettingsManager};
 use shortcuts::ShortcutManager;
-use transcription::{TranscriptionConfig, TranscriptionManager, HybridProvider, WhisperKitProvider, TranscriptionProvider};
+use transcription::{TranscriptionConfig, TranscriptionManager};
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
@@ -42,6 +47,7 @@ pub fn run() {
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_notification::init())
+        .plugin(tauri_plugin_clipboard_manager::init())
         .setup(|app| {
             // Initialize FileManager and add to managed state
             let file_manager = FileManager::new()
@@ -56,11 +62,16 @@ pub fn run() {
             app.manage(gem_store_arc.clone());
             
             // Initialize ProjectStore with same connection
-            let project_store = projects::SqliteProjectStore::new(shared_conn)
+            let project_store = projects::SqliteProjectStore::new(shared_conn.clone())
                 .map_err(|e| format!("Failed to initialize project store: {}", e))?;
             let project_store_arc = Arc::new(project_store) as Arc<dyn projects::ProjectStore>;
             app.manage(project_store_arc.clone());
             
+            // Search history shares gems.db too
+            let search_history = search::SearchHistory::new(shared_conn)
+                .map_err(|e| format!("Failed to initialize search history: {}", e))?;
+            app.manage(Arc::new(search_history));
+            
             // Initialize Knowledge Store
             let app_data_dir = app.path().app_data_dir()
                 .map_err(|e| format!("Failed to get app data dir: {}", e))?;
@@ -92,6 +103,11 @@ pub fn run() {
                 .expect("Failed to acquire settings read lock")
                 .get();
 
+            // Route extractor requests through the configured proxy/timeouts
+            if let Err(e) = crate::browser::http::configure(&settings.network) {
+                eprintln!("Warning: {}", e);
+            }
+
             // Resolve Python path: use venv if ready, else base python from settings
             let resolved_python = venv_manager_arc.resolve_python_path(&settings.intelligence.python_path);
             eprintln!("Intelligence: Resolved python path: {}", resolved_python);
@@ -109,6 +125,9 @@ pub fn run() {
             let (intel_provider, mlx_provider) = tauri::async_runtime::block_on(async move {
                 intelligence::create_provider(app_handle, &settings, &llm_manager_for_provider, &venv_manager_for_provider).await
             });
+            // Record per-call durations for provider_metrics / estimate_batch
+            let intel_provider: Arc<dyn intelligence::IntelProvider> =
+                Arc::new(intelligence::TimedProvider::new(intel_provider));
             
             let availability = tauri::async_runtime::block_on(intel_provider.check_availability());
             if availability.available {
@@ -158,78 +177,23 @@ pub fn run() {
                 eprintln!("Transcription will be disabled. Recording will continue to work.");
                 // Don't initialize provider with invalid config
             } else {
-                // Select provider based on transcription_engine setting
-                let engine = settings.transcription.transcription_engine.as_str();
-                eprintln!("TranscriptionManager: Selected engine: {}", engine);
-                
-                // Helper closure to create and initialize HybridProvider fallback
-                let create_hybrid_fallback = || -> Option<Box<dyn TranscriptionProvider>> {
-                    let mut hybrid = HybridProvider::new(&settings.transcription, app.handle().clone());
-                    match hybrid.initialize(&transcription_config) {
-                        Ok(()) => {
-                            eprintln!("TranscriptionManager: Initialized fallback provider '{}'", hybrid.name());
-                            Some(Box::new(hybrid))
-                        }
-                        Err(e) => {
-                            eprintln!("Warning: Failed to initialize fallback HybridProvider: {}", e);
-                            None
-                        }
-                    }
-                };
-                
-                let provider: Option<Box<dyn TranscriptionProvider>> = match engine {
-                    "whisperkit" => {
-                        // Try to create WhisperKitProvider
-                        let model_name = &settings.transcription.whisperkit_model;
-                        let mut whisperkit_provider = WhisperKitProvider::new(model_name);
-                        
-                        if !whisperkit_provider.is_available() {
-                            let reason = whisperkit_provider.unavailable_reason()
-                                .unwrap_or("Unknown reason");
-                            eprintln!("Warning: WhisperKit unavailable: {}", reason);
-                            eprintln!("Falling back to whisper-rs (HybridProvider)");
-                            create_hybrid_fallback()
-                        } else {
-                            // WhisperKit is available, try to initialize it
-                            match whisperkit_provider.initialize(&transcription_config) {
-                                Ok(()) => {
-                                    eprintln!("TranscriptionManager: Initialized with provider '{}'", whisperkit_provider.name());
-                                    Some(Box::new(whisperkit_provider))
-                                }
-                                Err(e) => {
-                                    eprintln!("Warning: Failed to initialize WhisperKitProvider: {}", e);
-                                    eprintln!("Falling back to whisper-rs (HybridProvider)");
-                                    create_hybrid_fallback()
-                                }
-                            }
-                        }
+                match transcription::build_provider(
+                    &settings.transcription,
+                    app.handle(),
+                    &transcription_config,
+                ) {
+                    Ok(provider) => {
+                        let transcription_manager = TranscriptionManager::new(
+                            provider,
+                            app.handle().clone(),
+                            &settings.transcription,
+                        );
+                        app.manage(tokio::sync::Mutex::new(transcription_manager));
                     }
-                    _ => {
-                        // Default to HybridProvider (whisper-rs)
-                        let mut hybrid = HybridProvider::new(&settings.transcription, app.handle().clone());
-                        match hybrid.initialize(&transcription_config) {
-                            Ok(()) => {
-                                eprintln!("TranscriptionManager: Initialized with provider '{}'", hybrid.name());
-                                Some(Box::new(hybrid))
-                            }
-                            Err(e) => {
-                                eprintln!("Warning: Failed to initialize HybridProvider: {}", e);
-                                None
-                            }
-                        }
+                    Err(e) => {
+                        eprintln!("Warning: {}", e);
+                        eprintln!("Transcription will be disabled. Recording will continue to work.");
                     }
-                };
-                
-                // If we successfully created a provider, add TranscriptionManager to state
-                if let Some(provider) = provider {
-                    let transcription_manager = TranscriptionManager::new(
-                        provider,
-                        app.handle().clone(),
-                        settings.transcription.window_duration,
-                    );
-                    app.manage(tokio::sync::Mutex::new(transcription_manager));
-                } else {
-                    eprintln!("Transcription will be disabled. Recording will continue to work.");
                 }
             }
             
@@ -272,6 +236,15 @@ pub fn run() {
             } else {
                 eprintln!("BrowserObserver: Auto-start disabled in settings");
             }
+
+            // System tray with recording status and quick actions (non-fatal)
+            if let Err(e) = tray::setup_tray(app.handle()) {
+                eprintln!("Warning: {}", e);
+            }
+
+            // Save finished recordings as gems when auto_save_gem is enabled
+            autosave::setup_auto_save(app.handle());
+            autosave::setup_incremental_save(app.handle());
             
             // Run knowledge migration in background (non-blocking)
             let ks_clone = knowledge_store_arc.clone();
@@ -350,6 +323,8 @@ pub fn run() {
             );
             let search_provider_for_agent = search_provider.clone();
             app.manage(search_provider);
+            app.manage(search::commands::SearchRebuildState::default());
+            app.manage(commands::DemoMode::default());
             
             // ── Project Research Agent Setup ──
             let project_agent = agents::project_agent::ProjectResearchAgent::new(
@@ -366,16 +341,28 @@ pub fn run() {
         })
         .invoke_handler(tauri::generate_handler![
             commands::start_recording,
+            commands::start_recording_append,
             commands::stop_recording,
+            commands::toggle_recording,
+            commands::get_audio_level,
             commands::list_recordings,
+            commands::list_recordings_detailed,
+            commands::filter_recordings,
+            commands::verify_recording,
             commands::convert_to_wav,
             commands::delete_recording,
+            commands::concat_recordings,
             commands::check_platform_support,
             commands::open_system_settings,
             commands::get_transcript,
             commands::get_transcription_status,
+            commands::switch_transcription_engine,
+            commands::transcription_diagnostics,
             commands::get_settings,
+            commands::set_demo_mode,
+            commands::get_demo_mode,
             commands::update_settings,
+            commands::update_shortcuts,
             commands::list_models,
             commands::download_model,
             commands::cancel_download,
@@ -391,34 +378,84 @@ pub fn run() {
             commands::update_browser_settings,
             commands::list_browser_tabs,
             commands::prepare_tab_gist,
+            commands::preview_extraction,
             commands::export_gist,
             commands::save_gem,
+            commands::create_text_gem,
+            commands::import_markdown_dir,
+            commands::import_bookmarks,
             commands::list_gems,
             search::commands::search_gems,
+            search::commands::get_search_history,
+            search::commands::clear_search_history,
+            search::commands::hybrid_search_gems,
             search::commands::check_search_availability,
             search::commands::setup_semantic_search,
             search::commands::rebuild_search_index,
+            search::commands::cancel_search_index_rebuild,
+            search::commands::clear_web_search_cache,
             commands::delete_gem,
+            commands::delete_gems,
             commands::update_gem_title,
+            commands::set_gem_notes,
+            commands::set_gem_label,
+            commands::list_gems_by_label,
+            commands::mark_gem_viewed,
+            commands::list_recently_viewed,
+            commands::list_most_viewed,
+            commands::merge_recording_gems,
+            commands::compact_database,
+            commands::repair_library,
+            commands::rebuild_fts_index,
+            commands::create_backup,
+            commands::restore_backup,
             commands::get_gem,
+            commands::get_gem_thumbnail,
             commands::enrich_gem,
+            commands::enrich_gem_with_diff,
+            commands::enrich_all_pending,
+            commands::reextract_gem,
+            commands::check_gems_for_updates,
+            commands::set_gem_source_type,
+            commands::extract_key_concepts,
+            commands::extract_action_items,
+            commands::list_action_items_across_gems,
+            commands::set_action_item_done,
+            commands::export_action_items,
             commands::transcribe_gem,
             commands::transcribe_recording,
+            commands::estimate_batch,
+            commands::provider_metrics,
             commands::check_recording_gem,
             commands::check_recording_gems_batch,
             commands::save_recording_gem,
             commands::check_intel_availability,
+            commands::get_provider_capabilities,
             commands::check_mlx_dependencies,
             commands::filter_gems_by_tag,
+            commands::rename_tag,
+            commands::delete_tag,
+            commands::tag_cooccurrence,
+            commands::search_suggestions,
+            commands::search_transcripts,
+            commands::filter_gems_by_language,
+            commands::list_gem_languages,
+            commands::backfill_transcript_language,
             commands::capture_claude_conversation,
             commands::check_claude_panel,
+            commands::capture_chatgpt_conversation,
+            commands::check_chatgpt_panel,
             commands::check_accessibility_permission,
+            commands::request_accessibility_permission,
             commands::prepare_tab_gist_with_claude,
             commands::list_llm_models,
             commands::download_llm_model,
             commands::cancel_llm_download,
             commands::delete_llm_model,
             commands::switch_llm_model,
+            commands::list_api_endpoints,
+            commands::add_api_endpoint,
+            commands::set_active_api_endpoint,
             commands::setup_mlx_venv,
             commands::reset_mlx_venv,
             commands::start_copilot,
@@ -427,14 +464,27 @@ pub fn run() {
             commands::dismiss_copilot_question,
             commands::chat_with_recording,
             commands::chat_send_message,
+            commands::chat_with_library,
+            commands::chat_library_send_message,
             commands::chat_get_history,
             commands::chat_end_session,
+            commands::list_chat_sessions,
+            commands::chat_resume_session,
             commands::get_saved_transcript,
+            commands::copy_transcript,
+            commands::read_recent_logs,
+            commands::read_mlx_sidecar_log,
+            commands::list_log_files,
+            commands::run_diagnostics,
+            commands::stop_all_activity,
+            commands::calibrate_vad,
             knowledge::commands::get_gem_knowledge,
             knowledge::commands::get_gem_knowledge_assembled,
             knowledge::commands::get_gem_knowledge_subfile,
             knowledge::commands::regenerate_gem_knowledge,
             knowledge::commands::check_knowledge_availability,
+            knowledge::commands::find_orphaned_knowledge,
+            knowledge::commands::prune_orphaned_knowledge,
             projects::commands::create_project,
             projects::commands::list_projects,
             projects::commands::get_project,
@@ -442,8 +492,11 @@ pub fn run() {
             projects::commands::delete_project,
             projects::commands::add_gems_to_project,
             projects::commands::remove_gem_from_project,
+            projects::commands::move_gems_to_project,
             projects::commands::get_project_gems,
             projects::commands::get_gem_projects,
+            projects::commands::get_project_activity,
+            projects::commands::suggest_projects_for_gem,
             projects::commands::suggest_project_topics,
             projects::commands::run_project_research,
             projects::commands::get_project_summary,
@@ -459,6 +512,11 @@ pub fn run() {
             projects::commands::load_project_research_state,
             projects::commands::clear_project_research_state,
         ])
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+        .build(tauri::generate_context!())
+        .expect("error while building tauri application")
+        .run(|app_handle, event| {
+            if let tauri::RunEvent::Exit = event {
+                tauri::async_runtime::block_on(shutdown::cleanup_on_exit(app_handle));
+            }
+        });
 }
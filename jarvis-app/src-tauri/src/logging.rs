@@ -3,16 +3,42 @@
 // Creates a new log file on every app launch:
 //   ~/Library/Application Support/com.jarvis.app/logs/jarvis-2026-03-01_14-30-00.log
 //
-// Keeps last 5 log files, deletes older ones.
+// While running, rolls over to a fresh file once the current one reaches
+// MAX_LOG_FILE_BYTES or the local date changes (always-on machines).
+// Keeps the last MAX_LOG_FILES log files, deletes older ones.
 
+use serde::Serialize;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, Read, Seek, SeekFrom, Write};
 use std::os::unix::io::FromRawFd;
-use std::path::PathBuf;
-use std::sync::Once;
+use std::path::{Path, PathBuf};
+use std::sync::{Once, RwLock};
 
 static INIT: Once = Once::new();
 
+/// Log file currently being written (set once logging is initialized, updated on rollover)
+static CURRENT_LOG: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Roll over to a new log file once the current one reaches this size (10 MB)
+pub const MAX_LOG_FILE_BYTES: u64 = 10 * 1024 * 1024;
+
+/// Number of `jarvis-*.log` files to keep (older ones are deleted)
+pub const MAX_LOG_FILES: usize = 5;
+
+/// Upper bound for `read_recent_lines` so the UI never pulls a huge file
+pub const MAX_RECENT_LOG_LINES: usize = 5000;
+
+/// Metadata for one log file in the logs directory
+#[derive(Debug, Clone, Serialize)]
+pub struct LogFileInfo {
+    pub filename: String,
+    pub size_bytes: u64,
+    /// Last modified time (Unix seconds)
+    pub modified_at: u64,
+    /// True for the log file written by the running app
+    pub is_current: bool,
+}
+
 /// Initialize file logging. Call once at app startup, before any eprintln! calls.
 ///
 /// Sets up stderr to write to both the terminal AND a log file via an OS-level
@@ -30,20 +56,10 @@ fn setup_logging(logs_dir: &std::path::Path) -> Result<(), Box<dyn std::error::E
     // Create logs directory
     fs::create_dir_all(logs_dir)?;
 
-    // Rotate old logs (keep last 5)
-    rotate_logs(logs_dir, 5)?;
-
-    // Create new log file with timestamp
-    let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
-    let log_file_path = logs_dir.join(format!("jarvis-{}.log", timestamp));
-
-    // Open log file
-    let log_file = fs::OpenOptions::new()
-        .create(true)
-        .append(true)
-        .open(&log_file_path)?;
+    // Rotate old logs and create new log file with timestamp
+    let mut log_writer = LogWriter::open(logs_dir)?;
 
-    eprintln!("Logging: Writing to {}", log_file_path.display());
+    eprintln!("Logging: Writing to {}", log_writer.path.display());
 
     // Create a pipe: redirect stderr fd to write end,
     // then tee from read end to both original stderr and log file.
@@ -69,7 +85,6 @@ fn setup_logging(logs_dir: &std::path::Path) -> Result<(), Box<dyn std::error::E
     // Wrap fds into File objects for the tee thread
     let read_file = unsafe { std::fs::File::from_raw_fd(read_fd) };
     let mut original_stderr = unsafe { std::fs::File::from_raw_fd(original_stderr_fd) };
-    let mut log_writer = log_file;
 
     std::thread::spawn(move || {
         use std::io::{BufRead, BufReader};
@@ -81,8 +96,7 @@ fn setup_logging(logs_dir: &std::path::Path) -> Result<(), Box<dyn std::error::E
                     let _ = writeln!(original_stderr, "{}", line);
                     // Write to log file with timestamp
                     let ts = chrono::Local::now().format("%H:%M:%S%.3f");
-                    let _ = writeln!(log_writer, "[{}] {}", ts, line);
-                    let _ = log_writer.flush();
+                    log_writer.write_line(&format!("[{}] {}", ts, line));
                 }
                 Err(_) => break,
             }
@@ -92,6 +106,70 @@ fn setup_logging(logs_dir: &std::path::Path) -> Result<(), Box<dyn std::error::E
     Ok(())
 }
 
+/// Log file handle that rolls over by size and by day
+struct LogWriter {
+    logs_dir: PathBuf,
+    path: PathBuf,
+    file: fs::File,
+    bytes_written: u64,
+    opened_on: chrono::NaiveDate,
+}
+
+impl LogWriter {
+    /// Rotate old logs, then create a new timestamped log file
+    fn open(logs_dir: &Path) -> Result<Self, io::Error> {
+        // Keep room for the new file within MAX_LOG_FILES
+        rotate_logs(logs_dir, MAX_LOG_FILES.saturating_sub(1))?;
+
+        let now = chrono::Local::now();
+        let timestamp = now.format("%Y-%m-%d_%H-%M-%S");
+        let mut path = logs_dir.join(format!("jarvis-{}.log", timestamp));
+        // A size rollover can happen within the same second as the previous file
+        let mut suffix = 1;
+        while path.exists() {
+            path = logs_dir.join(format!("jarvis-{}-{}.log", timestamp, suffix));
+            suffix += 1;
+        }
+
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)?;
+        let bytes_written = file.metadata().map(|m| m.len()).unwrap_or(0);
+
+        if let Ok(mut current) = CURRENT_LOG.write() {
+            *current = Some(path.clone());
+        }
+
+        Ok(Self {
+            logs_dir: logs_dir.to_path_buf(),
+            path,
+            file,
+            bytes_written,
+            opened_on: now.date_naive(),
+        })
+    }
+
+    fn write_line(&mut self, line: &str) {
+        if needs_rollover(self.bytes_written, self.opened_on, chrono::Local::now().date_naive()) {
+            // On failure keep writing to the current file rather than losing logs
+            if let Ok(next) = Self::open(&self.logs_dir) {
+                *self = next;
+            }
+        }
+
+        if writeln!(self.file, "{}", line).is_ok() {
+            self.bytes_written += line.len() as u64 + 1;
+        }
+        let _ = self.file.flush();
+    }
+}
+
+/// Whether the current log file should be closed and a new one started
+fn needs_rollover(bytes_written: u64, opened_on: chrono::NaiveDate, today: chrono::NaiveDate) -> bool {
+    bytes_written >= MAX_LOG_FILE_BYTES || today != opened_on
+}
+
 /// Delete old log files, keeping the most recent `keep` files.
 fn rotate_logs(logs_dir: &std::path::Path, keep: usize) -> Result<(), io::Error> {
     let mut log_files: Vec<(PathBuf, std::time::SystemTime)> = Vec::new();
@@ -128,3 +206,125 @@ fn rotate_logs(logs_dir: &std::path::Path, keep: usize) -> Result<(), io::Error>
 pub fn logs_dir() -> Option<PathBuf> {
     dirs::data_dir().map(|d| d.join("com.jarvis.app").join("logs"))
 }
+
+/// Path of the log file for this app launch.
+///
+/// Falls back to the newest `jarvis-*.log` when file logging was not initialized.
+pub fn current_log_file(logs_dir: &Path) -> Option<PathBuf> {
+    if let Some(path) = CURRENT_LOG.read().ok().and_then(|c| c.clone()) {
+        return Some(path);
+    }
+
+    list_log_files(logs_dir).ok()?
+        .into_iter()
+        .find(|f| f.filename.starts_with("jarvis-"))
+        .map(|f| logs_dir.join(f.filename))
+}
+
+/// List `.log` files in the logs directory, newest first.
+pub fn list_log_files(logs_dir: &Path) -> Result<Vec<LogFileInfo>, io::Error> {
+    let current = CURRENT_LOG.read().ok().and_then(|c| c.clone());
+    let mut files = Vec::new();
+
+    for entry in fs::read_dir(logs_dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("log") {
+            continue;
+        }
+        let Some(filename) = path.file_name().and_then(|n| n.to_str()).map(str::to_string) else {
+            continue;
+        };
+        let metadata = entry.metadata()?;
+        let modified_at = metadata.modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| d.as_secs())
+            .unwrap_or(0);
+
+        files.push(LogFileInfo {
+            is_current: current.as_ref() == Some(&path),
+            filename,
+            size_bytes: metadata.len(),
+            modified_at,
+        });
+    }
+
+    files.sort_by(|a, b| b.modified_at.cmp(&a.modified_at).then_with(|| b.filename.cmp(&a.filename)));
+    Ok(files)
+}
+
+/// Read the last `lines` lines of a file.
+///
+/// Reads backwards in fixed-size chunks, so only the tail of a large log is
+/// loaded into memory.
+pub fn read_recent_lines(path: &Path, lines: usize) -> Result<Vec<String>, io::Error> {
+    const CHUNK_SIZE: u64 = 8 * 1024;
+
+    let mut file = fs::File::open(path)?;
+    let file_len = file.metadata()?.len();
+    let mut pos = file_len;
+    let mut tail: Vec<u8> = Vec::new();
+
+    // Stop once the buffer holds more newlines than requested lines (the extra
+    // one bounds the first, possibly partial, line), or at the start of the file
+    while pos > 0 && tail.iter().filter(|&&b| b == b'\n').count() <= lines {
+        let read_len = CHUNK_SIZE.min(pos);
+        pos -= read_len;
+        file.seek(SeekFrom::Start(pos))?;
+        let mut chunk = vec![0u8; read_len as usize];
+        file.read_exact(&mut chunk)?;
+        chunk.extend_from_slice(&tail);
+        tail = chunk;
+    }
+
+    let text = String::from_utf8_lossy(&tail);
+    let all: Vec<&str> = text.lines().collect();
+    let start = all.len().saturating_sub(lines);
+    Ok(all[start..].iter().map(|l| l.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_needs_rollover_boundaries() {
+        let day = chrono::NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let next_day = day.succ_opt().unwrap();
+
+        assert!(!needs_rollover(0, day, day));
+        assert!(!needs_rollover(MAX_LOG_FILE_BYTES - 1, day, day));
+        assert!(needs_rollover(MAX_LOG_FILE_BYTES, day, day));
+        assert!(needs_rollover(0, day, next_day));
+    }
+
+    #[test]
+    fn test_read_recent_lines_returns_tail() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("jarvis-test.log");
+        let content: String = (0..5000).map(|i| format!("line {}\n", i)).collect();
+        fs::write(&path, content).unwrap();
+
+        let lines = read_recent_lines(&path, 3).unwrap();
+        assert_eq!(lines, vec!["line 4997", "line 4998", "line 4999"]);
+
+        let all = read_recent_lines(&path, 10_000).unwrap();
+        assert_eq!(all.len(), 5000);
+        assert_eq!(all[0], "line 0");
+    }
+
+    #[test]
+    fn test_list_log_files_skips_non_logs() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("jarvis-2026-01-01_00-00-00.log"), "a").unwrap();
+        fs::write(dir.path().join("gem_save.log"), "b").unwrap();
+        fs::write(dir.path().join("notes.txt"), "c").unwrap();
+
+        let files = list_log_files(dir.path()).unwrap();
+        let mut names: Vec<&str> = files.iter().map(|f| f.filename.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["gem_save.log", "jarvis-2026-01-01_00-00-00.log"]);
+        assert!(files.iter().all(|f| !f.is_current));
+    }
+}
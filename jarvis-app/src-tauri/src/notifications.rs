@@ -0,0 +1,53 @@
+// Native notifications for background jobs
+//
+// Long-running work (model downloads, batch enrichment, background transcription)
+// finishes while the user may be elsewhere. These helpers show a native
+// notification when such a job completes, fails or is cancelled, unless the user turned
+// them off via `NotificationSettings::background_jobs`.
+
+use std::sync::{Arc, RwLock};
+use tauri::{AppHandle, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+use crate::settings::SettingsManager;
+
+/// Notify that a background job finished successfully
+///
+/// `job` is a short human-readable name (e.g., "Model download").
+pub fn notify_job_completed(app_handle: &AppHandle, job: &str, detail: &str) {
+    notify(app_handle, &format!("{} complete", job), detail);
+}
+
+/// Notify that a background job failed
+pub fn notify_job_failed(app_handle: &AppHandle, job: &str, error: &str) {
+    notify(app_handle, &format!("{} failed", job), error);
+}
+
+/// Notify that a background job was cancelled
+pub fn notify_job_cancelled(app_handle: &AppHandle, job: &str, detail: &str) {
+    notify(app_handle, &format!("{} cancelled", job), detail);
+}
+
+fn notify(app_handle: &AppHandle, title: &str, body: &str) {
+    if !background_notifications_enabled(app_handle) {
+        return;
+    }
+
+    if let Err(e) = app_handle
+        .notification()
+        .builder()
+        .title(title)
+        .body(body)
+        .show()
+    {
+        eprintln!("Notifications: Failed to show '{}': {}", title, e);
+    }
+}
+
+/// Defaults to enabled when settings are unavailable
+fn background_notifications_enabled(app_handle: &AppHandle) -> bool {
+    app_handle
+        .try_state::<Arc<RwLock<SettingsManager>>>()
+        .and_then(|manager| manager.read().ok().map(|m| m.get().notifications.background_jobs))
+        .unwrap_or(true)
+}
@@ -42,6 +42,22 @@ impl PlatformDetector {
     pub fn open_system_settings() -> Result<(), String> {
         Err("System settings not available on this platform".to_string())
     }
+
+    /// Opens the Accessibility privacy settings (macOS only)
+    /// Returns an error on non-macOS platforms
+    #[cfg(target_os = "macos")]
+    pub fn open_accessibility_settings() -> Result<(), String> {
+        std::process::Command::new("open")
+            .arg("x-apple.systempreferences:com.apple.preference.security?Privacy_Accessibility")
+            .spawn()
+            .map_err(|e| format!("Failed to open System Settings: {}", e))?;
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    pub fn open_accessibility_settings() -> Result<(), String> {
+        Err("System settings not available on this platform".to_string())
+    }
 }
 
 #[cfg(test)]
@@ -97,4 +113,12 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().contains("not available on this platform"));
     }
+
+    #[test]
+    #[cfg(not(target_os = "macos"))]
+    fn test_open_accessibility_settings_non_macos() {
+        let result = PlatformDetector::open_accessibility_settings();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().contains("not available on this platform"));
+    }
 }
@@ -11,9 +11,13 @@ pub async fn create_project(
     title: String,
     description: Option<String>,
     objective: Option<String>,
+    color: Option<String>,
     project_store: State<'_, Arc<dyn ProjectStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<Project, String> {
-    project_store.create(CreateProject { title, description, objective }).await
+    demo_mode.ensure_writable()?;
+
+    project_store.create(CreateProject { title, description, objective, color }).await
 }
 
 #[tauri::command]
@@ -32,22 +36,31 @@ pub async fn get_project(
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn update_project(
     id: String,
     title: Option<String>,
     description: Option<String>,
     objective: Option<String>,
     status: Option<String>,
+    color: Option<String>,
+    cover_gem_id: Option<String>,
     project_store: State<'_, Arc<dyn ProjectStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<Project, String> {
-    project_store.update(&id, UpdateProject { title, description, objective, status }).await
+    demo_mode.ensure_writable()?;
+
+    project_store.update(&id, UpdateProject { title, description, objective, status, color, cover_gem_id }).await
 }
 
 #[tauri::command]
 pub async fn delete_project(
     id: String,
     project_store: State<'_, Arc<dyn ProjectStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     project_store.delete(&id).await
 }
 
@@ -56,7 +69,10 @@ pub async fn add_gems_to_project(
     project_id: String,
     gem_ids: Vec<String>,
     project_store: State<'_, Arc<dyn ProjectStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<usize, String> {
+    demo_mode.ensure_writable()?;
+
     project_store.add_gems(&project_id, &gem_ids).await
 }
 
@@ -65,10 +81,28 @@ pub async fn remove_gem_from_project(
     project_id: String,
     gem_id: String,
     project_store: State<'_, Arc<dyn ProjectStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     project_store.remove_gem(&project_id, &gem_id).await
 }
 
+/// Move gems into `to_project`, removing them from `from_project` when given.
+/// All-or-nothing: either every association changes or none do.
+#[tauri::command]
+pub async fn move_gems_to_project(
+    gem_ids: Vec<String>,
+    from_project: Option<String>,
+    to_project: String,
+    project_store: State<'_, Arc<dyn ProjectStore>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
+) -> Result<MoveGemsResult, String> {
+    demo_mode.ensure_writable()?;
+
+    project_store.move_gems(&gem_ids, from_project.as_deref(), &to_project).await
+}
+
 #[tauri::command]
 pub async fn get_project_gems(
     project_id: String,
@@ -79,6 +113,26 @@ pub async fn get_project_gems(
     project_store.get_project_gems(&project_id, query.as_deref(), limit).await
 }
 
+/// Chronological feed of gems added to a project, newest first (default 50).
+#[tauri::command]
+pub async fn get_project_activity(
+    project_id: String,
+    limit: Option<usize>,
+    project_store: State<'_, Arc<dyn ProjectStore>>,
+) -> Result<Vec<ProjectActivity>, String> {
+    project_store.get_activity(&project_id, limit.unwrap_or(50)).await
+}
+
+/// Projects a gem likely belongs to, ranked by tag/domain overlap (default 3).
+#[tauri::command]
+pub async fn suggest_projects_for_gem(
+    gem_id: String,
+    limit: Option<usize>,
+    project_store: State<'_, Arc<dyn ProjectStore>>,
+) -> Result<Vec<ProjectSuggestion>, String> {
+    project_store.suggest_for_gem(&gem_id, limit.unwrap_or(3)).await
+}
+
 #[tauri::command]
 pub async fn get_gem_projects(
     gem_id: String,
@@ -169,7 +223,10 @@ pub async fn end_project_chat(
 pub async fn save_project_research_state(
     project_id: String,
     state: String,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     let data_dir = dirs::data_dir()
         .ok_or_else(|| "Failed to determine app data directory".to_string())?;
     let project_dir = data_dir
@@ -217,7 +274,10 @@ pub async fn load_project_research_state(
 #[tauri::command]
 pub async fn clear_project_research_state(
     project_id: String,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
     let data_dir = dirs::data_dir()
         .ok_or_else(|| "Failed to determine app data directory".to_string())?;
     let file_path = data_dir
@@ -242,7 +302,10 @@ pub async fn clear_project_research_state(
 pub async fn generate_project_summary_checkpoint(
     project_id: String,
     agent: State<'_, Arc<TokioMutex<ProjectResearchAgent>>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<ProjectSummaryResult, String> {
+    demo_mode.ensure_writable()?;
+
     let agent = agent.lock().await;
     let result = agent.generate_summary_checkpoint(&project_id).await?;
 
@@ -290,7 +353,10 @@ pub async fn save_project_summary_checkpoint(
     summary_content: String,
     composite_doc: String,
     agent: State<'_, Arc<TokioMutex<ProjectResearchAgent>>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<Gem, String> {
+    demo_mode.ensure_writable()?;
+
     let agent = agent.lock().await;
     agent.save_summary_checkpoint(&project_id, &summary_content, &composite_doc).await
 }
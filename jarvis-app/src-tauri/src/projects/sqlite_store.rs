@@ -1,4 +1,5 @@
 use rusqlite::Connection;
+use std::collections::HashMap;
 use std::sync::{Arc, Mutex};
 use uuid::Uuid;
 use crate::gems::GemPreview;
@@ -47,7 +48,8 @@ impl SqliteProjectStore {
                 captured_at TEXT NOT NULL,
                 ai_enrichment TEXT,
                 transcript TEXT,
-                transcript_language TEXT
+                transcript_language TEXT,
+                label TEXT
             )",
             [],
         ).map_err(|e| format!("Failed to create gems table: {}", e))?;
@@ -73,7 +75,12 @@ impl SqliteProjectStore {
     fn initialize_schema(&self) -> Result<(), String> {
         let conn = self.conn.lock()
             .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+        Self::initialize_schema_on(&conn)
+    }
 
+    /// Create or migrate the project tables on `conn` (also used after a
+    /// backup restore replaces the shared database)
+    pub(crate) fn initialize_schema_on(conn: &Connection) -> Result<(), String> {
         // Enable foreign keys (required for CASCADE)
         conn.execute("PRAGMA foreign_keys = ON", [])
             .map_err(|e| format!("Failed to enable foreign keys: {}", e))?;
@@ -103,23 +110,101 @@ impl SqliteProjectStore {
             CREATE INDEX IF NOT EXISTS idx_projects_updated ON projects(updated_at DESC);
         ").map_err(|e| format!("Failed to create projects tables: {}", e))?;
 
+        // Migration: Add color and cover_gem_id columns if they don't exist
+        let columns: Vec<String> = conn.prepare("PRAGMA table_info(projects)")
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| row.get::<_, String>(1))?
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .map_err(|e| format!("Failed to read projects columns: {}", e))?;
+        for column in ["color", "cover_gem_id"] {
+            if !columns.iter().any(|c| c == column) {
+                conn.execute(&format!("ALTER TABLE projects ADD COLUMN {} TEXT", column), [])
+                    .map_err(|e| format!("Failed to add {} column: {}", column, e))?;
+            }
+        }
+
         Ok(())
     }
 }
 
+/// Project columns in `row_to_project` order. A cover gem that has since
+/// left the project (removed, moved or deleted) reads as NULL.
+const PROJECT_COLUMNS: &str = "p.id, p.title, p.description, p.objective, p.status, p.created_at, p.updated_at, p.color,
+     CASE WHEN EXISTS (SELECT 1 FROM project_gems WHERE project_id = p.id AND gem_id = p.cover_gem_id)
+          THEN p.cover_gem_id END";
+
+/// Map the GemPreview columns (id, source_type, source_url, domain, title,
+/// author, description, content preview, captured_at, ai_enrichment,
+/// transcript_language, label), mirroring SqliteGemStore
+fn row_to_gem_preview(row: &rusqlite::Row) -> rusqlite::Result<GemPreview> {
+    let ai_enrichment: Option<String> = row.get(9)?;
+    let (tags, summary, enrichment_source) = parse_ai_enrichment(ai_enrichment.as_deref());
+
+    Ok(GemPreview {
+        id: row.get(0)?,
+        source_type: row.get(1)?,
+        source_url: row.get(2)?,
+        domain: row.get(3)?,
+        title: row.get(4)?,
+        author: row.get(5)?,
+        description: row.get(6)?,
+        content_preview: row.get(7)?,
+        captured_at: row.get(8)?,
+        tags,
+        summary,
+        enrichment_source,
+        transcript_language: row.get(10)?,
+        label: row.get(11)?,
+    })
+}
+
+/// Map `PROJECT_COLUMNS` followed by a gem count column
+fn row_to_preview(row: &rusqlite::Row) -> rusqlite::Result<ProjectPreview> {
+    let project = row_to_project(row)?;
+    Ok(ProjectPreview {
+        id: project.id,
+        title: project.title,
+        description: project.description,
+        status: project.status,
+        gem_count: row.get::<_, i64>(9)? as usize,
+        updated_at: project.updated_at,
+        color: project.color,
+        cover_gem_id: project.cover_gem_id,
+    })
+}
+
+fn row_to_project(row: &rusqlite::Row) -> rusqlite::Result<Project> {
+    Ok(Project {
+        id: row.get(0)?,
+        title: row.get(1)?,
+        description: row.get(2)?,
+        objective: row.get(3)?,
+        status: row.get(4)?,
+        created_at: row.get(5)?,
+        updated_at: row.get(6)?,
+        color: row.get(7)?,
+        cover_gem_id: row.get(8)?,
+    })
+}
+
 #[async_trait::async_trait]
 impl ProjectStore for SqliteProjectStore {
     async fn create(&self, input: CreateProject) -> Result<Project, String> {
         let conn = self.conn.lock()
             .map_err(|e| format!("Failed to acquire lock: {}", e))?;
 
+        if let Some(color) = input.color.as_deref() {
+            validate_project_color(color)?;
+        }
+
         let id = Uuid::new_v4().to_string();
         let now = chrono::Utc::now().to_rfc3339();
 
         conn.execute(
-            "INSERT INTO projects (id, title, description, objective, status, created_at, updated_at)
-             VALUES (?1, ?2, ?3, ?4, 'active', ?5, ?5)",
-            rusqlite::params![id, input.title, input.description, input.objective, now],
+            "INSERT INTO projects (id, title, description, objective, status, created_at, updated_at, color)
+             VALUES (?1, ?2, ?3, ?4, 'active', ?5, ?5, ?6)",
+            rusqlite::params![id, input.title, input.description, input.objective, now, input.color],
         ).map_err(|e| format!("Failed to create project: {}", e))?;
 
         Ok(Project {
@@ -130,6 +215,8 @@ impl ProjectStore for SqliteProjectStore {
             status: "active".to_string(),
             created_at: now.clone(),
             updated_at: now,
+            color: input.color,
+            cover_gem_id: None,
         })
     }
 
@@ -137,25 +224,15 @@ impl ProjectStore for SqliteProjectStore {
         let conn = self.conn.lock()
             .map_err(|e| format!("Failed to acquire lock: {}", e))?;
 
-        let mut stmt = conn.prepare(
-            "SELECT p.id, p.title, p.description, p.status, p.updated_at,
-                    COUNT(pg.gem_id) as gem_count
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {},
+                    (SELECT COUNT(*) FROM project_gems WHERE project_id = p.id) as gem_count
              FROM projects p
-             LEFT JOIN project_gems pg ON p.id = pg.project_id
-             GROUP BY p.id
-             ORDER BY p.updated_at DESC"
-        ).map_err(|e| format!("Failed to prepare list query: {}", e))?;
-
-        let projects = stmt.query_map([], |row| {
-            Ok(ProjectPreview {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                description: row.get(2)?,
-                status: row.get(3)?,
-                updated_at: row.get(4)?,
-                gem_count: row.get::<_, i64>(5)? as usize,
-            })
-        })
+             ORDER BY p.updated_at DESC",
+            PROJECT_COLUMNS
+        )).map_err(|e| format!("Failed to prepare list query: {}", e))?;
+
+        let projects = stmt.query_map([], row_to_preview)
         .map_err(|e| format!("Failed to query projects: {}", e))?
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Failed to collect projects: {}", e))?;
@@ -169,20 +246,9 @@ impl ProjectStore for SqliteProjectStore {
 
         // Get the project
         let project = conn.query_row(
-            "SELECT id, title, description, objective, status, created_at, updated_at
-             FROM projects WHERE id = ?1",
+            &format!("SELECT {} FROM projects p WHERE p.id = ?1", PROJECT_COLUMNS),
             rusqlite::params![id],
-            |row| {
-                Ok(Project {
-                    id: row.get(0)?,
-                    title: row.get(1)?,
-                    description: row.get(2)?,
-                    objective: row.get(3)?,
-                    status: row.get(4)?,
-                    created_at: row.get(5)?,
-                    updated_at: row.get(6)?,
-                })
-            },
+            row_to_project,
         ).map_err(|e| match e {
             rusqlite::Error::QueryReturnedNoRows => "Project not found".to_string(),
             _ => format!("Failed to get project: {}", e),
@@ -192,33 +258,14 @@ impl ProjectStore for SqliteProjectStore {
         let mut stmt = conn.prepare(
             "SELECT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
                     g.description, SUBSTR(g.content, 1, 200) as content_preview,
-                    g.captured_at, g.ai_enrichment, g.transcript_language
+                    g.captured_at, g.ai_enrichment, g.transcript_language, g.label
              FROM gems g
              INNER JOIN project_gems pg ON g.id = pg.gem_id
              WHERE pg.project_id = ?1
              ORDER BY pg.added_at DESC"
         ).map_err(|e| format!("Failed to prepare gems query: {}", e))?;
 
-        let gems = stmt.query_map(rusqlite::params![id], |row| {
-            let ai_enrichment: Option<String> = row.get(9)?;
-            let (tags, summary, enrichment_source) = parse_ai_enrichment(ai_enrichment.as_deref());
-
-            Ok(GemPreview {
-                id: row.get(0)?,
-                source_type: row.get(1)?,
-                source_url: row.get(2)?,
-                domain: row.get(3)?,
-                title: row.get(4)?,
-                author: row.get(5)?,
-                description: row.get(6)?,
-                content_preview: row.get(7)?,
-                captured_at: row.get(8)?,
-                tags,
-                summary,
-                enrichment_source,
-                transcript_language: row.get(10)?,
-            })
-        })
+        let gems = stmt.query_map(rusqlite::params![id], row_to_gem_preview)
         .map_err(|e| format!("Failed to query gems: {}", e))?
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Failed to collect gems: {}", e))?;
@@ -236,6 +283,20 @@ impl ProjectStore for SqliteProjectStore {
         let conn = self.conn.lock()
             .map_err(|e| format!("Failed to acquire lock: {}", e))?;
 
+        if let Some(color) = updates.color.as_deref().filter(|c| !c.is_empty()) {
+            validate_project_color(color)?;
+        }
+        if let Some(cover_gem_id) = updates.cover_gem_id.as_deref().filter(|g| !g.is_empty()) {
+            let in_project: bool = conn.query_row(
+                "SELECT EXISTS(SELECT 1 FROM project_gems WHERE project_id = ?1 AND gem_id = ?2)",
+                rusqlite::params![id, cover_gem_id],
+                |row| row.get(0),
+            ).map_err(|e| format!("Failed to look up cover gem: {}", e))?;
+            if !in_project {
+                return Err(format!("Cover gem {} is not in this project", cover_gem_id));
+            }
+        }
+
         let now = chrono::Utc::now().to_rfc3339();
 
         // Build dynamic UPDATE query based on which fields are Some
@@ -263,6 +324,14 @@ impl ProjectStore for SqliteProjectStore {
             params.push(Box::new(status.clone()));
             param_index += 1;
         }
+        // Empty string clears these
+        for (column, value) in [("color", &updates.color), ("cover_gem_id", &updates.cover_gem_id)] {
+            if let Some(value) = value {
+                set_clauses.push(format!("{} = ?{}", column, param_index));
+                params.push(Box::new(Some(value.clone()).filter(|v| !v.is_empty())));
+                param_index += 1;
+            }
+        }
 
         let sql = format!(
             "UPDATE projects SET {} WHERE id = ?{}",
@@ -281,20 +350,9 @@ impl ProjectStore for SqliteProjectStore {
 
         // Return updated project
         conn.query_row(
-            "SELECT id, title, description, objective, status, created_at, updated_at
-             FROM projects WHERE id = ?1",
+            &format!("SELECT {} FROM projects p WHERE p.id = ?1", PROJECT_COLUMNS),
             rusqlite::params![id],
-            |row| {
-                Ok(Project {
-                    id: row.get(0)?,
-                    title: row.get(1)?,
-                    description: row.get(2)?,
-                    objective: row.get(3)?,
-                    status: row.get(4)?,
-                    created_at: row.get(5)?,
-                    updated_at: row.get(6)?,
-                })
-            },
+            row_to_project,
         ).map_err(|e| format!("Failed to get updated project: {}", e))
     }
 
@@ -354,6 +412,59 @@ impl ProjectStore for SqliteProjectStore {
         Ok(())
     }
 
+    async fn move_gems(
+        &self,
+        gem_ids: &[String],
+        from_project: Option<&str>,
+        to_project: &str,
+    ) -> Result<MoveGemsResult, String> {
+        let mut conn = self.conn.lock()
+            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+        let tx = conn.transaction()
+            .map_err(|e| format!("Failed to start transaction: {}", e))?;
+
+        for project_id in from_project.into_iter().chain([to_project]) {
+            let exists: bool = tx.query_row(
+                "SELECT EXISTS(SELECT 1 FROM projects WHERE id = ?1)",
+                rusqlite::params![project_id],
+                |row| row.get(0),
+            ).map_err(|e| format!("Failed to look up project: {}", e))?;
+            if !exists {
+                return Err(format!("Project not found: {}", project_id));
+            }
+        }
+
+        let now = chrono::Utc::now().to_rfc3339();
+        let mut result = MoveGemsResult::default();
+
+        for gem_id in gem_ids {
+            // Moving within the same project leaves the association (and its added_at) alone
+            if let Some(from) = from_project.filter(|from| *from != to_project) {
+                result.removed += tx.execute(
+                    "DELETE FROM project_gems WHERE project_id = ?1 AND gem_id = ?2",
+                    rusqlite::params![from, gem_id],
+                ).map_err(|e| format!("Failed to remove gem from project: {}", e))?;
+            }
+
+            result.added += tx.execute(
+                "INSERT OR IGNORE INTO project_gems (project_id, gem_id, added_at)
+                 VALUES (?1, ?2, ?3)",
+                rusqlite::params![to_project, gem_id, now],
+            ).map_err(|e| format!("Failed to add gem to project: {}", e))?;
+        }
+
+        // Update project timestamps
+        for project_id in from_project.into_iter().chain([to_project]) {
+            tx.execute(
+                "UPDATE projects SET updated_at = ?1 WHERE id = ?2",
+                rusqlite::params![now, project_id],
+            ).map_err(|e| format!("Failed to update project timestamp: {}", e))?;
+        }
+
+        tx.commit().map_err(|e| format!("Failed to commit project move: {}", e))?;
+        Ok(result)
+    }
+
     async fn get_project_gems(
         &self,
         project_id: &str,
@@ -368,7 +479,7 @@ impl ProjectStore for SqliteProjectStore {
                 // Empty query — return all project gems
                 let sql = "SELECT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
                                   g.description, SUBSTR(g.content, 1, 200), g.captured_at,
-                                  g.ai_enrichment, g.transcript_language
+                                  g.ai_enrichment, g.transcript_language, g.label
                            FROM gems g
                            INNER JOIN project_gems pg ON g.id = pg.gem_id
                            WHERE pg.project_id = ?1
@@ -382,7 +493,7 @@ impl ProjectStore for SqliteProjectStore {
                 // Search within project gems using FTS5
                 let sql = "SELECT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
                                   g.description, SUBSTR(g.content, 1, 200), g.captured_at,
-                                  g.ai_enrichment, g.transcript_language
+                                  g.ai_enrichment, g.transcript_language, g.label
                            FROM gems g
                            INNER JOIN project_gems pg ON g.id = pg.gem_id
                            INNER JOIN gems_fts ON gems_fts.rowid = g.rowid
@@ -398,7 +509,7 @@ impl ProjectStore for SqliteProjectStore {
         } else {
             let sql = "SELECT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
                               g.description, SUBSTR(g.content, 1, 200), g.captured_at,
-                              g.ai_enrichment, g.transcript_language
+                              g.ai_enrichment, g.transcript_language, g.label
                        FROM gems g
                        INNER JOIN project_gems pg ON g.id = pg.gem_id
                        WHERE pg.project_id = ?1
@@ -414,26 +525,7 @@ impl ProjectStore for SqliteProjectStore {
         let mut stmt = conn.prepare(&sql)
             .map_err(|e| format!("Failed to prepare query: {}", e))?;
 
-        let gems = stmt.query_map(param_refs.as_slice(), |row| {
-            let ai_enrichment: Option<String> = row.get(9)?;
-            let (tags, summary, enrichment_source) = parse_ai_enrichment(ai_enrichment.as_deref());
-
-            Ok(GemPreview {
-                id: row.get(0)?,
-                source_type: row.get(1)?,
-                source_url: row.get(2)?,
-                domain: row.get(3)?,
-                title: row.get(4)?,
-                author: row.get(5)?,
-                description: row.get(6)?,
-                content_preview: row.get(7)?,
-                captured_at: row.get(8)?,
-                tags,
-                summary,
-                enrichment_source,
-                transcript_language: row.get(10)?,
-            })
-        })
+        let gems = stmt.query_map(param_refs.as_slice(), row_to_gem_preview)
         .map_err(|e| format!("Failed to query gems: {}", e))?
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Failed to collect gems: {}", e))?;
@@ -441,29 +533,125 @@ impl ProjectStore for SqliteProjectStore {
         Ok(gems)
     }
 
-    async fn get_gem_projects(&self, gem_id: &str) -> Result<Vec<ProjectPreview>, String> {
+    async fn get_activity(&self, project_id: &str, limit: usize) -> Result<Vec<ProjectActivity>, String> {
+        let conn = self.conn.lock()
+            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+
+        let mut stmt = conn.prepare(
+            "SELECT g.id, g.source_type, g.source_url, g.domain, g.title, g.author,
+                    g.description, SUBSTR(g.content, 1, 200), g.captured_at,
+                    g.ai_enrichment, g.transcript_language, g.label, pg.added_at
+             FROM gems g
+             INNER JOIN project_gems pg ON g.id = pg.gem_id
+             WHERE pg.project_id = ?1
+             ORDER BY pg.added_at DESC
+             LIMIT ?2"
+        ).map_err(|e| format!("Failed to prepare activity query: {}", e))?;
+
+        let activity = stmt.query_map(rusqlite::params![project_id, limit as i64], |row| {
+            Ok(ProjectActivity {
+                gem: row_to_gem_preview(row)?,
+                added_at: row.get(12)?,
+            })
+        })
+        .map_err(|e| format!("Failed to query project activity: {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Failed to collect project activity: {}", e))?;
+
+        Ok(activity)
+    }
+
+    async fn suggest_for_gem(&self, gem_id: &str, limit: usize) -> Result<Vec<ProjectSuggestion>, String> {
         let conn = self.conn.lock()
             .map_err(|e| format!("Failed to acquire lock: {}", e))?;
 
+        let (domain, ai_enrichment): (String, Option<String>) = conn.query_row(
+            "SELECT domain, ai_enrichment FROM gems WHERE id = ?1",
+            rusqlite::params![gem_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        ).map_err(|e| match e {
+            rusqlite::Error::QueryReturnedNoRows => format!("Gem not found: {}", gem_id),
+            _ => format!("Failed to get gem: {}", e),
+        })?;
+        let tags: Vec<String> = parse_ai_enrichment(ai_enrichment.as_deref()).0
+            .unwrap_or_default()
+            .iter()
+            .map(|t| t.to_lowercase())
+            .collect();
+
+        // Member gems of every candidate project (not archived, gem not already in it)
         let mut stmt = conn.prepare(
-            "SELECT p.id, p.title, p.description, p.status, p.updated_at,
+            "SELECT pg.project_id, g.domain, g.ai_enrichment
+             FROM project_gems pg
+             INNER JOIN gems g ON g.id = pg.gem_id
+             INNER JOIN projects p ON p.id = pg.project_id
+             WHERE p.status != 'archived'
+               AND pg.project_id NOT IN (SELECT project_id FROM project_gems WHERE gem_id = ?1)"
+        ).map_err(|e| format!("Failed to prepare suggestion query: {}", e))?;
+
+        let mut overlap: HashMap<String, ProjectOverlap> = HashMap::new();
+        let rows = stmt.query_map(rusqlite::params![gem_id], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?, row.get::<_, Option<String>>(2)?))
+        }).map_err(|e| format!("Failed to query project gems: {}", e))?;
+        for row in rows {
+            let (project_id, member_domain, member_enrichment) =
+                row.map_err(|e| format!("Failed to read project gem: {}", e))?;
+            let entry = overlap.entry(project_id).or_default();
+            if !domain.is_empty() && member_domain == domain {
+                entry.same_domain += 1;
+            }
+            for tag in parse_ai_enrichment(member_enrichment.as_deref()).0.unwrap_or_default() {
+                let tag = tag.to_lowercase();
+                if tags.contains(&tag) {
+                    *entry.shared_tags.entry(tag).or_default() += 1;
+                }
+            }
+        }
+        drop(stmt);
+
+        let mut ranked: Vec<(String, ProjectOverlap)> = overlap
+            .into_iter()
+            .filter(|(_, o)| o.score() > 0)
+            .collect();
+        ranked.sort_by(|(a_id, a), (b_id, b)| b.score().cmp(&a.score()).then_with(|| a_id.cmp(b_id)));
+        ranked.truncate(limit);
+
+        let mut suggestions = Vec::with_capacity(ranked.len());
+        for (project_id, overlap) in ranked {
+            let project = conn.query_row(
+                &format!(
+                    "SELECT {}, (SELECT COUNT(*) FROM project_gems WHERE project_id = p.id)
+                     FROM projects p WHERE p.id = ?1",
+                    PROJECT_COLUMNS
+                ),
+                rusqlite::params![project_id],
+                row_to_preview,
+            ).map_err(|e| format!("Failed to get project: {}", e))?;
+            suggestions.push(ProjectSuggestion {
+                project,
+                score: overlap.score(),
+                reason: overlap.reason(&tags, &domain),
+            });
+        }
+
+        Ok(suggestions)
+    }
+
+    async fn get_gem_projects(&self, gem_id: &str) -> Result<Vec<ProjectPreview>, String> {
+        let conn = self.conn.lock()
+            .map_err(|e| format!("Failed to acquire lock: {}", e))?;
+
+        let mut stmt = conn.prepare(&format!(
+            "SELECT {},
                     (SELECT COUNT(*) FROM project_gems WHERE project_id = p.id) as gem_count
              FROM projects p
              INNER JOIN project_gems pg ON p.id = pg.project_id
              WHERE pg.gem_id = ?1
-             ORDER BY p.updated_at DESC"
-        ).map_err(|e| format!("Failed to prepare query: {}", e))?;
-
-        let projects = stmt.query_map(rusqlite::params![gem_id], |row| {
-            Ok(ProjectPreview {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                description: row.get(2)?,
-                status: row.get(3)?,
-                updated_at: row.get(4)?,
-                gem_count: row.get::<_, i64>(5)? as usize,
-            })
-        })
+             ORDER BY p.updated_at DESC",
+            PROJECT_COLUMNS
+        )).map_err(|e| format!("Failed to prepare query: {}", e))?;
+
+        let projects = stmt.query_map(rusqlite::params![gem_id], row_to_preview)
         .map_err(|e| format!("Failed to query projects: {}", e))?
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Failed to collect projects: {}", e))?;
@@ -472,6 +660,40 @@ impl ProjectStore for SqliteProjectStore {
     }
 }
 
+/// How a project's gems overlap with the gem being placed
+#[derive(Default)]
+struct ProjectOverlap {
+    /// Lowercased shared tag -> number of project gems carrying it
+    shared_tags: HashMap<String, usize>,
+    /// Project gems from the gem's domain
+    same_domain: usize,
+}
+
+impl ProjectOverlap {
+    fn score(&self) -> usize {
+        self.shared_tags.values().sum::<usize>() + self.same_domain
+    }
+
+    /// Shared tags in the gem's own tag order, then the domain count
+    fn reason(&self, tags: &[String], domain: &str) -> String {
+        let mut parts = Vec::new();
+        let shared: Vec<&str> = tags
+            .iter()
+            .filter(|t| self.shared_tags.contains_key(*t))
+            .map(String::as_str)
+            .collect();
+        if !shared.is_empty() {
+            parts.push(format!("Shares tags: {}", shared.join(", ")));
+        }
+        match self.same_domain {
+            0 => {}
+            1 => parts.push(format!("1 gem from {}", domain)),
+            n => parts.push(format!("{} gems from {}", n, domain)),
+        }
+        parts.join(" · ")
+    }
+}
+
 /// Parse ai_enrichment JSON to extract tags, summary, and enrichment source.
 /// Duplicated from SqliteGemStore — consider extracting to a shared util.
 fn parse_ai_enrichment(json_str: Option<&str>) -> (Option<Vec<String>>, Option<String>, Option<String>) {
@@ -496,3 +718,131 @@ fn parse_ai_enrichment(json_str: Option<&str>) -> (Option<Vec<String>>, Option<S
 
     (tags, summary, enrichment_source)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn insert_gem(store: &SqliteProjectStore, id: &str) {
+        insert_tagged_gem(store, id, "example.com", &[]);
+    }
+
+    fn insert_tagged_gem(store: &SqliteProjectStore, id: &str, domain: &str, tags: &[&str]) {
+        store.conn.lock().unwrap().execute(
+            "INSERT INTO gems (id, source_type, source_url, domain, title, source_meta, captured_at, ai_enrichment)
+             VALUES (?1, 'Article', ?2, ?3, ?1, '{}', '2024-01-01T00:00:00Z', ?4)",
+            rusqlite::params![
+                id,
+                format!("https://{}/{}", domain, id),
+                domain,
+                serde_json::json!({ "tags": tags }).to_string(),
+            ],
+        ).unwrap();
+    }
+
+    async fn create(store: &SqliteProjectStore, title: &str) -> String {
+        store.create(CreateProject { title: title.to_string(), description: None, objective: None, color: None })
+            .await
+            .unwrap()
+            .id
+    }
+
+    #[tokio::test]
+    async fn test_move_gems() {
+        let store = SqliteProjectStore::new_in_memory().unwrap();
+        for id in ["g1", "g2", "g3"] {
+            insert_gem(&store, id);
+        }
+        let from = create(&store, "From").await;
+        let to = create(&store, "To").await;
+        store.add_gems(&from, &["g1".to_string(), "g2".to_string()]).await.unwrap();
+        store.add_gems(&to, &["g2".to_string()]).await.unwrap();
+
+        let ids: Vec<String> = ["g1", "g2", "g3"].iter().map(|s| s.to_string()).collect();
+        let result = store.move_gems(&ids, Some(&from), &to).await.unwrap();
+        assert_eq!(result, MoveGemsResult { removed: 2, added: 2 });
+        assert_eq!(store.get(&from).await.unwrap().gem_count, 0);
+        assert_eq!(store.get(&to).await.unwrap().gem_count, 3);
+
+        // Unknown destination: nothing changes
+        let err = store.move_gems(&ids, Some(&to), "missing").await.unwrap_err();
+        assert!(err.contains("missing"));
+        assert_eq!(store.get(&to).await.unwrap().gem_count, 3);
+    }
+
+    #[tokio::test]
+    async fn test_project_color_and_cover() {
+        let store = SqliteProjectStore::new_in_memory().unwrap();
+        insert_gem(&store, "g1");
+        insert_gem(&store, "g2");
+        let id = create(&store, "P").await;
+        store.add_gems(&id, &["g1".to_string()]).await.unwrap();
+
+        let update = |color: Option<&str>, cover: Option<&str>| UpdateProject {
+            title: None,
+            description: None,
+            objective: None,
+            status: None,
+            color: color.map(String::from),
+            cover_gem_id: cover.map(String::from),
+        };
+
+        let project = store.update(&id, update(Some("#3b82f6"), Some("g1"))).await.unwrap();
+        assert_eq!(project.color.as_deref(), Some("#3b82f6"));
+        assert_eq!(project.cover_gem_id.as_deref(), Some("g1"));
+        assert_eq!(store.list().await.unwrap()[0].cover_gem_id.as_deref(), Some("g1"));
+
+        assert!(store.update(&id, update(Some("blue"), None)).await.is_err());
+        assert!(store.update(&id, update(Some("#38f"), None)).await.is_err());
+        assert!(store.update(&id, update(None, Some("g2"))).await.unwrap_err().contains("not in this project"));
+
+        // Removing the cover gem from the project drops the cover
+        store.remove_gem(&id, "g1").await.unwrap();
+        assert!(store.get(&id).await.unwrap().project.cover_gem_id.is_none());
+
+        let project = store.update(&id, update(Some(""), None)).await.unwrap();
+        assert!(project.color.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_project_activity_newest_first() {
+        let store = SqliteProjectStore::new_in_memory().unwrap();
+        insert_gem(&store, "g1");
+        insert_gem(&store, "g2");
+        let id = create(&store, "P").await;
+        store.add_gems(&id, &["g1".to_string()]).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+        store.add_gems(&id, &["g2".to_string()]).await.unwrap();
+
+        let activity = store.get_activity(&id, 10).await.unwrap();
+        let ids: Vec<_> = activity.iter().map(|a| a.gem.id.as_str()).collect();
+        assert_eq!(ids, vec!["g2", "g1"]);
+        assert!(activity[0].added_at > activity[1].added_at);
+
+        assert_eq!(store.get_activity(&id, 1).await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_suggest_projects_for_gem() {
+        let store = SqliteProjectStore::new_in_memory().unwrap();
+        insert_tagged_gem(&store, "rust1", "github.com", &["Rust", "async"]);
+        insert_tagged_gem(&store, "rust2", "docs.rs", &["rust"]);
+        insert_tagged_gem(&store, "cook", "food.com", &["recipes"]);
+        insert_tagged_gem(&store, "new", "github.com", &["rust", "tokio"]);
+
+        let rust = create(&store, "Rust").await;
+        let cooking = create(&store, "Cooking").await;
+        let already = create(&store, "Already").await;
+        store.add_gems(&rust, &["rust1".to_string(), "rust2".to_string()]).await.unwrap();
+        store.add_gems(&cooking, &["cook".to_string()]).await.unwrap();
+        store.add_gems(&already, &["rust1".to_string(), "new".to_string()]).await.unwrap();
+
+        let suggestions = store.suggest_for_gem("new", 5).await.unwrap();
+        assert_eq!(suggestions.len(), 1, "no-overlap and already-containing projects are skipped");
+        assert_eq!(suggestions[0].project.id, rust);
+        assert_eq!(suggestions[0].score, 3);
+        assert_eq!(suggestions[0].reason, "Shares tags: rust · 1 gem from github.com");
+
+        assert!(store.suggest_for_gem("missing", 5).await.is_err());
+    }
+}
@@ -12,6 +12,8 @@ pub struct Project {
     pub status: String,      // "active" | "paused" | "completed" | "archived"
     pub created_at: String,  // ISO 8601
     pub updated_at: String,  // ISO 8601
+    pub color: Option<String>,        // "#RRGGBB"
+    pub cover_gem_id: Option<String>, // gem whose thumbnail is the cover; always a gem in the project
 }
 
 /// Lightweight project for list views.
@@ -23,6 +25,8 @@ pub struct ProjectPreview {
     pub status: String,
     pub gem_count: usize,
     pub updated_at: String,
+    pub color: Option<String>,
+    pub cover_gem_id: Option<String>,
 }
 
 /// Full project with associated gems.
@@ -33,21 +37,65 @@ pub struct ProjectDetail {
     pub gems: Vec<GemPreview>,
 }
 
-/// Input for creating a project.
+/// A gem's addition to a project, for the activity feed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectActivity {
+    pub gem: GemPreview,
+    pub added_at: String,  // ISO 8601
+}
+
+/// A project a gem likely belongs to, from `suggest_for_gem`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProjectSuggestion {
+    pub project: ProjectPreview,
+    /// Higher is a better match: one point per project gem sharing each of
+    /// the gem's tags, plus one per project gem from the same domain
+    pub score: usize,
+    /// Human-readable match reason, e.g. "Shares tags: rust, async · 2 gems from github.com"
+    pub reason: String,
+}
+
+/// Input for creating a project. A new project has no gems, so the cover is
+/// set later via `UpdateProject`.
 #[derive(Debug, Clone, Deserialize)]
 pub struct CreateProject {
     pub title: String,
     pub description: Option<String>,
     pub objective: Option<String>,
+    pub color: Option<String>,
 }
 
-/// Input for updating a project. Only `Some` fields are applied.
+/// Input for updating a project. Only `Some` fields are applied; for `color`
+/// and `cover_gem_id`, an empty string clears the value.
 #[derive(Debug, Clone, Deserialize)]
 pub struct UpdateProject {
     pub title: Option<String>,
     pub description: Option<String>,
     pub objective: Option<String>,
     pub status: Option<String>,
+    pub color: Option<String>,
+    pub cover_gem_id: Option<String>,
+}
+
+/// Check a project color is a `#RRGGBB` hex string.
+pub fn validate_project_color(color: &str) -> Result<(), String> {
+    let valid = color
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()));
+    if valid {
+        Ok(())
+    } else {
+        Err(format!("Invalid project color '{}': expected #RRGGBB", color))
+    }
+}
+
+/// Result of moving gems between projects.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MoveGemsResult {
+    /// Associations removed from the source project
+    pub removed: usize,
+    /// Associations newly added to the destination (already-present gems aren't counted)
+    pub added: usize,
 }
 
 /// Backend-agnostic project store.
@@ -78,6 +126,16 @@ pub trait ProjectStore: Send + Sync {
     /// Remove a single gem from a project. The gem itself is NOT deleted.
     async fn remove_gem(&self, project_id: &str, gem_id: &str) -> Result<(), String>;
 
+    /// Move gems to `to_project` in one transaction, removing them from
+    /// `from_project` when given. Fails (changing nothing) if either project
+    /// doesn't exist.
+    async fn move_gems(
+        &self,
+        gem_ids: &[String],
+        from_project: Option<&str>,
+        to_project: &str,
+    ) -> Result<MoveGemsResult, String>;
+
     /// Get gems associated with a project, with optional search and limit.
     async fn get_project_gems(
         &self,
@@ -86,6 +144,14 @@ pub trait ProjectStore: Send + Sync {
         limit: Option<usize>,
     ) -> Result<Vec<GemPreview>, String>;
 
+    /// Get the gems added to a project, most recently added first.
+    async fn get_activity(&self, project_id: &str, limit: usize) -> Result<Vec<ProjectActivity>, String>;
+
+    /// Rank projects the gem isn't in yet by tag/domain overlap with their
+    /// gems, best first. Archived projects and projects with no overlap are
+    /// left out.
+    async fn suggest_for_gem(&self, gem_id: &str, limit: usize) -> Result<Vec<ProjectSuggestion>, String>;
+
     /// Get all projects a gem belongs to.
     async fn get_gem_projects(&self, gem_id: &str) -> Result<Vec<ProjectPreview>, String>;
 }
@@ -1,5 +1,7 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
-use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, LazyLock, Mutex};
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_shell::process::{CommandChild, CommandEvent};
 use tauri_plugin_shell::ShellExt;
@@ -7,7 +9,9 @@ use chrono::Local;
 use tokio::sync::mpsc::{self, Receiver};
 use serde_json::json;
 
-use crate::transcription::{AudioRouter, TranscriptionManager};
+use crate::error::{JarvisError, JarvisResult};
+use crate::files::{has_wav_header, RecordingIntegrity};
+use crate::transcription::{AudioLevel, AudioLevelMeter, AudioRouter, PipelineStats, TranscriptionDiagnostics, TranscriptionManager};
 
 /// Manages the lifecycle of audio recording via the JarvisListen sidecar
 /// 
@@ -30,8 +34,167 @@ pub struct RecordingManager {
     
     /// Handle to the AudioRouter background task
     audio_router_task: Option<tokio::task::JoinHandle<()>>,
+
+    /// Input level meter of the current recording's AudioRouter
+    level_meter: Option<Arc<AudioLevelMeter>>,
+
+    /// Pipeline counters of the current (or most recent) recording
+    pipeline_stats: Option<Arc<PipelineStats>>,
+
+    /// Set while a `start_sample_capture` capture holds the microphone
+    sample_capture_active: Arc<AtomicBool>,
+
+    /// Held while the current recording appends to an existing file
+    append_guard: Option<RecordingAppendGuard>,
+}
+
+/// A microphone capture started by `RecordingManager::start_sample_capture`
+pub struct SampleCapture {
+    /// The capturing sidecar; the caller stops it with SIGTERM
+    pub child: CommandChild,
+
+    /// Keeps recordings from starting until dropped
+    pub guard: SampleCaptureGuard,
+}
+
+/// Marks the microphone as in use by a sample capture until dropped
+pub struct SampleCaptureGuard(Arc<AtomicBool>);
+
+impl SampleCaptureGuard {
+    /// Claim `active`, or None if another capture holds it
+    fn claim(active: &Arc<AtomicBool>) -> Option<Self> {
+        active
+            .compare_exchange(false, true, Ordering::SeqCst, Ordering::SeqCst)
+            .ok()
+            .map(|_| Self(active.clone()))
+    }
+}
+
+impl Drop for SampleCaptureGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+/// How a recording is in use
+enum RecordingUse {
+    /// Being read (e.g. transcribed) by this many readers
+    Reading(usize),
+    /// New audio is being appended
+    Appending,
+}
+
+/// Recordings currently being read or appended to
+static BUSY_RECORDINGS: LazyLock<Mutex<HashMap<String, RecordingUse>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn lock_busy_recordings() -> JarvisResult<std::sync::MutexGuard<'static, HashMap<String, RecordingUse>>> {
+    BUSY_RECORDINGS
+        .lock()
+        .map_err(|e| JarvisError::Other(format!("Failed to acquire busy recordings lock: {}", e)))
+}
+
+/// Marks a recording as being read until dropped
+///
+/// `RecordingManager::start_recording_append` refuses to append to a
+/// recording while any guard for it is alive.
+pub struct RecordingReadGuard {
+    filename: String,
+}
+
+impl RecordingReadGuard {
+    /// Claim `filename` for reading; fails while audio is being appended to it
+    pub fn acquire(filename: &str) -> JarvisResult<Self> {
+        let mut busy = lock_busy_recordings()?;
+        match busy.entry(filename.to_string()).or_insert(RecordingUse::Reading(0)) {
+            RecordingUse::Reading(count) => *count += 1,
+            RecordingUse::Appending => {
+                return Err(JarvisError::Validation(format!(
+                    "Recording {} is being appended to; try again when it stops",
+                    filename
+                )));
+            }
+        }
+        Ok(Self { filename: filename.to_string() })
+    }
+
+    /// Whether any read guard is held for `filename`
+    pub fn is_held(filename: &str) -> bool {
+        BUSY_RECORDINGS
+            .lock()
+            .map(|busy| matches!(busy.get(filename), Some(RecordingUse::Reading(_))))
+            .unwrap_or(false)
+    }
+}
+
+impl Drop for RecordingReadGuard {
+    fn drop(&mut self) {
+        if let Ok(mut busy) = BUSY_RECORDINGS.lock() {
+            if let Some(RecordingUse::Reading(count)) = busy.get_mut(&self.filename) {
+                *count -= 1;
+                if *count == 0 {
+                    busy.remove(&self.filename);
+                }
+            }
+        }
+    }
+}
+
+/// Marks a recording as being appended to until dropped; readers are
+/// refused meanwhile
+struct RecordingAppendGuard {
+    filename: String,
+}
+
+impl RecordingAppendGuard {
+    /// Claim `filename` for appending; fails while it's being read or
+    /// already appended to. Checked and claimed under one lock.
+    fn acquire(filename: &str) -> JarvisResult<Self> {
+        let mut busy = lock_busy_recordings()?;
+        if busy.contains_key(filename) {
+            return Err(JarvisError::Validation(format!(
+                "Recording {} is being read or transcribed; try again when it finishes",
+                filename
+            )));
+        }
+        busy.insert(filename.to_string(), RecordingUse::Appending);
+        Ok(Self { filename: filename.to_string() })
+    }
+}
+
+impl Drop for RecordingAppendGuard {
+    fn drop(&mut self) {
+        if let Ok(mut busy) = BUSY_RECORDINGS.lock() {
+            if matches!(busy.get(&self.filename), Some(RecordingUse::Appending)) {
+                busy.remove(&self.filename);
+            }
+        }
+    }
 }
 
+/// Written next to a recording's `transcript.md` when audio is appended.
+/// The old transcript stays readable, but Transcribe and Chat regenerate it
+/// instead of reusing it, and writing the new one removes the marker.
+pub const STALE_TRANSCRIPT_MARKER: &str = "transcript.stale";
+
+/// Whether `recording_dir/transcript.md` exists and covers the whole recording
+pub fn has_current_transcript(recording_dir: &Path) -> bool {
+    recording_dir.join("transcript.md").exists() && !recording_dir.join(STALE_TRANSCRIPT_MARKER).exists()
+}
+
+/// Save a newly generated `recording_dir/transcript.md` and clear the stale marker
+pub async fn write_transcript(recording_dir: &Path, transcript_md: &str) -> std::io::Result<()> {
+    tokio::fs::create_dir_all(recording_dir).await?;
+    tokio::fs::write(recording_dir.join("transcript.md"), transcript_md).await?;
+    match tokio::fs::remove_file(recording_dir.join(STALE_TRANSCRIPT_MARKER)).await {
+        Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+        _ => Ok(()),
+    }
+}
+
+/// Minimum interval between `audio-level` events (~20Hz)
+const AUDIO_LEVEL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+
 impl RecordingManager {
     /// Create a new RecordingManager instance
     /// 
@@ -55,8 +218,27 @@ impl RecordingManager {
             current_filepath: None,
             app_handle,
             audio_router_task: None,
+            level_meter: None,
+            pipeline_stats: None,
+            sample_capture_active: Arc::new(AtomicBool::new(false)),
+            append_guard: None,
         }
     }
+
+    /// Current input level of the active recording (None when idle or before audio arrives)
+    pub fn audio_level(&self) -> Option<AudioLevel> {
+        self.level_meter.as_ref()?.level()
+    }
+
+    /// Transcription pipeline health of the current or most recent recording
+    ///
+    /// All zeros if nothing has been recorded since launch.
+    pub fn transcription_diagnostics(&self) -> TranscriptionDiagnostics {
+        self.pipeline_stats
+            .as_ref()
+            .map(|stats| stats.snapshot())
+            .unwrap_or_default()
+    }
     
     /// Check if a recording is currently active
     /// 
@@ -84,6 +266,34 @@ impl RecordingManager {
     pub fn is_recording(&self) -> bool {
         self.current_child.is_some()
     }
+
+    /// Error if the microphone is taken by a recording or a sample capture
+    fn ensure_idle(&self) -> JarvisResult<()> {
+        if self.is_recording() {
+            return Err(JarvisError::ConcurrentRecording);
+        }
+        if self.sample_capture_active.load(Ordering::SeqCst) {
+            return Err(JarvisError::Validation(
+                "Microphone calibration is in progress; try again when it finishes".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Start capturing a short audio sample to `output_path` (16kHz mono PCM)
+    ///
+    /// Used for calibration: it doesn't touch the recording state, route audio
+    /// to transcription, or emit recording events. Fails while a recording or
+    /// another capture is in progress (the microphone is in use), and
+    /// recordings can't start until the returned guard is dropped. The caller
+    /// stops the returned process with SIGTERM.
+    pub fn start_sample_capture(&self, output_path: &Path) -> JarvisResult<SampleCapture> {
+        self.ensure_idle()?;
+        let guard = SampleCaptureGuard::claim(&self.sample_capture_active)
+            .ok_or_else(|| JarvisError::Validation("Microphone calibration is already in progress".to_string()))?;
+        let (_event_rx, child) = self.spawn_sidecar(output_path)?;
+        Ok(SampleCapture { child, guard })
+    }
     
     /// Get the current recording filepath
     /// 
@@ -167,7 +377,7 @@ impl RecordingManager {
     /// A `Result` containing:
     /// - `Ok((Receiver<CommandEvent>, CommandChild))` - A receiver for monitoring
     ///   sidecar events (stderr, termination) and the child process handle
-    /// - `Err(String)` - A descriptive error message if spawning fails
+    /// - `Err(JarvisError::Sidecar)` - If spawning fails
     /// 
     /// # Errors
     /// 
@@ -189,18 +399,18 @@ impl RecordingManager {
     fn spawn_sidecar(
         &self,
         output_path: &Path,
-    ) -> Result<(Receiver<CommandEvent>, CommandChild), String> {
+    ) -> JarvisResult<(Receiver<CommandEvent>, CommandChild)> {
         // Get the sidecar command from the shell plugin
         let sidecar = self
             .app_handle
             .shell()
             .sidecar("JarvisListen")
-            .map_err(|e| format!("Failed to get sidecar command: {}", e))?;
+            .map_err(|e| JarvisError::Sidecar(format!("Failed to get sidecar command: {}", e)))?;
         
         // Convert output path to string
         let output_path_str = output_path
             .to_str()
-            .ok_or_else(|| "Invalid output path: cannot convert to string".to_string())?;
+            .ok_or_else(|| JarvisError::Validation("Invalid output path: cannot convert to string".to_string()))?;
         
         // Add arguments: --mono, --sample-rate 16000, --output <filepath>
         let sidecar_with_args = sidecar.args([
@@ -214,11 +424,31 @@ impl RecordingManager {
         // Spawn the process
         let (rx, child) = sidecar_with_args
             .spawn()
-            .map_err(|e| format!("Failed to spawn sidecar process: {}", e))?;
+            .map_err(|e| JarvisError::Sidecar(format!("Failed to spawn sidecar process: {}", e)))?;
         
         Ok((rx, child))
     }
     
+    /// Emit `audio-level` events (`{ rms_dbfs, peak_dbfs }`) at most every 50ms
+    /// while the AudioRouter is routing, skipping polls with no new audio.
+    fn spawn_level_emitter(&self, level_meter: Arc<AudioLevelMeter>) {
+        let app_handle = self.app_handle.clone();
+        tauri::async_runtime::spawn(async move {
+            let mut last_update = 0;
+            while !level_meter.is_finished() {
+                tokio::time::sleep(AUDIO_LEVEL_INTERVAL).await;
+                let updates = level_meter.updates();
+                if updates == last_update {
+                    continue;
+                }
+                last_update = updates;
+                if let Some(level) = level_meter.level() {
+                    let _ = app_handle.emit("audio-level", level);
+                }
+            }
+        });
+    }
+    
     /// Monitor sidecar process events and emit appropriate Tauri events
     /// 
     /// This method spawns an async task that listens to the CommandEvent receiver
@@ -307,6 +537,7 @@ impl RecordingManager {
                                     // Clear state since process terminated unexpectedly
                                     recording_manager.current_child = None;
                                     recording_manager.current_filepath = None;
+                                    recording_manager.append_guard = None;
                                     
                                     // Emit appropriate event based on exit code
                                     if payload.code != Some(0) {
@@ -358,7 +589,8 @@ impl RecordingManager {
     /// 
     /// A `Result` containing:
     /// - `Ok(String)` - The filename of the new recording (e.g., "20240315_143022.pcm")
-    /// - `Err(String)` - A descriptive error message if the recording cannot be started
+    /// - `Err(JarvisError)` - If the recording cannot be started
+    ///   (`ConcurrentRecording` if one is already in progress)
     /// 
     /// # Errors
     /// 
@@ -386,16 +618,14 @@ impl RecordingManager {
     ///         }
     ///         Err(e) => {
     ///             eprintln!("Failed to start recording: {}", e);
-    ///             Err(e)
+    ///             Err(e.into())
     ///         }
     ///     }
     /// }
     /// ```
-    pub fn start_recording(&mut self, recordings_dir: &std::path::Path) -> Result<String, String> {
-        // Check if already recording (concurrent recording prevention)
-        if self.is_recording() {
-            return Err("A recording is already in progress".to_string());
-        }
+    pub fn start_recording(&mut self, recordings_dir: &std::path::Path) -> JarvisResult<String> {
+        // Check if already recording or calibrating (concurrent recording prevention)
+        self.ensure_idle()?;
         
         // Generate timestamped filepath
         let output_path = self.generate_timestamped_path(recordings_dir);
@@ -404,20 +634,80 @@ impl RecordingManager {
         let filename = output_path
             .file_name()
             .and_then(|s| s.to_str())
-            .ok_or_else(|| "Failed to extract filename from path".to_string())?
+            .ok_or_else(|| JarvisError::Other("Failed to extract filename from path".to_string()))?
             .to_string();
         
+        self.start_capture(output_path, false)?;
+        Ok(filename)
+    }
+
+    /// Resume recording into an existing PCM file
+    ///
+    /// New audio is appended to `recordings_dir/filename` so a meeting that
+    /// resumes after stopping stays in one recording. Live transcription
+    /// continues as for a new recording. The stem's saved transcript
+    /// (`recordings/{stem}/transcript.md`) is kept but marked stale (see
+    /// `STALE_TRANSCRIPT_MARKER`), so the next Transcribe covers the whole
+    /// recording. Readers are refused until the appended audio is written.
+    ///
+    /// # Errors
+    ///
+    /// - `ConcurrentRecording` if a recording is already in progress
+    /// - `Validation` if the filename isn't a plain `.pcm` name, the file
+    ///   isn't raw 16-bit PCM (WAV header or a partial trailing sample), or
+    ///   the recording is currently being read or transcribed
+    /// - `NotFound` if the recording doesn't exist
+    pub fn start_recording_append(&mut self, recordings_dir: &Path, filename: &str) -> JarvisResult<String> {
+        self.ensure_idle()?;
+
+        if filename.contains('/') || filename.contains('\\') || filename.contains("..") {
+            return Err(JarvisError::Validation("Invalid filename: path separators not allowed".to_string()));
+        }
+        if !filename.ends_with(".pcm") {
+            return Err(JarvisError::Validation(format!("Not a PCM recording: {}", filename)));
+        }
+
+        let output_path = recordings_dir.join(filename);
+        check_appendable(&output_path)?;
+
+        let append_guard = RecordingAppendGuard::acquire(filename)?;
+
+        // The saved transcript won't cover the whole recording any more
+        let recording_dir = recordings_dir.join(filename.trim_end_matches(".pcm"));
+        if recording_dir.join("transcript.md").exists() {
+            std::fs::write(recording_dir.join(STALE_TRANSCRIPT_MARKER), "")?;
+        }
+
+        self.start_capture(output_path, true)?;
+        self.append_guard = Some(append_guard);
+        Ok(filename.to_string())
+    }
+
+    /// Spawn the sidecar and audio pipeline writing to `output_path`
+    /// (appending if `append`), and emit `recording-started`
+    fn start_capture(&mut self, output_path: PathBuf, append: bool) -> JarvisResult<()> {
+        let filename = output_path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| JarvisError::Other("Failed to extract filename from path".to_string()))?
+            .to_string();
+
         // Create mpsc channel for audio routing (AudioRouter → TranscriptionManager)
-        // Large buffer (1000 chunks × 3200 bytes = 100s of audio) to avoid backpressure
-        // from blocking Whisper inference reaching JarvisListen via FIFO pipe
+        // Large buffer (1000 chunks × 3200 bytes = 100s of audio) to absorb slow
+        // Whisper inference; beyond that AudioRouter waits briefly, then drops chunks (see pipeline_stats)
         let (tx, rx) = mpsc::channel::<Vec<u8>>(1000);
         
         // Create AudioRouter (creates FIFO, returns path)
-        let audio_router = AudioRouter::new(output_path.clone(), tx)
-            .map_err(|e| format!("Failed to create AudioRouter: {}", e))?;
+        let mut audio_router = AudioRouter::new(output_path.clone(), tx)
+            .map_err(|e| JarvisError::Io(format!("Failed to create AudioRouter: {}", e)))?;
+        if append {
+            audio_router = audio_router.append_to_existing();
+        }
         
         // Get FIFO path to pass to sidecar
         let fifo_path = audio_router.fifo_path().to_path_buf();
+        let level_meter = audio_router.level_meter();
+        let pipeline_stats = audio_router.stats();
         
         // Spawn sidecar with --output pointing to FIFO path
         let (event_rx, child) = self.spawn_sidecar(&fifo_path)?;
@@ -433,15 +723,18 @@ impl RecordingManager {
         
         // Start TranscriptionManager with mpsc receiver (spawn task to avoid holding lock)
         let app_handle_clone = self.app_handle.clone();
+        let transcription_stats = pipeline_stats.clone();
         tokio::spawn(async move {
             if let Some(transcription_manager_mutex) = app_handle_clone.try_state::<tokio::sync::Mutex<TranscriptionManager>>() {
                 let mut transcription_manager = transcription_manager_mutex.lock().await;
                 // Update window_duration from latest settings
-                if let Some(settings_manager) = app_handle_clone.try_state::<std::sync::Arc<crate::settings::SettingsManager>>() {
-                    let window_dur = settings_manager.get().transcription.window_duration;
-                    transcription_manager.set_window_duration(window_dur);
+                let settings = app_handle_clone
+                    .try_state::<Arc<std::sync::RwLock<crate::settings::SettingsManager>>>()
+                    .and_then(|manager| manager.read().ok().map(|m| m.get().transcription));
+                if let Some(settings) = settings {
+                    transcription_manager.apply_settings(&settings).await;
                 }
-                if let Err(e) = transcription_manager.start(rx).await {
+                if let Err(e) = transcription_manager.start(rx, transcription_stats).await {
                     eprintln!("Warning: Failed to start transcription: {}", e);
                     // Don't fail recording start if transcription fails
                 }
@@ -455,18 +748,20 @@ impl RecordingManager {
         self.current_child = Some(child);
         self.current_filepath = Some(output_path);
         self.audio_router_task = Some(audio_router_task);
+        self.spawn_level_emitter(level_meter.clone());
+        self.level_meter = Some(level_meter);
+        self.pipeline_stats = Some(pipeline_stats);
         
         // Start monitoring events in background
         self.monitor_events(event_rx);
         
         // Emit "recording-started" event with filename
-        if let Err(e) = self.app_handle.emit("recording-started", json!({ "filename": filename })) {
+        if let Err(e) = self.app_handle.emit("recording-started", json!({ "filename": filename, "appended": append })) {
             eprintln!("Warning: Failed to emit recording-started event: {}", e);
             // Don't fail the recording start if event emission fails
         }
         
-        // Return filename on success
-        Ok(filename)
+        Ok(())
     }
     
     /// Stop the current recording
@@ -486,7 +781,8 @@ impl RecordingManager {
     /// 
     /// A `Result` containing:
     /// - `Ok(())` - Recording stopped successfully
-    /// - `Err(String)` - A descriptive error message if stopping fails
+    /// - `Err(JarvisError)` - If stopping fails (`Validation` if no recording
+    ///   is in progress)
     /// 
     /// # Errors
     /// 
@@ -519,12 +815,12 @@ impl RecordingManager {
     ///         }
     ///         Err(e) => {
     ///             eprintln!("Failed to stop recording: {}", e);
-    ///             Err(e)
+    ///             Err(e.into())
     ///         }
     ///     }
     /// }
     /// ```
-    pub fn stop_recording(&mut self) -> Result<(), String> {
+    pub fn stop_recording(&mut self) -> JarvisResult<()> {
         use nix::sys::signal::{kill, Signal};
         use nix::unistd::Pid;
         
@@ -532,15 +828,17 @@ impl RecordingManager {
         let child = self
             .current_child
             .take()
-            .ok_or("No recording in progress")?;
+            .ok_or_else(|| JarvisError::Validation("No recording in progress".to_string()))?;
         
         let filepath = self
             .current_filepath
             .take()
-            .ok_or("No recording filepath found")?;
+            .ok_or_else(|| JarvisError::Other("No recording filepath found".to_string()))?;
         
-        // Take AudioRouter task
+        // Take AudioRouter task (and the append claim, released once it has written everything)
         let audio_router_task = self.audio_router_task.take();
+        let append_guard = self.append_guard.take();
+        self.level_meter = None;
         
         // Stop TranscriptionManager to drain remaining audio (spawn task to avoid blocking)
         let app_handle_clone = self.app_handle.clone();
@@ -559,7 +857,7 @@ impl RecordingManager {
         
         // Send SIGTERM to allow signal handlers to flush buffers
         kill(Pid::from_raw(pid as i32), Signal::SIGTERM)
-            .map_err(|e| format!("Failed to send SIGTERM to process: {}", e))?;
+            .map_err(|e| JarvisError::Sidecar(format!("Failed to send SIGTERM to process: {}", e)))?;
         
         // Spawn async task to poll for process exit without blocking
         // This allows other commands to acquire the mutex while we wait
@@ -599,35 +897,26 @@ impl RecordingManager {
                     eprintln!("Warning: AudioRouter task join error: {}", e);
                 }
             }
+            drop(append_guard);
             
-            // Verify PCM file exists and has data
-            if !filepath_for_task.exists() {
+            // Verify PCM file exists, has data, and wasn't cut off mid-sample
+            let integrity = RecordingIntegrity::check(&filepath_for_task);
+            if !integrity.exists {
                 eprintln!(
                     "Warning: Recording file does not exist: {}",
                     filepath_for_task.display()
                 );
-                if let Err(e) = app_handle.emit("recording-stopped", ()) {
-                    eprintln!("Warning: Failed to emit recording-stopped event: {}", e);
-                }
-                return;
-            }
-            
-            let metadata = match std::fs::metadata(&filepath_for_task) {
-                Ok(m) => m,
-                Err(e) => {
-                    eprintln!("Warning: Failed to read file metadata: {}", e);
-                    if let Err(e) = app_handle.emit("recording-stopped", ()) {
-                        eprintln!("Warning: Failed to emit recording-stopped event: {}", e);
-                    }
-                    return;
-                }
-            };
-            
-            if metadata.len() == 0 {
+            } else if !integrity.nonempty {
                 eprintln!(
                     "Warning: Recording file is empty: {}",
                     filepath_for_task.display()
                 );
+            } else if !integrity.likely_valid {
+                eprintln!(
+                    "Warning: Recording file ends with a partial sample ({} bytes), it may be truncated: {}",
+                    integrity.size_bytes,
+                    filepath_for_task.display()
+                );
             }
             
             // Emit "recording-stopped" event
@@ -640,8 +929,43 @@ impl RecordingManager {
     }
 }
 
+/// Check that `path` is a raw 16-bit PCM recording new audio can be appended to
+fn check_appendable(path: &Path) -> JarvisResult<()> {
+    let integrity = RecordingIntegrity::check(path);
+    if !integrity.exists {
+        return Err(JarvisError::NotFound(format!("Recording not found: {}", path.display())));
+    }
+    if integrity.nonempty && !integrity.likely_valid {
+        return Err(JarvisError::Validation(
+            "Recording ends with a partial sample; appending would misalign the audio".to_string(),
+        ));
+    }
+
+    if has_wav_header(path) {
+        return Err(JarvisError::Validation(
+            "Recording has a WAV header; only raw PCM recordings can be appended to".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sample_capture_guard_is_exclusive() {
+        let active = Arc::new(AtomicBool::new(false));
+
+        let guard = SampleCaptureGuard::claim(&active).expect("First capture should claim the microphone");
+        assert!(active.load(Ordering::SeqCst));
+        assert!(SampleCaptureGuard::claim(&active).is_none());
+
+        drop(guard);
+        assert!(!active.load(Ordering::SeqCst));
+        assert!(SampleCaptureGuard::claim(&active).is_some());
+    }
     
     // Note: Full integration tests for RecordingManager require a running Tauri app
     // and are better suited for end-to-end testing. These unit tests cover the
@@ -787,4 +1111,71 @@ mod tests {
         // Paths should be different (different timestamps)
         assert_ne!(path1, path2);
     }
+
+    #[test]
+    fn test_check_appendable() {
+        use super::check_appendable;
+        use crate::error::JarvisError;
+
+        let dir = tempfile::tempdir().unwrap();
+        let pcm = dir.path().join("a.pcm");
+        std::fs::write(&pcm, [0u8; 3200]).unwrap();
+        assert!(check_appendable(&pcm).is_ok());
+
+        std::fs::write(&pcm, [0u8; 3201]).unwrap();
+        assert!(matches!(check_appendable(&pcm), Err(JarvisError::Validation(_))));
+
+        std::fs::write(&pcm, b"RIFF\0\0\0\0WAVE").unwrap();
+        assert!(matches!(check_appendable(&pcm), Err(JarvisError::Validation(_))));
+
+        let missing = dir.path().join("missing.pcm");
+        assert!(matches!(check_appendable(&missing), Err(JarvisError::NotFound(_))));
+    }
+
+    #[test]
+    fn test_read_guard_tracks_readers() {
+        use super::RecordingReadGuard;
+
+        let name = "read_guard_test.pcm";
+        let first = RecordingReadGuard::acquire(name).unwrap();
+        let second = RecordingReadGuard::acquire(name).unwrap();
+        drop(first);
+        assert!(RecordingReadGuard::is_held(name));
+        drop(second);
+        assert!(!RecordingReadGuard::is_held(name));
+    }
+
+    #[test]
+    fn test_append_and_read_guards_exclude_each_other() {
+        let name = "append_guard_test.pcm";
+
+        let reading = RecordingReadGuard::acquire(name).unwrap();
+        assert!(matches!(RecordingAppendGuard::acquire(name), Err(JarvisError::Validation(_))));
+        drop(reading);
+
+        let appending = RecordingAppendGuard::acquire(name).unwrap();
+        assert!(matches!(RecordingReadGuard::acquire(name), Err(JarvisError::Validation(_))));
+        assert!(RecordingAppendGuard::acquire(name).is_err());
+        drop(appending);
+
+        assert!(RecordingReadGuard::acquire(name).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_stale_transcript_kept_until_rewritten() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!has_current_transcript(dir.path()));
+
+        write_transcript(dir.path(), "first").await.unwrap();
+        assert!(has_current_transcript(dir.path()));
+
+        // What start_recording_append does
+        std::fs::write(dir.path().join(STALE_TRANSCRIPT_MARKER), "").unwrap();
+        assert!(!has_current_transcript(dir.path()));
+        assert_eq!(std::fs::read_to_string(dir.path().join("transcript.md")).unwrap(), "first");
+
+        write_transcript(dir.path(), "second").await.unwrap();
+        assert!(has_current_transcript(dir.path()));
+        assert!(!dir.path().join(STALE_TRANSCRIPT_MARKER).exists());
+    }
 }
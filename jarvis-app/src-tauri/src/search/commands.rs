@@ -1,24 +1,49 @@
 use std::sync::{Arc, RwLock};
 use tauri::{Emitter, Manager, State};
+use tokio_util::sync::CancellationToken;
 
 use crate::gems::GemStore;
 use crate::intelligence::AvailabilityResult;
 use crate::settings::SettingsManager;
+use super::composite_provider::merge_hybrid_results;
+use super::fts_provider::FtsResultProvider;
+use super::history::{SearchHistory, SearchHistoryEntry};
 use super::provider::*;
 
 /// Search gems via the active search result provider.
 ///
 /// Delegates to whichever provider is registered (FTS5 or QMD).
 /// Joins search results with gem metadata from the database.
+///
+/// `accuracy` (0–100) overrides the semantic relevance threshold for this
+/// query only, so the UI can switch between precise and broad results.
+///
+/// `fallback_to_keyword` (default true) re-runs the query through FTS5 when
+/// the provider finds nothing; those results are tagged `MatchType::Keyword`.
+///
+/// Keyword (FTS5) search understands `field:value` filters for `source_type`,
+/// `domain`, `author`, and `tag`, e.g. `source_type:YouTube tag:rust async`.
+/// Quote values with spaces: `author:"Jane Doe"`. Semantic providers receive
+/// the query as typed.
+///
+/// Non-empty queries are recorded in the search history (`get_search_history`).
 #[tauri::command]
 pub async fn search_gems(
     query: String,
     limit: Option<usize>,
+    accuracy: Option<u8>,
+    fallback_to_keyword: Option<bool>,
     provider: State<'_, Arc<dyn SearchResultProvider>>,
     gem_store: State<'_, Arc<dyn GemStore>>,
+    history: State<'_, Arc<SearchHistory>>,
 ) -> Result<Vec<GemSearchResult>, String> {
     let limit = limit.unwrap_or(20);
-    eprintln!("Search: search_gems called — query=\"{}\" limit={}", query, limit);
+    eprintln!("Search: search_gems called — query=\"{}\" limit={} accuracy={:?}", query, limit, accuracy);
+
+    // History is best-effort; a failed write shouldn't fail the search
+    if let Err(e) = history.record(&query) {
+        eprintln!("Search: Failed to record search history: {}", e);
+    }
 
     // Handle empty query — delegate to gem_store.list() for consistency
     if query.trim().is_empty() {
@@ -45,10 +70,82 @@ pub async fn search_gems(
     }
 
     // Search via provider (QMD semantic or FTS5)
-    let search_results = provider.search(&query, limit).await?;
+    let search_results = provider.search(&query, limit, accuracy).await?;
     eprintln!("Search: Provider returned {} raw results for \"{}\"", search_results.len(), query);
 
-    // Join each SearchResult with gem metadata from DB
+    let mut enriched = enrich_search_results(search_results, gem_store.inner()).await;
+
+    // Fallback: if semantic provider returned 0 results, try FTS5 keyword search.
+    // Fallback results carry MatchType::Keyword so the UI can label them.
+    if enriched.is_empty() && fallback_to_keyword.unwrap_or(true) {
+        eprintln!("Search: Semantic returned 0 results, falling back to FTS5 for \"{}\"", query);
+        let fts = FtsResultProvider::new(gem_store.inner().clone());
+        if let Ok(fts_results) = fts.search(&query, limit, None).await {
+            eprintln!("Search: FTS5 fallback returned {} results for \"{}\"", fts_results.len(), query);
+            enriched = enrich_search_results(fts_results, gem_store.inner()).await;
+        }
+    }
+
+    eprintln!("Search: Returning {} enriched results for \"{}\"", enriched.len(), query);
+    Ok(enriched)
+}
+
+/// Search gems with both the semantic provider and FTS5, merged into one list.
+///
+/// Runs the active provider and FTS5 keyword search concurrently, then merges
+/// by gem id so each gem appears once. `match_type` tells the UI how a gem was
+/// found: `Semantic`, `Keyword`, or `Hybrid` (both). Gems found both ways get a
+/// combined score and rank above single-side matches of similar strength.
+///
+/// When semantic search is disabled, this returns plain keyword results.
+#[tauri::command]
+pub async fn hybrid_search_gems(
+    query: String,
+    limit: Option<usize>,
+    accuracy: Option<u8>,
+    provider: State<'_, Arc<dyn SearchResultProvider>>,
+    gem_store: State<'_, Arc<dyn GemStore>>,
+) -> Result<Vec<GemSearchResult>, String> {
+    let limit = limit.unwrap_or(20);
+    eprintln!("Search: hybrid_search_gems called — query=\"{}\" limit={}", query, limit);
+
+    if query.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let fts = FtsResultProvider::new(gem_store.inner().clone());
+    let (semantic, keyword) = tokio::join!(
+        provider.search(&query, limit, accuracy),
+        fts.search(&query, limit, None),
+    );
+
+    // When FTS5 is the active provider its results are keyword matches too;
+    // drop them so they aren't double-counted as "both".
+    let semantic: Vec<SearchResult> = semantic?
+        .into_iter()
+        .filter(|r| !matches!(r.match_type, MatchType::Keyword))
+        .collect();
+    // FTS5 rejects some inputs (unbalanced quotes) that semantic search accepts
+    let keyword = keyword.unwrap_or_else(|e| {
+        eprintln!("Search: Keyword side of hybrid search failed: {}", e);
+        Vec::new()
+    });
+    eprintln!("Search: Hybrid — {} semantic, {} keyword raw results", semantic.len(), keyword.len());
+
+    let merged = merge_hybrid_results(semantic, keyword, limit);
+    let enriched = enrich_search_results(merged, gem_store.inner()).await;
+
+    eprintln!("Search: Returning {} hybrid results for \"{}\"", enriched.len(), query);
+    Ok(enriched)
+}
+
+/// Join each SearchResult with gem metadata from the DB.
+///
+/// Results whose gem no longer exists (orphaned index entries) are dropped.
+async fn enrich_search_results(
+    search_results: Vec<SearchResult>,
+    gem_store: &Arc<dyn GemStore>,
+) -> Vec<GemSearchResult> {
     let mut enriched = Vec::new();
     for result in search_results {
         if let Ok(Some(gem)) = gem_store.get(&result.gem_id).await {
@@ -88,34 +185,26 @@ pub async fn search_gems(
             eprintln!("Search: Gem {} not found in DB (orphaned index entry)", result.gem_id);
         }
     }
+    enriched
+}
 
-    // Fallback: if semantic provider returned 0 results, try FTS5 keyword search
-    if enriched.is_empty() {
-        eprintln!("Search: Semantic returned 0 results, falling back to FTS5 for \"{}\"", query);
-        if let Ok(fts_results) = gem_store.search(&query, limit).await {
-            eprintln!("Search: FTS5 fallback returned {} results for \"{}\"", fts_results.len(), query);
-            for gem in fts_results {
-                enriched.push(GemSearchResult {
-                    score: 1.0,
-                    matched_chunk: String::new(),
-                    match_type: MatchType::Keyword,
-                    id: gem.id,
-                    source_type: gem.source_type,
-                    source_url: gem.source_url,
-                    domain: gem.domain,
-                    title: gem.title,
-                    author: gem.author,
-                    description: gem.description,
-                    captured_at: gem.captured_at,
-                    tags: gem.tags,
-                    summary: gem.summary,
-                });
-            }
-        }
-    }
+/// Recent search queries, most recent first (at most `MAX_SEARCH_HISTORY`).
+#[tauri::command]
+pub async fn get_search_history(
+    history: State<'_, Arc<SearchHistory>>,
+) -> Result<Vec<SearchHistoryEntry>, String> {
+    Ok(history.list()?)
+}
 
-    eprintln!("Search: Returning {} enriched results for \"{}\"", enriched.len(), query);
-    Ok(enriched)
+/// Forget all recorded search queries.
+#[tauri::command]
+pub async fn clear_search_history(
+    history: State<'_, Arc<SearchHistory>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
+) -> Result<(), String> {
+    demo_mode.ensure_writable()?;
+
+    Ok(history.clear()?)
 }
 
 /// Check if the active search provider is available.
@@ -137,7 +226,10 @@ pub async fn check_search_availability(
 pub async fn setup_semantic_search(
     app_handle: tauri::AppHandle,
     settings_manager: State<'_, Arc<RwLock<SettingsManager>>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<QmdSetupResult, String> {
+    demo_mode.ensure_writable()?;
+
     let total_steps = 7;
 
     // Helper to emit progress
@@ -270,20 +362,107 @@ pub async fn setup_semantic_search(
     })
 }
 
+/// Tracks the in-progress search index rebuild so it can be cancelled.
+#[derive(Default)]
+pub struct SearchRebuildState {
+    cancel_token: tokio::sync::Mutex<Option<CancellationToken>>,
+}
+
 /// Rebuild the search index from scratch.
+///
+/// Emits progress events on the "search-index-rebuild" channel as
+/// `SetupProgressEvent { step: documents_indexed, total: documents_total, .. }`.
+/// Status is "running" while indexing, then "done", "failed" or "cancelled".
+/// Only one rebuild runs at a time; cancel with `cancel_search_index_rebuild`.
 #[tauri::command]
 pub async fn rebuild_search_index(
+    app_handle: tauri::AppHandle,
     provider: State<'_, Arc<dyn SearchResultProvider>>,
+    rebuild_state: State<'_, SearchRebuildState>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
 ) -> Result<usize, String> {
+    demo_mode.ensure_writable()?;
+
     eprintln!("Search: rebuild_search_index called");
-    let result = provider.reindex_all().await;
+
+    let cancel_token = {
+        let mut guard = rebuild_state.cancel_token.lock().await;
+        if guard.is_some() {
+            return Err("A search index rebuild is already running".to_string());
+        }
+        let token = CancellationToken::new();
+        *guard = Some(token.clone());
+        token
+    };
+
+    let emit_progress = {
+        let app_handle = app_handle.clone();
+        move |step: usize, total: usize, status: &str| {
+            let _ = app_handle.emit("search-index-rebuild", SetupProgressEvent {
+                step,
+                total,
+                description: format!("Indexed {} of {} documents", step, total),
+                status: status.to_string(),
+            });
+        }
+    };
+
+    let progress_emitter = emit_progress.clone();
+    let on_progress: ReindexProgress = Arc::new(move |indexed, total| {
+        progress_emitter(indexed, total, "running");
+    });
+
+    let result = provider.reindex_all_with_progress(on_progress, cancel_token.clone()).await;
+    *rebuild_state.cancel_token.lock().await = None;
+
     match &result {
-        Ok(count) => eprintln!("Search: rebuild_search_index completed — {} docs indexed", count),
-        Err(e) => eprintln!("Search: rebuild_search_index FAILED: {}", e),
+        Ok(count) => {
+            eprintln!("Search: rebuild_search_index completed — {} docs indexed", count);
+            emit_progress(*count, *count, "done");
+        }
+        Err(e) if cancel_token.is_cancelled() => {
+            eprintln!("Search: rebuild_search_index cancelled: {}", e);
+            emit_progress(0, 0, "cancelled");
+        }
+        Err(e) => {
+            eprintln!("Search: rebuild_search_index FAILED: {}", e);
+            emit_progress(0, 0, "failed");
+        }
     }
     result
 }
 
+/// Cancel the in-progress search index rebuild, if any.
+///
+/// The provider restores the previous index, so search keeps working.
+/// Returns true if a rebuild was running.
+#[tauri::command]
+pub async fn cancel_search_index_rebuild(
+    rebuild_state: State<'_, SearchRebuildState>,
+) -> Result<bool, String> {
+    match rebuild_state.cancel_token.lock().await.as_ref() {
+        Some(token) => {
+            eprintln!("Search: Cancelling search index rebuild");
+            token.cancel();
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Clear cached web search results so the next query hits the API.
+///
+/// Returns the number of cached queries removed.
+#[tauri::command]
+pub async fn clear_web_search_cache(
+    provider: State<'_, Arc<dyn SearchResultProvider>>,
+    demo_mode: State<'_, crate::commands::DemoMode>,
+) -> Result<usize, String> {
+    demo_mode.ensure_writable()?;
+
+    Ok(provider.clear_web_search_cache())
+}
+
 // ── Setup helper functions ──────────────────────────────
 
 async fn check_node_version() -> Result<String, String> {
@@ -519,3 +698,66 @@ async fn patch_qmd_rerank_context_size() -> Result<(), String> {
     eprintln!("Search/Setup: Patched QMD reranker context size 2048 → 4096 at {}", llm_js_path.display());
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gems::test_utils::test_gem;
+    use crate::gems::{Gem, SqliteGemStore};
+    use async_trait::async_trait;
+
+    /// Semantic provider that never finds anything
+    struct NoMatches;
+
+    #[async_trait]
+    impl SearchResultProvider for NoMatches {
+        async fn check_availability(&self) -> AvailabilityResult {
+            AvailabilityResult { available: true, reason: None }
+        }
+
+        async fn search(&self, _query: &str, _limit: usize, _accuracy: Option<u8>) -> Result<Vec<SearchResult>, String> {
+            Ok(Vec::new())
+        }
+
+        async fn index_gem(&self, _gem_id: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn remove_gem(&self, _gem_id: &str) -> Result<(), String> {
+            Ok(())
+        }
+
+        async fn reindex_all(&self) -> Result<usize, String> {
+            Ok(0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_search_gems_falls_back_to_keyword() {
+        let gem_store: Arc<dyn GemStore> = Arc::new(SqliteGemStore::new_in_memory().unwrap());
+        gem_store.save(Gem { title: "Tokio scheduler".to_string(), ..test_gem("tokio") }).await.unwrap();
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        let history = Arc::new(SearchHistory::new(Arc::new(std::sync::Mutex::new(conn))).unwrap());
+
+        let app = tauri::test::mock_app();
+        app.manage::<Arc<dyn SearchResultProvider>>(Arc::new(NoMatches));
+        app.manage(gem_store);
+        app.manage(history);
+        let search = |fallback_to_keyword| search_gems(
+            "tokio".to_string(),
+            None,
+            None,
+            fallback_to_keyword,
+            app.state(),
+            app.state(),
+            app.state(),
+        );
+
+        let results = search(None).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].id, "tokio");
+        assert!(matches!(results[0].match_type, MatchType::Keyword));
+
+        assert!(search(Some(false)).await.unwrap().is_empty());
+    }
+}
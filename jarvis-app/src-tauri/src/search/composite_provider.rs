@@ -1,10 +1,12 @@
 // CompositeSearchProvider - delegates gem search and web search to separate providers
 
+use std::collections::HashMap;
 use std::sync::Arc;
 use async_trait::async_trait;
+use tokio_util::sync::CancellationToken;
 use crate::intelligence::AvailabilityResult;
 use super::provider::{
-    SearchResultProvider, SearchResult, WebSearchResult,
+    MatchType, ReindexProgress, SearchResultProvider, SearchResult, WebSearchResult,
 };
 
 /// Composite search provider that delegates gem search to one provider
@@ -43,8 +45,13 @@ impl SearchResultProvider for CompositeSearchProvider {
         &self,
         query: &str,
         limit: usize,
+        accuracy: Option<u8>,
     ) -> Result<Vec<SearchResult>, String> {
-        self.gem_provider.search(query, limit).await
+        self.gem_provider.search(query, limit, accuracy).await
+    }
+
+    fn takes_fts_query(&self) -> bool {
+        self.gem_provider.takes_fts_query()
     }
 
     async fn index_gem(&self, gem_id: &str) -> Result<(), String> {
@@ -59,6 +66,14 @@ impl SearchResultProvider for CompositeSearchProvider {
         self.gem_provider.reindex_all().await
     }
 
+    async fn reindex_all_with_progress(
+        &self,
+        on_progress: ReindexProgress,
+        cancel: CancellationToken,
+    ) -> Result<usize, String> {
+        self.gem_provider.reindex_all_with_progress(on_progress, cancel).await
+    }
+
     async fn web_search(
         &self,
         query: &str,
@@ -75,4 +90,149 @@ impl SearchResultProvider for CompositeSearchProvider {
             .as_ref()
             .map_or(false, |wp| wp.supports_web_search())
     }
+
+    fn clear_web_search_cache(&self) -> usize {
+        self.web_provider
+            .as_ref()
+            .map_or(0, |wp| wp.clear_web_search_cache())
+    }
+}
+
+/// Reciprocal rank fusion constant (the usual 60): larger values flatten
+/// the gap between top and lower ranks
+const RRF_K: f64 = 60.0;
+
+/// Merge semantic and keyword results into one list, one entry per gem.
+///
+/// The two sides score on different scales (cosine similarity vs. a BM25
+/// rank), so results are fused by rank rather than raw score: each side adds
+/// `1 / (RRF_K + rank)` for a gem, with rank 1 for its best result. Gems
+/// found by only one side are labeled `Semantic` or `Keyword`; gems found by
+/// both are labeled `Hybrid` and so rank above single-side matches of similar
+/// position. Scores are then scaled so the best result is 1.0. The semantic
+/// snippet is kept when both sides have one. Returns at most `limit`
+/// results, best first.
+pub fn merge_hybrid_results(
+    semantic: Vec<SearchResult>,
+    keyword: Vec<SearchResult>,
+    limit: usize,
+) -> Vec<SearchResult> {
+    let mut merged: HashMap<String, SearchResult> = HashMap::new();
+
+    for (side, match_type) in [(semantic, MatchType::Semantic), (keyword, MatchType::Keyword)] {
+        for (rank, result) in ranked_by_gem(side).into_iter().enumerate() {
+            let contribution = 1.0 / (RRF_K + rank as f64 + 1.0);
+            match merged.get_mut(&result.gem_id) {
+                Some(entry) => {
+                    entry.score += contribution;
+                    entry.match_type = MatchType::Hybrid;
+                    if entry.matched_chunk.is_empty() {
+                        entry.matched_chunk = result.matched_chunk;
+                    }
+                }
+                None => {
+                    merged.insert(result.gem_id.clone(), SearchResult {
+                        score: contribution,
+                        match_type: match_type.clone(),
+                        ..result
+                    });
+                }
+            }
+        }
+    }
+
+    let mut results: Vec<SearchResult> = merged.into_values().collect();
+    let best = results.iter().map(|r| r.score).fold(0.0, f64::max);
+    if best > 0.0 {
+        for result in &mut results {
+            result.score /= best;
+        }
+    }
+    results.sort_by(|a, b| {
+        b.score.partial_cmp(&a.score)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.gem_id.cmp(&b.gem_id))
+    });
+    results.truncate(limit);
+    results
+}
+
+/// One side's results, best first, keeping each gem's best-scoring result
+/// (providers may return a gem more than once)
+fn ranked_by_gem(mut results: Vec<SearchResult>) -> Vec<SearchResult> {
+    results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+    let mut seen = std::collections::HashSet::new();
+    results.retain(|result| seen.insert(result.gem_id.clone()));
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(gem_id: &str, score: f64, match_type: MatchType) -> SearchResult {
+        SearchResult {
+            gem_id: gem_id.to_string(),
+            score,
+            matched_chunk: format!("{} chunk", gem_id),
+            match_type,
+        }
+    }
+
+    #[test]
+    fn test_merge_labels_and_dedups_by_gem() {
+        let semantic = vec![
+            result("a", 0.8, MatchType::Hybrid),
+            result("b", 0.6, MatchType::Hybrid),
+            result("b", 0.2, MatchType::Hybrid),
+        ];
+        let keyword = vec![
+            result("b", 0.5, MatchType::Keyword),
+            result("c", 0.9, MatchType::Keyword),
+        ];
+
+        let merged = merge_hybrid_results(semantic, keyword, 10);
+        assert_eq!(merged.len(), 3, "Gem b should appear once");
+
+        let by_id = |id: &str| merged.iter().find(|r| r.gem_id == id).unwrap();
+        assert!(matches!(by_id("a").match_type, MatchType::Semantic));
+        assert!(matches!(by_id("b").match_type, MatchType::Hybrid));
+        assert!(matches!(by_id("c").match_type, MatchType::Keyword));
+        assert_eq!(merged[0].gem_id, "b", "Found by both sides, so ranked first");
+        assert!((by_id("b").score - 1.0).abs() < 1e-9);
+        assert_eq!(by_id("b").matched_chunk, "b chunk");
+    }
+
+    #[test]
+    fn test_merge_sorts_by_score_and_truncates() {
+        let semantic = vec![result("a", 0.3, MatchType::Hybrid)];
+        let keyword = vec![
+            result("b", 1.0, MatchType::Keyword),
+            result("c", 0.95, MatchType::Keyword),
+        ];
+
+        let merged = merge_hybrid_results(semantic, keyword, 2);
+        let ids: Vec<&str> = merged.iter().map(|r| r.gem_id.as_str()).collect();
+        assert_eq!(ids, vec!["a", "b"], "Each side's top result ties; c is second on its side");
+    }
+
+    #[test]
+    fn test_merge_ignores_score_scales() {
+        // Cosine similarities cluster low; keyword scores here run well past 1.0
+        let semantic = vec![
+            result("s1", 0.42, MatchType::Hybrid),
+            result("s2", 0.41, MatchType::Hybrid),
+        ];
+        let keyword = vec![
+            result("k1", 14.0, MatchType::Keyword),
+            result("k2", 9.0, MatchType::Keyword),
+        ];
+
+        let merged = merge_hybrid_results(semantic, keyword, 10);
+        let score = |id: &str| merged.iter().find(|r| r.gem_id == id).unwrap().score;
+        assert!((score("s1") - score("k1")).abs() < 1e-9, "Top of each side ranks equally");
+        assert!((score("s2") - score("k2")).abs() < 1e-9);
+        assert!(score("k2") < score("s1"), "Raw keyword scores don't outrank better semantic matches");
+        assert!(merged.iter().all(|r| (0.0..=1.0).contains(&r.score)));
+    }
 }
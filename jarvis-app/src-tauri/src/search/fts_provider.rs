@@ -30,7 +30,7 @@ impl SearchResultProvider for FtsResultProvider {
         }
     }
 
-    async fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, String> {
+    async fn search(&self, query: &str, limit: usize, _accuracy: Option<u8>) -> Result<Vec<SearchResult>, String> {
         let gems = self.gem_store.search(query, limit).await?;
 
         Ok(gems
@@ -45,6 +45,10 @@ impl SearchResultProvider for FtsResultProvider {
             .collect())
     }
 
+    fn takes_fts_query(&self) -> bool {
+        true
+    }
+
     async fn index_gem(&self, _gem_id: &str) -> Result<(), String> {
         // No-op: FTS5 triggers (gems_ai, gems_ad, gems_au) handle indexing
         Ok(())
@@ -0,0 +1,175 @@
+// SearchHistory - recent search queries, stored in gems.db
+//
+// Feeds the search-suggestions dropdown. Only the last MAX_SEARCH_HISTORY
+// queries are kept, and repeating the most recent query just refreshes its
+// timestamp instead of adding a duplicate entry.
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+
+use crate::error::{JarvisError, JarvisResult};
+
+/// Maximum number of queries kept in the history
+pub const MAX_SEARCH_HISTORY: usize = 50;
+
+/// A past search query
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SearchHistoryEntry {
+    pub query: String,
+
+    /// RFC 3339 timestamp of the most recent time this query was run
+    pub searched_at: String,
+}
+
+/// Recent search queries. Shares gems.db with SqliteGemStore.
+pub struct SearchHistory {
+    conn: Arc<Mutex<Connection>>,
+}
+
+impl SearchHistory {
+    /// Create the history on the shared database connection, creating the
+    /// search_history table if it doesn't exist
+    pub fn new(conn: Arc<Mutex<Connection>>) -> JarvisResult<Self> {
+        let history = Self { conn };
+        history.initialize_schema()?;
+        Ok(history)
+    }
+
+    fn initialize_schema(&self) -> JarvisResult<()> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        Self::initialize_schema_on(&conn)
+    }
+
+    /// Create the search_history table on `conn` if it doesn't exist (also
+    /// used after a backup restore replaces the shared database)
+    pub(crate) fn initialize_schema_on(conn: &Connection) -> JarvisResult<()> {
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS search_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                query TEXT NOT NULL,
+                searched_at TEXT NOT NULL
+            )",
+            [],
+        ).map_err(|e| JarvisError::Db(format!("Failed to create search_history table: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Record a search query (blank queries are ignored)
+    pub fn record(&self, query: &str) -> JarvisResult<()> {
+        let query = query.trim();
+        if query.is_empty() {
+            return Ok(());
+        }
+
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+        let now = chrono::Utc::now().to_rfc3339();
+
+        let latest: Option<(i64, String)> = conn.query_row(
+            "SELECT id, query FROM search_history ORDER BY id DESC LIMIT 1",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        ).optional()?;
+
+        match latest {
+            Some((id, latest_query)) if latest_query == query => {
+                conn.execute(
+                    "UPDATE search_history SET searched_at = ?1 WHERE id = ?2",
+                    params![now, id],
+                ).map_err(|e| JarvisError::Db(format!("Failed to update search history: {}", e)))?;
+            }
+            _ => {
+                conn.execute(
+                    "INSERT INTO search_history (query, searched_at) VALUES (?1, ?2)",
+                    params![query, now],
+                ).map_err(|e| JarvisError::Db(format!("Failed to record search: {}", e)))?;
+            }
+        }
+
+        conn.execute(
+            "DELETE FROM search_history WHERE id NOT IN (
+                SELECT id FROM search_history ORDER BY id DESC LIMIT ?1
+            )",
+            params![MAX_SEARCH_HISTORY as i64],
+        ).map_err(|e| JarvisError::Db(format!("Failed to trim search history: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Recent queries, most recent first
+    pub fn list(&self) -> JarvisResult<Vec<SearchHistoryEntry>> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        let mut stmt = conn.prepare(
+            "SELECT query, searched_at FROM search_history ORDER BY id DESC"
+        ).map_err(|e| JarvisError::Db(format!("Failed to prepare statement: {}", e)))?;
+
+        let entries = stmt.query_map([], |row| {
+            Ok(SearchHistoryEntry {
+                query: row.get(0)?,
+                searched_at: row.get(1)?,
+            })
+        })
+            .map_err(|e| JarvisError::Db(format!("Failed to query search history: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| JarvisError::Db(format!("Failed to collect search history: {}", e)))?;
+
+        Ok(entries)
+    }
+
+    /// Remove all recorded queries
+    pub fn clear(&self) -> JarvisResult<()> {
+        let conn = self.conn.lock()
+            .map_err(|e| JarvisError::Db(format!("Failed to acquire lock: {}", e)))?;
+
+        conn.execute("DELETE FROM search_history", [])
+            .map_err(|e| JarvisError::Db(format!("Failed to clear search history: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> SearchHistory {
+        let conn = Connection::open_in_memory().expect("Failed to open in-memory database");
+        SearchHistory::new(Arc::new(Mutex::new(conn))).expect("Failed to create search history")
+    }
+
+    fn queries(history: &SearchHistory) -> Vec<String> {
+        history.list().unwrap().into_iter().map(|e| e.query).collect()
+    }
+
+    #[test]
+    fn test_record_dedupes_consecutive_and_skips_blank() {
+        let history = history();
+        history.record("rust").unwrap();
+        history.record(" rust ").unwrap();
+        history.record("   ").unwrap();
+        history.record("tokio").unwrap();
+        history.record("rust").unwrap();
+
+        assert_eq!(queries(&history), vec!["rust", "tokio", "rust"]);
+    }
+
+    #[test]
+    fn test_record_caps_length_and_clear() {
+        let history = history();
+        for i in 0..MAX_SEARCH_HISTORY + 5 {
+            history.record(&format!("query {}", i)).unwrap();
+        }
+
+        let recorded = queries(&history);
+        assert_eq!(recorded.len(), MAX_SEARCH_HISTORY);
+        assert_eq!(recorded[0], format!("query {}", MAX_SEARCH_HISTORY + 4));
+
+        history.clear().unwrap();
+        assert!(queries(&history).is_empty());
+    }
+}
@@ -4,6 +4,7 @@ pub mod qmd_provider;
 pub mod tavily_provider;
 pub mod composite_provider;
 pub mod commands;
+pub mod history;
 
 pub use provider::{
     SearchResultProvider,
@@ -14,8 +15,10 @@ pub use provider::{
     WebSourceType,
     QmdSetupResult,
     SetupProgressEvent,
+    ReindexProgress,
 };
 pub use fts_provider::FtsResultProvider;
 pub use qmd_provider::QmdResultProvider;
-pub use tavily_provider::TavilyProvider;
+pub use tavily_provider::{TavilyError, TavilyProvider};
 pub use composite_provider::CompositeSearchProvider;
+pub use history::{SearchHistory, SearchHistoryEntry};
@@ -1,7 +1,9 @@
 // SearchResultProvider trait - backend-agnostic search interface
 
+use std::sync::Arc;
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
 use crate::intelligence::AvailabilityResult;
 
 /// How a search result was matched
@@ -79,6 +81,9 @@ pub struct WebSearchResult {
     pub source_type: WebSourceType,
     pub domain: String,
     pub published_date: Option<String>,
+    /// Debug: true when served from the provider's result cache (no API call made)
+    #[serde(default)]
+    pub from_cache: bool,
 }
 
 /// Result of the semantic search setup flow
@@ -100,6 +105,9 @@ pub struct SetupProgressEvent {
     pub status: String, // "running", "done", "failed"
 }
 
+/// Progress callback for index rebuilds: `(documents_indexed, documents_total)`
+pub type ReindexProgress = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
 /// Backend-agnostic search result provider.
 ///
 /// Tauri commands call this trait, never a concrete implementation.
@@ -119,12 +127,24 @@ pub trait SearchResultProvider: Send + Sync {
     /// Providers MUST return scores normalized to 0.0–1.0.
     /// Providers MUST return at most `limit` results.
     /// Providers SHOULD return results sorted by score descending.
+    ///
+    /// `accuracy` overrides the provider's minimum relevance (0–100%) for this
+    /// query only. `None` keeps the provider's configured threshold. Providers
+    /// without a relevance cutoff (FTS5) ignore it.
     async fn search(
         &self,
         query: &str,
         limit: usize,
+        accuracy: Option<u8>,
     ) -> Result<Vec<SearchResult>, String>;
 
+    /// Whether `search` takes an FTS5 MATCH expression rather than plain text
+    ///
+    /// Default: false. FTS5 overrides it so callers can quote terms.
+    fn takes_fts_query(&self) -> bool {
+        false
+    }
+
     /// Notify the provider that a gem was created or updated
     ///
     /// FTS: no-op (triggers handle it). QMD: spawn `qmd update && qmd embed`.
@@ -142,6 +162,18 @@ pub trait SearchResultProvider: Send + Sync {
     /// Returns the number of documents indexed.
     async fn reindex_all(&self) -> Result<usize, String>;
 
+    /// Rebuild the entire search index, reporting progress and honoring cancellation
+    ///
+    /// Providers SHOULD leave the previous index usable if the rebuild fails or
+    /// is cancelled. Default: ignores progress/cancel and calls reindex_all().
+    async fn reindex_all_with_progress(
+        &self,
+        _on_progress: ReindexProgress,
+        _cancel: CancellationToken,
+    ) -> Result<usize, String> {
+        self.reindex_all().await
+    }
+
     /// Search the web for external resources (papers, articles, videos).
     ///
     /// Default: returns empty vec (provider does not support web search).
@@ -160,4 +192,11 @@ pub trait SearchResultProvider: Send + Sync {
     fn supports_web_search(&self) -> bool {
         false
     }
+
+    /// Drop any cached web search results.
+    ///
+    /// Default: no cache, returns 0. Returns the number of entries cleared.
+    fn clear_web_search_cache(&self) -> usize {
+        0
+    }
 }
@@ -1,10 +1,14 @@
 // QmdResultProvider - semantic search provider wrapping QMD CLI
 
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
 use async_trait::async_trait;
+use regex::Regex;
+use tokio::io::AsyncReadExt;
 use tokio::process::Command;
+use tokio_util::sync::CancellationToken;
 use crate::intelligence::AvailabilityResult;
-use super::provider::{SearchResultProvider, SearchResult, MatchType};
+use super::provider::{ReindexProgress, SearchResultProvider, SearchResult, MatchType};
 
 /// Opt-in semantic search provider — wraps QMD CLI.
 ///
@@ -19,8 +23,7 @@ pub struct QmdResultProvider {
     /// Path to the qmd binary (e.g., /opt/homebrew/bin/qmd)
     qmd_path: PathBuf,
     /// Root knowledge directory (e.g., ~/Library/.../knowledge/)
-    /// Kept for future use (e.g., verifying gem files exist before indexing)
-    #[allow(dead_code)]
+    /// Used to count the documents a full rebuild will embed
     knowledge_path: PathBuf,
     /// Minimum relevance score (0.0–1.0) — results below this are discarded
     min_score: f64,
@@ -28,7 +31,7 @@ pub struct QmdResultProvider {
 
 impl QmdResultProvider {
     pub fn new(qmd_path: PathBuf, knowledge_path: PathBuf, accuracy_pct: u8) -> Self {
-        let min_score = (accuracy_pct.min(100) as f64) / 100.0;
+        let min_score = accuracy_to_min_score(accuracy_pct);
         eprintln!("Search/QMD: min_score set to {:.0}%", min_score * 100.0);
         Self {
             qmd_path,
@@ -161,7 +164,7 @@ impl SearchResultProvider for QmdResultProvider {
         }
     }
 
-    async fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>, String> {
+    async fn search(&self, query: &str, limit: usize, accuracy: Option<u8>) -> Result<Vec<SearchResult>, String> {
         // Cap query length — QMD's reranker crashes on very long inputs
         // (context size exceeded error). 200 chars is plenty for any real search.
         let query = if query.len() > 200 {
@@ -257,7 +260,7 @@ impl SearchResultProvider for QmdResultProvider {
         }
 
         // Convert to SearchResult vec, filter low-confidence results, sort by score descending
-        let min_score = self.min_score;
+        let min_score = accuracy.map(accuracy_to_min_score).unwrap_or(self.min_score);
         let mut results: Vec<SearchResult> = best_by_gem
             .into_iter()
             .map(|(gem_id, (score, snippet))| SearchResult {
@@ -353,13 +356,86 @@ impl SearchResultProvider for QmdResultProvider {
     }
 
     async fn reindex_all(&self) -> Result<usize, String> {
+        self.reindex_all_with_progress(Arc::new(|_, _| {}), CancellationToken::new()).await
+    }
+
+    async fn reindex_all_with_progress(
+        &self,
+        on_progress: ReindexProgress,
+        cancel: CancellationToken,
+    ) -> Result<usize, String> {
+        let total = count_knowledge_documents(&self.knowledge_path);
+        eprintln!("Search/QMD: reindex_all — {} documents in {}", total, self.knowledge_path.display());
+        on_progress(0, total);
+
+        // Snapshot the current index so a failed/cancelled rebuild can be rolled back
+        let index_path = self.index_path().await;
+        let backup = match &index_path {
+            Some(path) => backup_index(path)?,
+            None => None,
+        };
+
+        let result = self.run_reindex(total, &on_progress, &cancel).await;
+
+        match (&result, &index_path, &backup) {
+            (Ok(_), Some(path), Some(_)) => remove_backup(path),
+            (Err(e), Some(path), Some(backup_path)) => {
+                eprintln!("Search/QMD: reindex_all failed ({}), restoring previous index", e);
+                if let Err(restore_err) = restore_index(path, backup_path) {
+                    eprintln!("Search/QMD: Failed to restore previous index: {}", restore_err);
+                }
+            }
+            _ => {}
+        }
+
+        result
+    }
+}
+
+impl QmdResultProvider {
+    /// The index file qmd reports in `qmd status`, or its documented default
+    /// location if the status can't be read
+    async fn index_path(&self) -> Option<PathBuf> {
+        let output = Command::new(&self.qmd_path)
+            .arg("status")
+            .output()
+            .await;
+        match output {
+            Ok(output) if output.status.success() => {
+                if let Some(path) = index_path_from_status(&String::from_utf8_lossy(&output.stdout)) {
+                    eprintln!("Search/QMD: Index at {}", path.display());
+                    return Some(path);
+                }
+                eprintln!("Search/QMD: qmd status didn't report an index path");
+            }
+            Ok(output) => {
+                let stderr = String::from_utf8_lossy(&output.stderr);
+                eprintln!("Search/QMD: qmd status failed (exit {}): {}", output.status, stderr.trim());
+            }
+            Err(e) => eprintln!("Search/QMD: qmd status error: {}", e),
+        }
+
+        let path = default_index_path()?;
+        eprintln!("Search/QMD: Assuming default index at {}", path.display());
+        Some(path)
+    }
+
+    /// Run `qmd update` then `qmd embed -f`, streaming embed output for progress.
+    async fn run_reindex(
+        &self,
+        total: usize,
+        on_progress: &ReindexProgress,
+        cancel: &CancellationToken,
+    ) -> Result<usize, String> {
         eprintln!("Search/QMD: reindex_all — running qmd update");
-        // Await: qmd update && qmd embed -f (force re-embed all)
         let update = Command::new(&self.qmd_path)
             .arg("update")
-            .output()
-            .await
-            .map_err(|e| format!("qmd update failed: {}", e))?;
+            .kill_on_drop(true)
+            .output();
+        let update = tokio::select! {
+            r = update => r.map_err(|e| format!("qmd update failed: {}", e))?,
+            _ = cancel.cancelled() => return Err("Search index rebuild cancelled".to_string()),
+        };
 
         if !update.status.success() {
             let stderr = String::from_utf8_lossy(&update.stderr);
@@ -370,26 +446,173 @@ impl SearchResultProvider for QmdResultProvider {
         eprintln!("Search/QMD: reindex_all qmd update succeeded: {}", stdout.trim());
 
         eprintln!("Search/QMD: reindex_all — running qmd embed -f");
-        let embed = Command::new(&self.qmd_path)
+        let mut child = Command::new(&self.qmd_path)
             .args(["embed", "-f"])
-            .output()
-            .await
+            .stdout(std::process::Stdio::piped())
+            .stderr(std::process::Stdio::piped())
+            .kill_on_drop(true)
+            .spawn()
+            .map_err(|e| format!("qmd embed failed: {}", e))?;
+
+        let mut stdout = child.stdout.take().ok_or("Failed to get qmd embed stdout")?;
+        let stderr = child.stderr.take().ok_or("Failed to get qmd embed stderr")?;
+
+        // Collect stderr in the background for error reporting
+        let stderr_handle = tokio::spawn(async move {
+            let mut stderr = stderr;
+            let mut buf = String::new();
+            let _ = stderr.read_to_string(&mut buf).await;
+            buf
+        });
+
+        // QMD redraws its progress bar with '\r', so split on both '\r' and '\n'
+        let mut indexed = 0;
+        let mut pending = Vec::new();
+        let mut chunk = [0u8; 4096];
+        loop {
+            let n = tokio::select! {
+                r = stdout.read(&mut chunk) => match r {
+                    Ok(n) => n,
+                    Err(e) => {
+                        let _ = child.kill().await;
+                        let stderr = stderr_handle.await.unwrap_or_default();
+                        eprintln!("Search/QMD: reindex_all failed to read qmd embed output: {} (stderr: {})", e, stderr.trim());
+                        return Err(format!("Failed to read qmd embed output: {}", e));
+                    }
+                },
+                _ = cancel.cancelled() => {
+                    let _ = child.kill().await;
+                    eprintln!("Search/QMD: reindex_all cancelled");
+                    return Err("Search index rebuild cancelled".to_string());
+                }
+            };
+            if n == 0 {
+                break;
+            }
+
+            pending.extend_from_slice(&chunk[..n]);
+            while let Some(pos) = pending.iter().position(|b| *b == b'\r' || *b == b'\n') {
+                let line: Vec<u8> = pending.drain(..=pos).collect();
+                if let Some((done, reported_total)) = parse_progress(&String::from_utf8_lossy(&line)) {
+                    // QMD reports chunks; scale onto our document count when we have one
+                    if total > 0 {
+                        indexed = done * total / reported_total;
+                        on_progress(indexed, total);
+                    } else {
+                        indexed = done;
+                        on_progress(done, reported_total);
+                    }
+                }
+            }
+        }
+
+        let status = child.wait().await
             .map_err(|e| format!("qmd embed failed: {}", e))?;
+        let stderr = stderr_handle.await.unwrap_or_default();
 
-        if !embed.status.success() {
-            let stderr = String::from_utf8_lossy(&embed.stderr);
+        if !status.success() {
             eprintln!("Search/QMD: reindex_all qmd embed FAILED: {}", stderr.trim());
             return Err(format!("qmd embed failed: {}", stderr));
         }
-        let stdout = String::from_utf8_lossy(&embed.stdout);
-        eprintln!("Search/QMD: reindex_all qmd embed succeeded: {}", stdout.trim());
+        eprintln!("Search/QMD: reindex_all qmd embed succeeded");
+
+        let indexed = if total > 0 { total } else { indexed };
+        on_progress(indexed, indexed);
+        Ok(indexed)
+    }
+}
+
+/// Count the markdown documents QMD indexes (`knowledge/{gem_id}/*.md`).
+fn count_knowledge_documents(knowledge_path: &Path) -> usize {
+    let Ok(entries) = std::fs::read_dir(knowledge_path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| std::fs::read_dir(e.path()).ok())
+        .flat_map(|files| files.flatten())
+        .filter(|f| f.path().extension().is_some_and(|ext| ext == "md"))
+        .count()
+}
+
+static PROGRESS_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(\d+)\s*/\s*(\d+)").unwrap()
+});
+
+/// Extract "N/M" progress from a line of QMD output (ANSI codes ignored).
+fn parse_progress(line: &str) -> Option<(usize, usize)> {
+    let caps = PROGRESS_REGEX.captures_iter(line).last()?;
+    let done = caps[1].parse().ok()?;
+    let total = caps[2].parse().ok()?;
+    if total == 0 || done > total {
+        return None;
+    }
+    Some((done, total))
+}
+
+static INDEX_PATH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?:~/|/)[^\s"']*\.sqlite\b"#).unwrap()
+});
+
+/// The index file named in `qmd status` output (an "Index: <path>" line),
+/// with a leading `~/` expanded
+fn index_path_from_status(status: &str) -> Option<PathBuf> {
+    let path = INDEX_PATH_REGEX.find(status)?.as_str();
+    match path.strip_prefix("~/") {
+        Some(rest) => dirs::home_dir().map(|home| home.join(rest)),
+        None => Some(PathBuf::from(path)),
+    }
+}
+
+/// QMD's default index location (`$XDG_CACHE_HOME/qmd/index.sqlite`, default ~/.cache).
+fn default_index_path() -> Option<PathBuf> {
+    let cache_dir = std::env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| dirs::home_dir().map(|h| h.join(".cache")))?;
+    Some(cache_dir.join("qmd").join("index.sqlite"))
+}
+
+/// Copy the index (and any WAL/SHM sidecars) to `*.bak`. Returns None if no index exists yet.
+fn backup_index(index_path: &Path) -> Result<Option<PathBuf>, String> {
+    if !index_path.exists() {
+        return Ok(None);
+    }
+    for suffix in ["", "-wal", "-shm"] {
+        let src = PathBuf::from(format!("{}{}", index_path.display(), suffix));
+        if src.exists() {
+            let dst = PathBuf::from(format!("{}{}.bak", index_path.display(), suffix));
+            std::fs::copy(&src, &dst)
+                .map_err(|e| format!("Failed to back up QMD index: {}", e))?;
+        }
+    }
+    Ok(Some(PathBuf::from(format!("{}.bak", index_path.display()))))
+}
 
-        // TODO: Parse output for indexed document count
-        // For now return 0 — refine after testing actual QMD CLI output
-        Ok(0)
+/// Delete the `*.bak` snapshot after a successful rebuild.
+fn remove_backup(index_path: &Path) {
+    for suffix in ["", "-wal", "-shm"] {
+        let _ = std::fs::remove_file(format!("{}{}.bak", index_path.display(), suffix));
     }
 }
 
+/// Put the `*.bak` snapshot back in place of a partially rebuilt index.
+fn restore_index(index_path: &Path, backup_path: &Path) -> Result<(), String> {
+    std::fs::rename(backup_path, index_path)
+        .map_err(|e| format!("Failed to restore QMD index: {}", e))?;
+    for suffix in ["-wal", "-shm"] {
+        let current = PathBuf::from(format!("{}{}", index_path.display(), suffix));
+        let backup = PathBuf::from(format!("{}{}.bak", index_path.display(), suffix));
+        if backup.exists() {
+            std::fs::rename(&backup, &current)
+                .map_err(|e| format!("Failed to restore QMD index: {}", e))?;
+        } else {
+            let _ = std::fs::remove_file(&current);
+        }
+    }
+    Ok(())
+}
+
 /// Extract gem_id from a QMD result file URI.
 ///
 /// QMD returns URIs like: "qmd://jarvis-gems/{gem_id}/enrichment.md"
@@ -419,3 +642,94 @@ fn normalize_qmd_score(raw_score: f64) -> f64 {
     // If QMD already returns 0-1, just clamp
     raw_score.clamp(0.0, 1.0)
 }
+
+/// Convert an accuracy percentage (0–100, clamped) to a minimum score.
+fn accuracy_to_min_score(accuracy_pct: u8) -> f64 {
+    (accuracy_pct.min(100) as f64) / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_progress() {
+        assert_eq!(parse_progress("Embedding chunks 12/40"), Some((12, 40)));
+        assert_eq!(parse_progress("\x1b[2K\x1b[32m████\x1b[0m 3 / 7 docs"), Some((3, 7)));
+        // The last N/M on the line is the counter
+        assert_eq!(parse_progress("batch 1/2: 30/60"), Some((30, 60)));
+        assert_eq!(parse_progress("0/0"), None);
+        assert_eq!(parse_progress("9/4"), None);
+        assert_eq!(parse_progress("Loading model..."), None);
+    }
+
+    /// `qmd` stand-in that prints `json` for every invocation
+    fn fake_qmd(dir: &tempfile::TempDir, json: &str) -> PathBuf {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = dir.path().join("qmd");
+        std::fs::write(&path, format!("#!/bin/sh\necho 'Searching...'\necho '{}'\n", json)).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    #[tokio::test]
+    async fn test_search_accuracy_override() {
+        let dir = tempfile::tempdir().unwrap();
+        let qmd = fake_qmd(&dir, r#"[
+            {"file": "qmd://jarvis-gems/strong/gem.md", "score": 0.9, "snippet": "tokio runtime"},
+            {"file": "qmd://jarvis-gems/strong/enrichment.md", "score": 0.6, "snippet": "tags"},
+            {"file": "qmd://jarvis-gems/weak/gem.md", "score": 0.4, "snippet": "async"}
+        ]"#);
+        let provider = QmdResultProvider::new(qmd, dir.path().to_path_buf(), 75);
+        let ids = |results: Vec<SearchResult>| results.into_iter().map(|r| r.gem_id).collect::<Vec<_>>();
+
+        // The configured threshold applies by default
+        assert_eq!(ids(provider.search("tokio", 10, None).await.unwrap()), vec!["strong"]);
+        // A broad query for this search only lets the weaker match through
+        assert_eq!(ids(provider.search("tokio", 10, Some(30)).await.unwrap()), vec!["strong", "weak"]);
+        assert!(provider.search("tokio", 10, Some(95)).await.unwrap().is_empty());
+        assert_eq!(ids(provider.search("tokio", 10, None).await.unwrap()), vec!["strong"]);
+    }
+
+    #[test]
+    fn test_index_path_from_status() {
+        let status = "QMD Status\n\nIndex: /Users/me/.cache/qmd/index.sqlite\nSize:  12.4 MB\n";
+        assert_eq!(
+            index_path_from_status(status),
+            Some(PathBuf::from("/Users/me/.cache/qmd/index.sqlite"))
+        );
+        assert_eq!(
+            index_path_from_status("Index: ~/.cache/qmd/index.sqlite"),
+            dirs::home_dir().map(|home| home.join(".cache/qmd/index.sqlite"))
+        );
+        assert_eq!(index_path_from_status("No collections yet"), None);
+    }
+
+    #[test]
+    fn test_backup_and_restore_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let index = dir.path().join("index.sqlite");
+        let wal = dir.path().join("index.sqlite-wal");
+
+        assert_eq!(backup_index(&index).unwrap(), None, "Nothing to back up yet");
+
+        std::fs::write(&index, "original").unwrap();
+        let backup = backup_index(&index).unwrap().expect("Index should be backed up");
+        assert_eq!(std::fs::read_to_string(&backup).unwrap(), "original");
+
+        // A failed rebuild leaves a partial index and a WAL the backup didn't have
+        std::fs::write(&index, "partial").unwrap();
+        std::fs::write(&wal, "partial wal").unwrap();
+        restore_index(&index, &backup).unwrap();
+        assert_eq!(std::fs::read_to_string(&index).unwrap(), "original");
+        assert!(!wal.exists(), "Stale WAL should be removed");
+        assert!(!backup.exists());
+
+        // After a successful rebuild the backup is discarded
+        let backup = backup_index(&index).unwrap().unwrap();
+        remove_backup(&index);
+        assert!(!backup.exists());
+        assert_eq!(std::fs::read_to_string(&index).unwrap(), "original");
+    }
+}
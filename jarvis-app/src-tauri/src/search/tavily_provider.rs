@@ -1,5 +1,9 @@
 // TavilyProvider - web search via Tavily Search API
 
+use std::collections::HashMap;
+use std::fmt::{self, Display, Formatter};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 use async_trait::async_trait;
 use reqwest::Client;
 use serde::{Deserialize, Serialize};
@@ -8,13 +12,30 @@ use super::provider::{
     SearchResultProvider, SearchResult, WebSearchResult, WebSourceType,
 };
 
+/// Tavily Search API endpoint
+const TAVILY_SEARCH_URL: &str = "https://api.tavily.com/search";
+
+/// How long a cached web search result stays valid
+const CACHE_TTL: Duration = Duration::from_secs(10 * 60);
+
+/// Cached results for one (normalized query, limit) pair
+struct CacheEntry {
+    results: Vec<WebSearchResult>,
+    fetched_at: Instant,
+}
+
 /// Web search provider backed by the Tavily Search API.
 ///
 /// Implements SearchResultProvider::web_search. All gem-related methods
 /// (search, index_gem, remove_gem, reindex_all) are no-ops.
+///
+/// Results are cached in memory for `CACHE_TTL`, keyed by normalized query
+/// and limit, so repeated research runs don't burn API quota.
 pub struct TavilyProvider {
     api_key: String,
     client: Client,
+    endpoint: String,
+    cache: Mutex<HashMap<(String, usize), CacheEntry>>,
 }
 
 impl TavilyProvider {
@@ -23,10 +44,108 @@ impl TavilyProvider {
         Self {
             api_key,
             client: Client::new(),
+            endpoint: TAVILY_SEARCH_URL.to_string(),
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Point the provider at a different endpoint (mock server in tests)
+    #[cfg(test)]
+    fn with_endpoint(mut self, endpoint: String) -> Self {
+        self.endpoint = endpoint;
+        self
+    }
+
+    /// Return unexpired cached results for this key, marked as cache hits.
+    fn cached_results(&self, key: &(String, usize)) -> Option<Vec<WebSearchResult>> {
+        let cache = self.cache.lock().ok()?;
+        let entry = cache.get(key)?;
+        if entry.fetched_at.elapsed() >= CACHE_TTL {
+            return None;
         }
+        Some(
+            entry.results
+                .iter()
+                .cloned()
+                .map(|mut r| {
+                    r.from_cache = true;
+                    r
+                })
+                .collect(),
+        )
+    }
+
+    /// Store fresh results, pruning expired entries while the lock is held.
+    fn store_results(&self, key: (String, usize), results: &[WebSearchResult]) {
+        if let Ok(mut cache) = self.cache.lock() {
+            cache.retain(|_, entry| entry.fetched_at.elapsed() < CACHE_TTL);
+            cache.insert(key, CacheEntry {
+                results: results.to_vec(),
+                fetched_at: Instant::now(),
+            });
+        }
+    }
+
+    /// Call the Tavily API and convert the response into WebSearchResults.
+    async fn fetch_results(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<WebSearchResult>, TavilyError> {
+        let request = TavilySearchRequest {
+            query: query.to_string(),
+            max_results: limit,
+            search_depth: "basic".to_string(),
+            api_key: self.api_key.clone(),
+        };
+
+        let response = self.client
+            .post(&self.endpoint)
+            .json(&request)
+            .send()
+            .await
+            .map_err(|e| TavilyError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            let status = response.status().as_u16();
+            let body = response.text().await.unwrap_or_default();
+            return Err(TavilyError::from_status(status, &body));
+        }
+
+        let tavily_response: TavilySearchResponse = response
+            .json()
+            .await
+            .map_err(|e| TavilyError::InvalidResponse(e.to_string()))?;
+
+        Ok(tavily_response
+            .results
+            .into_iter()
+            .map(|r| {
+                let source_type = classify_source_type(&r.url);
+                let domain = extract_domain(&r.url);
+                WebSearchResult {
+                    title: r.title,
+                    url: r.url,
+                    snippet: r.content,
+                    source_type,
+                    domain,
+                    published_date: r.published_date,
+                    from_cache: false,
+                }
+            })
+            .collect())
     }
 }
 
+/// Normalize a query for cache lookup: lowercase, collapse whitespace.
+fn normalize_query(query: &str) -> String {
+    query
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_lowercase()
+}
+
 // ── Tavily API request/response shapes ──
 
 #[derive(Serialize)]
@@ -51,6 +170,97 @@ struct TavilyResult {
     published_date: Option<String>,
 }
 
+// ── Errors ──
+
+/// Typed Tavily failures, mapped from HTTP status codes.
+///
+/// Converted to a user-facing `String` at the SearchResultProvider boundary.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TavilyError {
+    /// 401 — API key missing, revoked, or mistyped
+    InvalidApiKey,
+
+    /// 429 — too many requests in a short window
+    RateLimited,
+
+    /// 432/433 — plan or pay-as-you-go credits used up
+    QuotaExceeded,
+
+    /// Request never got a response (DNS, TLS, timeout, offline)
+    Network(String),
+
+    /// Response body could not be parsed
+    InvalidResponse(String),
+
+    /// Any other non-success status
+    Api { status: u16, message: String },
+}
+
+impl TavilyError {
+    /// Map a non-success HTTP status and response body to a TavilyError.
+    pub fn from_status(status: u16, body: &str) -> Self {
+        let message = error_detail(body);
+        let lower = message.to_lowercase();
+
+        match status {
+            401 => TavilyError::InvalidApiKey,
+            429 => TavilyError::RateLimited,
+            432 | 433 => TavilyError::QuotaExceeded,
+            _ if lower.contains("usage limit") || lower.contains("quota") => TavilyError::QuotaExceeded,
+            _ => TavilyError::Api { status, message },
+        }
+    }
+}
+
+impl Display for TavilyError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TavilyError::InvalidApiKey => {
+                write!(f, "Tavily API key is invalid. Check the key in Settings > Search.")
+            }
+            TavilyError::RateLimited => {
+                write!(f, "Tavily rate limit reached. Wait a minute and try again.")
+            }
+            TavilyError::QuotaExceeded => {
+                write!(f, "Tavily usage quota exceeded. Upgrade your plan or wait for the quota to reset.")
+            }
+            TavilyError::Network(msg) => {
+                write!(f, "Could not reach Tavily. Check your internet connection. ({})", msg)
+            }
+            TavilyError::InvalidResponse(msg) => {
+                write!(f, "Failed to parse Tavily response: {}", msg)
+            }
+            TavilyError::Api { status, message } => {
+                write!(f, "Tavily API returned {}: {}", status, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for TavilyError {}
+
+impl From<TavilyError> for String {
+    fn from(e: TavilyError) -> Self {
+        e.to_string()
+    }
+}
+
+/// Pull the error message out of a Tavily error body.
+///
+/// Tavily returns `{"detail": {"error": "..."}}`; fall back to the raw body.
+fn error_detail(body: &str) -> String {
+    serde_json::from_str::<serde_json::Value>(body)
+        .ok()
+        .and_then(|v| {
+            v.pointer("/detail/error")
+                .or_else(|| v.get("detail"))
+                .or_else(|| v.get("error"))
+                .and_then(|d| d.as_str())
+                .map(|d| d.to_string())
+        })
+        .unwrap_or_else(|| body.trim().to_string())
+}
+
 // ── Domain classification ──
 
 /// Classify a URL's domain into a WebSourceType.
@@ -94,7 +304,7 @@ impl SearchResultProvider for TavilyProvider {
     }
 
     // Gem search — not applicable for Tavily
-    async fn search(&self, _query: &str, _limit: usize) -> Result<Vec<SearchResult>, String> {
+    async fn search(&self, _query: &str, _limit: usize, _accuracy: Option<u8>) -> Result<Vec<SearchResult>, String> {
         Ok(Vec::new())
     }
 
@@ -118,48 +328,18 @@ impl SearchResultProvider for TavilyProvider {
     ) -> Result<Vec<WebSearchResult>, String> {
         eprintln!("Search/Tavily: web_search query=\"{}\" limit={}", query, limit);
 
-        let request = TavilySearchRequest {
-            query: query.to_string(),
-            max_results: limit,
-            search_depth: "basic".to_string(),
-            api_key: self.api_key.clone(),
-        };
-
-        let response = self.client
-            .post("https://api.tavily.com/search")
-            .json(&request)
-            .send()
-            .await
-            .map_err(|e| format!("Tavily API request failed: {}", e))?;
-
-        if !response.status().is_success() {
-            let status = response.status();
-            let body = response.text().await.unwrap_or_default();
-            eprintln!("Search/Tavily: API error {} — {}", status, body);
-            return Err(format!("Tavily API returned {}: {}", status, body));
+        let cache_key = (normalize_query(query), limit);
+        if let Some(results) = self.cached_results(&cache_key) {
+            eprintln!("Search/Tavily: Cache hit — returning {} results for \"{}\"", results.len(), query);
+            return Ok(results);
         }
 
-        let tavily_response: TavilySearchResponse = response
-            .json()
-            .await
-            .map_err(|e| format!("Failed to parse Tavily response: {}", e))?;
+        let results = self.fetch_results(query, limit).await.map_err(|e| {
+            eprintln!("Search/Tavily: web_search failed — {:?}", e);
+            String::from(e)
+        })?;
 
-        let results: Vec<WebSearchResult> = tavily_response
-            .results
-            .into_iter()
-            .map(|r| {
-                let source_type = classify_source_type(&r.url);
-                let domain = extract_domain(&r.url);
-                WebSearchResult {
-                    title: r.title,
-                    url: r.url,
-                    snippet: r.content,
-                    source_type,
-                    domain,
-                    published_date: r.published_date,
-                }
-            })
-            .collect();
+        self.store_results(cache_key, &results);
 
         eprintln!("Search/Tavily: Returning {} results for \"{}\"", results.len(), query);
         Ok(results)
@@ -168,4 +348,126 @@ impl SearchResultProvider for TavilyProvider {
     fn supports_web_search(&self) -> bool {
         true
     }
+
+    fn clear_web_search_cache(&self) -> usize {
+        match self.cache.lock() {
+            Ok(mut cache) => {
+                let cleared = cache.len();
+                cache.clear();
+                eprintln!("Search/Tavily: Cleared {} cached queries", cleared);
+                cleared
+            }
+            Err(_) => 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_401_maps_to_invalid_api_key() {
+        let body = r#"{"detail":{"error":"Unauthorized: missing or invalid API key."}}"#;
+        let err = TavilyError::from_status(401, body);
+        assert_eq!(err, TavilyError::InvalidApiKey);
+        assert!(String::from(err).contains("Settings"));
+    }
+
+    #[test]
+    fn test_429_maps_to_rate_limited() {
+        let err = TavilyError::from_status(429, "Too Many Requests");
+        assert_eq!(err, TavilyError::RateLimited);
+    }
+
+    #[test]
+    fn test_quota_statuses_map_to_quota_exceeded() {
+        let body = r#"{"detail":{"error":"This request exceeds your plan's set usage limit."}}"#;
+        assert_eq!(TavilyError::from_status(432, body), TavilyError::QuotaExceeded);
+        assert_eq!(TavilyError::from_status(433, body), TavilyError::QuotaExceeded);
+        // Quota wording on an unexpected status is still recognized
+        assert_eq!(TavilyError::from_status(403, body), TavilyError::QuotaExceeded);
+    }
+
+    #[test]
+    fn test_other_status_keeps_detail_message() {
+        let body = r#"{"detail":{"error":"Query is too long."}}"#;
+        let err = TavilyError::from_status(400, body);
+        assert_eq!(
+            err,
+            TavilyError::Api { status: 400, message: "Query is too long.".to_string() }
+        );
+        assert_eq!(String::from(err), "Tavily API returned 400: Query is too long.");
+    }
+
+    #[test]
+    fn test_non_json_body_falls_back_to_raw_text() {
+        let err = TavilyError::from_status(502, "  Bad Gateway\n");
+        assert_eq!(err, TavilyError::Api { status: 502, message: "Bad Gateway".to_string() });
+    }
+
+    /// Serve a single canned HTTP response on a local port, returning its URL.
+    async fn mock_tavily(status_line: &'static str, body: &'static str) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 8192];
+            let _ = socket.read(&mut buf).await;
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status_line,
+                body.len(),
+                body
+            );
+            let _ = socket.write_all(response.as_bytes()).await;
+        });
+        format!("http://{}/search", addr)
+    }
+
+    #[tokio::test]
+    async fn test_web_search_surfaces_rate_limit_message() {
+        let url = mock_tavily("429 Too Many Requests", r#"{"detail":{"error":"Rate limit exceeded"}}"#).await;
+        let provider = TavilyProvider::new("tvly-test-key".to_string()).with_endpoint(url);
+
+        let err = provider.web_search("rust async", 5).await.unwrap_err();
+        assert_eq!(err, TavilyError::RateLimited.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_web_search_surfaces_invalid_key_message() {
+        let url = mock_tavily("401 Unauthorized", r#"{"detail":{"error":"Unauthorized"}}"#).await;
+        let provider = TavilyProvider::new("tvly-bad-key".to_string()).with_endpoint(url);
+
+        let err = provider.web_search("rust async", 5).await.unwrap_err();
+        assert_eq!(err, TavilyError::InvalidApiKey.to_string());
+    }
+
+    #[tokio::test]
+    async fn test_web_search_parses_success_response() {
+        let url = mock_tavily(
+            "200 OK",
+            r#"{"results":[{"title":"Async Rust","url":"https://arxiv.org/abs/1234","content":"Futures explained"}]}"#,
+        ).await;
+        let provider = TavilyProvider::new("tvly-test-key".to_string()).with_endpoint(url);
+
+        let results = provider.web_search("rust async", 5).await.unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].domain, "arxiv.org");
+        assert!(matches!(results[0].source_type, WebSourceType::Paper));
+        assert!(!results[0].from_cache);
+    }
+
+    #[tokio::test]
+    async fn test_unreachable_host_maps_to_network_error() {
+        // Bind then drop a listener so the port is known to be closed
+        let addr = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap().local_addr().unwrap();
+        let provider = TavilyProvider::new("tvly-test-key".to_string())
+            .with_endpoint(format!("http://{}/search", addr));
+
+        let err = provider.web_search("rust async", 5).await.unwrap_err();
+        assert!(err.starts_with("Could not reach Tavily"), "got: {}", err);
+    }
 }
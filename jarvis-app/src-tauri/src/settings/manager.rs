@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, RwLock};
 
@@ -14,6 +15,16 @@ pub struct Settings {
     pub copilot: CoPilotSettings,
     #[serde(default)]
     pub search: SearchSettings,
+    #[serde(default)]
+    pub shortcuts: ShortcutSettings,
+    #[serde(default)]
+    pub notifications: NotificationSettings,
+    #[serde(default)]
+    pub recording: RecordingSettings,
+    #[serde(default)]
+    pub network: NetworkSettings,
+    #[serde(default)]
+    pub chat: ChatSettings,
 }
 
 /// Transcription-specific settings
@@ -32,12 +43,77 @@ pub struct TranscriptionSettings {
     pub mlx_omni_model: String,
     #[serde(default = "default_window_duration")]
     pub window_duration: f32,
+    /// Per-engine overrides of `window_duration`
+    #[serde(default)]
+    pub engine_window_durations: EngineWindowDurations,
+}
+
+/// Audio window duration (seconds) per transcription engine
+///
+/// Shorter windows give lower latency but less context per inference; longer
+/// windows improve accuracy at the cost of latency and memory. Whisper models
+/// are trained on 30s windows, so whisper-rs and WhisperKit gain accuracy up to
+/// ~30s. `None` falls back to `TranscriptionSettings::window_duration`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EngineWindowDurations {
+    /// whisper-rs (HybridProvider, with optional Vosk partials)
+    #[serde(default)]
+    pub whisper_rs: Option<f32>,
+    #[serde(default)]
+    pub whisperkit: Option<f32>,
+}
+
+impl TranscriptionSettings {
+    /// Window duration for the provider with the given `TranscriptionProvider::name()`
+    pub fn window_duration_for(&self, provider_name: &str) -> f32 {
+        let override_secs = match provider_name {
+            "hybrid-vad-vosk-whisper" | "whisper" => self.engine_window_durations.whisper_rs,
+            "whisperkit" => self.engine_window_durations.whisperkit,
+            _ => None,
+        };
+        override_secs.unwrap_or(self.window_duration)
+    }
 }
 
 /// Browser observer settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BrowserSettings {
     pub observer_enabled: bool,
+    /// Download gem OG images into ~/.jarvis/thumbnails instead of relying on the remote URL
+    #[serde(default)]
+    pub cache_gem_images: bool,
+    /// When merging a page with a Claude conversation, drop conversation
+    /// paragraphs that repeat the page
+    #[serde(default)]
+    pub dedup_merged_paragraphs: bool,
+    /// Store a hash of each captured page's full content in
+    /// `source_meta.content_hash`, so re-extraction and update checks can
+    /// tell whether the source changed
+    #[serde(default)]
+    pub track_content_changes: bool,
+    /// JavaScript run in the tab to capture pages on a domain (subdomains
+    /// included), replacing the built-in extractor; keyed by bare domain,
+    /// e.g. "example.com"
+    #[serde(default)]
+    pub domain_js_overrides: HashMap<String, String>,
+}
+
+impl BrowserSettings {
+    /// Check every `domain_js_overrides` key is a bare domain with a non-empty script
+    pub fn validate(&self) -> Result<(), String> {
+        for (domain, script) in &self.domain_js_overrides {
+            if domain.trim().is_empty() || domain.contains("://") || domain.contains('/') {
+                return Err(format!(
+                    "Invalid domain '{}' for a JS override (use a bare domain like \"example.com\")",
+                    domain
+                ));
+            }
+            if script.trim().is_empty() {
+                return Err(format!("JS override for '{}' is empty", domain));
+            }
+        }
+        Ok(())
+    }
 }
 
 /// Intelligence/AI provider settings
@@ -46,6 +122,65 @@ pub struct IntelligenceSettings {
     pub provider: String,       // "mlx" | "intelligencekit" | "api"
     pub active_model: String,   // catalog ID, e.g. "qwen3-8b-4bit"
     pub python_path: String,    // "python3" or absolute path
+    /// Captured page excerpts and gem content longer than this many characters
+    /// are cut and marked "[truncated]" (0 = no limit)
+    #[serde(default = "default_max_content_chars")]
+    pub max_content_chars: usize,
+    /// Shut the MLX sidecar down after this many idle seconds (0 = keep it running)
+    #[serde(default = "default_sidecar_idle_seconds")]
+    pub sidecar_idle_seconds: u64,
+    /// Generate tags/summary from a gem's transcript (when it has one) instead
+    /// of its content
+    #[serde(default = "default_prefer_transcript_for_enrichment")]
+    pub prefer_transcript_for_enrichment: bool,
+    /// Give up on tag generation after this many seconds
+    #[serde(default = "default_tags_timeout_secs")]
+    pub tags_timeout_secs: u64,
+    /// Give up on summarization after this many seconds
+    #[serde(default = "default_summary_timeout_secs")]
+    pub summary_timeout_secs: u64,
+    /// Give up on transcription after this many seconds
+    #[serde(default = "default_transcript_timeout_secs")]
+    pub transcript_timeout_secs: u64,
+    /// Endpoints the "api" provider can use
+    #[serde(default)]
+    pub api_endpoints: Vec<ApiEndpointConfig>,
+    /// Name of the `api_endpoints` entry the "api" provider uses
+    #[serde(default)]
+    pub active_api_endpoint: Option<String>,
+}
+
+/// An OpenAI-compatible chat endpoint for the "api" provider (OpenAI, a
+/// local Ollama, a work gateway, ...)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiEndpointConfig {
+    /// Unique display name, e.g. "ollama"
+    pub name: String,
+    /// Base URL, e.g. "http://localhost:11434/v1"
+    pub base_url: String,
+    /// Bearer token; None for endpoints that don't need one
+    #[serde(default)]
+    pub api_key: Option<String>,
+    /// Model name sent with each request
+    pub model: String,
+}
+
+impl ApiEndpointConfig {
+    /// Check the name and model are non-empty and the base URL is http(s)
+    pub fn validate(&self) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("API endpoint name cannot be empty".to_string());
+        }
+        let url = reqwest::Url::parse(self.base_url.trim())
+            .map_err(|e| format!("Invalid base URL for API endpoint '{}': {}", self.name, e))?;
+        if url.scheme() != "http" && url.scheme() != "https" {
+            return Err(format!("Base URL for API endpoint '{}' must be http or https", self.name));
+        }
+        if self.model.trim().is_empty() {
+            return Err(format!("Model for API endpoint '{}' cannot be empty", self.name));
+        }
+        Ok(())
+    }
 }
 
 /// Co-Pilot agent settings
@@ -59,6 +194,10 @@ pub struct CoPilotSettings {
     pub audio_overlap: u64,
     #[serde(default = "default_agent_logging")]
     pub agent_logging: bool,
+    /// Freeform hint the analysis should prioritize (e.g., "architecture decisions",
+    /// "action items"); None for general analysis
+    #[serde(default)]
+    pub focus: Option<String>,
 }
 
 /// Search-specific settings
@@ -74,6 +213,216 @@ pub struct SearchSettings {
     pub tavily_api_key: Option<String>,
 }
 
+/// Global keyboard shortcut settings (Tauri accelerator strings, e.g. "Cmd+Shift+G")
+///
+/// An empty string disables that shortcut.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortcutSettings {
+    /// Start or stop recording
+    #[serde(default = "default_toggle_recording_shortcut")]
+    pub toggle_recording: String,
+    /// Capture the active browser tab as a gem
+    #[serde(default = "default_capture_tab_shortcut")]
+    pub capture_tab: String,
+    /// Start or stop the Co-Pilot agent
+    #[serde(default = "default_toggle_copilot_shortcut")]
+    pub toggle_copilot: String,
+}
+
+impl ShortcutSettings {
+    /// Accelerators paired with their setting name, in registration order
+    pub fn bindings(&self) -> [(&'static str, &str); 3] {
+        [
+            ("toggle_recording", self.toggle_recording.as_str()),
+            ("capture_tab", self.capture_tab.as_str()),
+            ("toggle_copilot", self.toggle_copilot.as_str()),
+        ]
+    }
+
+    /// Check every non-empty accelerator parses and no two actions share one
+    pub fn validate(&self) -> Result<(), String> {
+        use tauri_plugin_global_shortcut::Shortcut;
+
+        let mut seen: Vec<(&str, &str, Shortcut)> = Vec::new();
+        for (name, accelerator) in self.bindings() {
+            if accelerator.trim().is_empty() {
+                continue;
+            }
+            let shortcut: Shortcut = accelerator.parse().map_err(|e| {
+                format!("Invalid shortcut for {}: '{}' ({})", name, accelerator, e)
+            })?;
+            if let Some((other, other_accelerator, _)) = seen.iter().find(|(_, _, s)| *s == shortcut) {
+                return Err(format!(
+                    "Shortcut conflict: {} ('{}') and {} ('{}') use the same keys",
+                    other, other_accelerator, name, accelerator
+                ));
+            }
+            seen.push((name, accelerator, shortcut));
+        }
+        Ok(())
+    }
+}
+
+/// Native notification settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationSettings {
+    /// Notify when a background job (model download, batch enrichment,
+    /// background transcription) completes or fails
+    #[serde(default = "default_background_job_notifications")]
+    pub background_jobs: bool,
+}
+
+/// Recording settings
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordingSettings {
+    /// Transcribe each recording after it stops and save it as a gem
+    #[serde(default)]
+    pub auto_save_gem: bool,
+    /// Periodically write finalized transcript segments into a draft gem while
+    /// recording, so a crash doesn't lose the meeting
+    #[serde(default)]
+    pub incremental_transcript_save: bool,
+}
+
+/// Recording chat settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ChatSettings {
+    /// Transcript context sent with each chat message is fitted into about
+    /// this many characters: the most recent part verbatim, earlier parts as
+    /// a summary
+    #[serde(default = "default_context_budget_chars")]
+    pub context_budget_chars: usize,
+}
+
+/// Smallest `ChatSettings::context_budget_chars` accepted
+pub const MIN_CONTEXT_BUDGET_CHARS: usize = 2_000;
+
+/// Outbound HTTP settings for page extraction and YouTube metadata
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NetworkSettings {
+    /// Proxy for all extractor requests (e.g. "http://proxy.corp:8080");
+    /// None connects directly
+    #[serde(default)]
+    pub proxy_url: Option<String>,
+    /// Give up connecting after this many seconds
+    #[serde(default = "default_connect_timeout_secs")]
+    pub connect_timeout_secs: u64,
+    /// Give up when a response stalls for this many seconds
+    #[serde(default = "default_read_timeout_secs")]
+    pub read_timeout_secs: u64,
+    /// Give up on a request, body included, after this many seconds
+    #[serde(default = "default_request_timeout_secs")]
+    pub request_timeout_secs: u64,
+    /// User-Agent header sent with every request
+    #[serde(default = "default_user_agent")]
+    pub user_agent: String,
+    /// User-Agent per fetcher, replacing `user_agent` for that fetcher's
+    /// requests; keys are from `USER_AGENT_OVERRIDE_KEYS`
+    #[serde(default)]
+    pub user_agent_overrides: HashMap<String, String>,
+}
+
+/// Fetchers that accept a `NetworkSettings::user_agent_overrides` entry
+pub const USER_AGENT_OVERRIDE_KEYS: [&str; 3] = ["generic", "youtube", "thumbnails"];
+
+impl NetworkSettings {
+    /// Check the proxy URL parses, the timeouts are non-zero and every
+    /// user-agent override names a known fetcher
+    pub fn validate(&self) -> Result<(), String> {
+        if let Some(proxy_url) = self.proxy_url.as_deref().filter(|u| !u.trim().is_empty()) {
+            reqwest::Proxy::all(proxy_url.trim())
+                .map_err(|e| format!("Invalid proxy URL '{}': {}", proxy_url, e))?;
+        }
+        for (name, secs) in [
+            ("connect_timeout_secs", self.connect_timeout_secs),
+            ("read_timeout_secs", self.read_timeout_secs),
+            ("request_timeout_secs", self.request_timeout_secs),
+        ] {
+            if secs == 0 {
+                return Err(format!("Network {} must be greater than 0", name));
+            }
+        }
+        if self.user_agent.trim().is_empty() {
+            return Err("Network user_agent cannot be empty".to_string());
+        }
+        for (fetcher, user_agent) in &self.user_agent_overrides {
+            if !USER_AGENT_OVERRIDE_KEYS.contains(&fetcher.as_str()) {
+                return Err(format!(
+                    "Unknown user_agent_overrides key '{}'. Must be one of: {}",
+                    fetcher,
+                    USER_AGENT_OVERRIDE_KEYS.join(", ")
+                ));
+            }
+            if user_agent.trim().is_empty() {
+                return Err(format!("Network user_agent override for {} cannot be empty", fetcher));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn default_background_job_notifications() -> bool {
+    true
+}
+
+fn default_toggle_recording_shortcut() -> String {
+    "Cmd+Shift+R".to_string()
+}
+
+fn default_capture_tab_shortcut() -> String {
+    "Cmd+Shift+G".to_string()
+}
+
+fn default_toggle_copilot_shortcut() -> String {
+    "Cmd+Shift+J".to_string()
+}
+
+fn default_connect_timeout_secs() -> u64 {
+    10
+}
+
+fn default_read_timeout_secs() -> u64 {
+    10
+}
+
+fn default_request_timeout_secs() -> u64 {
+    30
+}
+
+fn default_user_agent() -> String {
+    "Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36".to_string()
+}
+
+fn default_context_budget_chars() -> usize {
+    14_000
+}
+
+fn default_max_content_chars() -> usize {
+    100_000
+}
+
+fn default_sidecar_idle_seconds() -> u64 {
+    0
+}
+
+fn default_prefer_transcript_for_enrichment() -> bool {
+    true
+}
+
+fn default_tags_timeout_secs() -> u64 {
+    60
+}
+
+fn default_summary_timeout_secs() -> u64 {
+    120
+}
+
+/// Matches the MLX provider's own transcription budget, so the outer limit
+/// never cuts off a transcription the provider would still finish
+fn default_transcript_timeout_secs() -> u64 {
+    600
+}
+
 fn default_search_accuracy() -> u8 {
     75
 }
@@ -122,6 +471,7 @@ impl Default for TranscriptionSettings {
             whisperkit_model: default_whisperkit_model(),
             mlx_omni_model: default_mlx_omni_model(),
             window_duration: default_window_duration(),
+            engine_window_durations: EngineWindowDurations::default(),
         }
     }
 }
@@ -130,6 +480,10 @@ impl Default for BrowserSettings {
     fn default() -> Self {
         Self {
             observer_enabled: true,
+            cache_gem_images: false,
+            dedup_merged_paragraphs: false,
+            track_content_changes: false,
+            domain_js_overrides: HashMap::new(),
         }
     }
 }
@@ -140,6 +494,14 @@ impl Default for IntelligenceSettings {
             provider: "mlx".to_string(),
             active_model: "qwen3-8b-4bit".to_string(),
             python_path: "python3".to_string(),
+            max_content_chars: default_max_content_chars(),
+            sidecar_idle_seconds: default_sidecar_idle_seconds(),
+            prefer_transcript_for_enrichment: default_prefer_transcript_for_enrichment(),
+            tags_timeout_secs: default_tags_timeout_secs(),
+            summary_timeout_secs: default_summary_timeout_secs(),
+            transcript_timeout_secs: default_transcript_timeout_secs(),
+            api_endpoints: Vec::new(),
+            active_api_endpoint: None,
         }
     }
 }
@@ -151,6 +513,7 @@ impl Default for CoPilotSettings {
             cycle_interval: default_cycle_interval(),
             audio_overlap: default_audio_overlap(),
             agent_logging: default_agent_logging(),
+            focus: None,
         }
     }
 }
@@ -165,6 +528,45 @@ impl Default for SearchSettings {
     }
 }
 
+impl Default for ShortcutSettings {
+    fn default() -> Self {
+        Self {
+            toggle_recording: default_toggle_recording_shortcut(),
+            capture_tab: default_capture_tab_shortcut(),
+            toggle_copilot: default_toggle_copilot_shortcut(),
+        }
+    }
+}
+
+impl Default for NotificationSettings {
+    fn default() -> Self {
+        Self {
+            background_jobs: default_background_job_notifications(),
+        }
+    }
+}
+
+impl Default for NetworkSettings {
+    fn default() -> Self {
+        Self {
+            proxy_url: None,
+            connect_timeout_secs: default_connect_timeout_secs(),
+            read_timeout_secs: default_read_timeout_secs(),
+            request_timeout_secs: default_request_timeout_secs(),
+            user_agent: default_user_agent(),
+            user_agent_overrides: HashMap::new(),
+        }
+    }
+}
+
+impl Default for ChatSettings {
+    fn default() -> Self {
+        Self {
+            context_budget_chars: default_context_budget_chars(),
+        }
+    }
+}
+
 impl Default for Settings {
     fn default() -> Self {
         Self {
@@ -173,7 +575,54 @@ impl Default for Settings {
             intelligence: IntelligenceSettings::default(),
             copilot: CoPilotSettings::default(),
             search: SearchSettings::default(),
+            shortcuts: ShortcutSettings::default(),
+            notifications: NotificationSettings::default(),
+            recording: RecordingSettings::default(),
+            network: NetworkSettings::default(),
+            chat: ChatSettings::default(),
+        }
+    }
+}
+
+/// Stands in for a stored API key in settings sent to the frontend
+pub const REDACTED_SECRET: &str = "********";
+
+impl Settings {
+    /// Copy with API keys removed, so backup archives hold no credentials
+    pub fn without_secrets(&self) -> Settings {
+        self.map_secrets(|_| None)
+    }
+
+    /// Copy with each stored API key replaced by `REDACTED_SECRET`, for
+    /// settings sent to the frontend
+    pub fn redacted(&self) -> Settings {
+        self.map_secrets(|key| key.map(|_| REDACTED_SECRET.to_string()))
+    }
+
+    /// Carry the API keys in `current` over to keys that are missing (restored
+    /// backups) or still `REDACTED_SECRET` (settings edited in the frontend);
+    /// endpoints are matched by name
+    pub fn keep_secrets(&mut self, current: &Settings) {
+        let unset = |key: &Option<String>| key.as_deref().is_none_or(|k| k == REDACTED_SECRET);
+        for endpoint in &mut self.intelligence.api_endpoints {
+            if unset(&endpoint.api_key) {
+                endpoint.api_key = current.intelligence.api_endpoints.iter()
+                    .find(|e| e.name == endpoint.name)
+                    .and_then(|e| e.api_key.clone());
+            }
+        }
+        if unset(&self.search.tavily_api_key) {
+            self.search.tavily_api_key = current.search.tavily_api_key.clone();
+        }
+    }
+
+    fn map_secrets(&self, map: impl Fn(Option<String>) -> Option<String>) -> Settings {
+        let mut settings = self.clone();
+        for endpoint in &mut settings.intelligence.api_endpoints {
+            endpoint.api_key = map(endpoint.api_key.take());
         }
+        settings.search.tavily_api_key = map(settings.search.tavily_api_key.take());
+        settings
     }
 }
 
@@ -289,6 +738,8 @@ impl SettingsManager {
     /// - vad_threshold is not in range [0.0, 1.0]
     /// - whisper_model is an empty string
     /// - transcription_engine is not "whisper-rs" or "whisperkit"
+    /// - a shortcut accelerator is invalid or shared by two actions
+    /// - the network proxy URL is invalid or a network timeout is 0
     fn validate(settings: &Settings) -> Result<(), String> {
         // Validate VAD threshold range
         if settings.transcription.vad_threshold < 0.0 || settings.transcription.vad_threshold > 1.0 {
@@ -311,6 +762,19 @@ impl SettingsManager {
             ));
         }
 
+        // Validate per-engine window overrides (1-30 seconds, Whisper's native window)
+        let overrides = &settings.transcription.engine_window_durations;
+        for (engine, duration) in [("whisper-rs", overrides.whisper_rs), ("whisperkit", overrides.whisperkit)] {
+            if let Some(duration) = duration {
+                if !(1.0..=30.0).contains(&duration) {
+                    return Err(format!(
+                        "Window duration for {} must be between 1.0 and 30.0 seconds, got {}",
+                        engine, duration
+                    ));
+                }
+            }
+        }
+
         // Validate transcription_engine
         let engine = settings.transcription.transcription_engine.as_str();
         if engine != "whisper-rs" && engine != "whisperkit" && engine != "mlx-omni" {
@@ -338,6 +802,26 @@ impl SettingsManager {
         if settings.intelligence.python_path.trim().is_empty() {
             return Err("Intelligence python_path cannot be empty".to_string());
         }
+
+        // Validate max_content_chars leaves room for meaningful content
+        let max_content_chars = settings.intelligence.max_content_chars;
+        if max_content_chars != 0 && max_content_chars < 1_000 {
+            return Err(format!(
+                "Intelligence max_content_chars must be 0 (no limit) or at least 1000, got {}",
+                settings.intelligence.max_content_chars
+            ));
+        }
+
+        // Validate provider timeouts (0 would fail every call immediately)
+        for (name, secs) in [
+            ("tags_timeout_secs", settings.intelligence.tags_timeout_secs),
+            ("summary_timeout_secs", settings.intelligence.summary_timeout_secs),
+            ("transcript_timeout_secs", settings.intelligence.transcript_timeout_secs),
+        ] {
+            if secs == 0 {
+                return Err(format!("Intelligence {} must be greater than 0", name));
+            }
+        }
         
         // Validate copilot settings
         if settings.copilot.cycle_interval < 30 || settings.copilot.cycle_interval > 120 {
@@ -362,6 +846,31 @@ impl SettingsManager {
             ));
         }
         
+        // Validate API endpoints (unique names; the active one must exist)
+        let endpoints = &settings.intelligence.api_endpoints;
+        for (i, endpoint) in endpoints.iter().enumerate() {
+            endpoint.validate()?;
+            if endpoints[..i].iter().any(|e| e.name == endpoint.name) {
+                return Err(format!("Duplicate API endpoint name '{}'", endpoint.name));
+            }
+        }
+        if let Some(active) = &settings.intelligence.active_api_endpoint {
+            if !endpoints.iter().any(|e| &e.name == active) {
+                return Err(format!("Active API endpoint '{}' is not configured", active));
+            }
+        }
+
+        if settings.chat.context_budget_chars < MIN_CONTEXT_BUDGET_CHARS {
+            return Err(format!(
+                "Chat context_budget_chars must be at least {}, got {}",
+                MIN_CONTEXT_BUDGET_CHARS, settings.chat.context_budget_chars
+            ));
+        }
+
+        settings.browser.validate()?;
+        settings.shortcuts.validate()?;
+        settings.network.validate()?;
+        
         Ok(())
     }
     
@@ -4,5 +4,5 @@ pub mod model_manager;
 #[cfg(test)]
 mod tests;
 
-pub use manager::{BrowserSettings, CoPilotSettings, IntelligenceSettings, SearchSettings, Settings, SettingsManager, TranscriptionSettings};
+pub use manager::{ApiEndpointConfig, BrowserSettings, ChatSettings, CoPilotSettings, EngineWindowDurations, IntelligenceSettings, NetworkSettings, NotificationSettings, RecordingSettings, SearchSettings, Settings, SettingsManager, ShortcutSettings, TranscriptionSettings, REDACTED_SECRET, USER_AGENT_OVERRIDE_KEYS};
 pub use model_manager::{ModelInfo, ModelManager, ModelStatus};
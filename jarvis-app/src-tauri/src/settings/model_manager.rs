@@ -5,6 +5,8 @@ use tauri::{AppHandle, Emitter};
 use tokio::sync::Mutex as TokioMutex;
 use tokio_util::sync::CancellationToken;
 
+use crate::notifications;
+
 /// Static metadata for a model in the catalog
 struct ModelEntry {
     filename: &'static str,
@@ -420,11 +422,18 @@ impl ModelManager {
                         "model-download-complete",
                         serde_json::json!({ "model_name": model_name }),
                     );
+                    notifications::notify_job_completed(&app_handle, "Model download", &model_name);
                 }
                 Err(e) => {
                     // Store error state
                     error_states_clone.lock().await.insert(model_name.clone(), e.clone());
                     
+                    if e == "Download cancelled" {
+                        notifications::notify_job_cancelled(&app_handle, "Model download", &model_name);
+                    } else {
+                        notifications::notify_job_failed(&app_handle, "Model download", &format!("{}: {}", model_name, e));
+                    }
+
                     // Emit error event
                     let _ = app_handle.emit(
                         "model-download-error",
@@ -573,6 +582,19 @@ impl ModelManager {
         Ok(())
     }
     
+    /// Cancels every in-progress download, returning the cancelled model names
+    pub async fn cancel_all_downloads(&self) -> Vec<String> {
+        let model_names: Vec<String> = self.download_queue.lock().await.keys().cloned().collect();
+        let mut cancelled = Vec::new();
+        for model_name in model_names {
+            // A download may finish between listing and cancelling; skip those
+            if self.cancel_download(model_name.clone()).await.is_ok() {
+                cancelled.push(model_name);
+            }
+        }
+        cancelled
+    }
+
     /// Deletes a downloaded model
     /// 
     /// # Errors
@@ -741,8 +763,11 @@ impl ModelManager {
                         "model-download-complete",
                         serde_json::json!({ "model_name": model_name }),
                     );
+                    notifications::notify_job_completed(&app_handle, "Model download", &model_name);
                 }
                 Err(e) => {
+                    notifications::notify_job_failed(&app_handle, "Model download", &format!("{}: {}", model_name, e));
+
                     // Emit error event
                     let _ = app_handle.emit(
                         "model-download-error",
@@ -53,6 +53,12 @@ mod property_tests {
                 browser: crate::settings::BrowserSettings::default(),
                 intelligence: crate::settings::IntelligenceSettings::default(),
                 copilot: crate::settings::CoPilotSettings::default(),
+                search: crate::settings::SearchSettings::default(),
+                shortcuts: crate::settings::ShortcutSettings::default(),
+                notifications: crate::settings::NotificationSettings::default(),
+                recording: crate::settings::RecordingSettings::default(),
+                network: crate::settings::NetworkSettings::default(),
+                chat: crate::settings::ChatSettings::default(),
             };
 
             // Verify the settings can be updated successfully
@@ -147,6 +153,12 @@ mod property_tests {
             browser: crate::settings::BrowserSettings::default(),
             intelligence: crate::settings::IntelligenceSettings::default(),
             copilot: crate::settings::CoPilotSettings::default(),
+            search: crate::settings::SearchSettings::default(),
+            shortcuts: crate::settings::ShortcutSettings::default(),
+            notifications: crate::settings::NotificationSettings::default(),
+            recording: crate::settings::RecordingSettings::default(),
+            network: crate::settings::NetworkSettings::default(),
+            chat: crate::settings::ChatSettings::default(),
         };
 
         // Test that the manager update succeeds
@@ -287,9 +299,61 @@ mod whisperkit_catalog_tests {
     }
 }
 
+#[cfg(test)]
+mod browser_settings_tests {
+    use crate::settings::BrowserSettings;
+
+    /// Test JS override validation: keys must be bare domains with a script
+    #[test]
+    fn test_domain_js_override_validation() {
+        let with_override = |domain: &str, script: &str| BrowserSettings {
+            domain_js_overrides: [(domain.to_string(), script.to_string())].into(),
+            ..Default::default()
+        };
+
+        assert!(with_override("example.com", "document.body.innerText").validate().is_ok());
+        assert!(with_override("https://example.com", "document.body.innerText").validate().is_err());
+        assert!(with_override("example.com/blog", "document.body.innerText").validate().is_err());
+        let err = with_override("example.com", "  ").validate().unwrap_err();
+        assert!(err.contains("is empty"), "got: {}", err);
+    }
+}
+
+#[cfg(test)]
+mod transcription_settings_tests {
+    use crate::settings::{EngineWindowDurations, SettingsManager, TranscriptionSettings};
+
+    /// Test per-engine window durations override the shared one and are validated on save
+    #[test]
+    fn test_engine_window_durations() {
+        let mut transcription = TranscriptionSettings {
+            window_duration: 3.0,
+            engine_window_durations: EngineWindowDurations {
+                whisper_rs: None,
+                whisperkit: Some(25.0),
+            },
+            ..Default::default()
+        };
+        assert_eq!(transcription.window_duration_for("whisperkit"), 25.0);
+        assert_eq!(transcription.window_duration_for("hybrid-vad-vosk-whisper"), 3.0);
+        assert_eq!(transcription.window_duration_for("mock-provider"), 3.0);
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let manager = SettingsManager::new_with_path(temp_dir.path().join("settings.json")).unwrap();
+        let mut settings = manager.get();
+        settings.transcription = transcription.clone();
+        assert!(manager.update(settings.clone()).is_ok());
+
+        transcription.engine_window_durations.whisper_rs = Some(45.0);
+        settings.transcription = transcription;
+        let err = manager.update(settings).unwrap_err();
+        assert!(err.contains("whisper-rs"), "got: {}", err);
+    }
+}
+
 #[cfg(test)]
 mod intelligence_settings_tests {
-    use crate::settings::{Settings, SettingsManager};
+    use crate::settings::{ApiEndpointConfig, Settings, SettingsManager, REDACTED_SECRET};
 
     /// Test backward compatibility with settings files that don't have intelligence field
     /// 
@@ -346,6 +410,16 @@ mod intelligence_settings_tests {
             "intelligence.python_path should default to 'python3'"
         );
         
+        // Verify shortcuts field uses defaults
+        assert_eq!(loaded_settings.shortcuts.capture_tab, "Cmd+Shift+G");
+
+        // Verify notifications field uses defaults
+        assert!(loaded_settings.notifications.background_jobs);
+
+        // Verify recording field uses defaults
+        assert!(!loaded_settings.recording.auto_save_gem);
+        assert!(!loaded_settings.recording.incremental_transcript_save);
+        
         // Verify settings can be saved and reloaded without errors
         let result = manager.update(loaded_settings.clone());
         assert!(result.is_ok(), "Settings update should succeed after loading old format");
@@ -358,6 +432,37 @@ mod intelligence_settings_tests {
         );
     }
     
+    /// Test shortcut validation: bad accelerators and duplicate combos are rejected
+    #[test]
+    fn test_shortcut_settings_validation() {
+        use crate::settings::ShortcutSettings;
+
+        assert!(ShortcutSettings::default().validate().is_ok());
+
+        let disabled = ShortcutSettings {
+            toggle_copilot: String::new(),
+            ..Default::default()
+        };
+        assert!(disabled.validate().is_ok(), "Empty accelerator disables the shortcut");
+
+        let invalid = ShortcutSettings {
+            capture_tab: "Cmd+Shift+Banana".to_string(),
+            ..Default::default()
+        };
+        let err = invalid.validate().unwrap_err();
+        assert!(err.contains("capture_tab"), "got: {}", err);
+
+        // Same keys in a different order still conflict
+        let conflict = ShortcutSettings {
+            toggle_recording: "Cmd+Shift+R".to_string(),
+            toggle_copilot: "Shift+Cmd+R".to_string(),
+            ..Default::default()
+        };
+        let err = conflict.validate().unwrap_err();
+        assert!(err.contains("conflict"), "got: {}", err);
+        assert!(err.contains("toggle_recording") && err.contains("toggle_copilot"), "got: {}", err);
+    }
+
     /// Test settings validation for intelligence provider
     #[test]
     fn test_intelligence_provider_validation() {
@@ -423,4 +528,65 @@ mod intelligence_settings_tests {
             );
         }
     }
+
+    /// Test settings validation for API endpoints
+    #[test]
+    fn test_api_endpoint_validation() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let manager = SettingsManager::new_with_path(temp_dir.path().join("settings.json")).unwrap();
+
+        let endpoint = |name: &str, base_url: &str| ApiEndpointConfig {
+            name: name.to_string(),
+            base_url: base_url.to_string(),
+            api_key: None,
+            model: "llama3".to_string(),
+        };
+
+        let mut settings = manager.get();
+        settings.intelligence.api_endpoints = vec![endpoint("ollama", "http://localhost:11434/v1")];
+        settings.intelligence.active_api_endpoint = Some("ollama".to_string());
+        assert!(manager.update(settings).is_ok());
+
+        let mut settings = manager.get();
+        settings.intelligence.api_endpoints.push(endpoint("ollama", "https://api.openai.com/v1"));
+        assert!(manager.update(settings).unwrap_err().contains("Duplicate API endpoint name"));
+
+        let mut settings = manager.get();
+        settings.intelligence.api_endpoints.push(endpoint("work", "ftp://gateway"));
+        assert!(manager.update(settings).unwrap_err().contains("must be http or https"));
+
+        let mut settings = manager.get();
+        settings.intelligence.active_api_endpoint = Some("missing".to_string());
+        assert!(manager.update(settings).unwrap_err().contains("is not configured"));
+    }
+
+    /// Test API keys are redacted for the frontend and kept when redacted settings are saved back
+    #[test]
+    fn test_redacted_settings_keep_secrets() {
+        let endpoint = |name: &str, api_key: Option<&str>| ApiEndpointConfig {
+            name: name.to_string(),
+            base_url: "https://api.example.com/v1".to_string(),
+            api_key: api_key.map(String::from),
+            model: "llama3".to_string(),
+        };
+        let mut current = Settings::default();
+        current.intelligence.api_endpoints = vec![endpoint("work", Some("sk-work")), endpoint("ollama", None)];
+        current.search.tavily_api_key = Some("tvly-key".to_string());
+
+        let redacted = current.redacted();
+        let keys: Vec<Option<&str>> = redacted.intelligence.api_endpoints.iter().map(|e| e.api_key.as_deref()).collect();
+        assert_eq!(keys, vec![Some(REDACTED_SECRET), None]);
+        assert_eq!(redacted.search.tavily_api_key.as_deref(), Some(REDACTED_SECRET));
+
+        // Saved back unchanged: stored keys survive
+        let mut edited = redacted.clone();
+        edited.keep_secrets(&current);
+        assert_eq!(serde_json::to_value(&edited).unwrap(), serde_json::to_value(&current).unwrap());
+
+        // A key typed in the frontend replaces the stored one
+        let mut edited = redacted;
+        edited.search.tavily_api_key = Some("tvly-new".to_string());
+        edited.keep_secrets(&current);
+        assert_eq!(edited.search.tavily_api_key.as_deref(), Some("tvly-new"));
+    }
 }
@@ -1,7 +1,7 @@
 use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
-use std::sync::Mutex;
-use crate::recording::RecordingManager;
+use std::sync::{Arc, RwLock};
+use crate::settings::{SettingsManager, ShortcutSettings};
 
 /// Manages global keyboard shortcuts for the application
 /// 
@@ -10,7 +10,8 @@ use crate::recording::RecordingManager;
 /// - Handling shortcut events and toggling recording state
 /// - Emitting events to notify the frontend of shortcut actions
 /// 
-/// The manager registers Cmd+Shift+R on macOS to toggle recording on/off.
+/// Key combinations come from `ShortcutSettings` (toggle recording, capture tab,
+/// toggle Co-Pilot) and can be re-registered live via `apply`.
 /// If registration fails, a warning is logged but the application continues
 /// (shortcuts are a non-fatal feature).
 pub struct ShortcutManager {
@@ -39,16 +40,17 @@ impl ShortcutManager {
         Self { app_handle }
     }
     
-    /// Register global keyboard shortcuts
+    /// Register global keyboard shortcuts from settings
     /// 
-    /// This method registers the Cmd+Shift+R shortcut on macOS to toggle recording.
-    /// When the shortcut is pressed:
-    /// 1. The recording state is checked via RecordingManager
-    /// 2. If recording is active, a "shortcut-triggered" event is emitted with action "stop"
-    /// 3. If recording is idle, a "shortcut-triggered" event is emitted with action "start"
+    /// Reads accelerators from `ShortcutSettings` (defaults if settings are
+    /// unavailable) and registers one shortcut per action:
+    /// - `toggle_recording` (default Cmd+Shift+R): starts recording if idle, stops it
+    ///   if active (same path as the `toggle_recording` command)
+    /// - `capture_tab` (default Cmd+Shift+G): captures the active browser tab as a gem
+    /// - `toggle_copilot` (default Cmd+Shift+J): emits "shortcut-triggered" with
+    ///   action "toggle-copilot"
     /// 
-    /// The frontend listens to these events and calls the appropriate Tauri commands
-    /// (start_recording or stop_recording) to perform the actual state change.
+    /// The frontend follows recording state via the recording-started/stopped events.
     /// 
     /// # Returns
     /// 
@@ -75,40 +77,143 @@ impl ShortcutManager {
     /// }
     /// ```
     pub fn register_shortcuts(&self) -> Result<(), String> {
-        // Register Cmd+Shift+R shortcut
-        let result = self.app_handle
-            .global_shortcut()
-            .on_shortcut("Cmd+Shift+R", |app, _shortcut, event| {
+        let settings = self.shortcut_settings();
+        
+        // Log warning if registration fails, but continue (non-fatal)
+        if let Err(e) = self.apply(&settings) {
+            eprintln!("Warning: {}", e);
+            // Continue without shortcut(s) - not a fatal error
+        }
+        
+        Ok(())
+    }
+
+    /// Replace all registered shortcuts with the given settings
+    /// 
+    /// Validates the accelerators first, then unregisters every current shortcut
+    /// and registers the new ones. Empty accelerators are skipped (disabled).
+    /// Registration continues past individual failures (e.g. a combo already
+    /// taken by another app); all failures are reported in the error.
+    pub fn apply(&self, settings: &ShortcutSettings) -> Result<(), String> {
+        settings.validate()?;
+        
+        let global_shortcut = self.app_handle.global_shortcut();
+        global_shortcut
+            .unregister_all()
+            .map_err(|e| format!("Failed to unregister shortcuts: {}", e))?;
+        
+        let mut failures = Vec::new();
+        for (name, accelerator) in settings.bindings() {
+            if accelerator.trim().is_empty() {
+                continue;
+            }
+            let action = name.to_string();
+            let result = global_shortcut.on_shortcut(accelerator, move |app, _shortcut, event| {
                 // Only handle key press events (not release)
                 if event.state == ShortcutState::Pressed {
-                    // Get the RecordingManager from app state
-                    let recording_mgr = app.state::<Mutex<RecordingManager>>();
-                    
-                    // Check if currently recording
-                    let is_recording = recording_mgr.lock().unwrap().is_recording();
-                    
-                    // Emit appropriate event based on current state
-                    if is_recording {
-                        // Currently recording - emit "stop" action
-                        if let Err(e) = app.emit("shortcut-triggered", "stop") {
-                            eprintln!("Failed to emit shortcut-triggered event: {}", e);
-                        }
-                    } else {
-                        // Currently idle - emit "start" action
-                        if let Err(e) = app.emit("shortcut-triggered", "start") {
-                            eprintln!("Failed to emit shortcut-triggered event: {}", e);
-                        }
-                    }
+                    handle_shortcut(app, &action);
                 }
             });
-        
-        // Log warning if registration fails, but continue (non-fatal)
-        if let Err(e) = result {
-            eprintln!("Warning: Failed to register global shortcut Cmd+Shift+R: {}", e);
-            // Continue without shortcut - not a fatal error
+            if let Err(e) = result {
+                failures.push(format!("{} ({}): {}", name, accelerator, e));
+            } else {
+                eprintln!("Shortcuts: Registered {} = {}", name, accelerator);
+            }
         }
         
-        Ok(())
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(format!("Failed to register global shortcut(s): {}", failures.join("; ")))
+        }
+    }
+
+    /// Read shortcut settings, falling back to defaults if settings are unavailable
+    fn shortcut_settings(&self) -> ShortcutSettings {
+        self.app_handle
+            .try_state::<Arc<RwLock<SettingsManager>>>()
+            .and_then(|manager| manager.read().ok().map(|m| m.get().shortcuts))
+            .unwrap_or_default()
+    }
+}
+
+/// Run the action bound to a pressed shortcut (`name` from `ShortcutSettings::bindings`)
+fn handle_shortcut(app: &AppHandle, name: &str) {
+    match name {
+        "toggle_recording" => {
+            // Start if idle, stop if recording (runs on the async runtime:
+            // starting a recording spawns tokio tasks)
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = crate::commands::toggle_recording_state(&app) {
+                    eprintln!("Shortcuts: toggle recording failed — {}", e);
+                }
+            });
+        }
+        "capture_tab" => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                capture_active_tab(app).await;
+            });
+        }
+        "toggle_copilot" => {
+            if let Err(e) = app.emit("shortcut-triggered", "toggle-copilot") {
+                eprintln!("Failed to emit shortcut-triggered event: {}", e);
+            }
+        }
+        other => eprintln!("Shortcuts: No handler for {}", other),
+    }
+}
+
+/// Capture the active browser tab as a gem (capture-tab shortcut handler)
+///
+/// Runs the same path as the UI: `prepare_tab_gist_with_claude` then `save_gem`.
+/// Emits a "shortcut-capture-tab" event with `{ status: "saving" | "saved" | "failed", ... }`
+/// so the frontend can show progress and refresh the gems list.
+async fn capture_active_tab(app: AppHandle) {
+    let emit = |payload: serde_json::Value| {
+        if let Err(e) = app.emit("shortcut-capture-tab", payload) {
+            eprintln!("Failed to emit shortcut-capture-tab event: {}", e);
+        }
+    };
+
+    let url = match crate::browser::adapters::chrome::get_active_tab_url_sync() {
+        Ok(url) if !url.is_empty() => url,
+        Ok(_) => {
+            emit(serde_json::json!({ "status": "failed", "error": "No active browser tab" }));
+            return;
+        }
+        Err(e) => {
+            eprintln!("Shortcuts: capture tab failed — {}", e);
+            emit(serde_json::json!({ "status": "failed", "error": e }));
+            return;
+        }
+    };
+
+    emit(serde_json::json!({ "status": "saving", "url": url }));
+
+    let source_type = format!("{:?}", crate::browser::tabs::classify_url(&url));
+    let result = async {
+        let gist = crate::commands::prepare_tab_gist_with_claude(url.clone(), source_type, app.state()).await?;
+        crate::commands::save_gem(
+            app.clone(),
+            gist,
+            app.state(),
+            app.state(),
+            app.state(),
+            app.state(),
+        ).await
+    }.await;
+
+    match result {
+        Ok(gem) => {
+            eprintln!("Shortcuts: Captured tab as gem {} ({})", gem.id, gem.title);
+            emit(serde_json::json!({ "status": "saved", "gem_id": gem.id, "title": gem.title }));
+        }
+        Err(e) => {
+            eprintln!("Shortcuts: capture tab failed for {} — {}", url, e);
+            emit(serde_json::json!({ "status": "failed", "url": url, "error": e }));
+        }
     }
 }
 
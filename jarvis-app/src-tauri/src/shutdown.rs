@@ -0,0 +1,119 @@
+// Stopping background activity — the "panic button" and cleanup on app exit
+//
+// The JarvisListen recorder and the MLX Python sidecar are separate processes.
+// If the app quits while they are running they can linger as orphans, so on
+// exit we stop all background activity and then terminate the MLX sidecar.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+
+use crate::agents::copilot::CoPilotAgent;
+use crate::browser::BrowserObserver;
+use crate::intelligence::{LlmModelManager, MlxProvider};
+use crate::recording::RecordingManager;
+use crate::settings::ModelManager;
+
+/// Upper bound for each async cleanup step, so a stuck inference can't hang quit
+const STEP_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// What `stop_all_activity` stopped
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ActivityStopReport {
+    pub recording_stopped: bool,
+    pub browser_observer_stopped: bool,
+    pub copilot_stopped: bool,
+    /// True if an MLX command was in flight and got cancelled
+    pub inference_cancelled: bool,
+    /// Whisper model filenames and LLM model IDs whose downloads were cancelled
+    pub downloads_cancelled: Vec<String>,
+    /// Steps that failed; the remaining steps still ran
+    pub errors: Vec<String>,
+}
+
+/// Stop recording, the browser observer, the Co-Pilot agent, in-flight
+/// inference, and active model downloads
+///
+/// Each step is best-effort: failures are collected in the report and the
+/// remaining steps still run. Must be called from within the async runtime
+/// (stopping a recording spawns tokio tasks).
+pub async fn stop_all_activity(app_handle: &AppHandle) -> ActivityStopReport {
+    let mut report = ActivityStopReport::default();
+
+    // 1. Stop the active recording (terminates the JarvisListen process)
+    if let Some(manager) = app_handle.try_state::<Mutex<RecordingManager>>() {
+        match manager.lock() {
+            Ok(mut manager) if manager.is_recording() => match manager.stop_recording() {
+                Ok(()) => report.recording_stopped = true,
+                Err(e) => report.errors.push(format!("Failed to stop recording: {}", e)),
+            },
+            Ok(_) => {}
+            Err(e) => report.errors.push(format!("Failed to acquire RecordingManager lock: {}", e)),
+        }
+    }
+
+    // 2. Stop the browser observer
+    if let Some(observer) = app_handle.try_state::<Arc<tokio::sync::Mutex<BrowserObserver>>>() {
+        let mut observer = observer.lock().await;
+        if observer.is_running() {
+            match observer.stop().await {
+                Ok(()) => report.browser_observer_stopped = true,
+                Err(e) => report.errors.push(format!("Failed to stop browser observer: {}", e)),
+            }
+        }
+    }
+
+    // 3. Cancel in-flight inference first so the Co-Pilot cycle returns promptly
+    if let Some(mlx) = app_handle.try_state::<Arc<tokio::sync::Mutex<Option<Arc<MlxProvider>>>>>() {
+        if let Some(provider) = mlx.lock().await.as_ref() {
+            report.inference_cancelled = provider.cancel_inference();
+        }
+    }
+
+    // 4. Stop the Co-Pilot agent
+    if let Some(agent) = app_handle.try_state::<Arc<tokio::sync::Mutex<Option<CoPilotAgent>>>>() {
+        if let Some(mut agent) = agent.lock().await.take() {
+            match tokio::time::timeout(STEP_TIMEOUT, agent.stop()).await {
+                Ok(_) => report.copilot_stopped = true,
+                Err(_) => report.errors.push(format!(
+                    "Co-Pilot agent did not stop within {}s",
+                    STEP_TIMEOUT.as_secs()
+                )),
+            }
+        }
+    }
+
+    // 5. Cancel model downloads
+    if let Some(model_manager) = app_handle.try_state::<Arc<ModelManager>>() {
+        report.downloads_cancelled.extend(model_manager.cancel_all_downloads().await);
+    }
+    if let Some(llm_manager) = app_handle.try_state::<Arc<LlmModelManager>>() {
+        report.downloads_cancelled.extend(llm_manager.cancel_all_downloads().await);
+    }
+
+    report
+}
+
+/// Stop background processes before the app exits
+///
+/// Runs `stop_all_activity`, then terminates the MLX sidecar.
+pub async fn cleanup_on_exit(app_handle: &AppHandle) {
+    eprintln!("Shutdown: Cleaning up background processes");
+
+    let report = stop_all_activity(app_handle).await;
+    for error in &report.errors {
+        eprintln!("Shutdown: {}", error);
+    }
+
+    if let Some(mlx) = app_handle.try_state::<Arc<tokio::sync::Mutex<Option<Arc<MlxProvider>>>>>() {
+        let provider = mlx.lock().await.clone();
+        if let Some(provider) = provider {
+            if tokio::time::timeout(STEP_TIMEOUT, provider.shutdown()).await.is_err() {
+                eprintln!("Shutdown: MLX sidecar did not shut down within {}s", STEP_TIMEOUT.as_secs());
+            }
+        }
+    }
+
+    eprintln!("Shutdown: Cleanup complete");
+}
@@ -2,8 +2,158 @@
 // Routes PCM chunks from JarvisListen sidecar to both recording file and transcription pipeline
 
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use serde::Serialize;
 use tokio::sync::mpsc;
 use nix::sys::stat::Mode;
+use super::vad::amplitude_dbfs;
+
+/// Input level of the most recent audio chunk
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct AudioLevel {
+    /// RMS level in dBFS (-100 = silence)
+    pub rms_dbfs: f32,
+    /// Peak level in dBFS (-100 = silence)
+    pub peak_dbfs: f32,
+}
+
+/// Lock-free input level meter, updated by the routing loop for every chunk
+///
+/// Readers (the `audio-level` emitter, `get_audio_level`) only load atomics,
+/// so metering never blocks the audio path.
+#[derive(Debug, Default)]
+pub struct AudioLevelMeter {
+    rms_bits: AtomicU32,
+    peak_bits: AtomicU32,
+    updates: AtomicU64,
+    finished: AtomicBool,
+}
+
+impl AudioLevelMeter {
+    /// Measure a chunk of 16-bit little-endian PCM
+    fn record(&self, chunk: &[u8]) {
+        let mut sum_squares = 0.0f64;
+        let mut peak = 0i32;
+        let mut count = 0usize;
+        for sample in chunk.chunks_exact(2) {
+            let value = i16::from_le_bytes([sample[0], sample[1]]) as i32;
+            sum_squares += (value * value) as f64;
+            peak = peak.max(value.abs());
+            count += 1;
+        }
+        if count == 0 {
+            return;
+        }
+
+        let rms = (sum_squares / count as f64).sqrt() as f32 / 32768.0;
+        self.rms_bits.store(rms.to_bits(), Ordering::Relaxed);
+        self.peak_bits.store((peak as f32 / 32768.0).to_bits(), Ordering::Relaxed);
+        self.updates.fetch_add(1, Ordering::Release);
+    }
+
+    /// Latest level, or None before the first chunk
+    pub fn level(&self) -> Option<AudioLevel> {
+        if self.updates.load(Ordering::Acquire) == 0 {
+            return None;
+        }
+        Some(AudioLevel {
+            rms_dbfs: amplitude_dbfs(f32::from_bits(self.rms_bits.load(Ordering::Relaxed))),
+            peak_dbfs: amplitude_dbfs(f32::from_bits(self.peak_bits.load(Ordering::Relaxed))),
+        })
+    }
+
+    /// Number of chunks measured so far (lets pollers skip unchanged levels)
+    pub fn updates(&self) -> u64 {
+        self.updates.load(Ordering::Acquire)
+    }
+
+    /// True once routing has ended
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::Acquire)
+    }
+}
+
+/// Bytes per millisecond of 16kHz mono s16le audio
+const BYTES_PER_MS: u64 = 32;
+
+/// Bytes per frame (one mono s16le sample)
+const BYTES_PER_FRAME: u64 = 2;
+
+/// Longest routing waits on a full transcription channel before dropping the chunk
+const MAX_OVERRUN_WAIT: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// How often a full transcription channel is retried while waiting
+const OVERRUN_POLL: std::time::Duration = std::time::Duration::from_millis(10);
+
+/// Health of the AudioRouter → TranscriptionManager pipeline
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct TranscriptionDiagnostics {
+    /// Audio frames never transcribed because the transcriber stayed behind
+    /// for longer than routing waits for it
+    pub dropped_frames: u64,
+    /// Times the transcription channel was full, so routing waited for the
+    /// transcriber before sending the next chunk
+    pub overruns: u64,
+    /// Total time routing spent waiting on a full channel
+    pub overrun_wait_ms: u64,
+    /// Most chunks ever queued in the transcription channel
+    pub buffer_high_water: usize,
+    /// Audio routed but not yet picked up by the transcriber
+    pub processing_lag_ms: u64,
+}
+
+/// Lock-free counters shared by AudioRouter (producer) and
+/// TranscriptionManager (consumer)
+#[derive(Debug, Default)]
+pub struct PipelineStats {
+    dropped_frames: AtomicU64,
+    overruns: AtomicU64,
+    overrun_wait_ms: AtomicU64,
+    buffer_high_water: AtomicUsize,
+    routed_bytes: AtomicU64,
+    consumed_bytes: AtomicU64,
+}
+
+impl PipelineStats {
+    /// Record a wait of `waited` on a full channel because the transcriber
+    /// can't keep up; returns the overrun count so far
+    fn record_overrun(&self, waited: std::time::Duration) -> u64 {
+        self.overrun_wait_ms.fetch_add(waited.as_millis() as u64, Ordering::Relaxed);
+        self.overruns.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    /// Record a chunk of `bytes` that never reached the transcriber;
+    /// returns the dropped frame count so far
+    fn record_dropped(&self, bytes: usize) -> u64 {
+        let frames = bytes as u64 / BYTES_PER_FRAME;
+        self.dropped_frames.fetch_add(frames, Ordering::Relaxed) + frames
+    }
+
+    /// Record a chunk sent to the transcriber with `queued` chunks now in the channel
+    fn record_routed(&self, bytes: usize, queued: usize) {
+        self.routed_bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+        self.buffer_high_water.fetch_max(queued, Ordering::Relaxed);
+    }
+
+    /// Record a chunk received by the transcriber
+    pub fn record_consumed(&self, bytes: usize) {
+        self.consumed_bytes.fetch_add(bytes as u64, Ordering::Relaxed);
+    }
+
+    /// Current counters; the lag is derived from routed vs consumed bytes
+    pub fn snapshot(&self) -> TranscriptionDiagnostics {
+        let routed = self.routed_bytes.load(Ordering::Relaxed);
+        let consumed = self.consumed_bytes.load(Ordering::Relaxed);
+        TranscriptionDiagnostics {
+            dropped_frames: self.dropped_frames.load(Ordering::Relaxed),
+            overruns: self.overruns.load(Ordering::Relaxed),
+            overrun_wait_ms: self.overrun_wait_ms.load(Ordering::Relaxed),
+            buffer_high_water: self.buffer_high_water.load(Ordering::Relaxed),
+            processing_lag_ms: routed.saturating_sub(consumed) / BYTES_PER_MS,
+        }
+    }
+}
 
 /// AudioRouter manages a named pipe (FIFO) for receiving PCM audio from the JarvisListen sidecar.
 /// It routes each chunk to both the recording file and the transcription pipeline via mpsc channel.
@@ -11,6 +161,10 @@ pub struct AudioRouter {
     fifo_path: PathBuf,
     recording_file: PathBuf,
     tx: mpsc::Sender<Vec<u8>>,
+    level_meter: Arc<AudioLevelMeter>,
+    stats: Arc<PipelineStats>,
+    /// Append to the recording file instead of truncating it
+    append: bool,
 }
 
 impl AudioRouter {
@@ -44,13 +198,33 @@ impl AudioRouter {
             fifo_path,
             recording_file,
             tx,
+            level_meter: Arc::new(AudioLevelMeter::default()),
+            stats: Arc::new(PipelineStats::default()),
+            append: false,
         })
     }
+
+    /// Append routed audio to the end of an existing recording file
+    /// instead of overwriting it
+    pub fn append_to_existing(mut self) -> Self {
+        self.append = true;
+        self
+    }
     
     /// Get the FIFO path to pass to the JarvisListen sidecar via --output flag.
     pub fn fifo_path(&self) -> &Path {
         &self.fifo_path
     }
+
+    /// Input level meter fed by the routing loop
+    pub fn level_meter(&self) -> Arc<AudioLevelMeter> {
+        self.level_meter.clone()
+    }
+
+    /// Overrun and backlog counters, shared with the TranscriptionManager
+    pub fn stats(&self) -> Arc<PipelineStats> {
+        self.stats.clone()
+    }
     
     /// Start routing audio from FIFO to recording file and transcription pipeline.
     /// 
@@ -58,7 +232,8 @@ impl AudioRouter {
     /// 1. Opens the FIFO for reading (blocks until sidecar connects as writer)
     /// 2. Reads 3200-byte chunks (100ms at 16kHz stereo s16le)
     /// 3. Writes each chunk to the recording file
-    /// 4. Sends each chunk via mpsc to the transcription pipeline
+    /// 4. Sends each chunk via mpsc to the transcription pipeline, waiting
+    ///    (and counting an overrun) if the transcriber has fallen too far behind
     /// 5. Handles EOF when sidecar closes the FIFO
     /// 6. Retries transient read errors up to 3 times with 100ms delay
     /// 
@@ -73,8 +248,11 @@ impl AudioRouter {
         let fifo_path = self.fifo_path.clone();
         let recording_path = self.recording_file.clone();
         let tx = self.tx.clone();
+        let level_meter = self.level_meter.clone();
+        let stats = self.stats.clone();
+        let append = self.append;
         
-        tokio::task::spawn_blocking(move || {
+        let result = tokio::task::spawn_blocking(move || {
             use std::io::{Read, Write};
             
             // Open FIFO for reading (blocks until writer connects)
@@ -86,7 +264,12 @@ impl AudioRouter {
             eprintln!("AudioRouter: FIFO opened, writer connected. Starting audio routing...");
             
             // Open recording file for writing (synchronous)
-            let mut recording_file = std::fs::File::create(&recording_path)
+            let mut recording_file = std::fs::OpenOptions::new()
+                .write(true)
+                .create(true)
+                .append(append)
+                .truncate(!append)
+                .open(&recording_path)
                 .map_err(|e| format!("Failed to create recording file at {:?}: {}", recording_path, e))?;
             
             // Read chunks and route to both destinations
@@ -110,6 +293,7 @@ impl AudioRouter {
                         retry_count = 0; // Reset retry counter on success
                         let chunk = &buffer[..n];
                         total_bytes += n;
+                        level_meter.record(chunk);
 
                         // Route 1: Write to recording file (synchronous)
                         if let Err(e) = recording_file.write_all(chunk) {
@@ -117,12 +301,49 @@ impl AudioRouter {
                             eprintln!("AudioRouter: Warning - Failed to write to recording file: {}. Transcription continues.", e);
                         }
 
-                        // Route 2: Send to transcription pipeline via mpsc (blocking send)
+                        // Route 2: Send to transcription pipeline via mpsc
+                        // A full channel means transcription is behind; wait briefly
+                        // for room (backpressure) and count the overrun. If it stays
+                        // full, drop the chunk rather than stall the FIFO (and the
+                        // recording), and count the dropped frames for diagnostics.
                         // Skip if channel already closed (TranscriptionManager stopped)
                         if !channel_closed {
-                            if let Err(_) = tx.blocking_send(chunk.to_vec()) {
-                                eprintln!("AudioRouter: Transcription channel closed. Recording continues (file-only mode).");
-                                channel_closed = true;
+                            let sent = match tx.try_send(chunk.to_vec()) {
+                                Ok(()) => Ok(true),
+                                Err(mpsc::error::TrySendError::Full(mut pending)) => {
+                                    let wait_start = std::time::Instant::now();
+                                    let sent = loop {
+                                        std::thread::sleep(OVERRUN_POLL);
+                                        match tx.try_send(pending) {
+                                            Ok(()) => break Ok(true),
+                                            Err(mpsc::error::TrySendError::Full(retry)) => {
+                                                if wait_start.elapsed() >= MAX_OVERRUN_WAIT {
+                                                    break Ok(false);
+                                                }
+                                                pending = retry;
+                                            }
+                                            Err(mpsc::error::TrySendError::Closed(_)) => break Err(()),
+                                        }
+                                    };
+                                    let overruns = stats.record_overrun(wait_start.elapsed());
+                                    if overruns == 1 || overruns.is_multiple_of(100) {
+                                        eprintln!("AudioRouter: Warning - Transcription can't keep up, waited on a full channel {} times so far", overruns);
+                                    }
+                                    sent
+                                }
+                                Err(mpsc::error::TrySendError::Closed(_)) => Err(()),
+                            };
+                            match sent {
+                                Ok(true) => stats.record_routed(n, tx.max_capacity() - tx.capacity()),
+                                Ok(false) => {
+                                    let dropped = stats.record_dropped(n);
+                                    eprintln!("AudioRouter: Warning - Transcription still behind after {}ms, dropped audio ({} frames so far)",
+                                              MAX_OVERRUN_WAIT.as_millis(), dropped);
+                                }
+                                Err(()) => {
+                                    eprintln!("AudioRouter: Transcription channel closed. Recording continues (file-only mode).");
+                                    channel_closed = true;
+                                }
                             }
                         }
                     }
@@ -147,7 +368,11 @@ impl AudioRouter {
             Ok(())
         })
         .await
-        .map_err(|e| format!("Join error in routing task: {}", e))?
+        .map_err(|e| format!("Join error in routing task: {}", e));
+
+        // Routing has ended either way; stops the level emitter
+        self.level_meter.finished.store(true, Ordering::Release);
+        result?
     }
 }
 
@@ -198,4 +423,46 @@ mod tests {
         // Verify FIFO file is removed
         assert!(!fifo_path.exists());
     }
+
+    #[test]
+    fn test_level_meter_measures_chunk() {
+        let meter = AudioLevelMeter::default();
+        assert_eq!(meter.level(), None);
+
+        // Square wave at half scale: RMS and peak both ≈ -6 dBFS
+        let chunk: Vec<u8> = [16384i16, -16384].repeat(800)
+            .iter()
+            .flat_map(|s| s.to_le_bytes())
+            .collect();
+        meter.record(&chunk);
+
+        let level = meter.level().unwrap();
+        assert!((level.rms_dbfs - (-6.02)).abs() < 0.01);
+        assert!((level.peak_dbfs - (-6.02)).abs() < 0.01);
+        assert_eq!(meter.updates(), 1);
+
+        meter.record(&[0u8; 3200]);
+        assert_eq!(meter.level().unwrap().rms_dbfs, -100.0);
+    }
+
+    #[test]
+    fn test_pipeline_stats_snapshot() {
+        let stats = PipelineStats::default();
+        assert_eq!(stats.snapshot(), TranscriptionDiagnostics::default());
+
+        // Three 100ms chunks routed, one consumed, one overrun, one 100ms chunk dropped
+        stats.record_routed(3200, 1);
+        stats.record_routed(3200, 2);
+        stats.record_routed(3200, 3);
+        stats.record_consumed(3200);
+        stats.record_overrun(std::time::Duration::from_millis(40));
+        stats.record_dropped(3200);
+
+        let snapshot = stats.snapshot();
+        assert_eq!(snapshot.dropped_frames, 1600);
+        assert_eq!(snapshot.overruns, 1);
+        assert_eq!(snapshot.overrun_wait_ms, 40);
+        assert_eq!(snapshot.buffer_high_water, 3);
+        assert_eq!(snapshot.processing_lag_ms, 200);
+    }
 }
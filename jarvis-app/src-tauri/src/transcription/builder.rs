@@ -0,0 +1,149 @@
+// Transcription provider selection - picks and initializes the live
+// transcription provider for the configured engine
+//
+// "whisperkit" falls back to whisper-rs (HybridProvider) when WhisperKit is
+// unavailable or fails to initialize; any other engine uses HybridProvider.
+// Shared by startup (lib.rs) and the switch_transcription_engine command.
+
+use tauri::AppHandle;
+
+use crate::settings::TranscriptionSettings;
+use crate::transcription::provider::{TranscriptionConfig, TranscriptionProvider};
+use crate::transcription::{HybridProvider, WhisperKitProvider};
+
+/// Build and initialize the live transcription provider for
+/// `settings.transcription_engine`
+///
+/// # Returns
+///
+/// * `Ok(Box<dyn TranscriptionProvider>)` - The requested provider, or the
+///   whisper-rs fallback (check `name()` to tell them apart)
+/// * `Err(String)` - No provider could be initialized
+pub fn build_provider(
+    settings: &TranscriptionSettings,
+    app_handle: &AppHandle,
+    config: &TranscriptionConfig,
+) -> Result<Box<dyn TranscriptionProvider>, String> {
+    let engine = settings.transcription_engine.as_str();
+    eprintln!("TranscriptionManager: Selected engine: {}", engine);
+
+    select_provider(
+        engine,
+        || {
+            let provider = WhisperKitProvider::new(&settings.whisperkit_model);
+            if !provider.is_available() {
+                let reason = provider.unavailable_reason().unwrap_or("Unknown reason");
+                return Err(format!("WhisperKit unavailable: {}", reason));
+            }
+            initialize(provider, config)
+        },
+        || initialize(HybridProvider::new(settings, app_handle.clone()), config),
+    )
+}
+
+/// Fallback chain, with provider construction injected so it can be tested
+/// without real engines
+fn select_provider(
+    engine: &str,
+    whisperkit: impl FnOnce() -> Result<Box<dyn TranscriptionProvider>, String>,
+    hybrid: impl FnOnce() -> Result<Box<dyn TranscriptionProvider>, String>,
+) -> Result<Box<dyn TranscriptionProvider>, String> {
+    if engine == "whisperkit" {
+        match whisperkit() {
+            Ok(provider) => return Ok(provider),
+            Err(e) => {
+                eprintln!("Warning: {}", e);
+                eprintln!("Falling back to whisper-rs (HybridProvider)");
+                return hybrid().map_err(|fallback_err| {
+                    format!("{}; whisper-rs fallback also failed: {}", e, fallback_err)
+                });
+            }
+        }
+    }
+
+    hybrid()
+}
+
+/// Initialize a provider, boxing it on success
+fn initialize<P: TranscriptionProvider + 'static>(
+    mut provider: P,
+    config: &TranscriptionConfig,
+) -> Result<Box<dyn TranscriptionProvider>, String> {
+    match provider.initialize(config) {
+        Ok(()) => {
+            eprintln!("TranscriptionManager: Initialized with provider '{}'", provider.name());
+            Ok(Box::new(provider))
+        }
+        Err(e) => Err(format!("Failed to initialize {}: {}", provider.name(), e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transcription::TranscriptionSegment;
+    use std::error::Error;
+
+    /// Provider that only reports its name
+    struct StubProvider(&'static str);
+
+    impl TranscriptionProvider for StubProvider {
+        fn name(&self) -> &str {
+            self.0
+        }
+
+        fn initialize(&mut self, _config: &TranscriptionConfig) -> Result<(), Box<dyn Error>> {
+            Ok(())
+        }
+
+        fn transcribe(&mut self, _audio: &[f32]) -> Result<Vec<TranscriptionSegment>, Box<dyn Error>> {
+            Ok(Vec::new())
+        }
+    }
+
+    fn stub(name: &'static str) -> Result<Box<dyn TranscriptionProvider>, String> {
+        Ok(Box::new(StubProvider(name)))
+    }
+
+    fn failing(reason: &str) -> Result<Box<dyn TranscriptionProvider>, String> {
+        Err(reason.to_string())
+    }
+
+    #[test]
+    fn test_whisperkit_used_when_available() {
+        let provider = select_provider("whisperkit", || stub("whisperkit"), || stub("hybrid")).unwrap();
+        assert_eq!(provider.name(), "whisperkit");
+    }
+
+    #[test]
+    fn test_whisperkit_falls_back_to_hybrid() {
+        let provider = select_provider("whisperkit", || failing("unavailable"), || stub("hybrid")).unwrap();
+        assert_eq!(provider.name(), "hybrid");
+    }
+
+    #[test]
+    fn test_errors_when_fallback_also_fails() {
+        let err = select_provider("whisperkit", || failing("unavailable"), || failing("no models"))
+            .err()
+            .unwrap();
+        assert!(err.contains("unavailable") && err.contains("no models"));
+
+        let err = select_provider("whisper-rs", || stub("whisperkit"), || failing("no models"))
+            .err()
+            .unwrap();
+        assert_eq!(err, "no models");
+    }
+
+    #[test]
+    fn test_other_engines_never_try_whisperkit() {
+        for engine in ["whisper-rs", "mlx-omni"] {
+            let provider = select_provider(
+                engine,
+                || panic!("whisperkit should not be built for {}", engine),
+                || stub("hybrid"),
+            )
+            .unwrap();
+            assert_eq!(provider.name(), "hybrid");
+        }
+    }
+}
@@ -202,6 +202,7 @@ impl HybridProvider {
                     start_ms: 0,
                     end_ms: 0,
                     is_final: false,
+                    confidence: None,
                 });
             }
         }
@@ -8,6 +8,8 @@ use serde_json::json;
 
 use crate::transcription::provider::{TranscriptionProvider, TranscriptionSegment, TranscriptionStatus};
 use crate::transcription::audio_buffer::AudioBuffer;
+use crate::transcription::audio_router::PipelineStats;
+use crate::settings::TranscriptionSettings;
 
 /// TranscriptionManager orchestrates the transcription lifecycle.
 /// 
@@ -34,12 +36,14 @@ impl TranscriptionManager {
     /// # Arguments
     /// * `provider` - The transcription provider (HybridProvider, etc.)
     /// * `app_handle` - Tauri app handle for emitting events
-    /// * `window_duration` - Audio window size in seconds for batch transcription
+    /// * `settings` - Transcription settings; the audio window size is picked
+    ///   for the provider's `name()` (see `TranscriptionSettings::window_duration_for`)
     pub fn new(
         provider: Box<dyn TranscriptionProvider>,
         app_handle: AppHandle,
-        window_duration: f32,
+        settings: &TranscriptionSettings,
     ) -> Self {
+        let window_duration = settings.window_duration_for(provider.name());
         Self {
             provider: Arc::new(TokioMutex::new(provider)),
             transcript: Arc::new(TokioMutex::new(Vec::new())),
@@ -50,9 +54,27 @@ impl TranscriptionManager {
         }
     }
     
-    /// Update the window duration (takes effect on next start())
-    pub fn set_window_duration(&mut self, duration: f32) {
-        self.window_duration = duration;
+    /// Replace the transcription provider
+    ///
+    /// Refused while transcription is active. The window duration is re-read
+    /// for the new provider.
+    pub async fn replace_provider(
+        &mut self,
+        provider: Box<dyn TranscriptionProvider>,
+        settings: &TranscriptionSettings,
+    ) -> Result<(), String> {
+        if *self.status.lock().await == TranscriptionStatus::Active {
+            return Err("Stop transcription before switching engines".to_string());
+        }
+        self.window_duration = settings.window_duration_for(provider.name());
+        *self.provider.lock().await = provider;
+        Ok(())
+    }
+
+    /// Re-read the window duration for the active provider (takes effect on next start())
+    pub async fn apply_settings(&mut self, settings: &TranscriptionSettings) {
+        let provider = self.provider.lock().await;
+        self.window_duration = settings.window_duration_for(provider.name());
     }
 
     /// Start transcription
@@ -66,11 +88,17 @@ impl TranscriptionManager {
     /// 
     /// # Arguments
     /// * `rx` - mpsc receiver for PCM chunks from AudioRouter
+    /// * `stats` - AudioRouter's pipeline counters; received bytes are recorded
+    ///   so `transcription_diagnostics` can report the processing lag
     /// 
     /// # Returns
     /// * `Ok(())` - Transcription started successfully
     /// * `Err(String)` - Failed to start transcription
-    pub async fn start(&mut self, mut rx: mpsc::Receiver<Vec<u8>>) -> Result<(), String> {
+    pub async fn start(
+        &mut self,
+        mut rx: mpsc::Receiver<Vec<u8>>,
+        stats: Arc<PipelineStats>,
+    ) -> Result<(), String> {
         // Set status to active
         *self.status.lock().await = TranscriptionStatus::Active;
         
@@ -92,7 +120,8 @@ impl TranscriptionManager {
         
         // Read latest window_duration (may have been updated via settings since construction)
         let window_duration = self.window_duration;
-        eprintln!("TranscriptionManager: Using window_duration={:.1}s", window_duration);
+        eprintln!("TranscriptionManager: Using window_duration={:.1}s for provider '{}'",
+                  window_duration, self.provider.lock().await.name());
 
         // Spawn background transcription task
         tokio::spawn(async move {
@@ -119,6 +148,7 @@ impl TranscriptionManager {
                         match chunk_opt {
                             Some(chunk) => {
                                 total_chunks += 1;
+                                stats.record_consumed(chunk.len());
 
                                 // Push chunk to audio buffer
                                 audio_buffer.push(&chunk);
@@ -275,6 +305,7 @@ mod tests {
                         start_ms: 0,
                         end_ms: 1000,
                         is_final: true,
+                        confidence: None,
                     }
                 ],
             }
@@ -10,6 +10,7 @@ pub mod whisperkit_provider;
 pub mod hybrid_provider;
 pub mod audio_router;
 pub mod manager;
+pub mod builder;
 
 // Re-export commonly used types (only implemented modules)
 pub use provider::{TranscriptionProvider, TranscriptionSegment, TranscriptionConfig, TranscriptionStatus};
@@ -19,5 +20,6 @@ pub use vosk_provider::VoskProvider;
 pub use whisper_provider::WhisperProvider;
 pub use whisperkit_provider::WhisperKitProvider;
 pub use hybrid_provider::HybridProvider;
-pub use audio_router::AudioRouter;
+pub use audio_router::{AudioLevel, AudioLevelMeter, AudioRouter, PipelineStats, TranscriptionDiagnostics};
 pub use manager::TranscriptionManager;
+pub use builder::build_provider;
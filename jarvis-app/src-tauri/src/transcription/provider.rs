@@ -18,6 +18,10 @@ pub struct TranscriptionSegment {
     
     /// false = Vosk partial (gray text), true = Whisper final (normal text)
     pub is_final: bool,
+
+    /// Recognition confidence in 0–1 (None when the engine doesn't report it)
+    #[serde(default)]
+    pub confidence: Option<f32>,
 }
 
 impl TranscriptionSegment {
@@ -28,10 +32,18 @@ impl TranscriptionSegment {
             start_ms,
             end_ms,
             is_final,
+            confidence: None,
         }
     }
 }
 
+/// Map an average token log-probability to a 0–1 confidence
+///
+/// exp(avg logprob) is the geometric mean of the token probabilities.
+pub fn confidence_from_avg_logprob(avg_logprob: f32) -> f32 {
+    avg_logprob.exp().clamp(0.0, 1.0)
+}
+
 /// Helper function to check if two segments overlap in time
 pub fn segments_overlap(seg1: &TranscriptionSegment, seg2: &TranscriptionSegment) -> bool {
     (seg1.start_ms < seg2.end_ms) && (seg2.start_ms < seg1.end_ms)
@@ -210,6 +222,15 @@ pub trait TranscriptionProvider: Send + Sync {
 mod tests {
     use super::*;
     
+    #[test]
+    fn test_confidence_from_avg_logprob() {
+        assert_eq!(confidence_from_avg_logprob(0.0), 1.0);
+        assert!((confidence_from_avg_logprob(-0.5) - 0.6065).abs() < 1e-3);
+        assert!(confidence_from_avg_logprob(-50.0) < 1e-6);
+        // Positive values (shouldn't happen) are clamped
+        assert_eq!(confidence_from_avg_logprob(0.3), 1.0);
+    }
+
     #[test]
     fn test_segment_creation() {
         let segment = TranscriptionSegment::new(
@@ -133,6 +133,19 @@ impl SileroVad {
         Some(false)
     }
 
+    /// Speech probability of each 512-sample chunk
+    ///
+    /// Returns `None` if VAD is unavailable. A chunk that fails inference is skipped.
+    pub fn speech_probabilities(&mut self, samples: &[f32]) -> Option<Vec<f32>> {
+        self.session.as_ref()?;
+
+        let probabilities = samples
+            .chunks_exact(Self::CHUNK_SIZE)
+            .filter_map(|chunk| self.process_chunk(chunk).ok())
+            .collect();
+        Some(probabilities)
+    }
+
     /// Process a single 512-sample chunk through the ONNX model
     fn process_chunk(&mut self, chunk: &[f32]) -> Result<f32, String> {
         let session = self.session.as_ref()
@@ -205,6 +218,50 @@ impl SileroVad {
     }
 }
 
+/// Level of `samples` relative to full scale, in dB (floored at -100 dBFS)
+pub fn rms_dbfs(samples: &[f32]) -> f32 {
+    if samples.is_empty() {
+        return -100.0;
+    }
+    let mean_square = samples.iter().map(|s| s * s).sum::<f32>() / samples.len() as f32;
+    amplitude_dbfs(mean_square.sqrt())
+}
+
+/// Linear amplitude (0–1) relative to full scale, in dB (floored at -100 dBFS)
+pub fn amplitude_dbfs(linear: f32) -> f32 {
+    if linear <= 0.0 {
+        return -100.0;
+    }
+    (20.0 * linear.log10()).max(-100.0)
+}
+
+/// Value at quantile `q` (0.0–1.0) of `values`, or None if empty
+pub fn quantile(values: &[f32], q: f32) -> Option<f32> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.total_cmp(b));
+    let index = ((sorted.len() - 1) as f32 * q.clamp(0.0, 1.0)).round() as usize;
+    Some(sorted[index])
+}
+
+/// Suggest a `vad_threshold` from ambient (non-speech) audio
+///
+/// With VAD available the threshold sits a margin above the 95th percentile of
+/// the speech probability Silero assigns to the ambient noise. Without it, the
+/// noise floor picks a level: quiet rooms get a more sensitive threshold.
+/// The result is clamped to 0.3–0.9 and rounded to 0.05.
+pub fn suggest_vad_threshold(noise_floor_dbfs: f32, ambient_speech_p95: Option<f32>) -> f32 {
+    let raw = match ambient_speech_p95 {
+        Some(p95) => p95 + 0.15,
+        None if noise_floor_dbfs < -60.0 => 0.4,
+        None if noise_floor_dbfs < -45.0 => 0.5,
+        None => 0.6,
+    };
+    (raw.clamp(0.3, 0.9) * 20.0).round() / 20.0
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -245,4 +302,27 @@ mod tests {
         let result = vad.contains_speech(&silence);
         assert_eq!(result, Some(false), "VAD should detect silence in zero audio");
     }
+
+    #[test]
+    fn test_rms_dbfs() {
+        assert_eq!(rms_dbfs(&[]), -100.0);
+        assert_eq!(rms_dbfs(&[0.0; 512]), -100.0);
+        assert!((rms_dbfs(&[1.0; 512]) - 0.0).abs() < 1e-4);
+        assert!((rms_dbfs(&[0.1; 512]) - (-20.0)).abs() < 1e-3);
+        assert_eq!(amplitude_dbfs(0.0), -100.0);
+        assert!((amplitude_dbfs(0.5) - (-6.02)).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_suggest_vad_threshold() {
+        // From ambient speech probability: p95 + margin, rounded to 0.05
+        assert_eq!(suggest_vad_threshold(-50.0, Some(0.2)), 0.35);
+        // Clamped to the usable range
+        assert_eq!(suggest_vad_threshold(-50.0, Some(0.01)), 0.3);
+        assert_eq!(suggest_vad_threshold(-50.0, Some(0.95)), 0.9);
+        // Noise-floor fallback when VAD is unavailable
+        assert_eq!(suggest_vad_threshold(-70.0, None), 0.4);
+        assert_eq!(suggest_vad_threshold(-50.0, None), 0.5);
+        assert_eq!(suggest_vad_threshold(-30.0, None), 0.6);
+    }
 }
@@ -5,7 +5,7 @@
 use std::path::PathBuf;
 use std::error::Error;
 use whisper_rs::{WhisperContext, WhisperContextParameters, FullParams, SamplingStrategy};
-use crate::transcription::provider::{TranscriptionProvider, TranscriptionSegment, TranscriptionConfig};
+use crate::transcription::provider::{confidence_from_avg_logprob, TranscriptionProvider, TranscriptionSegment, TranscriptionConfig};
 
 /// Whisper provider for accurate final transcriptions
 /// 
@@ -84,6 +84,9 @@ impl TranscriptionProvider for WhisperProvider {
     fn transcribe(&mut self, audio: &[f32]) -> Result<Vec<TranscriptionSegment>, Box<dyn Error>> {
         let context = self.context.as_mut()
             .ok_or("Whisper context not initialized")?;
+
+        // Token ids at or above end-of-text are special (timestamps, markers)
+        let token_eot = context.token_eot();
         
         // Create a new state for this transcription
         let mut state = context.create_state()
@@ -158,22 +161,33 @@ impl TranscriptionProvider for WhisperProvider {
             let start_ms = (start_cs as i64) * 10;
             let end_ms = (end_cs as i64) * 10;
             
-            segments.push(TranscriptionSegment {
-                text: text.trim().to_string(),
-                start_ms,
-                end_ms,
-                is_final: true, // Whisper segments are always final
-            });
-            
-            // Collect tokens for context carryover
+            // Collect tokens for context carryover, and text-token
+            // log-probabilities for the segment confidence
             let num_tokens = state.full_n_tokens(i)
                 .map_err(|e| format!("Failed to get token count: {}", e))?;
+            let mut logprob_sum = 0.0f32;
+            let mut logprob_count = 0usize;
             
             for j in 0..num_tokens {
-                if let Ok(token) = state.full_get_token_id(i, j) {
-                    new_tokens.push(token);
+                if let Ok(token_data) = state.full_get_token_data(i, j) {
+                    new_tokens.push(token_data.id);
+                    if token_data.id < token_eot {
+                        logprob_sum += token_data.plog;
+                        logprob_count += 1;
+                    }
                 }
             }
+
+            let confidence = (logprob_count > 0)
+                .then(|| confidence_from_avg_logprob(logprob_sum / logprob_count as f32));
+
+            segments.push(TranscriptionSegment {
+                text: text.trim().to_string(),
+                start_ms,
+                end_ms,
+                is_final: true, // Whisper segments are always final
+                confidence,
+            });
         }
         
         // Store tokens for next inference (context carryover)
@@ -1,4 +1,4 @@
-use crate::transcription::provider::{TranscriptionConfig, TranscriptionProvider, TranscriptionSegment};
+use crate::transcription::provider::{confidence_from_avg_logprob, TranscriptionConfig, TranscriptionProvider, TranscriptionSegment};
 use std::path::PathBuf;
 use std::process::{Child, Command, Stdio};
 use std::net::TcpListener;
@@ -17,6 +17,9 @@ struct WhisperKitSegment {
     text: String,
     start: f64,  // seconds
     end: f64,    // seconds
+    /// Average token log-probability (OpenAI verbose_json), when reported
+    #[serde(default)]
+    avg_logprob: Option<f32>,
 }
 
 /// WhisperKit transcription provider using whisperkit-cli as a local HTTP server
@@ -486,6 +489,7 @@ impl TranscriptionProvider for WhisperKitProvider {
                 start_ms: (seg.start * 1000.0) as i64,
                 end_ms: (seg.end * 1000.0) as i64,
                 is_final: true, // whisperkit-cli batch mode returns final segments
+                confidence: seg.avg_logprob.map(confidence_from_avg_logprob),
             })
             .collect();
         
@@ -676,6 +680,7 @@ mod tests {
             text: "Hello world".to_string(),
             start: 1.5,
             end: 3.25,
+            avg_logprob: None,
         };
         
         let mapped = TranscriptionSegment {
@@ -683,6 +688,7 @@ mod tests {
             start_ms: (segment.start * 1000.0) as i64,
             end_ms: (segment.end * 1000.0) as i64,
             is_final: true,
+            confidence: None,
         };
         
         assert_eq!(mapped.start_ms, 1500);
@@ -698,6 +704,7 @@ mod tests {
             text: "Test".to_string(),
             start: 0.0,
             end: 1.0,
+            avg_logprob: None,
         };
         
         let mapped = TranscriptionSegment {
@@ -705,6 +712,7 @@ mod tests {
             start_ms: (segment.start * 1000.0) as i64,
             end_ms: (segment.end * 1000.0) as i64,
             is_final: true,
+            confidence: None,
         };
         
         assert!(mapped.is_final);
@@ -722,6 +730,7 @@ mod tests {
                 start_ms: (seg.start * 1000.0) as i64,
                 end_ms: (seg.end * 1000.0) as i64,
                 is_final: true,
+                confidence: None,
             })
             .collect();
         
@@ -736,11 +745,13 @@ mod tests {
                 text: "First segment".to_string(),
                 start: 0.0,
                 end: 2.5,
+                avg_logprob: None,
             },
             WhisperKitSegment {
                 text: "Second segment".to_string(),
                 start: 2.5,
                 end: 5.0,
+                avg_logprob: None,
             },
         ];
         
@@ -751,6 +762,7 @@ mod tests {
                 start_ms: (seg.start * 1000.0) as i64,
                 end_ms: (seg.end * 1000.0) as i64,
                 is_final: true,
+                confidence: None,
             })
             .collect();
         
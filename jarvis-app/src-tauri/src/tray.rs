@@ -0,0 +1,147 @@
+// System tray — shows recording status and offers quick actions
+//
+// The tray menu mirrors app state: a status line (idle / recording), a
+// start/stop recording item, a browser observer toggle, plus "Open Jarvis"
+// and "Quit". State is refreshed whenever the recording or observer events fire.
+
+use std::sync::{Arc, Mutex};
+use tauri::menu::{Menu, MenuItem, PredefinedMenuItem};
+use tauri::tray::TrayIconBuilder;
+use tauri::{AppHandle, Listener, Manager, Wry};
+
+use crate::browser::BrowserObserver;
+use crate::recording::RecordingManager;
+
+const TRAY_ID: &str = "main";
+
+/// Events after which the tray re-reads recording and observer state
+const REFRESH_EVENTS: &[&str] = &[
+    "recording-started",
+    "recording-stopped",
+    "sidecar-crashed",
+    "browser-observer-changed",
+];
+
+/// Menu items whose labels follow app state (kept in managed state)
+struct TrayMenu {
+    status: MenuItem<Wry>,
+    recording: MenuItem<Wry>,
+    observer: MenuItem<Wry>,
+}
+
+/// Build the system tray and subscribe it to state changes
+///
+/// Must be called after `RecordingManager` and `BrowserObserver` are managed.
+pub fn setup_tray(app: &AppHandle) -> Result<(), String> {
+    let status = MenuItem::with_id(app, "status", "Jarvis: Idle", false, None::<&str>)
+        .map_err(|e| format!("Failed to create tray menu item: {}", e))?;
+    let recording = MenuItem::with_id(app, "toggle_recording", "Start Recording", true, None::<&str>)
+        .map_err(|e| format!("Failed to create tray menu item: {}", e))?;
+    let observer = MenuItem::with_id(app, "toggle_observer", "Start Browser Observer", true, None::<&str>)
+        .map_err(|e| format!("Failed to create tray menu item: {}", e))?;
+    let open_window = MenuItem::with_id(app, "open_window", "Open Jarvis", true, None::<&str>)
+        .map_err(|e| format!("Failed to create tray menu item: {}", e))?;
+    let quit = MenuItem::with_id(app, "quit", "Quit Jarvis", true, None::<&str>)
+        .map_err(|e| format!("Failed to create tray menu item: {}", e))?;
+    let separator = PredefinedMenuItem::separator(app)
+        .map_err(|e| format!("Failed to create tray menu separator: {}", e))?;
+    let separator2 = PredefinedMenuItem::separator(app)
+        .map_err(|e| format!("Failed to create tray menu separator: {}", e))?;
+
+    let menu = Menu::with_items(
+        app,
+        &[&status, &separator, &recording, &observer, &separator2, &open_window, &quit],
+    )
+    .map_err(|e| format!("Failed to create tray menu: {}", e))?;
+
+    let mut builder = TrayIconBuilder::with_id(TRAY_ID)
+        .menu(&menu)
+        .tooltip("Jarvis: Idle")
+        .show_menu_on_left_click(true)
+        .on_menu_event(|app, event| handle_menu_event(app, event.id.as_ref()));
+    if let Some(icon) = app.default_window_icon() {
+        builder = builder.icon(icon.clone());
+    }
+    builder
+        .build(app)
+        .map_err(|e| format!("Failed to create tray icon: {}", e))?;
+
+    app.manage(TrayMenu { status, recording, observer });
+
+    for event in REFRESH_EVENTS {
+        let app_handle = app.clone();
+        app.listen(*event, move |_| refresh_tray(&app_handle));
+    }
+
+    refresh_tray(app);
+    Ok(())
+}
+
+fn handle_menu_event(app: &AppHandle, id: &str) {
+    match id {
+        "toggle_recording" => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                if let Err(e) = crate::commands::toggle_recording_state(&app) {
+                    eprintln!("Tray: Failed to toggle recording: {}", e);
+                }
+            });
+        }
+        "toggle_observer" => {
+            let app = app.clone();
+            tauri::async_runtime::spawn(async move {
+                let observer = app.state::<Arc<tokio::sync::Mutex<BrowserObserver>>>();
+                let mut observer = observer.lock().await;
+                let result = if observer.is_running() {
+                    observer.stop().await
+                } else {
+                    observer.start().await
+                };
+                if let Err(e) = result {
+                    eprintln!("Tray: Failed to toggle browser observer: {}", e);
+                }
+            });
+        }
+        "open_window" => {
+            if let Some(window) = app.get_webview_window("main") {
+                let _ = window.unminimize();
+                let _ = window.show();
+                let _ = window.set_focus();
+            }
+        }
+        "quit" => app.exit(0),
+        _ => {}
+    }
+}
+
+/// Re-read recording and observer state and update the tray labels
+pub fn refresh_tray(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let recording = app
+            .try_state::<Mutex<RecordingManager>>()
+            .and_then(|manager| manager.lock().ok().map(|m| m.is_recording()))
+            .unwrap_or(false);
+        let observing = match app.try_state::<Arc<tokio::sync::Mutex<BrowserObserver>>>() {
+            Some(observer) => observer.lock().await.is_running(),
+            None => false,
+        };
+        update_tray(&app, recording, observing);
+    });
+}
+
+fn update_tray(app: &AppHandle, recording: bool, observing: bool) {
+    let status = if recording { "Jarvis: Recording" } else { "Jarvis: Idle" };
+
+    if let Some(menu) = app.try_state::<TrayMenu>() {
+        let _ = menu.status.set_text(status);
+        let _ = menu.recording.set_text(if recording { "Stop Recording" } else { "Start Recording" });
+        let _ = menu.observer.set_text(if observing { "Stop Browser Observer" } else { "Start Browser Observer" });
+    }
+
+    if let Some(tray) = app.tray_by_id(TRAY_ID) {
+        let _ = tray.set_tooltip(Some(status));
+        // Menu bar title next to the icon (macOS); cleared when idle
+        let _ = tray.set_title(recording.then_some("REC"));
+    }
+}
@@ -23,6 +23,14 @@ import type {
 
 interface BrowserSettings {
   observer_enabled: boolean;
+  /** Download gem OG images into ~/.jarvis/thumbnails. Default: false */
+  cache_gem_images: boolean;
+  /** Drop conversation paragraphs that repeat the page when merging a Claude conversation. Default: false */
+  dedup_merged_paragraphs: boolean;
+  /** Store a hash of captured page content so update checks can tell whether the source changed. Default: false */
+  track_content_changes: boolean;
+  /** JS snippet per domain that captures page content instead of the built-in extractor. Default: {} */
+  domain_js_overrides: Record<string, string>;
 }
 
 interface SettingsProps {
@@ -374,6 +382,27 @@ export function Settings({ onClose }: SettingsProps) {
     }
   };
 
+  const handleEngineWindowDurationChange = async (
+    engine: 'whisper_rs' | 'whisperkit',
+    duration: number | null
+  ) => {
+    try {
+      const updatedSettings = {
+        ...settings,
+        transcription: {
+          ...settings.transcription,
+          engine_window_durations: {
+            ...settings.transcription.engine_window_durations,
+            [engine]: duration,
+          },
+        },
+      };
+      await invoke('update_settings', { settings: updatedSettings });
+    } catch (err) {
+      setError(err instanceof Error ? err.message : String(err));
+    }
+  };
+
   const handleEngineChange = async (engine: "whisper-rs" | "whisperkit" | "mlx-omni") => {
     try {
       const updatedSettings = {
@@ -407,7 +436,7 @@ export function Settings({ onClose }: SettingsProps) {
   const handleBrowserObserverChange = async (enabled: boolean) => {
     try {
       await invoke('update_browser_settings', { observerEnabled: enabled });
-      setBrowserSettings({ observer_enabled: enabled });
+      setBrowserSettings((prev) => (prev ? { ...prev, observer_enabled: enabled } : prev));
     } catch (err) {
       console.error('Failed to update browser settings:', err);
       setError(err instanceof Error ? err.message : String(err));
@@ -1119,6 +1148,41 @@ export function Settings({ onClose }: SettingsProps) {
               Shorter = lower latency, longer = better accuracy. Takes effect on next recording.
             </p>
           </div>
+          {(['whisper_rs', 'whisperkit'] as const).map((engine) => {
+            const override = settings.transcription.engine_window_durations[engine];
+            const label = engine === 'whisper_rs' ? 'whisper-rs' : 'WhisperKit';
+            return (
+              <div className="setting-row" key={engine}>
+                <label htmlFor={`window-duration-${engine}`}>
+                  <input
+                    type="checkbox"
+                    checked={override !== null}
+                    onChange={(e) =>
+                      handleEngineWindowDurationChange(
+                        engine,
+                        e.target.checked ? settings.transcription.window_duration : null
+                      )
+                    }
+                  />
+                  {label} override{override !== null && `: ${override.toFixed(1)}s`}
+                </label>
+                {override !== null && (
+                  <input
+                    type="range"
+                    id={`window-duration-${engine}`}
+                    min="1"
+                    max="30"
+                    step="0.5"
+                    value={override}
+                    onChange={(e) => handleEngineWindowDurationChange(engine, parseFloat(e.target.value))}
+                  />
+                )}
+              </div>
+            );
+          })}
+          <p className="setting-info">
+            Whisper models are trained on 30s windows, so longer windows can improve accuracy for that engine.
+          </p>
         </section>
 
         <section className="settings-section">
@@ -1404,6 +1468,7 @@ export function Settings({ onClose }: SettingsProps) {
                 type="password"
                 placeholder="tvly-..."
                 value={settings.search.tavily_api_key || ''}
+                onFocus={(e) => e.target.select()}
                 onChange={async (e) => {
                   const value = e.target.value.trim() || null;
                   const updatedSettings = {
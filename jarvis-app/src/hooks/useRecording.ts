@@ -6,7 +6,6 @@ import type {
   RecordingMetadata,
   RecordingStartedEvent,
   ErrorEvent,
-  ShortcutEvent,
   CrashedEvent,
   TranscriptionSegment,
   TranscriptionStoppedEvent,
@@ -223,20 +222,6 @@ export function useRecording() {
     }, [])
   );
 
-  /**
-   * Listen for shortcut-triggered event from backend
-   * Emitted when the user presses the global shortcut (Cmd+Shift+R)
-   */
-  useTauriEvent<ShortcutEvent>("shortcut-triggered", 
-    useCallback((payload) => {
-      if (payload.action === "start") {
-        startRecording();
-      } else if (payload.action === "stop") {
-        stopRecording();
-      }
-    }, [startRecording, stopRecording])
-  );
-
   /**
    * Listen for transcription-started event from backend
    * Emitted when transcription begins for a recording
@@ -32,6 +32,46 @@ export interface RecordingMetadata {
   duration_seconds: number;
 }
 
+/**
+ * PCM file check returned by verify_recording, matching Rust RecordingIntegrity
+ */
+export interface RecordingIntegrity {
+  exists: boolean;
+  size_bytes: number;
+  nonempty: boolean;
+  estimated_duration_seconds: number;
+  /** Nonempty and a whole number of 16-bit samples */
+  likely_valid: boolean;
+}
+
+/**
+ * Criteria for filter_recordings, matching Rust RecordingFilter
+ *
+ * All bounds are inclusive; omitted fields don't filter. Dates are Unix
+ * timestamps in seconds.
+ */
+export interface RecordingFilter {
+  min_duration_seconds?: number;
+  max_duration_seconds?: number;
+  created_after?: number;
+  created_before?: number;
+}
+
+/**
+ * Recording with gem and transcript status, matching Rust RecordingDetails
+ * (returned by list_recordings_detailed)
+ */
+export interface RecordingDetails extends RecordingMetadata {
+  /** True if a gem was saved from this recording */
+  has_gem: boolean;
+
+  /** ID of that gem */
+  gem_id: string | null;
+
+  /** True if a saved transcript exists for this recording */
+  has_transcript: boolean;
+}
+
 /**
  * Transcription segment matching Rust TranscriptionSegment struct
  * 
@@ -49,6 +89,9 @@ export interface TranscriptionSegment {
   
   /** false = Vosk partial (gray text), true = Whisper final (normal text) */
   is_final: boolean;
+
+  /** Recognition confidence in 0–1; null when the engine doesn't report it */
+  confidence: number | null;
 }
 
 /**
@@ -62,6 +105,55 @@ export interface TranscriptionSegment {
  */
 export type TranscriptionStatus = "idle" | "active" | "error" | "disabled";
 
+/**
+ * Result of switch_transcription_engine
+ */
+export interface TranscriptionEngineSwitch {
+  /** Engine that was requested (now saved in settings) */
+  engine: string;
+  
+  /** Name of the provider actually in use */
+  provider: string;
+  
+  /** True when the requested engine was unavailable and whisper-rs is used instead */
+  fell_back: boolean;
+}
+
+/**
+ * Audio level payload of the `audio-level` event and `get_audio_level`
+ *
+ * Matches the Rust AudioLevel struct in src-tauri/src/transcription/audio_router.rs
+ */
+export interface AudioLevel {
+  /** RMS of the latest chunk in dBFS (-100..0; silence is clamped to -100) */
+  rms_dbfs: number;
+
+  /** Peak sample of the latest chunk in dBFS */
+  peak_dbfs: number;
+}
+
+/**
+ * Transcription pipeline health returned by `transcription_diagnostics`
+ *
+ * Matches the Rust TranscriptionDiagnostics struct in src-tauri/src/transcription/audio_router.rs
+ */
+export interface TranscriptionDiagnostics {
+  /** Audio frames skipped because transcription stayed behind too long */
+  dropped_frames: number;
+
+  /** Times audio routing waited for transcription because it fell behind */
+  overruns: number;
+
+  /** Total time spent waiting on transcription, in milliseconds */
+  overrun_wait_ms: number;
+
+  /** Most chunks ever queued for transcription */
+  buffer_high_water: number;
+
+  /** Audio waiting to be transcribed, in milliseconds */
+  processing_lag_ms: number;
+}
+
 /**
  * Application state interface
  * 
@@ -134,6 +226,8 @@ export type AppAction =
 /** Payload for recording-started event */
 export interface RecordingStartedEvent {
   filename: string;
+  /** True when resuming into an existing recording (start_recording_append) */
+  appended: boolean;
 }
 
 /** Payload for error events (permission-error, sidecar-error) */
@@ -141,11 +235,21 @@ export interface ErrorEvent {
   message: string;
 }
 
-/** Payload for shortcut-triggered event */
-export interface ShortcutEvent {
-  action: "start" | "stop";
+/** Result of toggle_recording and payload of recording-toggled event */
+export interface RecordingToggleResult {
+  /** True if a recording is now in progress */
+  recording: boolean;
+  /** Filename of the recording just started (null when stopping) */
+  filename: string | null;
 }
 
+/**
+ * Payload for shortcut-triggered event (the action name, emitted as a bare string).
+ * The recording shortcut toggles recording in the backend and reports via
+ * recording-started/recording-stopped/recording-toggled instead.
+ */
+export type ShortcutEvent = "toggle-copilot";
+
 /** Payload for sidecar-crashed event */
 export interface CrashedEvent {
   code: number | null;
@@ -206,6 +310,15 @@ export interface TranscriptionSettings {
 
   /** Audio window duration in seconds (1.0 to 10.0) for batch transcription */
   window_duration: number;
+
+  /** Per-engine window overrides in seconds (1.0 to 30.0); null uses window_duration */
+  engine_window_durations: EngineWindowDurations;
+}
+
+/** Per-engine audio window overrides matching Rust EngineWindowDurations struct */
+export interface EngineWindowDurations {
+  whisper_rs: number | null;
+  whisperkit: number | null;
 }
 
 /** Intelligence settings matching Rust IntelligenceSettings struct */
@@ -218,6 +331,59 @@ export interface IntelligenceSettings {
   
   /** Python executable path (e.g., "python3" or absolute path) */
   python_path: string;
+  
+  /** Captured excerpts and gem content beyond this many characters are cut and marked "[truncated]" (0 = no limit). Default: 100000 */
+  max_content_chars: number;
+
+  /** Seconds of inactivity before the MLX sidecar is shut down (0 = keep running). Default: 0 */
+  sidecar_idle_seconds: number;
+
+  /** Enrich from the gem's transcript instead of its content when it has one. Default: true */
+  prefer_transcript_for_enrichment: boolean;
+
+  /** Seconds before tag generation is abandoned. Default: 60 */
+  tags_timeout_secs: number;
+
+  /** Seconds before summarization is abandoned. Default: 120 */
+  summary_timeout_secs: number;
+
+  /** Seconds before transcription is abandoned. Default: 600 */
+  transcript_timeout_secs: number;
+
+  /** Endpoints the "api" provider can use */
+  api_endpoints: ApiEndpointConfig[];
+
+  /** Name of the api_endpoints entry in use */
+  active_api_endpoint: string | null;
+}
+
+/** OpenAI-compatible endpoint matching Rust ApiEndpointConfig struct */
+export interface ApiEndpointConfig {
+  /** Unique display name, e.g. "ollama" */
+  name: string;
+
+  /** Base URL, e.g. "http://localhost:11434/v1" */
+  base_url: string;
+
+  api_key: string | null;
+
+  /** Model name sent with each request */
+  model: string;
+}
+
+/** Endpoint as listed by list_api_endpoints, matching Rust ApiEndpointSummary struct (the key itself is never sent) */
+export interface ApiEndpointSummary {
+  name: string;
+  base_url: string;
+  model: string;
+  /** Whether an API key is configured */
+  has_key: boolean;
+}
+
+/** Result of list_api_endpoints matching Rust ApiEndpoints struct */
+export interface ApiEndpoints {
+  endpoints: ApiEndpointSummary[];
+  active: string | null;
 }
 
 /** Co-Pilot settings matching Rust CoPilotSettings struct */
@@ -233,6 +399,9 @@ export interface CoPilotSettings {
   
   /** Whether to write prompt/response logs to disk */
   agent_logging: boolean;
+  
+  /** Freeform hint the analysis should prioritize (e.g., "architecture decisions"). null for general analysis */
+  focus: string | null;
 }
 
 /** Search settings matching Rust SearchSettings struct */
@@ -241,10 +410,62 @@ export interface SearchSettings {
   semantic_search_enabled: boolean;
   /** Minimum relevance score (0-100) for semantic search results. Default: 75 */
   semantic_search_accuracy: number;
-  /** Tavily API key for web search integration. null if not configured */
+  /** Tavily API key for web search integration. null if not configured; get_settings returns "********" for a stored key */
   tavily_api_key: string | null;
 }
 
+/** Global shortcut settings matching Rust ShortcutSettings struct (empty string disables) */
+export interface ShortcutSettings {
+  /** Accelerator that starts/stops recording. Default: "Cmd+Shift+R" */
+  toggle_recording: string;
+  /** Accelerator that captures the active browser tab as a gem. Default: "Cmd+Shift+G" */
+  capture_tab: string;
+  /** Accelerator that starts/stops the Co-Pilot agent. Default: "Cmd+Shift+J" */
+  toggle_copilot: string;
+}
+
+/** Native notification settings matching Rust NotificationSettings struct */
+export interface NotificationSettings {
+  /** Notify when background jobs (model downloads, batch enrichment, transcription) finish or fail. Default: true */
+  background_jobs: boolean;
+}
+
+/** Recording settings matching Rust RecordingSettings struct */
+export interface RecordingSettings {
+  /** Transcribe each recording after it stops and save it as a gem. Default: false */
+  auto_save_gem: boolean;
+
+  /** Periodically save finalized transcript segments into a draft gem while recording */
+  incremental_transcript_save: boolean;
+}
+
+/** Recording chat settings matching Rust ChatSettings struct */
+export interface ChatSettings {
+  /** Transcript context per chat message is fitted into about this many characters (recent part verbatim, earlier part summarized). Default: 14000, minimum 2000 */
+  context_budget_chars: number;
+}
+
+/** Outbound HTTP settings for page extraction matching Rust NetworkSettings */
+export interface NetworkSettings {
+  /** Proxy for extractor requests (e.g. "http://proxy.corp:8080"); null connects directly */
+  proxy_url: string | null;
+
+  /** Give up connecting after this many seconds. Default: 10 */
+  connect_timeout_secs: number;
+
+  /** Give up when a response stalls for this many seconds. Default: 10 */
+  read_timeout_secs: number;
+
+  /** Give up on a request, body included, after this many seconds. Default: 30 */
+  request_timeout_secs: number;
+
+  /** User-Agent header sent with every request */
+  user_agent: string;
+
+  /** User-Agent per fetcher ("generic", "youtube", "thumbnails"), replacing user_agent */
+  user_agent_overrides: Record<string, string>;
+}
+
 /**
  * Projects types
  * 
@@ -260,6 +481,10 @@ export interface Project {
   status: 'active' | 'paused' | 'completed' | 'archived';
   created_at: string;
   updated_at: string;
+  /** "#RRGGBB" accent color, if set */
+  color: string | null;
+  /** Gem (in this project) whose thumbnail is the cover, if set */
+  cover_gem_id: string | null;
 }
 
 /** Lightweight project for list views matching Rust ProjectPreview struct */
@@ -270,6 +495,8 @@ export interface ProjectPreview {
   status: string;
   gem_count: number;
   updated_at: string;
+  color: string | null;
+  cover_gem_id: string | null;
 }
 
 /** Full project with associated gems matching Rust ProjectDetail struct */
@@ -279,6 +506,30 @@ export interface ProjectDetail {
   gems: GemPreview[];
 }
 
+/** Activity feed entry from get_project_activity matching Rust ProjectActivity struct */
+export interface ProjectActivity {
+  gem: GemPreview;
+  /** When the gem was added to the project (ISO 8601) */
+  added_at: string;
+}
+
+/** Ranked project candidate from suggest_projects_for_gem matching Rust ProjectSuggestion struct */
+export interface ProjectSuggestion {
+  project: ProjectPreview;
+  /** Higher is a better match (tag and domain overlap with the project's gems) */
+  score: number;
+  /** e.g. "Shares tags: rust, async · 2 gems from github.com" */
+  reason: string;
+}
+
+/** Result of move_gems_to_project matching Rust MoveGemsResult struct */
+export interface MoveGemsResult {
+  /** Associations removed from the source project */
+  removed: number;
+  /** Associations newly added to the destination */
+  added: number;
+}
+
 /** Main settings structure matching Rust Settings struct */
 export interface Settings {
   /** Transcription-specific settings */
@@ -292,6 +543,11 @@ export interface Settings {
   
   /** Search settings (semantic search via QMD) */
   search: SearchSettings;
+  shortcuts: ShortcutSettings;
+  notifications: NotificationSettings;
+  recording: RecordingSettings;
+  network: NetworkSettings;
+  chat: ChatSettings;
 }
 
 /** Model status enum matching Rust ModelStatus */
@@ -374,6 +630,9 @@ export interface LlmModelInfo {
   
   /** Model capabilities (e.g., ["text"], ["audio", "text"]) */
   capabilities: string[];
+  
+  /** Maximum context window in tokens */
+  context_length: number;
 }
 
 /** Payload for llm-model-download-progress event */
@@ -463,6 +722,14 @@ export interface ClaudePanelStatus {
   needs_accessibility: boolean;
 }
 
+/** ChatGPT accessibility detection status matching Rust ChatGptPanelStatus struct */
+export interface ChatGptPanelStatus {
+  detected: boolean;
+  /** Where the conversation was found */
+  surface: 'desktop_app' | 'chrome' | null;
+  needs_accessibility: boolean;
+}
+
 /** Page gist (extracted metadata) matching Rust PageGist struct */
 export interface PageGist {
   url: string;
@@ -477,6 +744,14 @@ export interface PageGist {
   extra: Record<string, unknown>;
 }
 
+/** Result of preview_extraction matching Rust ExtractionPreview struct */
+export interface ExtractionPreview {
+  gist: PageGist;
+
+  /** Time spent fetching and extracting */
+  timing_ms: number;
+}
+
 /** Payload for youtube-video-detected event */
 export interface YouTubeDetectedEvent {
   /** Full YouTube URL */
@@ -553,6 +828,12 @@ export interface Gem {
   
   /** Language detected by MLX Omni during transcription (ISO 639-1 code) */
   transcript_language: string | null;
+
+  /** User-written notes (set via set_gem_notes; kept across re-enrichment) */
+  user_notes: string | null;
+
+  /** Color label from the fixed palette (set via set_gem_label) */
+  label: GemLabel | null;
 }
 
 /** Lightweight gem for list/search results matching Rust GemPreview struct */
@@ -578,7 +859,7 @@ export interface GemPreview {
   /** Short description or summary (optional) */
   description: string | null;
   
-  /** Content truncated to 200 characters */
+  /** Content truncated to preview_chars characters (default 200) */
   content_preview: string | null;
   
   /** ISO 8601 timestamp when gem was captured */
@@ -595,6 +876,181 @@ export interface GemPreview {
   
   /** Language detected by MLX Omni during transcription (ISO 639-1 code) */
   transcript_language: string | null;
+
+  /** Color label from the fixed palette */
+  label: GemLabel | null;
+}
+
+/** Gem color labels matching Rust GEM_LABELS */
+export type GemLabel = 'red' | 'orange' | 'yellow' | 'green' | 'blue' | 'purple' | 'gray';
+
+/** Tag pair from tag_cooccurrence matching Rust TagCooccurrence struct */
+export interface TagCooccurrence {
+  /** Lowercased tag; sorts before tag_b */
+  tag_a: string;
+  /** Lowercased tag */
+  tag_b: string;
+  /** Number of gems carrying both tags */
+  count: number;
+}
+
+/** Language facet entry from list_gem_languages matching Rust LanguageCount struct */
+export interface LanguageCount {
+  /** ISO 639-1 language code (e.g., "en", "zh") */
+  language: string;
+
+  /** Number of gems with this transcript language */
+  count: number;
+}
+
+/** Past search query from get_search_history matching Rust SearchHistoryEntry struct */
+export interface SearchHistoryEntry {
+  query: string;
+
+  /** ISO 8601 timestamp of the most recent time this query was run */
+  searched_at: string;
+}
+
+/** Transcript-only search hit from search_transcripts matching Rust TranscriptMatch struct */
+export interface TranscriptMatch extends GemPreview {
+  /** Transcript excerpt around the match, matched terms wrapped in ** */
+  transcript_snippet: string;
+}
+
+/** Gem with view stats from list_recently_viewed / list_most_viewed matching Rust ViewedGem struct */
+export interface ViewedGem extends GemPreview {
+  /** ISO 8601 timestamp of the last mark_gem_viewed */
+  last_viewed_at: string;
+  /** Number of times the gem was opened */
+  view_count: number;
+}
+
+/** Open action items of one recording gem from list_action_items_across_gems matching Rust GemActionItems struct */
+export interface GemActionItems {
+  gem_id: string;
+  title: string;
+  /** The gem's jarvis://recording/... URL */
+  source_url: string;
+  captured_at: string;
+  /** Items not yet marked done with set_action_item_done */
+  action_items: string[];
+}
+
+/** Output format of export_action_items */
+export type ActionItemFormat = "markdown" | "ics" | "csv";
+
+/** Result of compact_database matching Rust CompactionResult struct */
+export interface CompactionResult {
+  /** Database size in bytes before compaction */
+  size_before_bytes: number;
+
+  /** Database size in bytes after compaction */
+  size_after_bytes: number;
+}
+
+/** Search index findings matching Rust SearchIndexRepair struct */
+export interface SearchIndexRepair {
+  /** Sync triggers were missing and had to be recreated */
+  triggers_restored: boolean;
+
+  /** Index entries whose gem no longer exists */
+  orphaned_entries: number;
+
+  /** Gems that were missing from the index */
+  missing_entries: number;
+
+  /** The index was cleared and repopulated */
+  index_rebuilt: boolean;
+}
+
+/** Result of repair_library matching Rust LibraryRepairReport struct */
+export interface LibraryRepairReport {
+  search_index: SearchIndexRepair;
+
+  /** IDs of gems without knowledge files */
+  gems_missing_knowledge: string[];
+}
+
+/** Entry of provider_metrics matching Rust OperationMetrics struct */
+export interface OperationMetrics {
+  operation: 'enrich' | 'tags' | 'summarize' | 'transcribe';
+
+  /** Calls since the app started, successful or not */
+  count: number;
+
+  failures: number;
+
+  /** Average duration of recent successful calls */
+  average_ms: number | null;
+
+  last_ms: number | null;
+}
+
+/** Scope for estimate_batch matching Rust BatchScope enum */
+export type BatchScope = 'all' | 'missing' | { ids: string[] };
+
+/** Result of estimate_batch matching Rust BatchEstimate struct */
+export interface BatchEstimate {
+  item_count: number;
+
+  /** Null until an item has been processed since app start */
+  estimated_seconds: number | null;
+}
+
+/** Result of enrich_all_pending matching Rust BatchEnrichReport struct */
+export interface BatchEnrichReport {
+  /** Gems enriched */
+  enriched: number;
+  /** Gems whose enrichment failed (skipped) */
+  failed: number;
+}
+
+/** Result of create_backup / restore_backup matching Rust BackupManifest struct */
+export interface BackupManifest {
+  format_version: number;
+
+  /** RFC 3339 timestamp of when the backup was taken */
+  created_at: string;
+
+  /** Version of the app that wrote the backup */
+  app_version: string;
+
+  /** Whether recording audio files are included */
+  includes_recordings: boolean;
+}
+
+/** Result of import_markdown_dir / import_bookmarks matching Rust ImportReport struct */
+export interface ImportReport {
+  /** Gems created */
+  imported: number;
+  /** Items already in the library, or that couldn't be read or parsed */
+  skipped: number;
+}
+
+/** What re-enriching a gem changed, matching Rust EnrichmentDiff struct */
+export interface EnrichmentDiff {
+  /** Tags in the new enrichment but not the previous one */
+  tags_added: string[];
+  /** Tags in the previous enrichment but not the new one */
+  tags_removed: string[];
+  /** Whether the summary text differs */
+  summary_changed: boolean;
+}
+
+/** Result of enrich_gem_with_diff matching Rust EnrichGemResult struct */
+export interface EnrichGemResult {
+  /** The enriched gem */
+  gem: Gem;
+  /** Diff against the gem's previous enrichment */
+  diff: EnrichmentDiff;
+}
+
+/** Result of reextract_gem matching Rust ReextractResult struct */
+export interface ReextractResult {
+  /** The refreshed gem */
+  gem: Gem;
+  /** Whether the source content differs from what was saved */
+  changed: boolean;
 }
 
 /** Match type for search results */
@@ -647,7 +1103,7 @@ export interface GemSearchResult {
   /** Language detected by MLX Omni during transcription (ISO 639-1 code) */
   transcript_language?: string | null;
   
-  /** Content truncated to 200 characters */
+  /** Content truncated to preview_chars characters (default 200) */
   content_preview?: string | null;
 }
 
@@ -741,6 +1197,20 @@ export interface AvailabilityResult {
   reason?: string;
 }
 
+/**
+ * Operations the active intelligence provider supports
+ * 
+ * Returned by get_provider_capabilities. Used to hide actions (e.g.
+ * "Transcribe") the provider can't perform.
+ */
+export interface ProviderCapabilities {
+  tags: boolean;
+  summary: boolean;
+  transcript: boolean;
+  translate: boolean;
+  chat: boolean;
+}
+
 /** MLX dependencies diagnostic information matching Rust MlxDiagnostics struct */
 export interface MlxDiagnostics {
   /** Whether Python was found at the configured path */
@@ -1006,6 +1476,9 @@ export interface WebSearchResult {
   
   /** Published date (ISO 8601, optional) */
   published_date: string | null;
+
+  /** Debug: true when served from the web search cache (no API call made) */
+  from_cache: boolean;
 }
 
 /** Project research results matching Rust ProjectResearchResults struct */
@@ -1034,3 +1507,105 @@ export interface ProjectSummaryResult {
   /** Number of chunks used in summarization */
   chunks_used: number;
 }
+
+/** Log file metadata matching Rust LogFileInfo struct */
+export interface LogFileInfo {
+  filename: string;
+  size_bytes: number;
+  /** Last modified time (Unix seconds) */
+  modified_at: number;
+  /** True for the log written by the running app */
+  is_current: boolean;
+}
+
+/** Single diagnostic check matching Rust DiagnosticCheck struct */
+export interface DiagnosticCheck {
+  ok: boolean;
+  detail: string | null;
+}
+
+/** Health report returned by run_diagnostics, matching Rust DiagnosticsReport struct */
+export interface DiagnosticsReport {
+  generated_at: string;
+  app_version: string;
+  /** OS and architecture, e.g. "macos aarch64" */
+  os: string;
+  platform_supported: boolean;
+  accessibility_permission: boolean;
+  intel_provider: string;
+  intel_availability: AvailabilityResult;
+  /** MLX sidecar lifecycle state; null when MLX is not the active provider */
+  mlx_sidecar: "running" | "idle" | "stopped" | "failed" | null;
+  venv_status: string;
+  venv_python_path: string | null;
+  transcription_engine: string;
+  /** null when the transcription manager failed to initialize */
+  transcription_status: TranscriptionStatus | null;
+  whisperkit: WhisperKitStatus;
+  /** Path to the QMD binary, null if not installed */
+  qmd_path: string | null;
+  search_availability: AvailabilityResult;
+  database: DiagnosticCheck;
+  recordings_dir: string;
+  recordings_free_bytes: number | null;
+}
+
+/** Result of stop_all_activity, matching Rust ActivityStopReport struct */
+export interface ActivityStopReport {
+  recording_stopped: boolean;
+  browser_observer_stopped: boolean;
+  copilot_stopped: boolean;
+  /** True if an MLX command was in flight and got cancelled */
+  inference_cancelled: boolean;
+  /** Whisper model filenames and LLM model IDs whose downloads were cancelled */
+  downloads_cancelled: string[];
+  /** Steps that failed; the remaining steps still ran */
+  errors: string[];
+}
+
+/** Result of calibrate_vad, matching Rust VadCalibration struct */
+export interface VadCalibration {
+  /** Seconds of ambient audio analyzed */
+  duration_seconds: number;
+  /** Median level of the ambient audio (dBFS) */
+  noise_floor_dbfs: number;
+  /** Loudest sample in the capture (dBFS) */
+  peak_dbfs: number;
+  /** 95th percentile speech probability of the ambient audio; null without the VAD model */
+  ambient_speech_probability: number | null;
+  current_threshold: number;
+  suggested_threshold: number;
+  /** True if the suggestion was written to settings */
+  applied: boolean;
+}
+
+/** Chat message matching Rust ChatMessage struct */
+export interface ChatMessage {
+  role: 'user' | 'assistant';
+  content: string;
+  /** Local time, "HH:MM:SS" */
+  timestamp: string;
+  /** Gem IDs the answer drew from (library chat assistant messages) */
+  sources?: string[];
+}
+
+/** Saved chat session from list_chat_sessions, matching Rust ChatSessionSummary struct */
+export interface ChatSessionSummary {
+  session_id: string;
+  /** Recording the session chats with (pass to chat_send_message); null for library chats */
+  recording_filename: string | null;
+  /** Local start time from the log header */
+  started_at: string | null;
+  message_count: number;
+  /** Start of the first user message */
+  preview: string | null;
+  /** Session is loaded; no need to resume */
+  active: boolean;
+}
+
+/** Result of chat_resume_session matching Rust ResumedChatSession struct */
+export interface ResumedChatSession {
+  session_id: string;
+  recording_filename: string | null;
+  messages: ChatMessage[];
+}